@@ -9,7 +9,21 @@ extern crate libc;
 #[cfg(feature = "nightly")]
 #[start]
 fn start(_argc: isize, _argv: *const *const u8) -> isize {
+    use palette::{FromColor, Hsv, Lab, Srgb};
+
     let _magenta = palette::Srgb::new(255u8, 0, 255);
+    let _gradient = palette::Gradient::from_array([
+        (0.0, palette::LinSrgb::new(0.0f32, 0.0, 0.0)),
+        (1.0, palette::LinSrgb::new(1.0, 0.0, 1.0)),
+    ])
+    .get(0.5);
+
+    // Transfer functions and Lab need `powf` and `cbrt`, which come from libm.
+    let orange = Srgb::new(1.0f32, 0.5, 0.0);
+    let _linear = orange.into_linear();
+    let _lab = Lab::from_color(orange);
+    let _hsv = Hsv::from_color(orange);
+    let _encoded = Srgb::from_linear(_linear).into_format::<u8>();
 
     0
 }
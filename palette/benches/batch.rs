@@ -0,0 +1,118 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use palette::convert::{convert_slice, convert_slice_in_place};
+use palette::{Hsv, IntoColor, Lab, LinSrgb, Srgb, Xyz};
+
+/* Benches the following conversions of whole buffers:
+    - rgb_u8 to linsrgb_f32
+    - rgb_f32 to linsrgb_f32, in place
+    - hsv to rgb, with convert_slice
+    - linsrgb to xyz
+    - xyz to lab
+
+   The `simd` and `rayon` features add the same conversions using the
+   `simd` module and parallel slice conversion.
+*/
+
+const WIDTH: usize = 512;
+const HEIGHT: usize = 512;
+
+fn image() -> Vec<Srgb<u8>> {
+    (0..WIDTH * HEIGHT)
+        .map(|i| {
+            let (x, y) = (i % WIDTH, i / WIDTH);
+            Srgb::new(x as u8, y as u8, (x ^ y) as u8)
+        })
+        .collect()
+}
+
+fn batch_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Batch conversion");
+
+    let rgb_u8 = image();
+    let rgb: Vec<Srgb> = rgb_u8.iter().map(|c| c.into_format()).collect();
+    let linear: Vec<LinSrgb> = rgb.iter().map(|c| c.into_linear()).collect();
+    let hsv: Vec<Hsv> = rgb.iter().map(|&c| c.into_color()).collect();
+    let xyz: Vec<Xyz> = linear.iter().map(|&c| c.into_color()).collect();
+
+    group.throughput(Throughput::Elements(rgb_u8.len() as u64));
+
+    group.bench_with_input("rgb_u8 to linsrgb_f32", &rgb_u8, |b, rgb_u8| {
+        b.iter(|| {
+            rgb_u8
+                .iter()
+                .map(|c| c.into_linear::<f32>())
+                .collect::<Vec<LinSrgb>>()
+        })
+    });
+    group.bench_with_input("rgb to linsrgb in place", &rgb, |b, rgb| {
+        b.iter_batched_ref(
+            || rgb.clone(),
+            |buffer| {
+                black_box(convert_slice_in_place::<_, _, LinSrgb>(buffer));
+            },
+            criterion::BatchSize::LargeInput,
+        )
+    });
+    group.bench_with_input("hsv to rgb", &hsv, |b, hsv| {
+        let mut output = vec![Srgb::default(); hsv.len()];
+        b.iter(|| convert_slice(hsv, black_box(&mut output)))
+    });
+    group.bench_with_input("linsrgb to xyz", &linear, |b, linear| {
+        let mut output = vec![Xyz::default(); linear.len()];
+        b.iter(|| convert_slice(linear, black_box(&mut output)))
+    });
+    group.bench_with_input("xyz to lab", &xyz, |b, xyz| {
+        let mut output = vec![Lab::default(); xyz.len()];
+        b.iter(|| convert_slice(xyz, black_box(&mut output)))
+    });
+
+    #[cfg(feature = "simd")]
+    {
+        use palette::encoding;
+        use palette::matrix::rgb_to_xyz_matrix;
+        use palette::white_point::D65;
+        use palette::Pixel;
+
+        group.bench_with_input("rgb to linsrgb with simd", &rgb, |b, rgb| {
+            b.iter_batched_ref(
+                || Srgb::into_raw_slice(rgb).to_vec(),
+                |buffer| palette::simd::srgb_into_linear(buffer),
+                criterion::BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input("linsrgb to xyz with simd", &linear, |b, linear| {
+            let matrix = rgb_to_xyz_matrix::<encoding::Srgb, f32>();
+            b.iter_batched_ref(
+                || LinSrgb::into_raw_slice(linear).to_vec(),
+                |buffer| palette::simd::multiply_matrix(&matrix, buffer),
+                criterion::BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input("xyz to lab with simd", &xyz, |b, xyz| {
+            b.iter_batched_ref(
+                || Xyz::into_raw_slice(xyz).to_vec(),
+                |buffer| palette::simd::xyz_into_lab::<D65>(buffer),
+                criterion::BatchSize::LargeInput,
+            )
+        });
+    }
+
+    #[cfg(feature = "rayon")]
+    {
+        use palette::convert::par_convert_slice;
+
+        group.bench_with_input("hsv to rgb in parallel", &hsv, |b, hsv| {
+            let mut output = vec![Srgb::default(); hsv.len()];
+            b.iter(|| par_convert_slice(hsv, black_box(&mut output)))
+        });
+        group.bench_with_input("xyz to lab in parallel", &xyz, |b, xyz| {
+            let mut output = vec![Lab::default(); xyz.len()];
+            b.iter(|| par_convert_slice(xyz, black_box(&mut output)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, batch_conversion);
+criterion_main!(benches);
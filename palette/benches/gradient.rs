@@ -0,0 +1,89 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use palette::gradient::easing::{CubicBezier, Smoothstep};
+use palette::{Gradient, Lch, LinSrgb};
+
+/* Benches the following operations:
+    - get from a gradient with 2 colors
+    - get from a gradient with 16 colors
+    - get from an eased gradient
+    - get from a cubic bezier eased gradient
+    - get from an lch gradient
+    - take from a gradient
+    - take from a gradient slice
+*/
+
+fn gradient(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Gradient");
+
+    const SAMPLES: usize = 1000;
+    let positions: Vec<f32> = (0..SAMPLES).map(|i| i as f32 / SAMPLES as f32).collect();
+
+    let two_colors = Gradient::new(vec![
+        LinSrgb::new(1.0, 0.1, 0.1),
+        LinSrgb::new(0.1, 0.1, 1.0),
+    ]);
+    let many_colors =
+        Gradient::new((0..16).map(|i| LinSrgb::new(i as f32 / 16.0, 0.5, 1.0 - i as f32 / 16.0)));
+    let smoothstep = two_colors.clone().with_easing(Smoothstep);
+    let cubic_bezier = two_colors
+        .clone()
+        .with_easing(CubicBezier::new(0.25, 0.1, 0.25, 1.0));
+    let lch = Gradient::new(vec![Lch::new(50.0, 80.0, 0.0), Lch::new(50.0, 80.0, 270.0)]);
+
+    group.throughput(Throughput::Elements(SAMPLES as u64));
+
+    group.bench_with_input("get with 2 colors", &positions, |b, positions| {
+        b.iter(|| {
+            for &i in positions {
+                black_box(two_colors.get(i));
+            }
+        })
+    });
+    group.bench_with_input("get with 16 colors", &positions, |b, positions| {
+        b.iter(|| {
+            for &i in positions {
+                black_box(many_colors.get(i));
+            }
+        })
+    });
+    group.bench_with_input("get with smoothstep", &positions, |b, positions| {
+        b.iter(|| {
+            for &i in positions {
+                black_box(smoothstep.get(i));
+            }
+        })
+    });
+    group.bench_with_input("get with cubic bezier", &positions, |b, positions| {
+        b.iter(|| {
+            for &i in positions {
+                black_box(cubic_bezier.get(i));
+            }
+        })
+    });
+    group.bench_with_input("get lch", &positions, |b, positions| {
+        b.iter(|| {
+            for &i in positions {
+                black_box(lch.get(i));
+            }
+        })
+    });
+    group.bench_function("take", |b| {
+        b.iter(|| {
+            for color in many_colors.take(SAMPLES) {
+                black_box(color);
+            }
+        })
+    });
+    group.bench_function("take from slice", |b| {
+        b.iter(|| {
+            for color in many_colors.slice(0.25..0.75).take(SAMPLES) {
+                black_box(color);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, gradient);
+criterion_main!(benches);
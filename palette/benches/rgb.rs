@@ -35,6 +35,8 @@ use data_color_mine::{load_data, ColorMine};
     - linsrgb to rgb
     - rgb_u8 to linsrgb_f32
     - linsrgb_f32 to rgb_u8
+    - rgb_u8 to linsrgb_f32 with lookup table
+    - linsrgb_f32 to rgb_u8 with lookup table
 */
 
 fn rgb_conversion(c: &mut Criterion) {
@@ -181,6 +183,28 @@ fn rgb_conversion(c: &mut Criterion) {
             }
         })
     });
+    group.bench_with_input(
+        "rgb_u8 to linsrgb_f32 with lookup table",
+        &rgb_u8,
+        |b, rgb_u8| {
+            b.iter(|| {
+                for c in rgb_u8 {
+                    black_box(c.into_linear::<f32>());
+                }
+            })
+        },
+    );
+    group.bench_with_input(
+        "linsrgb_f32 to rgb_u8 with lookup table",
+        &colormine,
+        |b, colormine| {
+            b.iter(|| {
+                for c in colormine {
+                    black_box(Srgb::from_linear_u8(c.linear_rgb));
+                }
+            })
+        },
+    );
 
     group.finish();
 }
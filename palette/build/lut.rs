@@ -0,0 +1,83 @@
+use std::fs::File;
+use std::io::Write;
+
+pub fn build() {
+    use std::path::Path;
+
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("srgb_lut.rs");
+    let mut writer = File::create(dest_path).expect("couldn't create srgb_lut.rs");
+    build_srgb_u8(&mut writer);
+}
+
+/// The number of ranges that linear values are divided into when converting
+/// them to 8 bit sRGB. Each range is small enough to contain at most one
+/// rounding threshold.
+const LINEAR_BUCKETS: usize = 4096;
+
+fn srgb_from_linear(x: f64) -> f64 {
+    if x <= 0.0031308 {
+        x * 12.92
+    } else {
+        x.powf(1.0 / 2.4) * 1.055 - 0.055
+    }
+}
+
+fn srgb_into_linear(x: f64) -> f64 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn build_srgb_u8(writer: &mut File) {
+    let linear: Vec<_> = (0..256)
+        .map(|i| format!("{:?}", srgb_into_linear(i as f64 / 255.0)))
+        .collect();
+
+    // The linear values that are half way between two encoded values
+    let thresholds: Vec<_> = (0..255)
+        .map(|i| format!("{:?}", srgb_into_linear((i as f64 + 0.5) / 255.0)))
+        .collect();
+
+    // The encoded value at the start of each bucket of linear values, which
+    // is at most one step from the nearest encoded value within the bucket
+    let buckets: Vec<_> = (0..LINEAR_BUCKETS)
+        .map(|i| {
+            let start = srgb_from_linear(i as f64 / LINEAR_BUCKETS as f64);
+            format!("{}", (start * 255.0).round() as u8)
+        })
+        .collect();
+
+    writeln!(writer, "/// The linear value of each 8 bit sRGB value.").unwrap();
+    writeln!(
+        writer,
+        "const SRGB_U8_TO_LINEAR: [f64; 256] = [{}];",
+        linear.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "/// The linear values where an 8 bit sRGB value rounds up to the next one."
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "const SRGB_U8_THRESHOLDS: [f64; 255] = [{}];",
+        thresholds.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "/// The nearest 8 bit sRGB value at the start of each range of linear values."
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "const SRGB_LINEAR_TO_U8: [u8; {}] = [{}];",
+        LINEAR_BUCKETS,
+        buckets.join(", ")
+    )
+    .unwrap();
+}
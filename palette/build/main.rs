@@ -1,8 +1,12 @@
 #[cfg(feature = "phf_codegen")]
 extern crate phf_codegen;
 
+mod lut;
 mod named;
+mod spectral;
 
 fn main() {
     named::build();
+    lut::build();
+    spectral::build();
 }
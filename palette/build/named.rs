@@ -73,7 +73,7 @@ pub fn build_gradients(writer: &mut File) {
         writeln!(writer, "/// This gradient is perfectly perceptually-uniform, both in regular form and also when converted to black-and-white.").unwrap();
         writeln!(writer, "/// The colormap is released under the CC0 license public domain dedication.").unwrap();
         write!(writer,
-            "pub const {0}: crate::gradient::Gradient<{1}, [(f32,{1});{2}]> = crate::gradient::Gradient([",
+            "pub const {0}: crate::gradient::Gradient<{1}, [(f32,{1});{2}]> = crate::gradient::Gradient {{ points: [",
             name.to_uppercase(), color_type, number_of_colors).unwrap();
         for i in 0..number_of_colors {
             let color = line_iter
@@ -95,7 +95,7 @@ pub fn build_gradients(writer: &mut File) {
                 .unwrap_or_else(|| panic!("couldn't get the {}th blue-value for {}", i, name));
             write!(writer, "({:.10},{}{{red: {}, green: {}, blue: {}, standard: ::core::marker::PhantomData}}),", (i as f32/number_of_colors as f32), color_type, red, green, blue).unwrap();
         }
-        write!(writer, "], ::core::marker::PhantomData);\n").unwrap();
+        write!(writer, "], interpolation: crate::gradient::interpolation::Linear, wrap: crate::gradient::Wrap::Clamp, color: ::core::marker::PhantomData }};\n").unwrap();
     }
 }
 
@@ -0,0 +1,91 @@
+use std::fs::File;
+
+pub fn build() {
+    use std::path::Path;
+
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("cie_1931.rs");
+    let mut writer = File::create(dest_path).expect("couldn't create cie_1931.rs");
+    build_color_matching_functions(&mut writer);
+}
+
+#[cfg(feature = "spectral")]
+fn build_color_matching_functions(writer: &mut File) {
+    use std::io::{BufRead, BufReader, Write};
+
+    let reader = BufReader::new(
+        File::open("build/cie_1931_2deg.txt").expect("could not open cie_1931_2deg.txt"),
+    );
+
+    let mut wavelengths = vec![];
+    let mut values = vec![];
+
+    for line in reader.lines() {
+        let line = line.unwrap();
+        let mut parts = line.split('\t');
+        let wavelength: u32 = parts
+            .next()
+            .and_then(|w| w.trim().parse().ok())
+            .expect("couldn't get the wavelength");
+        let xyz: Vec<f64> = parts
+            .next()
+            .unwrap_or_else(|| panic!("couldn't get the values for {} nm", wavelength))
+            .split(", ")
+            .map(|v| {
+                v.trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("couldn't parse the values for {} nm", wavelength))
+            })
+            .collect();
+        assert_eq!(xyz.len(), 3, "expected three values for {} nm", wavelength);
+
+        wavelengths.push(wavelength);
+        values.push(xyz);
+    }
+
+    let step = wavelengths[1] - wavelengths[0];
+    for pair in wavelengths.windows(2) {
+        assert_eq!(
+            pair[1] - pair[0],
+            step,
+            "the wavelengths are not evenly spaced"
+        );
+    }
+
+    writeln!(
+        writer,
+        "/// The first wavelength in the table, in nanometers."
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "const FIRST_WAVELENGTH: f64 = {}.0;",
+        wavelengths[0]
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "/// The distance between the wavelengths in the table, in nanometers."
+    )
+    .unwrap();
+    writeln!(writer, "const WAVELENGTH_STEP: f64 = {}.0;", step).unwrap();
+    writeln!(
+        writer,
+        "/// The CIE 1931 2° standard observer color matching functions."
+    )
+    .unwrap();
+    write!(
+        writer,
+        "static COLOR_MATCHING_FUNCTIONS: [[f64; 3]; {}] = [",
+        values.len()
+    )
+    .unwrap();
+    for xyz in &values {
+        write!(writer, "[{:?}, {:?}, {:?}],", xyz[0], xyz[1], xyz[2]).unwrap();
+    }
+    writeln!(writer, "];").unwrap();
+}
+
+#[allow(unused)]
+#[cfg(not(feature = "spectral"))]
+fn build_color_matching_functions(_writer: &mut File) {}
@@ -2,6 +2,7 @@ use core::fmt;
 use core::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::{CheckedAdd, CheckedSub, Saturating};
 #[cfg(feature = "random")]
 use rand::distributions::uniform::{SampleBorrow, SampleUniform, Uniform, UniformSampler};
 #[cfg(feature = "random")]
@@ -14,12 +15,37 @@ use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
 use crate::encoding::pixel::RawPixel;
 use crate::float::Float;
 use crate::{
-    clamp, Blend, Component, ComponentWise, GetHue, Hue, Limited, Mix, Pixel, Saturate, Shade,
-    WithAlpha,
+    clamp, Blend, CheckedArithmetic, Component, ComponentWise, ComponentWiseMap, GetHue, Hue,
+    HueInterpolation, InvalidComponents, Limited, Mix, MixHue, NonlinearMix, Pixel, Saturate,
+    SaturatingArithmetic, Shade, WithAlpha,
 };
 
 /// An alpha component wrapper for colors.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// With the `random` feature, transparent colors can be sampled in one call,
+/// with a uniformly distributed alpha, using the `Standard` distribution or a
+/// `Uniform` range between two transparent colors:
+///
+/// ```no_run
+/// # #[cfg(feature = "random")]
+/// # fn main() {
+/// use palette::Srgba;
+/// use rand::distributions::Uniform;
+/// use rand::Rng;
+///
+/// fn random_colors<R: Rng>(rng: &mut R) -> (Srgba, Srgba) {
+///     let translucent = Uniform::new_inclusive(
+///         Srgba::new(0.0, 0.0, 0.0, 0.25),
+///         Srgba::new(1.0, 1.0, 1.0, 0.75),
+///     );
+///
+///     (rng.gen(), rng.sample(translucent))
+/// }
+/// # }
+/// # #[cfg(not(feature = "random"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub struct Alpha<C, T> {
@@ -33,6 +59,44 @@ pub struct Alpha<C, T> {
 }
 
 impl<C, T: Component> Alpha<C, T> {
+    /// Create a fully opaque color, with an alpha of `T::max_intensity()`.
+    ///
+    /// ```
+    /// use palette::{Srgb, Srgba};
+    ///
+    /// let color = Srgba::opaque(Srgb::new(255u8, 128, 0));
+    /// assert_eq!(color, Srgba::new(255u8, 128, 0, 255));
+    /// assert!(color.is_opaque());
+    /// ```
+    pub fn opaque(color: C) -> Self {
+        Alpha {
+            color,
+            alpha: T::max_intensity(),
+        }
+    }
+
+    /// Create a fully transparent color, with an alpha of 0.
+    ///
+    /// ```
+    /// use palette::{Srgb, Srgba};
+    ///
+    /// let color = Srgba::transparent(Srgb::new(1.0, 0.5, 0.0));
+    /// assert_eq!(color, Srgba::new(1.0, 0.5, 0.0, 0.0));
+    /// assert!(!color.is_opaque());
+    /// ```
+    pub fn transparent(color: C) -> Self {
+        Alpha {
+            color,
+            alpha: T::zero(),
+        }
+    }
+
+    /// Check if the color is fully opaque, meaning that its alpha is at least
+    /// `T::max_intensity()`.
+    pub fn is_opaque(&self) -> bool {
+        self.alpha >= T::max_intensity()
+    }
+
     /// Return the `alpha` value minimum.
     pub fn min_alpha() -> T {
         T::zero()
@@ -44,6 +108,37 @@ impl<C, T: Component> Alpha<C, T> {
     }
 }
 
+impl<C, T> Alpha<C, T>
+where
+    C: Blend<Color = C> + ComponentWise<Scalar = T>,
+    T: Float,
+{
+    /// Place the color over an opaque background, using premultiplied
+    /// source-over compositing, and return the opaque result.
+    ///
+    /// This is the same as `self.over(Alpha::opaque(background))` from
+    /// [`Blend`], but without the alpha channel in the result, and without
+    /// having to wrap the background. The colors need to be linear, so
+    /// non-linear colors, such as `Srgba`, should be converted with
+    /// `into_linear` first.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, LinSrgba};
+    ///
+    /// let glass = LinSrgba::new(1.0, 0.0, 0.0, 0.25);
+    /// let color = glass.over_background(LinSrgb::new(0.0, 0.0, 1.0));
+    /// assert_eq!(color, LinSrgb::new(0.25, 0.0, 0.75));
+    /// ```
+    pub fn over_background(self, background: C) -> C {
+        let source = PreAlpha::from(self);
+        let background_weight = T::one() - source.alpha;
+
+        source
+            .color
+            .component_wise(&background, |a, b| a + b * background_weight)
+    }
+}
+
 impl<C1: WithAlpha<T>, C2, T: Component> FromColorUnclamped<C1> for Alpha<C2, T>
 where
     C1::Color: IntoColorUnclamped<C2>,
@@ -101,6 +196,31 @@ impl<C: Mix> Mix for Alpha<C, C::Scalar> {
     }
 }
 
+impl<C: MixHue> MixHue for Alpha<C, C::Scalar> {
+    fn mix_hue(
+        &self,
+        other: &Alpha<C, C::Scalar>,
+        factor: C::Scalar,
+        method: HueInterpolation,
+    ) -> Alpha<C, C::Scalar> {
+        Alpha {
+            color: self.color.mix_hue(&other.color, factor, method),
+            alpha: self.alpha + factor * (other.alpha - self.alpha),
+        }
+    }
+}
+
+impl<C: NonlinearMix> NonlinearMix for Alpha<C, C::Scalar> {
+    type Scalar = C::Scalar;
+
+    fn mix_encoded(&self, other: &Alpha<C, C::Scalar>, factor: C::Scalar) -> Alpha<C, C::Scalar> {
+        Alpha {
+            color: self.color.mix_encoded(&other.color, factor),
+            alpha: self.alpha + factor * (other.alpha - self.alpha),
+        }
+    }
+}
+
 impl<C: Shade> Shade for Alpha<C, C::Scalar> {
     type Scalar = C::Scalar;
 
@@ -110,6 +230,13 @@ impl<C: Shade> Shade for Alpha<C, C::Scalar> {
             alpha: self.alpha,
         }
     }
+
+    fn lighten_relative(&self, factor: C::Scalar) -> Alpha<C, C::Scalar> {
+        Alpha {
+            color: self.color.lighten_relative(factor),
+            alpha: self.alpha,
+        }
+    }
 }
 
 impl<C: GetHue, T> GetHue for Alpha<C, T> {
@@ -118,6 +245,10 @@ impl<C: GetHue, T> GetHue for Alpha<C, T> {
     fn get_hue(&self) -> Option<C::Hue> {
         self.color.get_hue()
     }
+
+    fn has_hue(&self) -> bool {
+        self.color.has_hue()
+    }
 }
 
 impl<C: Hue, T: Clone> Hue for Alpha<C, T> {
@@ -145,6 +276,13 @@ impl<C: Saturate> Saturate for Alpha<C, C::Scalar> {
             alpha: self.alpha,
         }
     }
+
+    fn saturate_fixed(&self, amount: C::Scalar) -> Alpha<C, C::Scalar> {
+        Alpha {
+            color: self.color.saturate_fixed(amount),
+            alpha: self.alpha,
+        }
+    }
 }
 
 impl<C: Limited, T: Component> Limited for Alpha<C, T> {
@@ -163,21 +301,40 @@ impl<C: Limited, T: Component> Limited for Alpha<C, T> {
         self.color.clamp_self();
         self.alpha = clamp(self.alpha, T::zero(), T::max_intensity());
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        self.color.invalid_components().check(
+            "alpha",
+            self.alpha >= T::zero() && self.alpha <= T::max_intensity(),
+        )
+    }
 }
 
 impl<C: Blend, T: Float> Blend for Alpha<C, T>
 where
     C::Color: ComponentWise<Scalar = T>,
-    Alpha<C, T>: Into<Alpha<C::Color, T>> + From<Alpha<C::Color, T>>,
 {
     type Color = C::Color;
 
     fn into_premultiplied(self) -> PreAlpha<C::Color, T> {
-        PreAlpha::<C::Color, T>::from(self.into())
+        // The color is opaque on its own, so this only converts it
+        let color = self.color.into_premultiplied().color;
+        PreAlpha::from(Alpha {
+            color,
+            alpha: self.alpha,
+        })
     }
 
     fn from_premultiplied(color: PreAlpha<C::Color, T>) -> Alpha<C, T> {
-        Alpha::<C::Color, T>::from(color).into()
+        let Alpha { color, alpha } = Alpha::<C::Color, T>::from(color);
+
+        Alpha {
+            color: C::from_premultiplied(PreAlpha {
+                color,
+                alpha: T::one(),
+            }),
+            alpha,
+        }
     }
 }
 
@@ -199,16 +356,39 @@ impl<C: ComponentWise<Scalar = T>, T: Clone> ComponentWise for Alpha<C, T> {
     }
 }
 
+impl<C, T, U> ComponentWiseMap<U> for Alpha<C, T>
+where
+    C: ComponentWiseMap<U, Scalar = T>,
+    T: Clone,
+{
+    type Output = Alpha<C::Output, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(
+        &self,
+        other: &Alpha<C, T>,
+        mut f: F,
+    ) -> Alpha<C::Output, U> {
+        Alpha {
+            color: self.color.component_wise_zip(&other.color, &mut f),
+            alpha: f(self.alpha.clone(), other.alpha.clone()),
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> Alpha<C::Output, U> {
+        Alpha {
+            color: self.color.component_wise_map(&mut f),
+            alpha: f(self.alpha.clone()),
+        }
+    }
+}
+
 unsafe impl<T, C: Pixel<T>> Pixel<T> for Alpha<C, T> {
     const CHANNELS: usize = C::CHANNELS + 1;
 }
 
 impl<C: Default, T: Component> Default for Alpha<C, T> {
     fn default() -> Alpha<C, T> {
-        Alpha {
-            color: C::default(),
-            alpha: T::max_intensity(),
-        }
+        Alpha::opaque(C::default())
     }
 }
 
@@ -340,6 +520,72 @@ impl<T: SubAssign + Copy, C: SubAssign<T>> SubAssign<T> for Alpha<C, T> {
     }
 }
 
+impl<C: SaturatingArithmetic, T: Saturating> SaturatingArithmetic for Alpha<C, T> {
+    fn saturating_add(self, other: Alpha<C, T>) -> Self {
+        Alpha {
+            color: self.color.saturating_add(other.color),
+            alpha: self.alpha.saturating_add(other.alpha),
+        }
+    }
+
+    fn saturating_sub(self, other: Alpha<C, T>) -> Self {
+        Alpha {
+            color: self.color.saturating_sub(other.color),
+            alpha: self.alpha.saturating_sub(other.alpha),
+        }
+    }
+}
+
+impl<T: Saturating + Clone, C: SaturatingArithmetic<T>> SaturatingArithmetic<T> for Alpha<C, T> {
+    fn saturating_add(self, c: T) -> Self {
+        Alpha {
+            color: self.color.saturating_add(c.clone()),
+            alpha: self.alpha.saturating_add(c),
+        }
+    }
+
+    fn saturating_sub(self, c: T) -> Self {
+        Alpha {
+            color: self.color.saturating_sub(c.clone()),
+            alpha: self.alpha.saturating_sub(c),
+        }
+    }
+}
+
+impl<C: CheckedArithmetic, T: CheckedAdd + CheckedSub> CheckedArithmetic for Alpha<C, T> {
+    fn checked_add(self, other: Alpha<C, T>) -> Option<Self> {
+        Some(Alpha {
+            color: self.color.checked_add(other.color)?,
+            alpha: self.alpha.checked_add(&other.alpha)?,
+        })
+    }
+
+    fn checked_sub(self, other: Alpha<C, T>) -> Option<Self> {
+        Some(Alpha {
+            color: self.color.checked_sub(other.color)?,
+            alpha: self.alpha.checked_sub(&other.alpha)?,
+        })
+    }
+}
+
+impl<T: CheckedAdd + CheckedSub + Clone, C: CheckedArithmetic<T>> CheckedArithmetic<T>
+    for Alpha<C, T>
+{
+    fn checked_add(self, c: T) -> Option<Self> {
+        Some(Alpha {
+            color: self.color.checked_add(c.clone())?,
+            alpha: self.alpha.checked_add(&c)?,
+        })
+    }
+
+    fn checked_sub(self, c: T) -> Option<Self> {
+        Some(Alpha {
+            color: self.color.checked_sub(c.clone())?,
+            alpha: self.alpha.checked_sub(&c)?,
+        })
+    }
+}
+
 impl<C: Mul, T: Float> Mul for Alpha<C, T> {
     type Output = Alpha<C::Output, <T as Mul>::Output>;
 
@@ -434,10 +680,7 @@ where
 
 impl<C, T: Component> From<C> for Alpha<C, T> {
     fn from(color: C) -> Alpha<C, T> {
-        Alpha {
-            color,
-            alpha: T::max_intensity(),
-        }
+        Alpha::opaque(color)
     }
 }
 
@@ -556,6 +799,55 @@ where
 mod test {
     use crate::encoding::Srgb;
     use crate::rgb::Rgba;
+    use crate::Limited;
+
+    #[test]
+    fn invalid_components() {
+        let mut color = Rgba::<Srgb, f32>::new(0.5, 1.5, 0.5, -1.0);
+        assert_eq!(
+            color.invalid_components().iter().collect::<Vec<_>>(),
+            ["green", "alpha"]
+        );
+
+        let invalid = color.clamp_self_and_report();
+        assert_eq!(invalid.len(), 2);
+        assert!(invalid.contains("alpha"));
+        assert_eq!(color, Rgba::new(0.5, 1.0, 0.5, 0.0));
+        assert!(color.invalid_components().is_empty());
+    }
+
+    #[test]
+    fn over_background() {
+        use crate::{Alpha, Blend, LinSrgb, LinSrgba};
+
+        let background = LinSrgb::new(0.2, 0.4, 0.8);
+        let color = LinSrgba::new(0.9, 0.6, 0.1, 0.6);
+        let blended = color.over(Alpha::opaque(background));
+
+        assert_relative_eq!(color.over_background(background), blended.color);
+        assert_eq!(
+            LinSrgba::new(0.9, 0.6, 0.1, 1.0).over_background(background),
+            LinSrgb::new(0.9, 0.6, 0.1)
+        );
+        assert_eq!(
+            LinSrgba::new(0.9, 0.6, 0.1, 0.0).over_background(background),
+            background
+        );
+    }
+
+    #[test]
+    fn min_max_components() {
+        use crate::{ComponentWise, LinSrgba};
+
+        let color = LinSrgba::new(0.4, 1.5, 0.2, 0.1);
+
+        assert_eq!(color.min_component(), 0.1);
+        assert_eq!(color.max_component(), 1.5);
+        assert_eq!(
+            color.clamp_components(0.15, 1.0),
+            LinSrgba::new(0.4, 1.0, 0.2, 0.15)
+        );
+    }
 
     #[test]
     fn lower_hex() {
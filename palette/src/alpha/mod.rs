@@ -15,6 +15,23 @@ mod alpha;
 /// has a representation of transparency. This would typically be done by
 /// wrapping it in an [`Alpha`](crate::Alpha) instance.
 ///
+/// It's implemented for all color types in this library, including `Alpha`,
+/// [`PreAlpha`](crate::blend::PreAlpha) and [`CssColor`](crate::css::CssColor),
+/// so generic code can work with both opaque and transparent colors:
+///
+/// ```
+/// use palette::{Hsl, Srgb, Srgba, WithAlpha};
+///
+/// fn faded<C: WithAlpha<f32> + Copy>(color: C) -> C::WithAlpha {
+///     let (_, alpha) = color.split();
+///     color.with_alpha(alpha * 0.5)
+/// }
+///
+/// assert_eq!(faded(Srgb::new(1.0, 0.5, 0.0)).alpha, 0.5);
+/// assert_eq!(faded(Srgba::new(1.0, 0.5, 0.0, 0.5)).alpha, 0.25);
+/// assert_eq!(faded(Hsl::new(30.0, 1.0, 0.5)).alpha, 0.5);
+/// ```
+///
 /// # Deriving
 /// The trait is trivial enough to be automatically derived. If the color type
 /// has a field for transparency (an alpha channel), it has to be marked with
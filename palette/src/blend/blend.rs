@@ -1,6 +1,6 @@
 use num_traits::{One, Zero};
 
-use crate::blend::{BlendFunction, PreAlpha};
+use crate::blend::{BlendFunction, NonSeparable, PreAlpha};
 use crate::float::Float;
 use crate::{clamp, ComponentWise};
 
@@ -430,4 +430,99 @@ where
 
         Self::from_premultiplied(result)
     }
+
+    /// Combine the hue of `self` with the saturation and luminosity of
+    /// `other`. This is a non-separable blend mode.
+    fn hue(self, other: Self) -> Self
+    where
+        Self::Color: NonSeparable,
+    {
+        Self::from_premultiplied(blend_non_separable(
+            self.into_premultiplied(),
+            other.into_premultiplied(),
+            |src, dst| src.with_sat(dst.sat()).with_lum(dst.lum()),
+        ))
+    }
+
+    /// Combine the saturation of `self` with the hue and luminosity of
+    /// `other`. This is a non-separable blend mode.
+    fn saturation(self, other: Self) -> Self
+    where
+        Self::Color: NonSeparable,
+    {
+        Self::from_premultiplied(blend_non_separable(
+            self.into_premultiplied(),
+            other.into_premultiplied(),
+            |src, dst| dst.with_sat(src.sat()).with_lum(dst.lum()),
+        ))
+    }
+
+    /// Combine the hue and saturation of `self` with the luminosity of
+    /// `other`. This is a non-separable blend mode, that can be used for
+    /// coloring monochrome images.
+    fn color(self, other: Self) -> Self
+    where
+        Self::Color: NonSeparable,
+    {
+        Self::from_premultiplied(blend_non_separable(
+            self.into_premultiplied(),
+            other.into_premultiplied(),
+            |src, dst| src.with_lum(dst.lum()),
+        ))
+    }
+
+    /// Combine the luminosity of `self` with the hue and saturation of
+    /// `other`. This is a non-separable blend mode, and the inverse of
+    /// `color`.
+    fn luminosity(self, other: Self) -> Self
+    where
+        Self::Color: NonSeparable,
+    {
+        Self::from_premultiplied(blend_non_separable(
+            self.into_premultiplied(),
+            other.into_premultiplied(),
+            |src, dst| dst.with_lum(src.lum()),
+        ))
+    }
+}
+
+/// Composite `src` over `dst`, where the overlapping area is the result of
+/// `blend_function`. It's applied to the colors without premultiplied alpha.
+fn blend_non_separable<C, F>(
+    src: PreAlpha<C, C::Scalar>,
+    dst: PreAlpha<C, C::Scalar>,
+    blend_function: F,
+) -> PreAlpha<C, C::Scalar>
+where
+    C: NonSeparable,
+    C::Scalar: Float,
+    F: FnOnce(C, C) -> C,
+{
+    let one = C::Scalar::one();
+    let zero = C::Scalar::zero();
+
+    let unpremultiply = |color: &PreAlpha<C, C::Scalar>| {
+        color.color.component_wise_self(|a| {
+            if color.alpha.is_normal() {
+                a / color.alpha
+            } else {
+                zero
+            }
+        })
+    };
+
+    let blended = blend_function(unpremultiply(&src), unpremultiply(&dst));
+    let overlap = src.alpha * dst.alpha;
+
+    let color = src
+        .color
+        .component_wise(&dst.color, |a, b| {
+            a * (one - dst.alpha) + b * (one - src.alpha)
+        })
+        .component_wise(&blended, |a, b| a + b * overlap);
+
+    PreAlpha {
+        color,
+        alpha: clamp(src.alpha + dst.alpha - overlap, zero, one),
+    }
 }
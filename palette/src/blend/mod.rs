@@ -14,6 +14,20 @@
 //! let c = a.overlay(b);
 //! ```
 //!
+//! The separable blend modes, like `overlay`, blend each color component on
+//! its own. The non-separable blend modes, `hue`, `saturation`, `color` and
+//! `luminosity`, mix properties of the whole colors instead, and are available
+//! for colors that implement [`NonSeparable`](crate::blend::NonSeparable), such
+//! as linear RGB:
+//!
+//! ```
+//! use palette::{LinSrgba, Blend};
+//!
+//! let a = LinSrgba::new(0.2, 0.5, 0.1, 0.8);
+//! let b = LinSrgba::new(0.6, 0.3, 0.5, 0.1);
+//! let c = a.luminosity(b);
+//! ```
+//!
 //! Blending equations can be defined using the
 //! [`Equations`](crate::blend::Equations) type, which is then passed to the
 //! `blend` function, from the `Blend` trait:
@@ -32,6 +46,24 @@
 //! let c = a.blend(b, blend_mode);
 //! ```
 //!
+//! Blending is performed in linear light. Colors that aren't linear RGB, like
+//! `Hsl`, `Hsv`, `Hwb`, `Lab` and `Lch`, are converted to linear RGB before
+//! they are blended, and the result is converted back. `Hsl`, `Hsv` and `Hwb`
+//! use their own RGB space, while `Lab` and `Lch` use the sRGB primaries with
+//! their own white point:
+//!
+//! ```
+//! use palette::{Hsva, Blend};
+//!
+//! let a = Hsva::new(30.0, 0.5, 0.8, 0.5);
+//! let b = Hsva::new(200.0, 0.8, 0.6, 1.0);
+//! let c = a.overlay(b);
+//! ```
+//!
+//! Whole buffers of colors, such as image rows, can be blended at once using
+//! [`blend_slices`](crate::blend::blend_slices) and
+//! [`blend_slices_in_place`](crate::blend::blend_slices_in_place).
+//!
 //! Note that blending will use [premultiplied alpha](crate::blend::PreAlpha),
 //! which may result in loss of some color information in some cases. One such
 //! case is that a completely transparent resultant color will become black.
@@ -42,10 +74,12 @@ use crate::ComponentWise;
 pub use self::blend::Blend;
 pub use self::equations::{Equation, Equations, Parameter, Parameters};
 pub use self::pre_alpha::PreAlpha;
+pub use self::slices::{blend_slices, blend_slices_in_place};
 
 mod blend;
 mod equations;
 mod pre_alpha;
+mod slices;
 
 #[cfg(test)]
 mod test;
@@ -77,3 +111,29 @@ where
         (self)(source, destination)
     }
 }
+
+/// A trait for colors that can be blended using the non-separable blend modes,
+/// as defined in the W3C Compositing and Blending specification.
+///
+/// The non-separable blend modes, like [`Blend::hue`] and
+/// [`Blend::luminosity`], combine the hue, saturation and luminosity of two
+/// colors, instead of blending each component on its own.
+pub trait NonSeparable: ComponentWise
+where
+    Self::Scalar: Float,
+{
+    /// Get the luminosity of the color. This is the weighted sum `0.3 * red +
+    /// 0.59 * green + 0.11 * blue` for RGB.
+    fn lum(&self) -> Self::Scalar;
+
+    /// Change the luminosity of the color, while keeping its hue. The result
+    /// is clipped to the range `[0.0, 1.0]` without changing the luminosity.
+    fn with_lum(&self, lum: Self::Scalar) -> Self;
+
+    /// Get the saturation of the color. This is the difference between the
+    /// largest and smallest components for RGB.
+    fn sat(&self) -> Self::Scalar;
+
+    /// Change the saturation of the color, while keeping its hue.
+    fn with_sat(&self, sat: Self::Scalar) -> Self;
+}
@@ -1,10 +1,22 @@
 use core::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
-
+#[cfg(feature = "random")]
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+#[cfg(feature = "random")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "random")]
+use rand::Rng;
+
+#[cfg(feature = "random")]
+use crate::alpha::UniformAlpha;
 use crate::encoding::pixel::RawPixel;
 use crate::float::Float;
-use crate::{clamp, Alpha, Blend, ComponentWise, Mix, Pixel};
+#[cfg(feature = "random")]
+use crate::FloatComponent;
+use crate::{
+    clamp, Alpha, Blend, Component, ComponentWise, ComponentWiseMap, Mix, Pixel, WithAlpha,
+};
 
 /// Premultiplied alpha wrapper.
 ///
@@ -25,6 +37,18 @@ use crate::{clamp, Alpha, Blend, ComponentWise, Mix, Pixel};
 /// let res = LinSrgb::from_premultiplied(a.screen(b).overlay(c));
 /// ```
 ///
+/// Premultiplied buffers can be handled like any other pixel buffer, since
+/// `PreAlpha` implements [`Pixel`](crate::Pixel) when the color type does:
+///
+/// ```
+/// use palette::{LinSrgb, Pixel};
+/// use palette::blend::PreAlpha;
+///
+/// let buffer = [0.1f32, 0.2, 0.3, 0.5, 0.0, 0.4, 0.2, 0.8];
+/// let colors = PreAlpha::<LinSrgb<f32>, f32>::from_raw_slice(&buffer);
+/// assert_eq!(colors[1].alpha, 0.8);
+/// ```
+///
 /// Note that converting to and from premultiplied alpha will cause the alpha
 /// component to be clamped to [0.0, 1.0].
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -75,6 +99,30 @@ where
     }
 }
 
+impl<C, T> WithAlpha<T> for PreAlpha<C, T>
+where
+    C: ComponentWise<Scalar = T>,
+    T: Component + Float,
+{
+    type Color = C;
+    type WithAlpha = Self;
+
+    fn with_alpha(self, alpha: T) -> Self::WithAlpha {
+        let mut color: Alpha<C, T> = self.into();
+        color.alpha = alpha;
+        color.into()
+    }
+
+    fn without_alpha(self) -> Self::Color {
+        Alpha::<C, T>::from(self).color
+    }
+
+    fn split(self) -> (Self::Color, T) {
+        let Alpha { color, alpha }: Alpha<C, T> = self.into();
+        (color, alpha)
+    }
+}
+
 impl<C, T> Blend for PreAlpha<C, T>
 where
     C: Blend<Color = C> + ComponentWise<Scalar = T>,
@@ -124,6 +172,33 @@ impl<C: ComponentWise<Scalar = T>, T: Float> ComponentWise for PreAlpha<C, T> {
     }
 }
 
+impl<C, T, U> ComponentWiseMap<U> for PreAlpha<C, T>
+where
+    C: ComponentWiseMap<U, Scalar = T>,
+    T: Float,
+    U: Float,
+{
+    type Output = PreAlpha<C::Output, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(
+        &self,
+        other: &PreAlpha<C, T>,
+        mut f: F,
+    ) -> PreAlpha<C::Output, U> {
+        PreAlpha {
+            alpha: f(self.alpha, other.alpha),
+            color: self.color.component_wise_zip(&other.color, f),
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> PreAlpha<C::Output, U> {
+        PreAlpha {
+            alpha: f(self.alpha),
+            color: self.color.component_wise_map(f),
+        }
+    }
+}
+
 unsafe impl<T: Float, C: Pixel<T>> Pixel<T> for PreAlpha<C, T> {
     const CHANNELS: usize = C::CHANNELS + 1;
 }
@@ -372,12 +447,167 @@ impl<C, T: Float> DerefMut for PreAlpha<C, T> {
     }
 }
 
+/// Samples a random color and alpha, and premultiplies the color with the
+/// alpha, so the result is always a valid premultiplied color.
+#[cfg(feature = "random")]
+impl<C, T> Distribution<PreAlpha<C, T>> for Standard
+where
+    C: ComponentWise<Scalar = T>,
+    T: FloatComponent,
+    Standard: Distribution<C> + Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PreAlpha<C, T> {
+        rng.gen::<Alpha<C, T>>().into()
+    }
+}
+
+/// Samples colors uniformly between the non-premultiplied bounds, and
+/// premultiplies them with their alpha.
+#[cfg(feature = "random")]
+pub struct UniformPreAlpha<C, T>
+where
+    T: FloatComponent + SampleUniform,
+    C: SampleUniform,
+{
+    sampler: UniformAlpha<C, T>,
+}
+
+#[cfg(feature = "random")]
+impl<C, T> SampleUniform for PreAlpha<C, T>
+where
+    T: FloatComponent + SampleUniform,
+    C: ComponentWise<Scalar = T> + Copy + SampleUniform,
+{
+    type Sampler = UniformPreAlpha<C, T>;
+}
+
+#[cfg(feature = "random")]
+impl<C, T> UniformSampler for UniformPreAlpha<C, T>
+where
+    T: FloatComponent + SampleUniform,
+    C: ComponentWise<Scalar = T> + Copy + SampleUniform,
+{
+    type X = PreAlpha<C, T>;
+
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = Alpha::from(*low_b.borrow());
+        let high = Alpha::from(*high_b.borrow());
+
+        UniformPreAlpha {
+            sampler: UniformAlpha::new(low, high),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = Alpha::from(*low_b.borrow());
+        let high = Alpha::from(*high_b.borrow());
+
+        UniformPreAlpha {
+            sampler: UniformAlpha::new_inclusive(low, high),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PreAlpha<C, T> {
+        self.sampler.sample(rng).into()
+    }
+}
+
 #[cfg(test)]
-#[cfg(feature = "serializing")]
 mod test {
     use super::PreAlpha;
+    #[cfg(feature = "serializing")]
     use crate::encoding::Srgb;
+    #[cfg(feature = "serializing")]
     use crate::rgb::Rgb;
+    use crate::{ComponentWiseMap, LinSrgb, LinSrgba, Pixel, WithAlpha};
+
+    #[test]
+    fn with_alpha() {
+        let color = PreAlpha::from(LinSrgba::new(0.8, 0.4, 0.2, 0.5));
+
+        let changed = color.with_alpha(0.25);
+        assert_relative_eq!(changed.color, LinSrgb::new(0.2, 0.1, 0.05));
+        assert_relative_eq!(changed.alpha, 0.25);
+
+        assert_relative_eq!(color.without_alpha(), LinSrgb::new(0.8, 0.4, 0.2));
+        let (opaque, alpha) = color.split();
+        assert_relative_eq!(opaque, LinSrgb::new(0.8, 0.4, 0.2));
+        assert_relative_eq!(alpha, 0.5);
+    }
+
+    #[test]
+    fn component_wise_map() {
+        let color = PreAlpha::from(LinSrgba::new(0.8f32, 0.4, 0.2, 0.5));
+        let other = PreAlpha::from(LinSrgba::new(0.2f32, 0.4, 0.8, 1.0));
+
+        let mapped: PreAlpha<LinSrgb<f64>, f64> = color.component_wise_map(f64::from);
+        assert_relative_eq!(mapped.color, LinSrgb::new(0.4, 0.2, 0.1), epsilon = 1e-6);
+        assert_relative_eq!(mapped.alpha, 0.5);
+
+        let zipped = color.component_wise_zip(&other, |a, b| f64::from(a.max(b)));
+        assert_relative_eq!(zipped.color, LinSrgb::new(0.4, 0.4, 0.8), epsilon = 1e-6);
+        assert_relative_eq!(zipped.alpha, 1.0);
+    }
+
+    #[test]
+    fn default() {
+        let color = PreAlpha::<LinSrgb, f32>::default();
+        assert_eq!(LinSrgba::from(color), LinSrgba::new(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn raw_pixels() {
+        let raw = [0.1f32, 0.2, 0.3, 0.5, 0.0, 0.4, 0.2, 0.8];
+        let colors = PreAlpha::<LinSrgb, f32>::from_raw_slice(&raw);
+
+        assert_eq!(colors.len(), 2);
+        assert_eq!(
+            colors[1],
+            PreAlpha {
+                color: LinSrgb::new(0.0, 0.4, 0.2),
+                alpha: 0.8
+            }
+        );
+
+        let raw: &[f32] = PreAlpha::into_raw_slice(colors);
+        assert_eq!(raw.len(), 8);
+    }
+
+    #[test]
+    fn arithmetics() {
+        let a = PreAlpha {
+            color: LinSrgb::new(0.1, 0.2, 0.3),
+            alpha: 0.5,
+        };
+        let b = PreAlpha {
+            color: LinSrgb::new(0.2, 0.2, 0.1),
+            alpha: 0.25,
+        };
+
+        let expected = PreAlpha {
+            color: LinSrgb::new(0.3, 0.4, 0.4),
+            alpha: 0.75,
+        };
+        assert_relative_eq!(a + b, expected);
+
+        let mut c = a;
+        c *= 2.0;
+        assert_relative_eq!(
+            c,
+            PreAlpha {
+                color: LinSrgb::new(0.2, 0.4, 0.6),
+                alpha: 1.0
+            }
+        );
+    }
 
     #[cfg(feature = "serializing")]
     #[test]
@@ -408,4 +638,28 @@ mod test {
 
         assert_eq!(deserialized, expected);
     }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn random_colors_are_premultiplied() {
+        use rand::distributions::uniform::Uniform;
+        use rand::Rng;
+
+        let mut rng = rand_mt::Mt::new(1234);
+        let uniform_sampler = Uniform::new_inclusive(
+            PreAlpha::from(LinSrgba::new(0.0f32, 0.0, 0.0, 0.0)),
+            PreAlpha::from(LinSrgba::new(1.0, 1.0, 1.0, 1.0)),
+        );
+
+        for _ in 0..1000 {
+            let colors: [PreAlpha<LinSrgb, f32>; 2] = [rng.gen(), rng.sample(&uniform_sampler)];
+
+            for color in &colors {
+                assert!(color.alpha >= 0.0 && color.alpha <= 1.0);
+                assert!(color.red >= 0.0 && color.red <= color.alpha);
+                assert!(color.green >= 0.0 && color.green <= color.alpha);
+                assert!(color.blue >= 0.0 && color.blue <= color.alpha);
+            }
+        }
+    }
 }
@@ -0,0 +1,116 @@
+/// The number of colors that are blended together in the inner loop. A fixed
+/// size makes it easier for the compiler to unroll and vectorize the loop.
+const CHUNK_SIZE: usize = 8;
+
+/// Blend each color in `sources` with the corresponding color in
+/// `destinations`, using `blend_mode`, and write the results to `output`.
+///
+/// This is meant for compositing whole rows or buffers of pixels at once. Any
+/// function that takes a source and a destination color can be used as the
+/// blend mode, including the methods of [`Blend`](crate::Blend), and it's
+/// inlined into the loop, which lets the compiler vectorize it.
+///
+/// ```
+/// use palette::{Blend, LinSrgba};
+/// use palette::blend::blend_slices;
+///
+/// let sources = [LinSrgba::new(0.2, 0.5, 0.1, 0.8); 16];
+/// let destinations = [LinSrgba::new(0.6, 0.3, 0.5, 0.1); 16];
+/// let mut output = [LinSrgba::default(); 16];
+///
+/// blend_slices(&sources, &destinations, &mut output, LinSrgba::overlay);
+/// assert_eq!(output[0], sources[0].overlay(destinations[0]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the slices don't have the same length.
+pub fn blend_slices<C, F>(sources: &[C], destinations: &[C], output: &mut [C], mut blend_mode: F)
+where
+    C: Copy,
+    F: FnMut(C, C) -> C,
+{
+    assert_eq!(
+        sources.len(),
+        destinations.len(),
+        "the source and destination slices must have the same length"
+    );
+    assert_eq!(
+        sources.len(),
+        output.len(),
+        "the output slice must have the same length as the input slices"
+    );
+
+    let mut source_chunks = sources.chunks_exact(CHUNK_SIZE);
+    let mut destination_chunks = destinations.chunks_exact(CHUNK_SIZE);
+    let mut output_chunks = output.chunks_exact_mut(CHUNK_SIZE);
+
+    for ((sources, destinations), output) in (&mut source_chunks)
+        .zip(&mut destination_chunks)
+        .zip(&mut output_chunks)
+    {
+        for index in 0..CHUNK_SIZE {
+            output[index] = blend_mode(sources[index], destinations[index]);
+        }
+    }
+
+    let remainder = source_chunks
+        .remainder()
+        .iter()
+        .zip(destination_chunks.remainder());
+    for (output, (&source, &destination)) in
+        output_chunks.into_remainder().iter_mut().zip(remainder)
+    {
+        *output = blend_mode(source, destination);
+    }
+}
+
+/// Blend each color in `sources` with the corresponding color in
+/// `destinations`, using `blend_mode`, and replace the destination colors with
+/// the results.
+///
+/// This is the same as [`blend_slices`], but writes the result to the
+/// destination, like when painting a layer onto an image.
+///
+/// ```
+/// use palette::{Blend, LinSrgba};
+/// use palette::blend::blend_slices_in_place;
+///
+/// let sources = [LinSrgba::new(0.2, 0.5, 0.1, 0.8); 16];
+/// let mut image = [LinSrgba::new(0.6, 0.3, 0.5, 1.0); 16];
+///
+/// blend_slices_in_place(&sources, &mut image, LinSrgba::over);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the slices don't have the same length.
+pub fn blend_slices_in_place<C, F>(sources: &[C], destinations: &mut [C], mut blend_mode: F)
+where
+    C: Copy,
+    F: FnMut(C, C) -> C,
+{
+    assert_eq!(
+        sources.len(),
+        destinations.len(),
+        "the source and destination slices must have the same length"
+    );
+
+    let mut source_chunks = sources.chunks_exact(CHUNK_SIZE);
+    let mut destination_chunks = destinations.chunks_exact_mut(CHUNK_SIZE);
+
+    for (sources, destinations) in (&mut source_chunks).zip(&mut destination_chunks) {
+        for index in 0..CHUNK_SIZE {
+            destinations[index] = blend_mode(sources[index], destinations[index]);
+        }
+    }
+
+    let remainder = source_chunks.remainder();
+    for (destination, &source) in destination_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(remainder)
+    {
+        *destination = blend_mode(source, *destination);
+    }
+}
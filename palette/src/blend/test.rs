@@ -1,7 +1,7 @@
-use crate::blend::PreAlpha;
+use crate::blend::{blend_slices, blend_slices_in_place, PreAlpha};
 use crate::encoding::Linear;
 use crate::rgb::Rgb;
-use crate::{Blend, ComponentWise, LinSrgb, LinSrgba};
+use crate::{Blend, ComponentWise, FromColor, Hsla, Hsv, Lab, LinSrgb, LinSrgba, Srgb};
 
 #[test]
 fn blend_color() {
@@ -389,3 +389,130 @@ fn exclusion() {
 
     assert_relative_eq!(LinSrgba::new(0.5, 0.0, 0.3, 1.0), a.difference(b));
 }
+
+#[test]
+fn hue() {
+    let a = LinSrgb::new(1.0, 0.0, 0.0);
+    let b = LinSrgb::new(0.5, 0.5, 0.5);
+
+    assert_relative_eq!(LinSrgb::new(0.5, 0.5, 0.5), a.hue(b));
+
+    let a = LinSrgb::new(0.6, 0.4, 0.2);
+    let b = LinSrgb::new(1.0, 0.0, 0.0);
+
+    assert_relative_eq!(
+        LinSrgb::new(0.504202, 0.252101, 0.0),
+        a.hue(b),
+        epsilon = 0.000001
+    );
+}
+
+#[test]
+fn saturation() {
+    let a = LinSrgb::new(1.0, 0.0, 0.0);
+    let b = LinSrgb::new(0.6, 0.4, 0.2);
+
+    assert_relative_eq!(
+        LinSrgb::new(0.736134, 0.368067, 0.0),
+        a.saturation(b),
+        epsilon = 0.000001
+    );
+}
+
+#[test]
+fn color() {
+    let a = LinSrgb::new(1.0, 0.0, 0.0);
+    let b = LinSrgb::new(0.5, 0.5, 0.5);
+
+    assert_relative_eq!(
+        LinSrgb::new(1.0, 0.285714, 0.285714),
+        a.color(b),
+        epsilon = 0.000001
+    );
+}
+
+#[test]
+fn luminosity() {
+    let a = LinSrgb::new(1.0, 0.0, 0.0);
+    let b = LinSrgb::new(0.5, 0.5, 0.5);
+
+    assert_relative_eq!(LinSrgb::new(0.3, 0.3, 0.3), a.luminosity(b));
+
+    let a = LinSrgba::new(1.0, 0.0, 0.0, 0.5);
+    let b = LinSrgba::new(0.5, 0.5, 0.5, 1.0);
+
+    assert_relative_eq!(LinSrgba::new(0.4, 0.4, 0.4, 1.0), a.luminosity(b));
+
+    let a = LinSrgba::new(1.0, 0.0, 0.0, 1.0);
+    let b = LinSrgba::new(0.5, 0.5, 0.5, 0.0);
+
+    assert_relative_eq!(LinSrgba::new(1.0, 0.0, 0.0, 1.0), a.luminosity(b));
+}
+
+#[test]
+fn blend_through_linear_rgb() {
+    let a = Hsv::new(30.0, 0.5, 0.8);
+    let b = Hsv::new(200.0, 0.8, 0.6);
+
+    let expected = Srgb::from_color(a)
+        .into_linear()
+        .multiply(Srgb::from_color(b).into_linear());
+    assert_relative_eq!(
+        Hsv::from_color(Srgb::from_linear(expected)),
+        a.multiply(b),
+        epsilon = 0.0001
+    );
+
+    let a = Lab::new(60.0, 20.0, -30.0);
+    let b = Lab::new(40.0, -10.0, 10.0);
+
+    let expected = LinSrgb::from_color(a).screen(LinSrgb::from_color(b));
+    assert_relative_eq!(Lab::from_color(expected), a.screen(b), epsilon = 0.0001);
+}
+
+#[test]
+fn blend_alpha_through_linear_rgb() {
+    let a = Hsla::new(0.0, 1.0, 0.5, 0.5);
+    let b = Hsla::new(240.0, 1.0, 0.5, 1.0);
+
+    let c = a.over(b);
+    let expected = Srgb::from_linear(LinSrgb::new(0.5, 0.0, 0.5));
+
+    assert_relative_eq!(c.alpha, 1.0);
+    assert_relative_eq!(Srgb::from_color(c.color), expected, epsilon = 0.0001);
+}
+
+#[test]
+fn slices() {
+    // Long enough to have both full chunks and a remainder
+    let sources: Vec<_> = (0..21)
+        .map(|i| LinSrgba::new(i as f32 / 20.0, 0.5, 0.1, 0.8))
+        .collect();
+    let destinations: Vec<_> = (0..21)
+        .map(|i| LinSrgba::new(0.6, i as f32 / 20.0, 0.5, 0.5))
+        .collect();
+
+    let mut output = vec![LinSrgba::default(); 21];
+    blend_slices(&sources, &destinations, &mut output, LinSrgba::multiply);
+
+    let mut in_place = destinations.clone();
+    blend_slices_in_place(&sources, &mut in_place, LinSrgba::multiply);
+
+    for ((&source, &destination), (&output, &in_place)) in sources
+        .iter()
+        .zip(&destinations)
+        .zip(output.iter().zip(&in_place))
+    {
+        assert_relative_eq!(output, source.multiply(destination));
+        assert_relative_eq!(in_place, source.multiply(destination));
+    }
+}
+
+#[test]
+#[should_panic]
+fn slices_different_lengths() {
+    let sources = [LinSrgba::new(0.2, 0.5, 0.1, 0.8); 4];
+    let mut destinations = [LinSrgba::new(0.6, 0.3, 0.5, 0.1); 3];
+
+    blend_slices_in_place(&sources, &mut destinations, LinSrgba::over);
+}
@@ -0,0 +1,299 @@
+//! Canonical byte representations of colors.
+//!
+//! The in-memory representation of a color depends on the platform, and the
+//! same color may have more than one representation, such as `0.0` and `-0.0`.
+//! That makes it unreliable as a key for caches or for hashes that are stored
+//! or shared between machines. The [`ColorBytes`] trait converts colors to and
+//! from a canonical byte representation, which is the same on every platform:
+//!
+//! * The components are stored in the same order as in memory, and each
+//!   component is stored in little endian byte order.
+//! * Floating point components are normalized, so `-0.0` is stored as `0.0`
+//!   and every NaN value is stored as the same quiet NaN.
+//!
+//! ```
+//! use palette::bytes::ColorBytes;
+//! use palette::Srgb;
+//!
+//! let color = Srgb::new(1.0f32, 0.5, 0.0);
+//! let bytes = color.to_bytes();
+//! assert_eq!(bytes, [0, 0, 128, 63, 0, 0, 0, 63, 0, 0, 0, 0]);
+//! assert_eq!(Srgb::from_bytes(&bytes), Ok(color));
+//!
+//! // Equal colors have equal bytes.
+//! assert_eq!(Srgb::new(-0.0f32, 0.0, 0.0).to_bytes(), Srgb::new(0.0f32, 0.0, 0.0).to_bytes());
+//! ```
+//!
+//! The same representation is used by
+//! [`Gradient::to_bytes`](crate::Gradient::to_bytes), and
+//! [`fingerprint`] computes a hash that is stable across platforms and
+//! versions.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::gradient::GradientError;
+use crate::Pixel;
+
+/// A color component with a canonical byte representation.
+pub trait ComponentBytes: Copy {
+    /// The number of bytes in the representation.
+    const SIZE: usize;
+
+    /// Write the component to the first [`SIZE`](ComponentBytes::SIZE)
+    /// bytes of `bytes`, in little endian byte order.
+    ///
+    /// This will panic if `bytes` is too short.
+    fn write_le_bytes(self, bytes: &mut [u8]);
+
+    /// Read the component from the first [`SIZE`](ComponentBytes::SIZE)
+    /// bytes of `bytes`, in little endian byte order.
+    ///
+    /// This will panic if `bytes` is too short.
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_component_bytes_int {
+    ($($ty: ident),+) => {
+        $(
+            impl ComponentBytes for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                fn write_le_bytes(self, bytes: &mut [u8]) {
+                    bytes[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le_bytes(bytes: &[u8]) -> Self {
+                    let mut array = [0; core::mem::size_of::<$ty>()];
+                    array.copy_from_slice(&bytes[..Self::SIZE]);
+                    $ty::from_le_bytes(array)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_component_bytes_float {
+    ($($ty: ident),+) => {
+        $(
+            impl ComponentBytes for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                fn write_le_bytes(self, bytes: &mut [u8]) {
+                    let canonical = if self.is_nan() {
+                        $ty::NAN
+                    } else if self == 0.0 {
+                        0.0
+                    } else {
+                        self
+                    };
+
+                    bytes[..Self::SIZE].copy_from_slice(&canonical.to_le_bytes());
+                }
+
+                fn read_le_bytes(bytes: &[u8]) -> Self {
+                    let mut array = [0; core::mem::size_of::<$ty>()];
+                    array.copy_from_slice(&bytes[..Self::SIZE]);
+                    $ty::from_le_bytes(array)
+                }
+            }
+        )+
+    };
+}
+
+impl_component_bytes_int!(u8, u16, u32, u64);
+impl_component_bytes_float!(f32, f64);
+
+/// Conversion between colors and their canonical byte representation.
+///
+/// It's implemented for every color type that implements [`Pixel`] and
+/// [`Default`], with components that implement [`ComponentBytes`]. See the
+/// [module documentation](crate::bytes) for the details of the
+/// representation.
+pub trait ColorBytes<T: ComponentBytes>: Pixel<T> + Default {
+    /// The number of bytes in the representation of a color.
+    const BYTES: usize = Self::CHANNELS * T::SIZE;
+
+    /// Write the color to the first [`BYTES`](ColorBytes::BYTES) bytes of
+    /// `bytes`.
+    ///
+    /// This will panic if `bytes` is too short.
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        for (&component, chunk) in self
+            .as_raw::<[T]>()
+            .iter()
+            .zip(bytes[..Self::BYTES].chunks_exact_mut(T::SIZE))
+        {
+            component.write_le_bytes(chunk);
+        }
+    }
+
+    /// Convert the color to its canonical byte representation.
+    #[cfg(feature = "alloc")]
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; Self::BYTES];
+        self.write_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Convert a slice of colors to their canonical byte representation,
+    /// with one color after the other.
+    #[cfg(feature = "alloc")]
+    fn slice_to_bytes(colors: &[Self]) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; colors.len() * Self::BYTES];
+        for (color, chunk) in colors.iter().zip(bytes.chunks_exact_mut(Self::BYTES)) {
+            color.write_bytes(chunk);
+        }
+        bytes
+    }
+
+    /// Read a color from its canonical byte representation. The length of
+    /// `bytes` has to be exactly [`BYTES`](ColorBytes::BYTES).
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        if bytes.len() != Self::BYTES {
+            return Err(FromBytesError::Length {
+                length: bytes.len(),
+                expected: Self::BYTES,
+            });
+        }
+
+        let mut color = Self::default();
+        for (component, chunk) in color
+            .as_raw_mut::<[T]>()
+            .iter_mut()
+            .zip(bytes.chunks_exact(T::SIZE))
+        {
+            *component = T::read_le_bytes(chunk);
+        }
+
+        Ok(color)
+    }
+}
+
+impl<C, T> ColorBytes<T> for C
+where
+    C: Pixel<T> + Default,
+    T: ComponentBytes,
+{
+}
+
+/// The error type for reading colors and gradients from bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The number of bytes didn't match the expected length.
+    Length {
+        /// The number of bytes.
+        length: usize,
+
+        /// The expected number of bytes.
+        expected: usize,
+    },
+
+    /// A gradient had no control points.
+    EmptyGradient,
+
+    /// A gradient had an unknown wrap mode.
+    InvalidWrap(u8),
+
+    /// A gradient had control points that are not allowed, such as NaN or
+    /// unordered positions.
+    InvalidGradient(GradientError),
+}
+
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FromBytesError::Length { length, expected } => {
+                write!(f, "expected {} bytes, but got {}", expected, length)
+            }
+            FromBytesError::EmptyGradient => {
+                f.write_str("a Gradient must contain at least one color")
+            }
+            FromBytesError::InvalidWrap(wrap) => write!(f, "unknown wrap mode {}", wrap),
+            FromBytesError::InvalidGradient(error) => write!(f, "invalid gradient: {}", error),
+        }
+    }
+}
+
+impl From<GradientError> for FromBytesError {
+    fn from(error: GradientError) -> Self {
+        match error {
+            GradientError::Empty => FromBytesError::EmptyGradient,
+            error => FromBytesError::InvalidGradient(error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromBytesError {}
+
+/// Compute a 64 bit hash of `bytes` that is the same on every platform.
+///
+/// This is the FNV-1a hash function, which is fast for short inputs, such as
+/// colors and gradients, but not suitable where collisions have to be
+/// prevented on purpose. Unlike the hashers in `std`, its output is never
+/// going to change.
+///
+/// ```
+/// use palette::bytes::{fingerprint, ColorBytes};
+/// use palette::Srgb;
+///
+/// let palette = [Srgb::new(255u8, 0, 0), Srgb::new(0, 0, 255)];
+/// let hash = fingerprint(&Srgb::slice_to_bytes(&palette));
+/// assert_eq!(hash, 0xce3a_37fc_658f_3fc3);
+/// ```
+pub fn fingerprint(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fingerprint, ColorBytes, FromBytesError};
+    use crate::{Hsv, LinSrgba, Srgb};
+
+    #[test]
+    fn round_trip() {
+        let color = LinSrgba::new(0.1f64, 0.2, 0.3, 0.4);
+        let bytes = color.to_bytes();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(LinSrgba::from_bytes(&bytes), Ok(color));
+
+        let color = Hsv::new(120.0f32, 0.5, 0.25);
+        assert_eq!(Hsv::from_bytes(&color.to_bytes()), Ok(color));
+
+        let color = Srgb::new(0x0102u16, 0x0304, 0x0506);
+        assert_eq!(color.to_bytes(), [2, 1, 4, 3, 6, 5]);
+    }
+
+    #[test]
+    fn canonical_floats() {
+        let nan = f32::from_bits(0x7fc0_1234);
+        assert_eq!(
+            Srgb::new(nan, -0.0, 1.0).to_bytes(),
+            Srgb::new(f32::NAN, 0.0, 1.0).to_bytes()
+        );
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert_eq!(
+            Srgb::<u8>::from_bytes(&[1, 2]),
+            Err(FromBytesError::Length {
+                length: 2,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn fnv1a() {
+        assert_eq!(fingerprint(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fingerprint(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -23,10 +23,15 @@
 //! //Should print {x: 0.257963, y: 0.139776,z: 0.058825}
 //! println!("{:?}", c)
 //! ```
+//!
+//! Linear RGB colors can also be white balanced from a color temperature and
+//! tint, like in photo editors, with
+//! [`Rgb::white_balance`](crate::rgb::Rgb::white_balance).
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
 use crate::float::Float;
 use crate::from_f64;
-use crate::matrix::{multiply_3x3, multiply_xyz, Mat3};
+use crate::matrix::{matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix, Mat3};
+use crate::rgb::RgbSpace;
 use crate::white_point::WhitePoint;
 use crate::{FloatComponent, Xyz};
 
@@ -64,28 +69,125 @@ where
     fn generate_transform_matrix(&self) -> Mat3<T> {
         let s_wp: Xyz<Swp, T> = Swp::get_xyz();
         let t_wp: Xyz<Dwp, T> = Dwp::get_xyz();
-        let adapt = self.get_cone_response();
-
-        let resp_src: Xyz<Swp, _> = multiply_xyz(&adapt.ma, &s_wp);
-        let resp_dst: Xyz<Dwp, _> = multiply_xyz(&adapt.ma, &t_wp);
-        let z = T::zero();
-        let resp = [
-            resp_dst.x / resp_src.x,
-            z,
-            z,
-            z,
-            resp_dst.y / resp_src.y,
-            z,
-            z,
-            z,
-            resp_dst.z / resp_src.z,
-        ];
-
-        let tmp = multiply_3x3(&resp, &adapt.ma);
-        multiply_3x3(&adapt.inv_ma, &tmp)
+        adaptation_matrix(&self.get_cone_response(), &s_wp, &t_wp)
     }
 }
 
+/// Generates a 3x3 transformation matrix that adapts colors from the white
+/// `s_wp` to the white `t_wp`, with the given cone response.
+pub(crate) fn adaptation_matrix<Swp, Dwp, T>(
+    adapt: &ConeResponseMatrices<T>,
+    s_wp: &Xyz<Swp, T>,
+    t_wp: &Xyz<Dwp, T>,
+) -> Mat3<T>
+where
+    T: FloatComponent,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    let resp_src: Xyz<Swp, _> = multiply_xyz(&adapt.ma, s_wp);
+    let resp_dst: Xyz<Dwp, _> = multiply_xyz(&adapt.ma, t_wp);
+    let z = T::zero();
+    let resp = [
+        resp_dst.x / resp_src.x,
+        z,
+        z,
+        z,
+        resp_dst.y / resp_src.y,
+        z,
+        z,
+        z,
+        resp_dst.z / resp_src.z,
+    ];
+
+    let tmp = multiply_3x3(&resp, &adapt.ma);
+    multiply_3x3(&adapt.inv_ma, &tmp)
+}
+
+/// Generates a 3x3 matrix for linear RGB in the space `S`, that adapts colors
+/// from a light source with the correlated color `temperature` in Kelvin and
+/// the `tint`, to the white point of `S`, using the Bradford method.
+///
+/// The light source is placed `tint` units away from the Planckian locus, in
+/// the CIE 1960 UCS, where positive values are greener and negative values
+/// are more magenta.
+pub(crate) fn white_balance_matrix<S, T>(temperature: T, tint: T) -> Mat3<T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+    let min_temperature = from_f64(1667.0);
+    let max_temperature = from_f64(25000.0);
+    let temperature = temperature.max(min_temperature).min(max_temperature);
+
+    // The direction of the locus, for finding its normal.
+    let delta = from_f64(1.01);
+    let (u, v) = planckian_uv(temperature);
+    let (u_warm, v_warm) = planckian_uv((temperature / delta).max(min_temperature));
+    let (u_cool, v_cool) = planckian_uv((temperature * delta).min(max_temperature));
+    let (du, dv) = (u_cool - u_warm, v_cool - v_warm);
+    let length = (du * du + dv * dv).sqrt();
+
+    // The normal points away from the purple line, towards green.
+    let u = u + tint * dv / length;
+    let v = v - tint * du / length;
+
+    let denominator = from_f64::<T>(2.0) * u - from_f64::<T>(8.0) * v + from_f64(4.0);
+    let x = from_f64::<T>(3.0) * u / denominator;
+    let y = from_f64::<T>(2.0) * v / denominator;
+    let source: Xyz<S::WhitePoint, T> = Xyz::with_wp(x / y, T::one(), (T::one() - x - y) / y);
+
+    let bradford = <Method as TransformMatrix<S::WhitePoint, S::WhitePoint, T>>::get_cone_response(
+        &Method::Bradford,
+    );
+    let destination: Xyz<S::WhitePoint, T> = S::WhitePoint::get_xyz();
+    let adapt = adaptation_matrix(&bradford, &source, &destination);
+
+    let to_xyz = rgb_to_xyz_matrix::<S, T>();
+    multiply_3x3(&matrix_inverse(&to_xyz), &multiply_3x3(&adapt, &to_xyz))
+}
+
+/// The CIE 1960 UCS coordinates of the Planckian locus, using the cubic
+/// spline approximation by Kim et al. It's valid from 1667 K to 25000 K.
+fn planckian_uv<T: FloatComponent>(temperature: T) -> (T, T) {
+    let t = temperature;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let x = if t <= from_f64(4000.0) {
+        from_f64::<T>(-0.266_123_9e9) / t3 - from_f64::<T>(0.234_358_9e6) / t2
+            + from_f64::<T>(0.877_695_6e3) / t
+            + from_f64(0.179_910)
+    } else {
+        from_f64::<T>(-3.025_846_9e9) / t3
+            + from_f64::<T>(2.107_037_9e6) / t2
+            + from_f64::<T>(0.222_634_7e3) / t
+            + from_f64(0.240_390)
+    };
+
+    let x2 = x * x;
+    let x3 = x2 * x;
+    let y = if t <= from_f64(2222.0) {
+        from_f64::<T>(-1.106_381_4) * x3 - from_f64::<T>(1.348_110_20) * x2
+            + from_f64::<T>(2.185_558_32) * x
+            - from_f64(0.202_196_83)
+    } else if t <= from_f64(4000.0) {
+        from_f64::<T>(-0.954_947_6) * x3 - from_f64::<T>(1.374_185_93) * x2
+            + from_f64::<T>(2.091_370_15) * x
+            - from_f64(0.167_488_67)
+    } else {
+        from_f64::<T>(3.081_758_0) * x3 - from_f64::<T>(5.873_386_70) * x2
+            + from_f64::<T>(3.751_129_97) * x
+            - from_f64(0.370_014_83)
+    };
+
+    let denominator = from_f64::<T>(-2.0) * x + from_f64::<T>(12.0) * y + from_f64(3.0);
+    (
+        from_f64::<T>(4.0) * x / denominator,
+        from_f64::<T>(6.0) * y / denominator,
+    )
+}
+
 impl<Swp, Dwp, T> TransformMatrix<Swp, Dwp, T> for Method
 where
     T: FloatComponent,
@@ -212,8 +314,9 @@ where
 #[cfg(test)]
 mod test {
     use super::{AdaptFrom, AdaptInto, Method, TransformMatrix};
+    use crate::convert::FromColorUnclamped;
     use crate::white_point::{A, C, D50, D65};
-    use crate::Xyz;
+    use crate::{LinSrgb, Xyz};
 
     #[test]
     fn d65_to_d50_matrix_xyz_scaling() {
@@ -287,4 +390,37 @@ mod test {
         let computed_xyz_scaling: Xyz<C, _> = input_a.adapt_into_using(Method::XyzScaling);
         assert_relative_eq!(expected_xyz_scaling, computed_xyz_scaling, epsilon = 0.0001);
     }
+
+    #[test]
+    fn white_balance_near_white_point() {
+        // D65 is about 6504 K, slightly above the Planckian locus
+        let color = LinSrgb::new(0.2, 0.5, 0.8);
+        assert_relative_eq!(color.white_balance(6504.0, 0.0032), color, epsilon = 0.002);
+    }
+
+    #[test]
+    fn white_balance_to_white() {
+        let illuminant_a = Xyz::<D65, f64>::with_wp(1.09850, 1.0, 0.35585);
+        let color = LinSrgb::from_color_unclamped(illuminant_a);
+        assert_relative_eq!(
+            color.white_balance(2856.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn white_balance_direction() {
+        let gray = LinSrgb::new(0.5, 0.5, 0.5);
+
+        let warm = gray.white_balance(3000.0, 0.0);
+        assert!(warm.blue > warm.red);
+        let cool = gray.white_balance(10000.0, 0.0);
+        assert!(cool.red > cool.blue);
+
+        let green = gray.white_balance(6504.0, 0.02);
+        assert!(green.red > green.green && green.blue > green.green);
+        let magenta = gray.white_balance(6504.0, -0.02);
+        assert!(magenta.green > magenta.red && magenta.green > magenta.blue);
+    }
 }
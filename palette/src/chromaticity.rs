@@ -0,0 +1,101 @@
+use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::white_point::WhitePoint;
+use crate::{FloatComponent, Xyz, Yxy};
+
+/// CIE 1931 (x, y) chromaticity coordinates.
+///
+/// Chromaticity is the part of a color that remains when its luminance is
+/// removed, and is the same as the `x` and `y` components of [`Yxy`]. It's
+/// what's usually used to define the primaries and white point of an RGB
+/// color space, such as in specifications and in display metadata, and can
+/// be used for building RGB conversion matrices at run time, with
+/// [`rgb_to_xyz_matrix_from_primaries`](crate::matrix::rgb_to_xyz_matrix_from_primaries)
+/// or with [`Profile`](crate::profile::Profile).
+///
+/// ```
+/// use palette::white_point::D65;
+/// use palette::Chromaticity;
+///
+/// let white = Chromaticity::<f64>::from_white_point::<D65>();
+/// assert!((white.x - 0.3127).abs() < 0.0001);
+/// assert!((white.y - 0.3290).abs() < 0.0001);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Chromaticity<T = f32> {
+    /// The x coordinate, derived from the XYZ color space as X/(X+Y+Z).
+    pub x: T,
+
+    /// The y coordinate, derived from the XYZ color space as Y/(X+Y+Z).
+    pub y: T,
+}
+
+impl<T: FloatComponent> Chromaticity<T> {
+    /// Create chromaticity coordinates.
+    pub fn new(x: T, y: T) -> Self {
+        Chromaticity { x, y }
+    }
+
+    /// Get the chromaticity of the white point `Wp`.
+    pub fn from_white_point<Wp: WhitePoint>() -> Self {
+        Self::from_xyz(Wp::get_xyz::<Wp, T>())
+    }
+
+    /// Get the chromaticity of an XYZ color. Black has the chromaticity
+    /// `(0, 0)`.
+    pub fn from_xyz<Wp: WhitePoint>(color: Xyz<Wp, T>) -> Self {
+        Yxy::from_color_unclamped(color).into()
+    }
+
+    /// Convert into an XYZ color with the luminance `luma`.
+    pub fn into_xyz<Wp: WhitePoint>(self, luma: T) -> Xyz<Wp, T> {
+        self.into_yxy(luma).into_color_unclamped()
+    }
+
+    /// Convert into an Yxy color with the luminance `luma`.
+    pub fn into_yxy<Wp: WhitePoint>(self, luma: T) -> Yxy<Wp, T> {
+        Yxy::with_wp(self.x, self.y, luma)
+    }
+}
+
+impl<T> From<(T, T)> for Chromaticity<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Chromaticity { x, y }
+    }
+}
+
+impl<T> From<Chromaticity<T>> for (T, T) {
+    fn from(chromaticity: Chromaticity<T>) -> Self {
+        (chromaticity.x, chromaticity.y)
+    }
+}
+
+impl<Wp, T> From<Yxy<Wp, T>> for Chromaticity<T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from(color: Yxy<Wp, T>) -> Self {
+        Chromaticity {
+            x: color.x,
+            y: color.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Chromaticity;
+    use crate::white_point::D50;
+    use crate::Xyz;
+
+    #[test]
+    fn xyz_round_trip() {
+        let color = Xyz::<D50, f64>::with_wp(0.3, 0.4, 0.5);
+        let chromaticity = Chromaticity::from_xyz(color);
+
+        assert_relative_eq!(chromaticity.x, 0.25);
+        assert_relative_eq!(chromaticity.y, 1.0 / 3.0);
+        assert_relative_eq!(chromaticity.into_xyz::<D50>(0.4), color);
+    }
+}
@@ -10,6 +10,105 @@ pub trait ColorDifference {
     fn get_color_difference(&self, other: &Self) -> Self::Scalar;
 }
 
+/// Find the color in `colors` that is the closest to `color`, according to
+/// `metric`.
+///
+/// Returns the index of the closest color, together with its distance, or
+/// `None` if `colors` is empty. The first of several equally close colors is
+/// picked. Distances that can't be compared, such as `NaN`, are skipped, so
+/// `None` is also returned if none of the distances can be compared.
+///
+/// ```
+/// use palette::{find_nearest, ColorDifference, Lab};
+///
+/// let palette = [
+///     Lab::new(20.0, 0.0, 0.0),
+///     Lab::new(50.0, 40.0, 0.0),
+///     Lab::new(80.0, 0.0, 0.0),
+/// ];
+/// let color = Lab::new(75.0, 5.0, 0.0);
+///
+/// let (index, _distance) = find_nearest(&color, &palette, |a, b| a.get_color_difference(b))
+///     .expect("the palette is not empty");
+/// assert_eq!(index, 2);
+/// ```
+pub fn find_nearest<C, T, F>(color: &C, colors: &[C], mut metric: F) -> Option<(usize, T)>
+where
+    T: PartialOrd,
+    F: FnMut(&C, &C) -> T,
+{
+    let mut nearest: Option<(usize, T)> = None;
+
+    for (index, candidate) in colors.iter().enumerate() {
+        let distance = metric(color, candidate);
+
+        if !is_comparable(&distance) {
+            continue;
+        }
+
+        let is_nearer = match nearest {
+            Some((_, ref nearest_distance)) => distance < *nearest_distance,
+            None => true,
+        };
+
+        if is_nearer {
+            nearest = Some((index, distance));
+        }
+    }
+
+    nearest
+}
+
+/// Check if `value` has an order, which isn't the case for `NaN`.
+fn is_comparable<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_some()
+}
+
+/// An extension trait for finding the nearest color in a collection of
+/// colors, such as when mapping colors to a limited palette.
+///
+/// ```
+/// use palette::{Lab, NearestColor};
+///
+/// let palette = vec![
+///     Lab::new(20.0, 0.0, 0.0),
+///     Lab::new(50.0, 40.0, 0.0),
+///     Lab::new(80.0, 0.0, 0.0),
+/// ];
+///
+/// let (index, _distance) = palette.nearest_color(&Lab::new(45.0, 30.0, 5.0)).unwrap();
+/// assert_eq!(index, 1);
+/// ```
+pub trait NearestColor<C> {
+    /// Find the index and distance of the color that is the closest to
+    /// `color`, according to `metric`. See [`find_nearest`](crate::find_nearest)
+    /// for details.
+    fn nearest_color_by<T, F>(&self, color: &C, metric: F) -> Option<(usize, T)>
+    where
+        T: PartialOrd,
+        F: FnMut(&C, &C) -> T;
+
+    /// Find the index and distance of the color that is the closest to
+    /// `color`, using its [`ColorDifference`](crate::ColorDifference)
+    /// implementation as the metric.
+    fn nearest_color(&self, color: &C) -> Option<(usize, C::Scalar)>
+    where
+        C: ColorDifference,
+    {
+        self.nearest_color_by(color, C::get_color_difference)
+    }
+}
+
+impl<C> NearestColor<C> for [C] {
+    fn nearest_color_by<T, F>(&self, color: &C, metric: F) -> Option<(usize, T)>
+    where
+        T: PartialOrd,
+        F: FnMut(&C, &C) -> T,
+    {
+        find_nearest(color, self, metric)
+    }
+}
+
 /// Container of components necessary to calculate CIEDE color difference
 pub struct LabColorDiff<T: FloatComponent> {
     /// Lab color lightness
@@ -115,3 +214,51 @@ pub fn get_ciede_difference<T: FloatComponent>(this: &LabColorDiff<T>, other: &L
         + (r_t * delta_c_prime * delta_big_h_prime) / (k_c * s_c * k_h * s_h))
         .sqrt()
 }
+
+#[cfg(test)]
+mod test {
+    use super::{find_nearest, NearestColor};
+    use crate::{Lab, LinSrgb};
+
+    #[test]
+    fn nearest_by_metric() {
+        let colors = [
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+        ];
+        let metric = |a: &LinSrgb<f64>, b: &LinSrgb<f64>| {
+            let diff = *a - *b;
+            diff.red * diff.red + diff.green * diff.green + diff.blue * diff.blue
+        };
+
+        let (index, distance) =
+            find_nearest(&LinSrgb::new(0.9, 0.2, 0.0), &colors, metric).unwrap();
+        assert_eq!(index, 1);
+        assert_relative_eq!(distance, 0.05);
+
+        assert!(find_nearest(&LinSrgb::new(0.9, 0.2, 0.0), &[], metric).is_none());
+    }
+
+    #[test]
+    fn nearest_skips_nan() {
+        let distances = [f64::NAN, 3.0, 1.0, 1.0];
+        let result = find_nearest(&0usize, &[0, 1, 2, 3], |_, &i| distances[i]);
+
+        assert_eq!(result, Some((2, 1.0)));
+        assert_eq!(find_nearest(&0usize, &[0, 1], |_, _| f64::NAN), None);
+    }
+
+    #[test]
+    fn nearest_color_difference() {
+        let colors = [
+            Lab::new(20.0, 0.0, 0.0),
+            Lab::new(50.0, 40.0, 0.0),
+            Lab::new(80.0, 0.0, 0.0),
+        ];
+
+        let (index, distance) = colors.nearest_color(&Lab::new(80.0, 0.0, 0.0)).unwrap();
+        assert_eq!(index, 2);
+        assert_relative_eq!(distance, 0.0);
+    }
+}
@@ -245,6 +245,39 @@ convert_uint_to_uint!(u64; via f64 (u8, u16, u32, u128););
 convert_uint_to_float!(u128; via f64 (f32, f64););
 convert_uint_to_uint!(u128; via f64 (u8, u16, u32, u64););
 
+#[cfg(feature = "half")]
+impl Component for half::f16 {
+    fn max_intensity() -> Self {
+        half::f16::ONE
+    }
+}
+
+// Half precision floats are converted via f32, which can represent all of
+// their values.
+#[cfg(feature = "half")]
+macro_rules! convert_half {
+    ($($ty: ident),+) => {
+        $(
+            impl IntoComponent<$ty> for half::f16 {
+                #[inline]
+                fn into_component(self) -> $ty {
+                    self.to_f32().into_component()
+                }
+            }
+
+            impl IntoComponent<half::f16> for $ty {
+                #[inline]
+                fn into_component(self) -> half::f16 {
+                    half::f16::from_f32(self.into_component())
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "half")]
+convert_half!(f32, f64, u8, u16, u32, u64, u128);
+
 #[cfg(test)]
 mod test {
     use crate::IntoComponent;
@@ -359,4 +392,38 @@ mod test {
             )
         }
     }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_components() {
+        use half::f16;
+
+        assert_eq!(IntoComponent::<u8>::into_component(f16::from_f32(0.5)), 128);
+        assert_eq!(IntoComponent::<u8>::into_component(f16::from_f32(2.0)), 255);
+        assert_eq!(IntoComponent::<f16>::into_component(255u8), f16::ONE);
+        assert_eq!(
+            IntoComponent::<f32>::into_component(f16::from_f32(0.25)),
+            0.25
+        );
+        assert_eq!(
+            IntoComponent::<f16>::into_component(0.25f64),
+            f16::from_f32(0.25)
+        );
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_colors() {
+        use crate::{Hsv, IntoColor, LinSrgb, Pixel, Srgb};
+        use half::f16;
+
+        let buffer = [f16::ONE, f16::from_f32(0.5), f16::ZERO];
+        let color = LinSrgb::from_raw_slice(&buffer)[0];
+
+        let hsv: Hsv<_, f16> = color.into_color();
+        assert_eq!(hsv.saturation, f16::ONE);
+
+        let srgb: Srgb<u8> = Srgb::from_linear(color).into_format();
+        assert_eq!(srgb, Srgb::new(255, 187, 0));
+    }
 }
@@ -77,6 +77,42 @@
 //!
 //! * `alpha`: Specifies field as the color's transparency value.
 //!
+//! * `color`: Specifies field as a color in a struct that contains colors,
+//!   instead of being a color itself. See below.
+//!
+//! ## Structs With Color Fields
+//!
+//! Structs that contain colors, such as color themes, can derive
+//! `FromColorUnclamped` if the types of their color fields are type
+//! parameters, and the color fields are marked with `#[palette(color)]`. The
+//! colors are converted one by one, while the other fields are moved as they
+//! are, so they can't use the type parameters of the color fields:
+//!
+//! ```rust
+//! use palette::convert::{FromColorUnclamped, IntoColorUnclamped};
+//! use palette::{Hsl, Srgb};
+//!
+//! #[derive(Debug, PartialEq, FromColorUnclamped)]
+//! struct Theme<C> {
+//!     name: String,
+//!     #[palette(color)]
+//!     background: C,
+//!     #[palette(color)]
+//!     text: C,
+//! }
+//!
+//! let theme = Theme {
+//!     name: "Night".to_string(),
+//!     background: Hsl::new(0.0, 0.0, 0.0),
+//!     text: Hsl::new(0.0, 0.0, 1.0),
+//! };
+//! let theme: Theme<Srgb> = theme.into_color_unclamped();
+//!
+//! assert_eq!(theme.name, "Night");
+//! assert_eq!(theme.background, Srgb::new(0.0, 0.0, 0.0));
+//! assert_eq!(theme.text, Srgb::new(1.0, 1.0, 1.0));
+//! ```
+//!
 //! ## Examples
 //!
 //! Minimum requirements implementation:
@@ -274,25 +310,44 @@
 //! }
 //! ```
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt::{self, Display, Formatter};
 
 #[doc(hidden)]
 pub use palette_derive::FromColorUnclamped;
 
-use crate::Limited;
+use crate::{InvalidComponents, Limited, Pixel};
 
 /// The error type for a color conversion that converted a color into a color
 /// with invalid values.
+///
+/// It contains the unclamped color, and the names of its components that are
+/// out of bounds:
+///
+/// ```
+/// use palette::convert::TryFromColor;
+/// use palette::{Lch, Srgb};
+///
+/// let err = Srgb::try_from_color(Lch::new(50.0, 100.0, -175.0)).unwrap_err();
+/// assert_eq!(err.to_string(), "color conversion is out of bounds: red");
+/// assert!(err.invalid_components().contains("red"));
+/// assert!(err.color().red < 0.0);
+/// ```
 #[derive(Debug)]
 pub struct OutOfBounds<T> {
     color: T,
+    invalid_components: InvalidComponents,
 }
 
 impl<T> OutOfBounds<T> {
     /// Create a new error wrapping a color
     #[inline]
-    fn new(color: T) -> Self {
-        OutOfBounds { color }
+    fn new(color: T, invalid_components: InvalidComponents) -> Self {
+        OutOfBounds {
+            color,
+            invalid_components,
+        }
     }
 
     /// Consume this error and return the wrapped color
@@ -300,6 +355,12 @@ impl<T> OutOfBounds<T> {
     pub fn color(self) -> T {
         self.color
     }
+
+    /// The names of the wrapped color's components that are out of bounds.
+    #[inline]
+    pub fn invalid_components(&self) -> InvalidComponents {
+        self.invalid_components
+    }
 }
 
 #[cfg(feature = "std")]
@@ -311,7 +372,13 @@ impl<T: ::std::fmt::Debug> ::std::error::Error for OutOfBounds<T> {
 
 impl<T> Display for OutOfBounds<T> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        write!(fmt, "color conversion is out of bounds")
+        write!(fmt, "color conversion is out of bounds")?;
+
+        if !self.invalid_components.is_empty() {
+            write!(fmt, ": {}", self.invalid_components)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -486,7 +553,8 @@ where
         if this.is_valid() {
             Ok(this)
         } else {
-            Err(OutOfBounds::new(this))
+            let invalid_components = this.invalid_components();
+            Err(OutOfBounds::new(this, invalid_components))
         }
     }
 }
@@ -521,6 +589,251 @@ where
     }
 }
 
+/// Convert each color in `input` and write the results to `output`.
+///
+/// This is the same as calling [`FromColor::from_color`] for each color, but
+/// is meant for converting whole buffers, such as the pixels of an image, at
+/// once. The conversion is inlined into a single loop over both slices, which
+/// gives the compiler a good chance to unroll and vectorize it.
+///
+/// ```
+/// use palette::{Hsv, Srgb};
+/// use palette::convert::convert_slice;
+///
+/// let colors = [Hsv::new(0.0, 1.0, 1.0), Hsv::new(120.0, 1.0, 1.0)];
+/// let mut output = [Srgb::default(); 2];
+///
+/// convert_slice(&colors, &mut output);
+/// assert_eq!(output, [Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 1.0, 0.0)]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the slices don't have the same length.
+pub fn convert_slice<T, U>(input: &[T], output: &mut [U])
+where
+    T: Clone,
+    U: FromColor<T>,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "the output slice must have the same length as the input slice"
+    );
+
+    for (output, color) in output.iter_mut().zip(input) {
+        *output = U::from_color(color.clone());
+    }
+}
+
+/// Convert each color in `input`, without clamping, and write the results to
+/// `output`.
+///
+/// This is the same as [`convert_slice`], but uses
+/// [`FromColorUnclamped::from_color_unclamped`] for each color.
+///
+/// ```
+/// use palette::{Lch, Limited, Srgb};
+/// use palette::convert::convert_slice_unclamped;
+///
+/// let colors = [Lch::new(50.0, 100.0, -175.0); 4];
+/// let mut output = [Srgb::default(); 4];
+///
+/// convert_slice_unclamped(&colors, &mut output);
+/// assert!(!output[0].is_valid());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the slices don't have the same length.
+pub fn convert_slice_unclamped<T, U>(input: &[T], output: &mut [U])
+where
+    T: Clone,
+    U: FromColorUnclamped<T>,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "the output slice must have the same length as the input slice"
+    );
+
+    for (output, color) in output.iter_mut().zip(input) {
+        *output = U::from_color_unclamped(color.clone());
+    }
+}
+
+/// Convert each color in `colors` in place, and return the same memory as a
+/// slice of the converted colors.
+///
+/// The two color types need to be [`Pixel`] types with the same component
+/// type and number of channels, such as `Srgb` and `LinSrgb`, or `Srgb` and
+/// `Hsv`. This makes it possible to convert large buffers without allocating
+/// a second one. The raw components of the buffer can also be viewed directly,
+/// using [`Pixel::into_raw_slice_mut`].
+///
+/// ```
+/// use palette::{LinSrgb, Srgb};
+/// use palette::convert::convert_slice_in_place;
+///
+/// let mut buffer = [Srgb::new(1.0, 0.5, 0.0), Srgb::new(0.0, 0.2, 0.8)];
+/// let linear: &mut [LinSrgb] = convert_slice_in_place(&mut buffer);
+///
+/// assert_eq!(linear[0], Srgb::new(1.0, 0.5, 0.0).into_linear());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the color types don't have the same number of channels.
+pub fn convert_slice_in_place<'a, C: 'a, T, U>(colors: &'a mut [T]) -> &'a mut [U]
+where
+    T: Pixel<C> + Copy,
+    U: Pixel<C> + FromColor<T>,
+{
+    convert_raw_in_place(colors, U::from_color)
+}
+
+/// Convert each color in `colors` in place, without clamping, and return the
+/// same memory as a slice of the converted colors.
+///
+/// This is the same as [`convert_slice_in_place`], but uses
+/// [`FromColorUnclamped::from_color_unclamped`] for each color.
+///
+/// ```
+/// use palette::{Lab, Limited, Srgb};
+/// use palette::convert::convert_slice_in_place_unclamped;
+///
+/// let mut buffer = [Lab::new(50.0, 100.0, -50.0); 4];
+/// let rgb: &mut [Srgb] = convert_slice_in_place_unclamped(&mut buffer);
+///
+/// assert!(!rgb[0].is_valid());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the color types don't have the same number of channels.
+pub fn convert_slice_in_place_unclamped<'a, C: 'a, T, U>(colors: &'a mut [T]) -> &'a mut [U]
+where
+    T: Pixel<C> + Copy,
+    U: Pixel<C> + FromColorUnclamped<T>,
+{
+    convert_raw_in_place(colors, U::from_color_unclamped)
+}
+
+fn convert_raw_in_place<'a, C: 'a, T, U, F>(colors: &'a mut [T], mut convert: F) -> &'a mut [U]
+where
+    T: Pixel<C> + Copy,
+    U: Pixel<C>,
+    F: FnMut(T) -> U,
+{
+    assert_eq!(
+        T::CHANNELS,
+        U::CHANNELS,
+        "the color types must have the same number of channels"
+    );
+
+    let raw = T::into_raw_slice_mut(colors);
+
+    for pixel in raw.chunks_exact_mut(T::CHANNELS) {
+        let color = *T::from_raw(&*pixel);
+        *U::from_raw_mut(pixel) = convert(color);
+    }
+
+    U::from_raw_slice_mut(raw)
+}
+
+/// Convert each color in `input` and collect the results into a new `Vec`.
+///
+/// This is the allocating version of [`convert_slice`].
+///
+/// ```
+/// use palette::{Hsl, Srgb};
+/// use palette::convert::convert_to_vec;
+///
+/// let colors = [Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 0.0, 1.0)];
+/// let converted: Vec<Hsl> = convert_to_vec(&colors);
+///
+/// assert_eq!(converted, vec![Hsl::new(0.0, 1.0, 0.5), Hsl::new(240.0, 1.0, 0.5)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_to_vec<T, U>(input: &[T]) -> Vec<U>
+where
+    T: Clone,
+    U: FromColor<T>,
+{
+    input.iter().cloned().map(U::from_color).collect()
+}
+
+/// Convert each color in `input` in parallel and write the results to
+/// `output`.
+///
+/// This is the same as [`convert_slice`], but the slices are split into parts
+/// that are converted in parallel, using `rayon`. It's mainly worth it for
+/// large buffers, such as the pixels of an image. Requires the `rayon`
+/// feature.
+///
+/// ```
+/// use palette::{Hsv, Srgb};
+/// use palette::convert::par_convert_slice;
+///
+/// let colors = vec![Hsv::new(120.0, 1.0, 1.0); 10_000];
+/// let mut output = vec![Srgb::default(); 10_000];
+///
+/// par_convert_slice(&colors, &mut output);
+/// assert!(output.iter().all(|&color| color == Srgb::new(0.0, 1.0, 0.0)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the slices don't have the same length.
+#[cfg(feature = "rayon")]
+pub fn par_convert_slice<T, U>(input: &[T], output: &mut [U])
+where
+    T: Clone + Sync,
+    U: FromColor<T> + Send,
+{
+    par_convert_raw(input, output, U::from_color)
+}
+
+/// Convert each color in `input` in parallel, without clamping, and write
+/// the results to `output`.
+///
+/// This is the same as [`par_convert_slice`], but uses
+/// [`FromColorUnclamped::from_color_unclamped`] for each color. Requires the
+/// `rayon` feature.
+///
+/// # Panics
+///
+/// Panics if the slices don't have the same length.
+#[cfg(feature = "rayon")]
+pub fn par_convert_slice_unclamped<T, U>(input: &[T], output: &mut [U])
+where
+    T: Clone + Sync,
+    U: FromColorUnclamped<T> + Send,
+{
+    par_convert_raw(input, output, U::from_color_unclamped)
+}
+
+#[cfg(feature = "rayon")]
+fn par_convert_raw<T, U, F>(input: &[T], output: &mut [U], convert: F)
+where
+    T: Clone + Sync,
+    U: Send,
+    F: Fn(T) -> U + Sync,
+{
+    use rayon::prelude::*;
+
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "the output slice must have the same length as the input slice"
+    );
+
+    output
+        .par_iter_mut()
+        .zip(input)
+        .for_each(|(output, color)| *output = convert(color.clone()));
+}
+
 #[cfg(test)]
 mod tests {
     use core::marker::PhantomData;
@@ -907,4 +1220,82 @@ mod tests {
         let _hwb: Hwb<_, f64> = color.into_color();
         let _luma: Luma<Linear<crate::white_point::E>, f64> = color.into_color();
     }
+
+    #[test]
+    fn convert_slices() {
+        let input: Vec<_> = (0..21)
+            .map(|i| Lch::new(50.0, i as f64 * 10.0, i as f64 * 15.0))
+            .collect();
+
+        let mut clamped = vec![Rgb::<crate::encoding::Srgb, f64>::default(); input.len()];
+        super::convert_slice(&input, &mut clamped);
+
+        let mut unclamped = clamped.clone();
+        super::convert_slice_unclamped(&input, &mut unclamped);
+
+        let collected: Vec<Rgb<crate::encoding::Srgb, f64>> = super::convert_to_vec(&input);
+
+        for (index, &color) in input.iter().enumerate() {
+            assert_eq!(clamped[index], Rgb::from_color(color));
+            assert_eq!(unclamped[index], Rgb::from_color_unclamped(color));
+            assert_eq!(collected[index], clamped[index]);
+        }
+    }
+
+    #[test]
+    fn convert_slices_in_place() {
+        let input: Vec<_> = (0..21)
+            .map(|i| Lch::new(50.0, i as f64 * 10.0, i as f64 * 15.0))
+            .collect();
+
+        let mut buffer = input.clone();
+        let clamped: &mut [Rgb<crate::encoding::Srgb, f64>] =
+            super::convert_slice_in_place(&mut buffer);
+        for (&converted, &color) in clamped.iter().zip(&input) {
+            assert_eq!(converted, Rgb::from_color(color));
+        }
+
+        let mut buffer = input.clone();
+        let unclamped: &mut [Lab<_, f64>] = super::convert_slice_in_place_unclamped(&mut buffer);
+        for (&converted, &color) in unclamped.iter().zip(&input) {
+            assert_eq!(converted, Lab::from_color_unclamped(color));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_slices_in_place_channel_mismatch() {
+        let mut buffer = [Alpha::<Hsv<crate::encoding::Srgb, f64>, f64>::default(); 3];
+        let _: &mut [Hsl<crate::encoding::Srgb, f64>] = super::convert_slice_in_place(&mut buffer);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_slices_length_mismatch() {
+        let input = [Hsv::<crate::encoding::Srgb, f64>::default(); 3];
+        let mut output = [Hsl::<crate::encoding::Srgb, f64>::default(); 2];
+        super::convert_slice(&input, &mut output);
+    }
+
+    #[derive(FromColorUnclamped)]
+    #[palette(palette_internal)]
+    struct ColorFields<A, B: Copy>(#[palette(color)] A, #[palette(color)] B, u8)
+    where
+        A: Copy;
+
+    #[test]
+    fn from_color_fields() {
+        let color = ColorFields(
+            Hsl::<crate::encoding::Srgb, f64>::new(120.0, 1.0, 0.5),
+            Lch::<crate::white_point::D65, f64>::new(50.0, 0.0, 0.0),
+            7,
+        );
+
+        let converted: ColorFields<Hsv<crate::encoding::Srgb, f64>, Lab<_, f64>> =
+            ColorFields::from_color_unclamped(color);
+
+        assert_relative_eq!(converted.0, Hsv::new(120.0, 1.0, 1.0));
+        assert_relative_eq!(converted.1, Lab::new(50.0, 0.0, 0.0));
+        assert_eq!(converted.2, 7);
+    }
 }
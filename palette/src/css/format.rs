@@ -0,0 +1,366 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+use crate::encoding::{Linear, Srgb};
+use crate::float::Float;
+use crate::rgb::Rgb;
+use crate::white_point::{D50, D65};
+use crate::{Alpha, FloatComponent, Hsl, Hwb, Lab, Lch, Xyz};
+
+use super::CssColor;
+
+/// Formatting of colors as CSS color strings.
+///
+/// `Srgb<u8>` and `Srgba<u8>` are written as hexadecimal colors, while the
+/// other color types are written using the matching CSS color function, such
+/// as `rgb(255 127.5 0)` or `hsl(120 100% 50% / 0.5)`. The alpha component is
+/// left out of the functions when the color is fully opaque, and numbers are
+/// rounded to at most four decimals.
+///
+/// ```
+/// use palette::css::ToCss;
+/// use palette::{LinSrgb, Srgb, Srgba};
+///
+/// assert_eq!(Srgba::new(255u8, 0, 0, 128).to_css_string(), "#ff000080");
+/// assert_eq!(Srgb::new(1.0, 0.5, 0.0).to_css_string(), "rgb(255 127.5 0)");
+/// assert_eq!(
+///     LinSrgb::new(1.0, 0.5, 0.0).to_css_string(),
+///     "color(srgb-linear 1 0.5 0)"
+/// );
+///
+/// // Use `display_css` to format without allocating
+/// let style = format!("color: {};", Srgb::new(0u8, 0, 255).display_css());
+/// assert_eq!(style, "color: #0000ff;");
+/// ```
+pub trait ToCss {
+    /// Write the color to `out` as a CSS color string.
+    fn write_css<W: fmt::Write>(&self, out: &mut W) -> fmt::Result;
+
+    /// Wrap the color in a value that implements `Display` by writing it as a
+    /// CSS color string.
+    fn display_css(&self) -> DisplayCss<'_, Self> {
+        DisplayCss(self)
+    }
+
+    /// Format the color as a CSS color string.
+    #[cfg(feature = "alloc")]
+    fn to_css_string(&self) -> String {
+        let mut css = String::new();
+        self.write_css(&mut css)
+            .expect("a Display implementation returned an error unexpectedly");
+        css
+    }
+}
+
+/// Displays a color as a CSS color string. It's created using
+/// [`ToCss::display_css`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayCss<'a, C: ?Sized>(&'a C);
+
+impl<'a, C: ToCss + ?Sized> fmt::Display for DisplayCss<'a, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_css(f)
+    }
+}
+
+impl ToCss for Rgb<Srgb, u8> {
+    fn write_css<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        write!(out, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+impl ToCss for Alpha<Rgb<Srgb, u8>, u8> {
+    fn write_css<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        self.color.write_css(out)?;
+        write!(out, "{:02x}", self.alpha)
+    }
+}
+
+impl ToCss for CssColor {
+    fn write_css<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        match self {
+            CssColor::Rgb(color) => color.write_css(out),
+            CssColor::LinearRgb(color) => color.write_css(out),
+            CssColor::Hsl(color) => color.write_css(out),
+            CssColor::Hwb(color) => color.write_css(out),
+            CssColor::Lab(color) => color.write_css(out),
+            CssColor::Lch(color) => color.write_css(out),
+            CssColor::XyzD50(color) => color.write_css(out),
+            CssColor::XyzD65(color) => color.write_css(out),
+        }
+    }
+}
+
+/// A component of a CSS color function.
+enum Component {
+    Number(f64),
+    /// A percentage, as a fraction where `1.0` is `100%`.
+    Percentage(f64),
+}
+
+/// Colors that are written as a CSS color function.
+trait CssFunction {
+    /// The start of the function, including the opening parenthesis and any
+    /// color space name.
+    const PREFIX: &'static str;
+
+    fn components(&self) -> [Component; 3];
+}
+
+fn write_function<C, W>(color: &C, alpha: Option<f64>, out: &mut W) -> fmt::Result
+where
+    C: CssFunction,
+    W: fmt::Write,
+{
+    out.write_str(C::PREFIX)?;
+
+    for (index, component) in color.components().iter().enumerate() {
+        if index > 0 {
+            out.write_char(' ')?;
+        }
+
+        match *component {
+            Component::Number(number) => write_number(number, out)?,
+            Component::Percentage(fraction) => {
+                write_number(fraction * 100.0, out)?;
+                out.write_char('%')?;
+            }
+        }
+    }
+
+    if let Some(alpha) = alpha {
+        out.write_str(" / ")?;
+        write_number(alpha, out)?;
+    }
+
+    out.write_char(')')
+}
+
+/// Write a number with at most four decimals and without trailing zeros.
+fn write_number<W: fmt::Write>(number: f64, out: &mut W) -> fmt::Result {
+    // Adding zero turns -0 into 0
+    let rounded = (number * 10000.0).round() / 10000.0 + 0.0;
+    write!(out, "{}", rounded)
+}
+
+fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+macro_rules! impl_to_css {
+    ($($ty: ty),+) => {
+        $(
+            impl<T: FloatComponent> ToCss for $ty {
+                fn write_css<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+                    write_function(self, None, out)
+                }
+            }
+
+            impl<T: FloatComponent> ToCss for Alpha<$ty, T> {
+                fn write_css<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+                    let alpha = to_f64(self.alpha);
+                    let alpha = if alpha == 1.0 { None } else { Some(alpha) };
+                    write_function(&self.color, alpha, out)
+                }
+            }
+        )+
+    };
+}
+
+impl_to_css!(
+    Rgb<Srgb, T>,
+    Rgb<Linear<Srgb>, T>,
+    Hsl<Srgb, T>,
+    Hwb<Srgb, T>,
+    Lab<D50, T>,
+    Lch<D50, T>,
+    Xyz<D50, T>,
+    Xyz<D65, T>
+);
+
+impl<T: FloatComponent> CssFunction for Rgb<Srgb, T> {
+    const PREFIX: &'static str = "rgb(";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.red) * 255.0),
+            Component::Number(to_f64(self.green) * 255.0),
+            Component::Number(to_f64(self.blue) * 255.0),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Rgb<Linear<Srgb>, T> {
+    const PREFIX: &'static str = "color(srgb-linear ";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.red)),
+            Component::Number(to_f64(self.green)),
+            Component::Number(to_f64(self.blue)),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Hsl<Srgb, T> {
+    const PREFIX: &'static str = "hsl(";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.hue.to_positive_degrees())),
+            Component::Percentage(to_f64(self.saturation)),
+            Component::Percentage(to_f64(self.lightness)),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Hwb<Srgb, T> {
+    const PREFIX: &'static str = "hwb(";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.hue.to_positive_degrees())),
+            Component::Percentage(to_f64(self.whiteness)),
+            Component::Percentage(to_f64(self.blackness)),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Lab<D50, T> {
+    const PREFIX: &'static str = "lab(";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.l)),
+            Component::Number(to_f64(self.a)),
+            Component::Number(to_f64(self.b)),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Lch<D50, T> {
+    const PREFIX: &'static str = "lch(";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.l)),
+            Component::Number(to_f64(self.chroma)),
+            Component::Number(to_f64(self.hue.to_positive_degrees())),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Xyz<D50, T> {
+    const PREFIX: &'static str = "color(xyz-d50 ";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.x)),
+            Component::Number(to_f64(self.y)),
+            Component::Number(to_f64(self.z)),
+        ]
+    }
+}
+
+impl<T: FloatComponent> CssFunction for Xyz<D65, T> {
+    const PREFIX: &'static str = "color(xyz-d65 ";
+
+    fn components(&self) -> [Component; 3] {
+        [
+            Component::Number(to_f64(self.x)),
+            Component::Number(to_f64(self.y)),
+            Component::Number(to_f64(self.z)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToCss;
+    use crate::css::CssColor;
+    use crate::white_point::D50;
+    use crate::{Alpha, Hsl, Hwba, Lab, Lch, LinSrgba, Srgb, Srgba, Xyz, Xyza};
+
+    #[test]
+    fn hex() {
+        assert_eq!(Srgb::new(255u8, 128, 0).to_css_string(), "#ff8000");
+        assert_eq!(Srgba::new(0u8, 1, 2, 255).to_css_string(), "#000102ff");
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(
+            Srgba::new(1.0, 0.0, 0.2, 0.25).to_css_string(),
+            "rgb(255 0 51 / 0.25)"
+        );
+        assert_eq!(
+            LinSrgba::new(0.125, -0.0, 1.5, 1.0).to_css_string(),
+            "color(srgb-linear 0.125 0 1.5)"
+        );
+        assert_eq!(
+            Hsl::new(-90.0, 0.5, 0.333333).to_css_string(),
+            "hsl(270 50% 33.3333%)"
+        );
+        assert_eq!(
+            Hwba::new(180.0, 0.1, 0.2, 0.5).to_css_string(),
+            "hwb(180 10% 20% / 0.5)"
+        );
+        assert_eq!(
+            Lab::<D50>::with_wp(50.0, 40.0, -20.0).to_css_string(),
+            "lab(50 40 -20)"
+        );
+        assert_eq!(
+            Lch::<D50>::with_wp(50.0, 100.0, 90.0).to_css_string(),
+            "lch(50 100 90)"
+        );
+        assert_eq!(
+            Xyz::<D50>::with_wp(0.5, 0.25, 0.125).to_css_string(),
+            "color(xyz-d50 0.5 0.25 0.125)"
+        );
+        assert_eq!(
+            Xyza::new(0.5, 0.25, 0.125, 0.5).to_css_string(),
+            "color(xyz-d65 0.5 0.25 0.125 / 0.5)"
+        );
+    }
+
+    #[test]
+    fn css_color() {
+        let color = CssColor::Lab(Alpha {
+            color: Lab::with_wp(50.0, 40.0, -20.0),
+            alpha: 0.5,
+        });
+
+        assert_eq!(color.to_string(), "lab(50 40 -20 / 0.5)");
+        assert_eq!(
+            format!("{}", Srgb::new(1.0f64, 1.0, 1.0).display_css()),
+            "rgb(255 255 255)"
+        );
+    }
+
+    #[cfg(feature = "parsing")]
+    #[test]
+    fn round_trip() {
+        let inputs = [
+            "#ff8000",
+            "rgb(255 127.5 0 / 0.5)",
+            "hsl(120 100% 50%)",
+            "hwb(200 10% 20% / 0.25)",
+            "lab(50 40 -20)",
+            "lch(50 100 90 / 0.75)",
+            "color(srgb-linear 1 0.5 0)",
+            "color(xyz-d50 0.5 0.25 0.125)",
+        ];
+
+        for &input in &inputs {
+            let color: CssColor = input.parse().unwrap();
+            let expected = if input.starts_with('#') {
+                "rgb(255 128 0)"
+            } else {
+                input
+            };
+
+            assert_eq!(color.to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Parsing and formatting of CSS color strings.
+//!
+//! Colors can be formatted as CSS color strings, using the [`ToCss`] trait, so
+//! they can be written directly into stylesheets and SVG documents:
+//!
+//! ```
+//! use palette::css::ToCss;
+//! use palette::{Hsla, Srgb};
+//!
+//! assert_eq!(Srgb::new(255u8, 128, 0).to_css_string(), "#ff8000");
+//! assert_eq!(
+//!     Hsla::new(120.0, 1.0, 0.5, 0.5).to_css_string(),
+//!     "hsl(120 100% 50% / 0.5)"
+//! );
+//! ```
+//!
+//! Parsing requires the `"parsing"` feature. It understands the color syntax
+//! from CSS Color Module Level 4, which includes hexadecimal colors, named
+//! colors and the `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`,
+//! `lch()` and `color()` functions. Each of them is parsed into the closest
+//! matching palette type, wrapped in a [`CssColor`]:
+//!
+//! ```
+//! # #[cfg(feature = "parsing")] {
+//! use palette::css::CssColor;
+//! use palette::{Hsla, Srgba};
+//!
+//! let color: CssColor = "hsl(120deg 100% 50% / 0.5)".parse().unwrap();
+//! assert_eq!(color, CssColor::Hsl(Hsla::new(120.0, 1.0, 0.5, 0.5)));
+//!
+//! // Any parsed color can be converted to sRGB
+//! let color: CssColor = "rgb(255 0 0)".parse().unwrap();
+//! assert_eq!(color.into_srgba(), Srgba::new(1.0, 0.0, 0.0, 1.0));
+//! # }
+//! ```
+//!
+//! The CIE L\*a\*b\* based colors, as well as `color(xyz-d50 ...)`, use the D50
+//! white point, as specified by CSS, and are adapted to D65 when they are
+//! converted to sRGB.
+//!
+//! A [`CssColor`] works as a dynamic color type, that can hold a color from any
+//! of the supported color spaces. With the `"serializing"` feature, it's
+//! serialized as a CSS color string, which makes it convenient to use in hand
+//! written configuration files. Deserializing it also requires the
+//! `"parsing"` feature.
+
+use core::fmt;
+
+use crate::chromatic_adaptation::AdaptInto;
+use crate::convert::FromColorUnclamped;
+use crate::white_point::{D50, D65};
+use crate::{
+    Alpha, FromColor, Hsla, Hwba, Laba, Lcha, LinSrgba, Srgb, Srgba, WithAlpha, Xyz, Xyza,
+};
+
+pub use self::format::{DisplayCss, ToCss};
+#[cfg(feature = "parsing")]
+pub use self::parse::ParseCssColorError;
+
+mod format;
+#[cfg(feature = "parsing")]
+mod parse;
+
+/// A color that can be parsed from, or formatted as, a CSS color string.
+///
+/// The variant depends on the syntax of the color, and can be converted to
+/// sRGB using [`into_srgba`](CssColor::into_srgba).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CssColor {
+    /// An sRGB color, from a hexadecimal color, a named color, the
+    /// `transparent` keyword, `rgb()`, `rgba()` or `color(srgb ...)`.
+    Rgb(Srgba),
+
+    /// A linear sRGB color, from `color(srgb-linear ...)`.
+    LinearRgb(LinSrgba),
+
+    /// An HSL color, from `hsl()` or `hsla()`.
+    Hsl(Hsla),
+
+    /// An HWB color, from `hwb()`.
+    Hwb(Hwba),
+
+    /// A CIE L\*a\*b\* color, from `lab()`.
+    Lab(Laba<D50>),
+
+    /// A CIE L\*C\*h° color, from `lch()`.
+    Lch(Lcha<D50>),
+
+    /// A CIE XYZ color with the D50 white point, from `color(xyz-d50 ...)`.
+    XyzD50(Xyza<D50>),
+
+    /// A CIE XYZ color with the D65 white point, from `color(xyz ...)` or
+    /// `color(xyz-d65 ...)`.
+    XyzD65(Xyza<D65>),
+}
+
+impl CssColor {
+    /// The name of the color's color space, as used in the CSS `color()`
+    /// function, or the name of the function for the colors that don't have
+    /// one.
+    ///
+    /// ```
+    /// use palette::css::CssColor;
+    /// use palette::{Hsla, Srgba};
+    ///
+    /// assert_eq!(CssColor::from(Srgba::new(1.0, 0.5, 0.0, 1.0)).kind(), "srgb");
+    /// assert_eq!(CssColor::from(Hsla::new(30.0, 1.0, 0.5, 1.0)).kind(), "hsl");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CssColor::Rgb(_) => "srgb",
+            CssColor::LinearRgb(_) => "srgb-linear",
+            CssColor::Hsl(_) => "hsl",
+            CssColor::Hwb(_) => "hwb",
+            CssColor::Lab(_) => "lab",
+            CssColor::Lch(_) => "lch",
+            CssColor::XyzD50(_) => "xyz-d50",
+            CssColor::XyzD65(_) => "xyz-d65",
+        }
+    }
+
+    /// The transparency of the color.
+    pub fn alpha(&self) -> f32 {
+        match self {
+            CssColor::Rgb(color) => color.alpha,
+            CssColor::LinearRgb(color) => color.alpha,
+            CssColor::Hsl(color) => color.alpha,
+            CssColor::Hwb(color) => color.alpha,
+            CssColor::Lab(color) => color.alpha,
+            CssColor::Lch(color) => color.alpha,
+            CssColor::XyzD50(color) => color.alpha,
+            CssColor::XyzD65(color) => color.alpha,
+        }
+    }
+
+    /// Convert the color to CIE XYZ with the D65 white point, with alpha.
+    ///
+    /// Unlike [`into_srgba`](CssColor::into_srgba), this doesn't clamp colors
+    /// that are outside the sRGB gamut.
+    pub fn into_xyza(self) -> Xyza<D65> {
+        match self {
+            CssColor::Rgb(color) => Xyza::from_color_unclamped(color),
+            CssColor::LinearRgb(color) => Xyza::from_color_unclamped(color),
+            CssColor::Hsl(color) => Xyza::from_color_unclamped(color),
+            CssColor::Hwb(color) => Xyza::from_color_unclamped(color),
+            CssColor::Lab(color) => {
+                xyza_from_d50(Xyz::from_color_unclamped(color.color), color.alpha)
+            }
+            CssColor::Lch(color) => {
+                xyza_from_d50(Xyz::from_color_unclamped(color.color), color.alpha)
+            }
+            CssColor::XyzD50(color) => xyza_from_d50(color.color, color.alpha),
+            CssColor::XyzD65(color) => color,
+        }
+    }
+
+    /// Convert the color to sRGB, with alpha.
+    pub fn into_srgba(self) -> Srgba {
+        match self {
+            CssColor::Rgb(color) => color,
+            CssColor::LinearRgb(color) => Srgba::from_color(color),
+            CssColor::Hsl(color) => Srgba::from_color(color),
+            CssColor::Hwb(color) => Srgba::from_color(color),
+            CssColor::Lab(color) => srgba_from_d50(Xyz::from_color(color.color), color.alpha),
+            CssColor::Lch(color) => srgba_from_d50(Xyz::from_color(color.color), color.alpha),
+            CssColor::XyzD50(color) => srgba_from_d50(color.color, color.alpha),
+            CssColor::XyzD65(color) => Srgba::from_color(color),
+        }
+    }
+}
+
+impl WithAlpha<f32> for CssColor {
+    type Color = CssColor;
+    type WithAlpha = CssColor;
+
+    fn with_alpha(mut self, alpha: f32) -> Self::WithAlpha {
+        match &mut self {
+            CssColor::Rgb(color) => color.alpha = alpha,
+            CssColor::LinearRgb(color) => color.alpha = alpha,
+            CssColor::Hsl(color) => color.alpha = alpha,
+            CssColor::Hwb(color) => color.alpha = alpha,
+            CssColor::Lab(color) => color.alpha = alpha,
+            CssColor::Lch(color) => color.alpha = alpha,
+            CssColor::XyzD50(color) => color.alpha = alpha,
+            CssColor::XyzD65(color) => color.alpha = alpha,
+        }
+
+        self
+    }
+
+    fn without_alpha(self) -> Self::Color {
+        self.with_alpha(1.0)
+    }
+
+    fn split(self) -> (Self::Color, f32) {
+        let alpha = self.alpha();
+        (self.without_alpha(), alpha)
+    }
+}
+
+fn srgba_from_d50(color: Xyz<D50>, alpha: f32) -> Srgba {
+    let color: Xyz<D65> = color.adapt_into();
+
+    Alpha {
+        color: Srgb::from_color(color),
+        alpha,
+    }
+}
+
+fn xyza_from_d50(color: Xyz<D50>, alpha: f32) -> Xyza<D65> {
+    Alpha {
+        color: color.adapt_into(),
+        alpha,
+    }
+}
+
+macro_rules! impl_from_color {
+    ($($variant:ident($ty:ty)),+) => {
+        $(
+            impl From<$ty> for CssColor {
+                fn from(color: $ty) -> Self {
+                    CssColor::$variant(color)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_color!(
+    Rgb(Srgba),
+    LinearRgb(LinSrgba),
+    Hsl(Hsla),
+    Hwb(Hwba),
+    Lab(Laba<D50>),
+    Lch(Lcha<D50>),
+    XyzD50(Xyza<D50>),
+    XyzD65(Xyza<D65>)
+);
+
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_css(f)
+    }
+}
+
+#[cfg(feature = "serializing")]
+impl serde::Serialize for CssColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(all(feature = "serializing", feature = "parsing"))]
+impl<'de> serde::Deserialize<'de> for CssColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CssColorVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CssColorVisitor {
+            type Value = CssColor;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a CSS color string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<CssColor, E>
+            where
+                E: serde::de::Error,
+            {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CssColorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CssColor;
+    use crate::white_point::{D50, D65};
+    use crate::{Alpha, Hwba, Lab, WithAlpha, Xyz, Xyza};
+
+    #[test]
+    fn with_alpha() {
+        let color = CssColor::from(Hwba::new(120.0, 0.2, 0.3, 0.5));
+
+        assert_eq!(color.with_alpha(0.25).alpha(), 0.25);
+        assert_eq!(color.without_alpha().alpha(), 1.0);
+        assert_eq!(color.without_alpha().kind(), "hwb");
+
+        let (opaque, alpha) = color.split();
+        assert_eq!(opaque, CssColor::from(Hwba::new(120.0, 0.2, 0.3, 1.0)));
+        assert_eq!(alpha, 0.5);
+    }
+
+    #[test]
+    fn into_xyza() {
+        let white = CssColor::from(Alpha {
+            color: Lab::<D50>::with_wp(100.0, 0.0, 0.0),
+            alpha: 0.5,
+        });
+
+        assert_eq!(white.kind(), "lab");
+        assert_eq!(white.alpha(), 0.5);
+        assert_relative_eq!(
+            white.into_xyza(),
+            Xyza::<D65>::new(0.95047, 1.0, 1.08883, 0.5),
+            epsilon = 0.001
+        );
+
+        // Out of gamut colors are not clamped
+        let green = CssColor::from(Alpha::opaque(Xyz::<D65>::new(0.0, 1.0, 0.0)));
+        assert_eq!(green.into_xyza().color, Xyz::new(0.0, 1.0, 0.0));
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        use crate::{Hsla, Srgba};
+
+        let color = CssColor::from(Srgba::new(1.0, 0.5, 0.0, 1.0));
+        assert_eq!(
+            serde_json::to_string(&color).unwrap(),
+            r#""rgb(255 127.5 0)""#
+        );
+
+        let color = CssColor::from(Hsla::new(120.0, 1.0, 0.5, 0.5));
+        assert_eq!(
+            serde_json::to_string(&[color]).unwrap(),
+            r#"["hsl(120 100% 50% / 0.5)"]"#
+        );
+    }
+
+    #[cfg(all(feature = "serializing", feature = "parsing"))]
+    #[test]
+    fn deserialize() {
+        use crate::{Hsla, Srgba};
+
+        let colors: Vec<CssColor> =
+            serde_json::from_str(r##"["#ff8000", "hsl(120 100% 50% / 0.5)"]"##).unwrap();
+
+        assert_eq!(
+            colors,
+            [
+                CssColor::from(Srgba::new(1.0, 128.0 / 255.0, 0.0, 1.0)),
+                CssColor::from(Hsla::new(120.0, 1.0, 0.5, 0.5)),
+            ]
+        );
+
+        assert!(serde_json::from_str::<CssColor>(r#""not a color""#).is_err());
+    }
+}
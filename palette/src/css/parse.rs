@@ -0,0 +1,465 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::rgb::FromHexError;
+use crate::{Alpha, Hsla, Hwba, Lab, Lch, LinSrgba, Srgb, Srgba, Xyz, Xyza};
+
+use super::CssColor;
+
+impl FromStr for CssColor {
+    type Err = ParseCssColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+
+        if input.starts_with('#') {
+            return parse_hex(input).map(CssColor::Rgb);
+        }
+
+        if let Some(open) = input.find('(') {
+            let name = input[..open].trim_end();
+            let arguments =
+                input[open + 1..]
+                    .strip_suffix(')')
+                    .ok_or(ParseCssColorError::InvalidSyntax(
+                        "expected a closing parenthesis",
+                    ))?;
+
+            return if name.eq_ignore_ascii_case("rgb") || name.eq_ignore_ascii_case("rgba") {
+                parse_rgb(arguments)
+            } else if name.eq_ignore_ascii_case("hsl") || name.eq_ignore_ascii_case("hsla") {
+                parse_hsl(arguments)
+            } else if name.eq_ignore_ascii_case("hwb") {
+                parse_hwb(arguments)
+            } else if name.eq_ignore_ascii_case("lab") {
+                parse_lab(arguments)
+            } else if name.eq_ignore_ascii_case("lch") {
+                parse_lch(arguments)
+            } else if name.eq_ignore_ascii_case("color") {
+                parse_color_function(arguments)
+            } else if name.eq_ignore_ascii_case("oklab") || name.eq_ignore_ascii_case("oklch") {
+                Err(ParseCssColorError::UnsupportedColorSpace)
+            } else {
+                Err(ParseCssColorError::InvalidSyntax("unknown color function"))
+            };
+        }
+
+        if input.eq_ignore_ascii_case("transparent") {
+            return Ok(CssColor::Rgb(Srgba::transparent(Srgb::new(0.0, 0.0, 0.0))));
+        }
+
+        crate::named::from_str(input)
+            .map(|color| CssColor::Rgb(color.into_format().into()))
+            .ok_or(ParseCssColorError::UnknownName)
+    }
+}
+
+/// An error that occurred while parsing a CSS color string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseCssColorError {
+    /// The input is not a valid CSS color.
+    InvalidSyntax(&'static str),
+
+    /// The input is not a known color name.
+    UnknownName,
+
+    /// The input is valid CSS, but uses a color space that palette doesn't
+    /// support.
+    UnsupportedColorSpace,
+}
+
+impl fmt::Display for ParseCssColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseCssColorError::InvalidSyntax(s) => write!(f, "{}", s),
+            ParseCssColorError::UnknownName => write!(f, "unknown color name"),
+            ParseCssColorError::UnsupportedColorSpace => write!(f, "unsupported color space"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCssColorError {}
+
+fn parse_hex(hex: &str) -> Result<Srgba, ParseCssColorError> {
+    Srgba::<u8>::from_str(hex)
+        .map(Srgba::into_format)
+        .map_err(|error| match error {
+            FromHexError::InvalidDigit(_) => {
+                ParseCssColorError::InvalidSyntax("invalid hexadecimal digit")
+            }
+            FromHexError::InvalidLength(_) => ParseCssColorError::InvalidSyntax(
+                "a hexadecimal color must have 3, 4, 6 or 8 digits",
+            ),
+        })
+}
+
+fn parse_rgb(arguments: &str) -> Result<CssColor, ParseCssColorError> {
+    let ([red, green, blue], alpha) = split_arguments(arguments, true)?;
+    let channel = |input: &str| -> Result<f32, ParseCssColorError> {
+        let value = match parse_value(input)? {
+            Value::Number(number) => number / 255.0,
+            Value::Percentage(fraction) => fraction,
+        };
+
+        Ok(clamp_unit(value))
+    };
+
+    Ok(CssColor::Rgb(Srgba::new(
+        channel(red)?,
+        channel(green)?,
+        channel(blue)?,
+        parse_alpha(alpha)?,
+    )))
+}
+
+fn parse_hsl(arguments: &str) -> Result<CssColor, ParseCssColorError> {
+    let ([hue, saturation, lightness], alpha) = split_arguments(arguments, true)?;
+
+    Ok(CssColor::Hsl(Hsla::new(
+        parse_hue(hue)?,
+        clamp_unit(parse_fraction(saturation, 100.0)?),
+        clamp_unit(parse_fraction(lightness, 100.0)?),
+        parse_alpha(alpha)?,
+    )))
+}
+
+fn parse_hwb(arguments: &str) -> Result<CssColor, ParseCssColorError> {
+    let ([hue, whiteness, blackness], alpha) = split_arguments(arguments, false)?;
+
+    Ok(CssColor::Hwb(Hwba::new(
+        parse_hue(hue)?,
+        clamp_unit(parse_fraction(whiteness, 100.0)?),
+        clamp_unit(parse_fraction(blackness, 100.0)?),
+        parse_alpha(alpha)?,
+    )))
+}
+
+fn parse_lab(arguments: &str) -> Result<CssColor, ParseCssColorError> {
+    let ([l, a, b], alpha) = split_arguments(arguments, false)?;
+
+    Ok(CssColor::Lab(Alpha {
+        color: Lab::with_wp(
+            parse_scaled(l, 100.0)?.clamp(0.0, 100.0),
+            parse_scaled(a, 125.0)?,
+            parse_scaled(b, 125.0)?,
+        ),
+        alpha: parse_alpha(alpha)?,
+    }))
+}
+
+fn parse_lch(arguments: &str) -> Result<CssColor, ParseCssColorError> {
+    let ([l, chroma, hue], alpha) = split_arguments(arguments, false)?;
+
+    Ok(CssColor::Lch(Alpha {
+        color: Lch::with_wp(
+            parse_scaled(l, 100.0)?.clamp(0.0, 100.0),
+            parse_scaled(chroma, 150.0)?.max(0.0),
+            parse_hue(hue)?,
+        ),
+        alpha: parse_alpha(alpha)?,
+    }))
+}
+
+fn parse_color_function(arguments: &str) -> Result<CssColor, ParseCssColorError> {
+    let arguments = arguments.trim_start();
+    let space_end =
+        arguments
+            .find(char::is_whitespace)
+            .ok_or(ParseCssColorError::InvalidSyntax(
+                "expected a color space and three components",
+            ))?;
+    let space = &arguments[..space_end];
+    let ([first, second, third], alpha) = split_arguments(&arguments[space_end..], false)?;
+
+    let first = parse_scaled(first, 1.0)?;
+    let second = parse_scaled(second, 1.0)?;
+    let third = parse_scaled(third, 1.0)?;
+    let alpha = parse_alpha(alpha)?;
+
+    if space.eq_ignore_ascii_case("srgb") {
+        Ok(CssColor::Rgb(Srgba::new(first, second, third, alpha)))
+    } else if space.eq_ignore_ascii_case("srgb-linear") {
+        Ok(CssColor::LinearRgb(LinSrgba::new(
+            first, second, third, alpha,
+        )))
+    } else if space.eq_ignore_ascii_case("xyz") || space.eq_ignore_ascii_case("xyz-d65") {
+        Ok(CssColor::XyzD65(Xyza::new(first, second, third, alpha)))
+    } else if space.eq_ignore_ascii_case("xyz-d50") {
+        Ok(CssColor::XyzD50(Alpha {
+            color: Xyz::with_wp(first, second, third),
+            alpha,
+        }))
+    } else {
+        Err(ParseCssColorError::UnsupportedColorSpace)
+    }
+}
+
+/// Split the arguments of a color function into three components and an
+/// optional alpha value. The legacy syntax, with commas between the values,
+/// is only accepted if `legacy` is `true`.
+fn split_arguments(
+    arguments: &str,
+    legacy: bool,
+) -> Result<([&str; 3], Option<&str>), ParseCssColorError> {
+    let mut components = [""; 3];
+
+    if arguments.contains(',') {
+        if !legacy {
+            return Err(ParseCssColorError::InvalidSyntax(
+                "commas are only allowed in rgb() and hsl()",
+            ));
+        }
+
+        let mut parts = arguments.split(',').map(str::trim);
+        for component in &mut components {
+            *component = parts.next().ok_or(ParseCssColorError::InvalidSyntax(
+                "expected three components",
+            ))?;
+        }
+        let alpha = parts.next();
+
+        if parts.next().is_some() {
+            return Err(ParseCssColorError::InvalidSyntax("too many components"));
+        }
+
+        if components.iter().chain(&alpha).any(|part| part.is_empty()) {
+            return Err(ParseCssColorError::InvalidSyntax("empty component"));
+        }
+
+        return Ok((components, alpha));
+    }
+
+    let (arguments, alpha) = match arguments.find('/') {
+        Some(slash) => {
+            let alpha = arguments[slash + 1..].trim();
+            if alpha.is_empty() || alpha.contains(|c: char| c.is_whitespace() || c == '/') {
+                return Err(ParseCssColorError::InvalidSyntax(
+                    "expected a single alpha value after '/'",
+                ));
+            }
+
+            (&arguments[..slash], Some(alpha))
+        }
+        None => (arguments, None),
+    };
+
+    let mut parts = arguments.split_whitespace();
+    for component in &mut components {
+        *component = parts.next().ok_or(ParseCssColorError::InvalidSyntax(
+            "expected three components",
+        ))?;
+    }
+
+    if parts.next().is_some() {
+        return Err(ParseCssColorError::InvalidSyntax("too many components"));
+    }
+
+    Ok((components, alpha))
+}
+
+/// A parsed numeric value.
+enum Value {
+    Number(f32),
+    /// A percentage, as a fraction where `1.0` is `100%`.
+    Percentage(f32),
+}
+
+fn parse_value(input: &str) -> Result<Value, ParseCssColorError> {
+    if input.eq_ignore_ascii_case("none") {
+        return Ok(Value::Number(0.0));
+    }
+
+    if let Some(percentage) = input.strip_suffix('%') {
+        return parse_number(percentage).map(|percentage| Value::Percentage(percentage / 100.0));
+    }
+
+    parse_number(input).map(Value::Number)
+}
+
+fn parse_number(input: &str) -> Result<f32, ParseCssColorError> {
+    let starts_correctly = input
+        .bytes()
+        .next()
+        .is_some_and(|byte| byte.is_ascii_digit() || b"+-.".contains(&byte));
+
+    match input.parse::<f32>() {
+        Ok(number) if starts_correctly && number.is_finite() => Ok(number),
+        _ => Err(ParseCssColorError::InvalidSyntax("invalid number")),
+    }
+}
+
+/// Parse a number or a percentage, where numbers are divided by
+/// `number_scale` to become a fraction.
+fn parse_fraction(input: &str, number_scale: f32) -> Result<f32, ParseCssColorError> {
+    match parse_value(input)? {
+        Value::Number(number) => Ok(number / number_scale),
+        Value::Percentage(fraction) => Ok(fraction),
+    }
+}
+
+/// Parse a number or a percentage, where `100%` is `percentage_scale`.
+fn parse_scaled(input: &str, percentage_scale: f32) -> Result<f32, ParseCssColorError> {
+    match parse_value(input)? {
+        Value::Number(number) => Ok(number),
+        Value::Percentage(fraction) => Ok(fraction * percentage_scale),
+    }
+}
+
+fn parse_alpha(input: Option<&str>) -> Result<f32, ParseCssColorError> {
+    match input {
+        Some(input) => parse_fraction(input, 1.0).map(clamp_unit),
+        None => Ok(1.0),
+    }
+}
+
+/// Parse a hue, as degrees or as an angle with a unit.
+fn parse_hue(input: &str) -> Result<f32, ParseCssColorError> {
+    if input.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+
+    let units = [
+        ("deg", 1.0),
+        ("grad", 360.0 / 400.0),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    for &(unit, scale) in &units {
+        if input.len() > unit.len()
+            && input.is_char_boundary(input.len() - unit.len())
+            && input[input.len() - unit.len()..].eq_ignore_ascii_case(unit)
+        {
+            return parse_number(&input[..input.len() - unit.len()]).map(|angle| angle * scale);
+        }
+    }
+
+    parse_number(input)
+}
+
+fn clamp_unit(value: f32) -> f32 {
+    value.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CssColor, ParseCssColorError};
+    use crate::white_point::D50;
+    use crate::{Alpha, Hsla, Hwba, Lab, Lch, LinSrgba, Srgba, Xyza};
+
+    fn parse(input: &str) -> CssColor {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse("#f00"), CssColor::Rgb(Srgba::new(1.0, 0.0, 0.0, 1.0)));
+        assert_eq!(
+            parse("#ff000000"),
+            CssColor::Rgb(Srgba::new(1.0, 0.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            parse("#0000ff80").into_srgba().into_format(),
+            Srgba::new(0u8, 0, 255, 128)
+        );
+        assert!("#ff00f".parse::<CssColor>().is_err());
+        assert!("#+f0".parse::<CssColor>().is_err());
+        assert!("##f00".parse::<CssColor>().is_err());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            parse("RebeccaPurple").into_srgba().into_format(),
+            Srgba::new(102u8, 51, 153, 255)
+        );
+        assert_eq!(
+            parse("transparent"),
+            CssColor::Rgb(Srgba::new(0.0, 0.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            "notacolor".parse::<CssColor>(),
+            Err(ParseCssColorError::UnknownName)
+        );
+    }
+
+    #[test]
+    fn rgb() {
+        let red = CssColor::Rgb(Srgba::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(parse("rgb(255, 0, 0)"), red);
+        assert_eq!(parse("rgb(255 0 0)"), red);
+        assert_eq!(parse("RGB(100% 0% none)"), red);
+        assert_eq!(parse("rgb(300 -10 0)"), red);
+        assert_eq!(
+            parse("rgba(255, 0, 0, 0.5)"),
+            CssColor::Rgb(Srgba::new(1.0, 0.0, 0.0, 0.5))
+        );
+        assert_eq!(
+            parse("rgb(255 0 0 / 25%)"),
+            CssColor::Rgb(Srgba::new(1.0, 0.0, 0.0, 0.25))
+        );
+        assert!("rgb(255 0)".parse::<CssColor>().is_err());
+        assert!("rgb(255, 0 0)".parse::<CssColor>().is_err());
+        assert!("rgb(255 0 0 / )".parse::<CssColor>().is_err());
+        assert!("rgb(255 0 0".parse::<CssColor>().is_err());
+    }
+
+    #[test]
+    fn hsl_and_hwb() {
+        assert_eq!(
+            parse("hsl(120, 100%, 50%)"),
+            CssColor::Hsl(Hsla::new(120.0, 1.0, 0.5, 1.0))
+        );
+        assert_eq!(
+            parse("hsla(0.5turn 100 50 / 0.2)"),
+            CssColor::Hsl(Hsla::new(180.0, 1.0, 0.5, 0.2))
+        );
+        assert_eq!(
+            parse("hwb(200grad 10% 20%)"),
+            CssColor::Hwb(Hwba::new(180.0, 0.1, 0.2, 1.0))
+        );
+        assert!("hwb(180, 10%, 20%)".parse::<CssColor>().is_err());
+    }
+
+    #[test]
+    fn lab_and_lch() {
+        assert_eq!(
+            parse("lab(50% 40 -20)"),
+            CssColor::Lab(Alpha {
+                color: Lab::<D50>::with_wp(50.0, 40.0, -20.0),
+                alpha: 1.0
+            })
+        );
+        assert_eq!(
+            parse("lch(50 100% 90deg / 50%)"),
+            CssColor::Lch(Alpha {
+                color: Lch::<D50>::with_wp(50.0, 150.0, 90.0),
+                alpha: 0.5
+            })
+        );
+
+        let white = parse("lab(100 0 0)").into_srgba();
+        assert_relative_eq!(white, Srgba::new(1.0, 1.0, 1.0, 1.0), epsilon = 0.001);
+    }
+
+    #[test]
+    fn color_function() {
+        assert_eq!(
+            parse("color(srgb 1 0.5 0)"),
+            CssColor::Rgb(Srgba::new(1.0, 0.5, 0.0, 1.0))
+        );
+        assert_eq!(
+            parse("color(srgb-linear 100% 0 0 / 0.5)"),
+            CssColor::LinearRgb(LinSrgba::new(1.0, 0.0, 0.0, 0.5))
+        );
+        assert_eq!(
+            parse("color(xyz 0.5 0.5 0.5)"),
+            CssColor::XyzD65(Xyza::new(0.5, 0.5, 0.5, 1.0))
+        );
+        assert_eq!(
+            "color(display-p3 1 0 0)".parse::<CssColor>(),
+            Err(ParseCssColorError::UnsupportedColorSpace)
+        );
+    }
+}
@@ -0,0 +1,582 @@
+//! Dithering, for reducing the colors of an image without banding.
+//!
+//! Reducing each color of an image to the nearest color in a small palette,
+//! or to a lower bit depth, turns smooth gradients into visible bands.
+//! Dithering hides the bands by spreading the difference between the original
+//! and the reduced colors over neighboring pixels, so the average color of an
+//! area stays the same.
+//!
+//! The colors are reduced by a [`Quantizer`], which is either a
+//! [`FixedPalette`], that maps each color to the index of its nearest palette
+//! color, or a [`BitDepth`], that rounds each component to a number of bits.
+//! The images are slices of colors, stored row by row, and each function
+//! returns the quantized colors in the same order:
+//!
+//! ```
+//! use palette::dither::{floyd_steinberg, FixedPalette};
+//! use palette::quantize::median_cut;
+//! use palette::Lab;
+//!
+//! // A 4x2 image with a gradient in each row
+//! let image: Vec<Lab> = (0..8)
+//!     .map(|i| Lab::new((i % 4) as f32 * 30.0, 0.0, 0.0))
+//!     .collect();
+//!
+//! let palette = median_cut(&image, 2);
+//! let quantizer = FixedPalette::new(&palette, |a: &Lab, b: &Lab| (a.l - b.l).abs());
+//!
+//! // The indices can be stored in an indexed color image format
+//! let indices = floyd_steinberg(&image, 4, &quantizer);
+//! assert_eq!(indices.len(), 8);
+//! assert!(indices.iter().all(|&index| index < 2));
+//! ```
+//!
+//! [`ordered`] dithering adds a fixed pattern from a [`BayerMatrix`] to the
+//! colors, which gives a regular crosshatch texture and is fast, since each
+//! pixel is processed independently. [`floyd_steinberg`] diffuses the error of
+//! each pixel to the pixels that come after it, which gives a more natural
+//! result.
+//!
+//! The error is calculated and spread component by component, so the colors
+//! should be in a color space where that's meaningful. Linear RGB preserves
+//! the light intensity of each area, while [`Lab`](crate::Lab) preserves its
+//! perceived lightness and color.
+//!
+//! [`to_u8`] converts images with `f32` components to `u8` components, with
+//! or without ordered dithering, for storing or displaying them.
+
+use alloc::vec::Vec;
+
+use crate::{from_f64, ComponentWise, FloatComponent, IntoComponent, NearestColor, Pixel};
+
+/// A method for reducing colors to a limited set of colors.
+pub trait Quantizer<C> {
+    /// The reduced representation of a color, such as a palette index.
+    type Output;
+
+    /// Reduce `color`, and return the reduced representation, together with
+    /// the color it represents.
+    fn quantize(&self, color: &C) -> (Self::Output, C);
+}
+
+/// Reduces colors to the index of their nearest color in a palette.
+///
+/// The distance between two colors is measured by a metric function, the
+/// same as in [`find_nearest`](crate::find_nearest).
+///
+/// ```
+/// use palette::dither::{FixedPalette, Quantizer};
+/// use palette::{ColorDifference, Lab};
+///
+/// let palette = [Lab::new(0.0, 0.0, 0.0), Lab::new(100.0, 0.0, 0.0)];
+/// let quantizer = FixedPalette::new(&palette, Lab::get_color_difference);
+///
+/// let (index, color) = quantizer.quantize(&Lab::new(70.0, 0.0, 0.0));
+/// assert_eq!(index, 1);
+/// assert_eq!(color, palette[1]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedPalette<'a, C, F> {
+    colors: &'a [C],
+    metric: F,
+}
+
+impl<'a, C, F> FixedPalette<'a, C, F> {
+    /// Create a quantizer for `colors`, where the distance between two colors
+    /// is measured by `metric`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn new(colors: &'a [C], metric: F) -> Self {
+        assert!(!colors.is_empty(), "the palette is empty");
+
+        FixedPalette { colors, metric }
+    }
+
+    /// The colors of the palette.
+    pub fn colors(&self) -> &'a [C] {
+        self.colors
+    }
+}
+
+impl<'a, C, T, F> Quantizer<C> for FixedPalette<'a, C, F>
+where
+    C: Clone,
+    T: PartialOrd,
+    F: Fn(&C, &C) -> T,
+{
+    type Output = usize;
+
+    fn quantize(&self, color: &C) -> (usize, C) {
+        let (index, _) = self
+            .colors
+            .nearest_color_by(color, &self.metric)
+            .expect("the palette is empty");
+
+        (index, self.colors[index].clone())
+    }
+}
+
+/// Reduces the components of colors to a number of bits.
+///
+/// Each component is clamped to the range from 0 to 1 and rounded to the
+/// nearest of the `2^bits` evenly spaced values in that range, but stays a
+/// float. This matches how the components are rounded when the color is
+/// packed into a format like [`Rgb555`](crate::rgb::Rgb555), or converted to
+/// an integer type with that many bits.
+///
+/// ```
+/// use palette::dither::{BitDepth, Quantizer};
+/// use palette::LinSrgb;
+///
+/// let (color, _) = BitDepth::new(2).quantize(&LinSrgb::new(0.1, 0.5, 0.9));
+/// assert_eq!(color, LinSrgb::new(0.0, 2.0 / 3.0, 1.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitDepth {
+    bits: u32,
+}
+
+impl BitDepth {
+    /// Create a quantizer for `bits` bits per component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is 0 or larger than 32.
+    pub fn new(bits: u32) -> Self {
+        assert!(
+            bits > 0 && bits <= 32,
+            "the number of bits must be between 1 and 32"
+        );
+
+        BitDepth { bits }
+    }
+
+    /// The number of bits per component.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The distance between two neighboring component values, which is a
+    /// good `spread` for [`ordered`] dithering.
+    pub fn step<T: FloatComponent>(&self) -> T {
+        T::one() / self.max_value()
+    }
+
+    fn max_value<T: FloatComponent>(&self) -> T {
+        from_f64(2.0f64.powi(self.bits as i32) - 1.0)
+    }
+}
+
+impl<C, T> Quantizer<C> for BitDepth
+where
+    C: ComponentWise<Scalar = T> + Clone,
+    T: FloatComponent,
+{
+    type Output = C;
+
+    fn quantize(&self, color: &C) -> (C, C) {
+        let max_value = self.max_value::<T>();
+        let quantized = color.component_wise_self(|component| {
+            let component = component.max(T::zero()).min(T::one());
+            (component * max_value).round() / max_value
+        });
+
+        (quantized.clone(), quantized)
+    }
+}
+
+/// A threshold matrix for [`ordered`] dithering.
+///
+/// Larger matrices can represent more shades between two quantized colors,
+/// but have a larger repeating pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BayerMatrix {
+    /// A 2x2 matrix, with 4 thresholds.
+    Size2x2,
+    /// A 4x4 matrix, with 16 thresholds.
+    Size4x4,
+    /// An 8x8 matrix, with 64 thresholds.
+    Size8x8,
+}
+
+impl BayerMatrix {
+    /// The width and height of the matrix.
+    pub fn size(self) -> usize {
+        match self {
+            BayerMatrix::Size2x2 => 2,
+            BayerMatrix::Size4x4 => 4,
+            BayerMatrix::Size8x8 => 8,
+        }
+    }
+
+    /// The threshold for the pixel at column `x` and row `y`, between 0 and
+    /// 1. The matrix is repeated over the whole image.
+    ///
+    /// ```
+    /// use palette::dither::BayerMatrix;
+    ///
+    /// let thresholds: Vec<f64> = (0..4)
+    ///     .map(|i| BayerMatrix::Size2x2.threshold(i % 2, i / 2))
+    ///     .collect();
+    /// assert_eq!(thresholds, vec![0.125, 0.625, 0.875, 0.375]);
+    /// ```
+    pub fn threshold<T: FloatComponent>(self, x: usize, y: usize) -> T {
+        let size = self.size();
+        let (x, y) = (x % size, y % size);
+
+        // The bits of `x ^ y` and `y` are interleaved in reverse order
+        let mut index = 0;
+        let mut bit = 1;
+        while bit < size {
+            index = index << 2 | (((x ^ y) & bit != 0) as usize) << 1 | (y & bit != 0) as usize;
+            bit *= 2;
+        }
+
+        from_f64((index as f64 + 0.5) / (size * size) as f64)
+    }
+}
+
+/// Reduce the colors of an image with ordered dithering, where a repeated
+/// pattern of offsets is added to the colors before they are quantized.
+///
+/// The offsets are taken from `matrix` and range from `-spread / 2` to
+/// `spread / 2`. They are added to each component of each color, so `spread`
+/// should be about the distance between neighboring colors of the quantizer,
+/// such as [`BitDepth::step`]. The image is `width` pixels wide.
+///
+/// ```
+/// use palette::dither::{ordered, BayerMatrix, BitDepth};
+/// use palette::LinSrgb;
+///
+/// let quantizer = BitDepth::new(1);
+/// let image = vec![LinSrgb::new(0.5, 0.5, 0.5); 4];
+///
+/// let colors = ordered(&image, 2, BayerMatrix::Size2x2, quantizer.step(), &quantizer);
+/// let black = LinSrgb::new(0.0, 0.0, 0.0);
+/// let white = LinSrgb::new(1.0, 1.0, 1.0);
+/// assert_eq!(colors, vec![black, white, white, black]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `width` is zero, or if the length of `colors` is not a multiple
+/// of it.
+pub fn ordered<C, T, Q>(
+    colors: &[C],
+    width: usize,
+    matrix: BayerMatrix,
+    spread: T,
+    quantizer: &Q,
+) -> Vec<Q::Output>
+where
+    C: ComponentWise<Scalar = T>,
+    T: FloatComponent,
+    Q: Quantizer<C>,
+{
+    check_size(colors.len(), width);
+
+    let half = from_f64::<T>(0.5);
+
+    colors
+        .iter()
+        .enumerate()
+        .map(|(index, color)| {
+            let threshold: T = matrix.threshold(index % width, index / width);
+            let offset = (threshold - half) * spread;
+            let (output, _) =
+                quantizer.quantize(&color.component_wise_self(|component| component + offset));
+            output
+        })
+        .collect()
+}
+
+/// Reduce the colors of an image with Floyd–Steinberg dithering, where the
+/// error of each quantized color is diffused to the colors that haven't been
+/// quantized yet.
+///
+/// The pixels are processed row by row, from left to right, and the
+/// difference between each color and its quantized color is spread to the
+/// neighboring pixels to the right and below, with the weights 7/16, 3/16,
+/// 5/16 and 1/16. The image is `width` pixels wide.
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::dither::{floyd_steinberg, BitDepth};
+/// use palette::LinSrgb;
+///
+/// let image = vec![LinSrgb::new(0.25, 0.5, 0.75); 16];
+/// let colors = floyd_steinberg(&image, 4, &BitDepth::new(1));
+///
+/// // Each component is either 0 or 1, but the average color is close to the
+/// // original
+/// let sum = colors.iter().fold(LinSrgb::new(0.0, 0.0, 0.0), |sum, &color| sum + color);
+/// assert_relative_eq!(sum / 16.0, LinSrgb::new(0.25, 0.5, 0.75), epsilon = 0.1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `width` is zero, or if the length of `colors` is not a multiple
+/// of it.
+pub fn floyd_steinberg<C, T, Q>(colors: &[C], width: usize, quantizer: &Q) -> Vec<Q::Output>
+where
+    C: ComponentWise<Scalar = T> + Clone,
+    T: FloatComponent,
+    Q: Quantizer<C>,
+{
+    check_size(colors.len(), width);
+
+    let mut colors = colors.to_vec();
+    let mut output = Vec::with_capacity(colors.len());
+    let sixteenth = from_f64::<T>(1.0 / 16.0);
+
+    for index in 0..colors.len() {
+        let (quantized, quantized_color) = quantizer.quantize(&colors[index]);
+        let error = colors[index].component_wise(&quantized_color, |a, b| a - b);
+        output.push(quantized);
+
+        let x = index % width;
+        let mut diffuse = |index: usize, weight: f64| {
+            if let Some(color) = colors.get_mut(index) {
+                let weight = sixteenth * from_f64(weight);
+                *color = color.component_wise(&error, |a, b| a + b * weight);
+            }
+        };
+
+        if x + 1 < width {
+            diffuse(index + 1, 7.0);
+        }
+        if x > 0 {
+            diffuse(index + width - 1, 3.0);
+        }
+        diffuse(index + width, 5.0);
+        if x + 1 < width {
+            diffuse(index + width + 1, 1.0);
+        }
+    }
+
+    output
+}
+
+/// How float components are rounded by [`to_u8`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round each component to the nearest value. This is the same as
+    /// converting with [`into_format`](crate::rgb::Rgb::into_format).
+    Nearest,
+
+    /// Add a pattern of offsets from a Bayer matrix before rounding, to
+    /// prevent banding in smooth gradients. The offsets range from half a
+    /// step below to half a step above the component.
+    Ordered(BayerMatrix),
+}
+
+/// Convert an image with `f32` components to `u8` components, and write the
+/// result to `output`.
+///
+/// Each component is scaled from the range 0 to 1 to the range 0 to 255 and
+/// rounded, as chosen by `rounding`. Components outside the range are
+/// clamped, so `1.0` and anything above it always becomes `255`, instead of
+/// overflowing. The image is `width` pixels wide, which decides the pattern
+/// for [`Rounding::Ordered`].
+///
+/// The input and output types can be any colors with the same number of
+/// components, such as `Srgb<f32>` and `Srgb<u8>`, or `Srgba<f32>` and
+/// `Srgba<u8>`.
+///
+/// ```
+/// use palette::dither::{to_u8, BayerMatrix, Rounding};
+/// use palette::Srgb;
+///
+/// // A flat color between 100 and 101
+/// let value = 100.25 / 255.0;
+/// let image = vec![Srgb::new(value, value, value); 16];
+/// let mut output = vec![Srgb::new(0u8, 0, 0); 16];
+///
+/// to_u8(&image, 4, Rounding::Nearest, &mut output);
+/// assert!(output.iter().all(|color| color.red == 100));
+///
+/// // A quarter of the pixels are rounded up when dithering
+/// to_u8(&image, 4, Rounding::Ordered(BayerMatrix::Size4x4), &mut output);
+/// assert_eq!(output.iter().filter(|color| color.red == 101).count(), 4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `width` is zero, if the length of `colors` is not a multiple of
+/// it, if `colors` and `output` have different lengths, or if `C` and `U`
+/// have different numbers of components.
+pub fn to_u8<C, U>(colors: &[C], width: usize, rounding: Rounding, output: &mut [U])
+where
+    C: Pixel<f32>,
+    U: Pixel<u8>,
+{
+    check_size(colors.len(), width);
+    assert_eq!(
+        colors.len(),
+        output.len(),
+        "the input and output have different lengths"
+    );
+    assert_eq!(
+        C::CHANNELS,
+        U::CHANNELS,
+        "the input and output have different numbers of components"
+    );
+
+    for (index, (color, output)) in colors.iter().zip(output).enumerate() {
+        let offset = match rounding {
+            Rounding::Nearest => 0.0,
+            Rounding::Ordered(matrix) => {
+                (matrix.threshold::<f32>(index % width, index / width) - 0.5) / 255.0
+            }
+        };
+
+        for (&component, output) in color
+            .as_raw::<[f32]>()
+            .iter()
+            .zip(output.as_raw_mut::<[u8]>())
+        {
+            *output = (component + offset).max(0.0).into_component();
+        }
+    }
+}
+
+fn check_size(length: usize, width: usize) {
+    if length > 0 {
+        assert!(width > 0, "the image width can't be zero");
+        assert_eq!(
+            length % width,
+            0,
+            "the number of colors is not a multiple of the image width"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        floyd_steinberg, ordered, to_u8, BayerMatrix, BitDepth, FixedPalette, Quantizer, Rounding,
+    };
+    use crate::{LinSrgb, Srgb, Srgba};
+
+    #[test]
+    fn bayer_matrices() {
+        for &matrix in &[
+            BayerMatrix::Size2x2,
+            BayerMatrix::Size4x4,
+            BayerMatrix::Size8x8,
+        ] {
+            let size = matrix.size();
+            let mut thresholds: Vec<f64> = (0..size * size)
+                .map(|i| matrix.threshold(i % size, i / size))
+                .collect();
+            thresholds.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            // Each threshold is used once
+            for (i, &threshold) in thresholds.iter().enumerate() {
+                assert_relative_eq!(threshold, (i as f64 + 0.5) / (size * size) as f64);
+            }
+
+            // The matrix repeats
+            assert_eq!(
+                matrix.threshold::<f64>(1, 2),
+                matrix.threshold::<f64>(1 + size, 2 + 2 * size)
+            );
+        }
+
+        assert_eq!(BayerMatrix::Size4x4.threshold::<f64>(0, 0), 0.5 / 16.0);
+        assert_eq!(BayerMatrix::Size4x4.threshold::<f64>(2, 2), 1.5 / 16.0);
+        assert_eq!(BayerMatrix::Size4x4.threshold::<f64>(3, 3), 5.5 / 16.0);
+    }
+
+    #[test]
+    fn bit_depth() {
+        let (color, _) = BitDepth::new(8).quantize(&Srgb::new(0.5, -1.0, 2.0));
+        assert_eq!(color, Srgb::new(128.0 / 255.0, 0.0, 1.0));
+        assert_eq!(color.into_format::<u8>(), Srgb::new(128, 0, 255));
+    }
+
+    #[test]
+    fn fixed_palette_indices() {
+        let palette = [LinSrgb::new(0.0, 0.0, 0.0), LinSrgb::new(1.0, 1.0, 1.0)];
+        let quantizer =
+            FixedPalette::new(&palette, |a: &LinSrgb, b: &LinSrgb| (a.red - b.red).abs());
+
+        // A gradient from black to white
+        let image: Vec<_> = (0..8)
+            .map(|i| {
+                let value = i as f32 / 7.0;
+                LinSrgb::new(value, value, value)
+            })
+            .collect();
+
+        let indices = floyd_steinberg(&image, 8, &quantizer);
+        assert_eq!(indices, vec![0, 0, 0, 1, 0, 1, 1, 1]);
+
+        let indices = ordered(&image, 8, BayerMatrix::Size2x2, 1.0, &quantizer);
+        assert_eq!(indices, vec![0, 0, 0, 1, 0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn error_stays_in_image() {
+        // The error of the last column and row is not spread outside the image
+        let image = vec![LinSrgb::new(0.4, 0.4, 0.4); 6];
+        let colors = floyd_steinberg(&image, 3, &BitDepth::new(1));
+        assert_eq!(colors.len(), 6);
+
+        assert!(floyd_steinberg(&[] as &[LinSrgb], 0, &BitDepth::new(1)).is_empty());
+    }
+
+    #[test]
+    fn to_u8_edges() {
+        let image = [
+            Srgba::new(1.0, 0.0, 0.5, 1.0),
+            Srgba::new(2.0, -1.0, 254.5 / 255.0, 0.999),
+        ];
+
+        for &rounding in &[Rounding::Nearest, Rounding::Ordered(BayerMatrix::Size2x2)] {
+            let mut output = [Srgba::new(0u8, 0, 0, 0); 2];
+            to_u8(&image, 2, rounding, &mut output);
+
+            assert_eq!(output[0].red, 255);
+            assert_eq!(output[0].green, 0);
+            assert_eq!(output[0].alpha, 255);
+            assert_eq!(output[1].red, 255);
+            assert_eq!(output[1].green, 0);
+        }
+
+        let mut output = [Srgba::new(0u8, 0, 0, 0); 2];
+        to_u8(&image, 2, Rounding::Nearest, &mut output);
+        assert_eq!(output[0], image[0].into_format());
+        assert_eq!(output[1], Srgba::new(255, 0, 254, 255));
+    }
+
+    #[test]
+    fn to_u8_dithering() {
+        // Halfway between two values, which is dithered into both
+        let value = 10.5 / 255.0;
+        let image = vec![Srgb::new(value, value, value); 64];
+        let mut output = vec![Srgb::new(0u8, 0, 0); 64];
+        to_u8(
+            &image,
+            8,
+            Rounding::Ordered(BayerMatrix::Size8x8),
+            &mut output,
+        );
+
+        assert!(output
+            .iter()
+            .all(|color| color.red == 10 || color.red == 11));
+        let sum: u32 = output.iter().map(|color| u32::from(color.red)).sum();
+        assert_eq!(sum, 64 * 10 + 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of colors is not a multiple of the image width")]
+    fn wrong_width() {
+        let image = vec![LinSrgb::new(0.4, 0.4, 0.4); 5];
+        floyd_steinberg(&image, 3, &BitDepth::new(1));
+    }
+}
@@ -0,0 +1,209 @@
+//! Conversion between full range and limited range integer components.
+//!
+//! Video signals usually use limited range, also known as TV range or
+//! studio swing, where the lowest and highest values are reserved. For 8 bit
+//! components, black is `16` and white is `235`, and color difference
+//! components, such as in Y'CbCr, use `16` to `240`. Higher bit depths
+//! multiply these values by a power of two, so 10 bit black and white are
+//! `64` and `940`.
+//!
+//! The functions in this module convert a single component of the given bit
+//! depth, stored in a `u16`. The `Rgb` and `Luma` types have
+//! `into_limited_range` and `into_full_range` methods that convert all of
+//! their components:
+//!
+//! ```
+//! use palette::Srgb;
+//!
+//! let limited = Srgb::new(255u8, 128, 0).into_limited_range();
+//! assert_eq!(limited, Srgb::new(235, 126, 16));
+//!
+//! let full = limited.into_full_range();
+//! assert_eq!(full, Srgb::new(255, 128, 0));
+//! ```
+
+/// Convert a full range component to limited range, where the lowest value
+/// is `16` and the highest is `235`, for 8 bits.
+///
+/// Values that are too large for `bit_depth` are clamped.
+///
+/// ```
+/// use palette::encoding::limited_range::into_limited;
+///
+/// assert_eq!(into_limited(0, 8), 16);
+/// assert_eq!(into_limited(255, 8), 235);
+/// assert_eq!(into_limited(1023, 10), 940);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bit_depth` is not between 8 and 16.
+pub fn into_limited(value: u16, bit_depth: u32) -> u16 {
+    let (scale, max) = scale_and_max(bit_depth);
+    let value = u64::from(value).min(max);
+
+    (16 * scale + div_round(219 * scale * value, max)) as u16
+}
+
+/// Convert a limited range component, where the lowest value is `16` and the
+/// highest is `235`, for 8 bits, to full range.
+///
+/// Values in the reserved foot and head room, outside the limited range, are
+/// clamped to black and white.
+///
+/// ```
+/// use palette::encoding::limited_range::from_limited;
+///
+/// assert_eq!(from_limited(16, 8), 0);
+/// assert_eq!(from_limited(235, 8), 255);
+/// assert_eq!(from_limited(940, 10), 1023);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bit_depth` is not between 8 and 16.
+pub fn from_limited(value: u16, bit_depth: u32) -> u16 {
+    let (scale, max) = scale_and_max(bit_depth);
+    let value = u64::from(value).max(16 * scale).min(235 * scale) - 16 * scale;
+
+    div_round(value * max, 219 * scale) as u16
+}
+
+/// Convert a full range color difference component, centered around
+/// `2^(bit_depth - 1)`, to limited range, where the lowest value is `16` and
+/// the highest is `240`, for 8 bits.
+///
+/// ```
+/// use palette::encoding::limited_range::into_limited_chroma;
+///
+/// assert_eq!(into_limited_chroma(0, 8), 16);
+/// assert_eq!(into_limited_chroma(128, 8), 128);
+/// assert_eq!(into_limited_chroma(255, 8), 240);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bit_depth` is not between 8 and 16.
+pub fn into_limited_chroma(value: u16, bit_depth: u32) -> u16 {
+    let (scale, max) = scale_and_max(bit_depth);
+    let (scale, max) = (scale as i64, max as i64);
+    let offset = i64::from(value).min(max) - (max + 1) / 2;
+
+    let limited = 128 * scale + div_round_signed(224 * scale * offset, max);
+    limited.max(16 * scale).min(240 * scale) as u16
+}
+
+/// Convert a limited range color difference component, where the lowest
+/// value is `16` and the highest is `240`, for 8 bits, to full range,
+/// centered around `2^(bit_depth - 1)`.
+///
+/// ```
+/// use palette::encoding::limited_range::from_limited_chroma;
+///
+/// assert_eq!(from_limited_chroma(16, 8), 0);
+/// assert_eq!(from_limited_chroma(128, 8), 128);
+/// assert_eq!(from_limited_chroma(240, 8), 255);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bit_depth` is not between 8 and 16.
+pub fn from_limited_chroma(value: u16, bit_depth: u32) -> u16 {
+    let (scale, max) = scale_and_max(bit_depth);
+    let (scale, max) = (scale as i64, max as i64);
+    let offset = i64::from(value).max(16 * scale).min(240 * scale) - 128 * scale;
+
+    let full = (max + 1) / 2 + div_round_signed(offset * max, 224 * scale);
+    full.max(0).min(max) as u16
+}
+
+/// Returns the factor for scaling 8 bit limited range values to `bit_depth`,
+/// and the maximum full range value.
+fn scale_and_max(bit_depth: u32) -> (u64, u64) {
+    assert!(
+        (8..=16).contains(&bit_depth),
+        "the bit depth must be between 8 and 16, but it was {}",
+        bit_depth
+    );
+
+    (1 << (bit_depth - 8), (1 << bit_depth) - 1)
+}
+
+fn div_round(numerator: u64, denominator: u64) -> u64 {
+    (numerator + denominator / 2) / denominator
+}
+
+fn div_round_signed(numerator: i64, denominator: i64) -> i64 {
+    if numerator < 0 {
+        (numerator - denominator / 2) / denominator
+    } else {
+        (numerator + denominator / 2) / denominator
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_limited, from_limited_chroma, into_limited, into_limited_chroma};
+
+    #[test]
+    fn luma_range() {
+        for &(bit_depth, black, white) in &[(8, 16, 235), (10, 64, 940), (12, 256, 3760)] {
+            let max = (1 << bit_depth) - 1;
+
+            assert_eq!(into_limited(0, bit_depth), black);
+            assert_eq!(into_limited(max, bit_depth), white);
+            assert_eq!(from_limited(black, bit_depth), 0);
+            assert_eq!(from_limited(white, bit_depth), max);
+        }
+    }
+
+    #[test]
+    fn chroma_range() {
+        for &(bit_depth, min, center, max_limited) in
+            &[(8, 16, 128, 240), (10, 64, 512, 960), (12, 256, 2048, 3840)]
+        {
+            let max = (1 << bit_depth) - 1;
+
+            assert_eq!(into_limited_chroma(0, bit_depth), min);
+            assert_eq!(into_limited_chroma(center, bit_depth), center);
+            assert_eq!(into_limited_chroma(max, bit_depth), max_limited);
+            assert_eq!(from_limited_chroma(min, bit_depth), 0);
+            assert_eq!(from_limited_chroma(center, bit_depth), center);
+            assert_eq!(from_limited_chroma(max_limited, bit_depth), max);
+        }
+    }
+
+    #[test]
+    fn clamp_reserved_values() {
+        assert_eq!(from_limited(0, 8), 0);
+        assert_eq!(from_limited(255, 8), 255);
+        assert_eq!(from_limited_chroma(0, 8), 0);
+        assert_eq!(from_limited_chroma(255, 8), 255);
+        assert_eq!(into_limited(2000, 10), 940);
+    }
+
+    #[test]
+    fn round_trip() {
+        // Limited range has fewer levels, so every limited value should
+        // survive a round trip through full range
+        for bit_depth in &[8, 10] {
+            let scale = 1 << (bit_depth - 8);
+
+            for value in 16 * scale..=235 * scale {
+                let full = from_limited(value, *bit_depth);
+                assert_eq!(into_limited(full, *bit_depth), value);
+            }
+
+            for value in 16 * scale..=240 * scale {
+                let full = from_limited_chroma(value, *bit_depth);
+                assert_eq!(into_limited_chroma(full, *bit_depth), value);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_bit_depth() {
+        into_limited(0, 17);
+    }
+}
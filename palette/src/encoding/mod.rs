@@ -5,11 +5,16 @@ use crate::FromF64;
 
 pub use self::gamma::{F2p2, Gamma};
 pub use self::linear::Linear;
+pub use self::ops::EncodedOps;
+pub use self::pq::Pq;
 pub use self::srgb::Srgb;
 
 pub mod gamma;
+pub mod limited_range;
 pub mod linear;
+pub mod ops;
 pub mod pixel;
+pub mod pq;
 pub mod srgb;
 
 /// A transfer function to and from linear space.
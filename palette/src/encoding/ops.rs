@@ -0,0 +1,143 @@
+//! Arithmetic on encoded color components.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::ComponentWise;
+
+/// A wrapper that allows arithmetic on the encoded components of a color.
+///
+/// The arithmetic operators are only implemented for linear colors, since
+/// adding or scaling encoded values, such as sRGB, doesn't add or scale the
+/// amount of light. That's still useful for working with the encoded values
+/// themselves, for example when calculating the difference between two
+/// pixels in a codec. `EncodedOps` makes the operators available for any
+/// color, similar to how [`Wrapping`](core::num::Wrapping) changes the
+/// behavior of integer arithmetic, while making it clear that the result
+/// isn't physically meaningful.
+///
+/// ```
+/// use palette::encoding::EncodedOps;
+/// use palette::Srgb;
+///
+/// let a = EncodedOps(Srgb::new(200u8, 100, 50));
+/// let b = EncodedOps(Srgb::new(190u8, 100, 40));
+///
+/// let delta = a - b;
+/// assert_eq!(delta.0, Srgb::new(10, 0, 10));
+/// assert_eq!(b + delta, a);
+/// ```
+///
+/// The operations are applied to each component with the component type's
+/// own operator, so integer components may overflow.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct EncodedOps<C>(pub C);
+
+impl<C> EncodedOps<C> {
+    /// Unwrap the color.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C> From<C> for EncodedOps<C> {
+    fn from(color: C) -> Self {
+        EncodedOps(color)
+    }
+}
+
+macro_rules! impl_encoded_op {
+    ($op: ident, $op_fn: ident, $op_assign: ident, $op_assign_fn: ident) => {
+        impl<C> $op for EncodedOps<C>
+        where
+            C: ComponentWise,
+            C::Scalar: $op<Output = C::Scalar>,
+        {
+            type Output = Self;
+
+            fn $op_fn(self, other: Self) -> Self {
+                EncodedOps(self.0.component_wise(&other.0, |a, b| a.$op_fn(b)))
+            }
+        }
+
+        impl<C> $op_assign for EncodedOps<C>
+        where
+            C: ComponentWise,
+            C::Scalar: $op<Output = C::Scalar>,
+        {
+            fn $op_assign_fn(&mut self, other: Self) {
+                self.0 = self.0.component_wise(&other.0, |a, b| a.$op_fn(b));
+            }
+        }
+    };
+}
+
+impl_encoded_op!(Add, add, AddAssign, add_assign);
+impl_encoded_op!(Sub, sub, SubAssign, sub_assign);
+impl_encoded_op!(Mul, mul, MulAssign, mul_assign);
+impl_encoded_op!(Div, div, DivAssign, div_assign);
+
+impl<C> EncodedOps<C>
+where
+    C: ComponentWise,
+    C::Scalar: Clone,
+{
+    /// Multiply each component with `factor`.
+    ///
+    /// ```
+    /// use palette::encoding::EncodedOps;
+    /// use palette::Srgb;
+    ///
+    /// let color = EncodedOps(Srgb::new(0.2, 0.4, 0.8));
+    /// assert_eq!(color.scale(0.5).0, Srgb::new(0.1, 0.2, 0.4));
+    /// ```
+    pub fn scale(&self, factor: C::Scalar) -> Self
+    where
+        C::Scalar: Mul<Output = C::Scalar>,
+    {
+        EncodedOps(self.0.component_wise_self(|x| x * factor.clone()))
+    }
+
+    /// Add `offset` to each component.
+    pub fn offset(&self, offset: C::Scalar) -> Self
+    where
+        C::Scalar: Add<Output = C::Scalar>,
+    {
+        EncodedOps(self.0.component_wise_self(|x| x + offset.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EncodedOps;
+    use crate::{Srgb, SrgbLuma, Srgba};
+
+    #[test]
+    fn arithmetic() {
+        let a = EncodedOps(Srgb::new(0.5f64, 0.25, 1.0));
+        let b = EncodedOps(Srgb::new(0.25f64, 0.5, 0.5));
+
+        assert_eq!((a + b).0, Srgb::new(0.75, 0.75, 1.5));
+        assert_eq!((a - b).0, Srgb::new(0.25, -0.25, 0.5));
+        assert_eq!((a * b).0, Srgb::new(0.125, 0.125, 0.5));
+        assert_eq!((a / b).0, Srgb::new(2.0, 0.5, 2.0));
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn alpha_and_luma() {
+        let a = EncodedOps(Srgba::new(10u8, 20, 30, 255));
+        let b = EncodedOps(Srgba::new(1u8, 2, 3, 0));
+        assert_eq!((a - b).0, Srgba::new(9, 18, 27, 255));
+
+        let luma = EncodedOps(SrgbLuma::new(0.5f32));
+        assert_eq!(
+            luma.scale(0.5).offset(0.25).into_inner(),
+            SrgbLuma::new(0.5)
+        );
+    }
+}
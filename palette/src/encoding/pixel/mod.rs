@@ -3,8 +3,14 @@
 #[doc(hidden)]
 pub use palette_derive::Pixel;
 
+use core::fmt;
+use core::slice::{Chunks, ChunksMut};
+
 pub use self::raw::*;
+pub use self::strided::{Strided, StridedMut};
+
 mod raw;
+mod strided;
 
 /// Represents colors that can be serialized and deserialized from raw color
 /// components.
@@ -199,6 +205,107 @@ pub unsafe trait Pixel<T>: Sized {
         unsafe { ::core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self, new_length) }
     }
 
+    /// Cast a slice of raw color components to a slice of colors, or return
+    /// an error if its length isn't a multiple of the number of channels.
+    ///
+    /// This is the same as [`from_raw_slice`](Pixel::from_raw_slice), but
+    /// for buffers that may be cut off or have the wrong format.
+    ///
+    /// ```rust
+    /// use palette::{Pixel, Srgb};
+    ///
+    /// let raw = &[255u8, 128, 64, 10, 20, 30];
+    /// assert_eq!(Srgb::try_from_raw_slice(raw).unwrap().len(), 2);
+    ///
+    /// let error = Srgb::try_from_raw_slice(&raw[..5]).unwrap_err();
+    /// assert_eq!(error.length, 5);
+    /// assert_eq!(error.channels, 3);
+    /// ```
+    #[inline]
+    fn try_from_raw_slice(slice: &[T]) -> Result<&[Self], SliceLengthError> {
+        check_slice_length::<T, Self>(slice.len())?;
+        Ok(Self::from_raw_slice(slice))
+    }
+
+    /// Cast a mutable slice of raw color components to a mutable slice of
+    /// colors, or return an error if its length isn't a multiple of the
+    /// number of channels.
+    ///
+    /// This is the same as [`from_raw_slice_mut`](Pixel::from_raw_slice_mut),
+    /// but for buffers that may be cut off or have the wrong format.
+    ///
+    /// ```rust
+    /// use palette::{Pixel, Srgb};
+    ///
+    /// let raw = &mut [255u8, 128, 64, 10, 20, 30];
+    /// for color in Srgb::try_from_raw_slice_mut(raw).unwrap() {
+    ///     color.red = 0;
+    /// }
+    ///
+    /// assert_eq!(raw, &[0, 128, 64, 0, 20, 30]);
+    /// assert!(Srgb::try_from_raw_slice_mut(&mut raw[1..]).is_err());
+    /// ```
+    #[inline]
+    fn try_from_raw_slice_mut(slice: &mut [T]) -> Result<&mut [Self], SliceLengthError> {
+        check_slice_length::<T, Self>(slice.len())?;
+        Ok(Self::from_raw_slice_mut(slice))
+    }
+
+    /// Cast a slice of raw color components to an iterator over chunks of
+    /// `chunk_size` colors. The last chunk is shorter if the number of colors
+    /// isn't a multiple of `chunk_size`.
+    ///
+    /// A chunk size of the image width gives an iterator over its rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0, or if the length of `slice` isn't a
+    /// multiple of the number of channels.
+    ///
+    /// ```rust
+    /// use palette::{Pixel, Srgb};
+    ///
+    /// let raw = &[255u8, 128, 64, 10, 20, 30, 0, 0, 0];
+    /// let mut rows = Srgb::from_raw_slice_chunks(raw, 2);
+    ///
+    /// assert_eq!(rows.next().unwrap().len(), 2);
+    /// assert_eq!(rows.next().unwrap(), &[Srgb::new(0, 0, 0)]);
+    /// assert!(rows.next().is_none());
+    /// ```
+    #[inline]
+    fn from_raw_slice_chunks(slice: &[T], chunk_size: usize) -> Chunks<'_, Self> {
+        Self::from_raw_slice(slice).chunks(chunk_size)
+    }
+
+    /// Cast a mutable slice of raw color components to an iterator over
+    /// mutable chunks of `chunk_size` colors. The last chunk is shorter if
+    /// the number of colors isn't a multiple of `chunk_size`.
+    ///
+    /// The chunks don't overlap, so they can be processed in parallel, for
+    /// example by sending them to different threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0, or if the length of `slice` isn't a
+    /// multiple of the number of channels.
+    ///
+    /// ```rust
+    /// use palette::{Pixel, Srgb};
+    ///
+    /// let raw = &mut [255u8, 128, 64, 10, 20, 30, 0, 0, 0, 1, 2, 3];
+    /// for (y, row) in Srgb::from_raw_slice_chunks_mut(raw, 2).enumerate() {
+    ///     for color in row {
+    ///         color.red = y as u8;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(raw, &[0, 128, 64, 0, 20, 30, 1, 0, 0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from_raw_slice_chunks_mut(slice: &mut [T], chunk_size: usize) -> ChunksMut<'_, Self> {
+        Self::from_raw_slice_mut(slice).chunks_mut(chunk_size)
+    }
+
     /// Cast a slice of colors to a slice of raw color components.
     ///
     /// ```rust
@@ -241,3 +348,40 @@ pub unsafe trait Pixel<T>: Sized {
         unsafe { ::core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, new_length) }
     }
 }
+
+/// The error type for casting a slice of raw color components with a length
+/// that isn't a multiple of the number of channels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SliceLengthError {
+    /// The length of the slice.
+    pub length: usize,
+
+    /// The number of channels in each color.
+    pub channels: usize,
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a slice length of {} is not a multiple of {} color channels",
+            self.length, self.channels
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceLengthError {}
+
+fn check_slice_length<T, P: Pixel<T>>(length: usize) -> Result<(), SliceLengthError> {
+    let remainder = length % P::CHANNELS;
+
+    if remainder == 0 {
+        Ok(())
+    } else {
+        Err(SliceLengthError {
+            length,
+            channels: P::CHANNELS,
+        })
+    }
+}
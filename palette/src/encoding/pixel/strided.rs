@@ -0,0 +1,340 @@
+use core::marker::PhantomData;
+
+use super::Pixel;
+
+/// A view of a buffer of raw pixel data, where each row may be followed by
+/// padding.
+///
+/// Textures, framebuffers and other image memory often align each row, so
+/// the distance between the start of two rows, the row stride, is larger than
+/// the width of the image. This view skips the padding, and lets each row be
+/// accessed as a slice of colors. The row stride is counted in components of
+/// type `T`, not in bytes or pixels.
+///
+/// ```
+/// use palette::Srgb;
+/// use palette::encoding::pixel::Strided;
+///
+/// // Two rows with two pixels each, padded to eight components per row
+/// let buffer = [
+///     255u8, 0, 0, 0, 255, 0, /* padding: */ 0, 0,
+///     0, 0, 255, 255, 255, 255, /* padding: */ 0, 0,
+/// ];
+/// let image = Strided::<Srgb<u8>, _>::new(&buffer, 2, 2, 8);
+///
+/// assert_eq!(image.row(1), &[Srgb::new(0, 0, 255), Srgb::new(255, 255, 255)]);
+/// assert_eq!(image.get(1, 0), Some(&Srgb::new(0, 255, 0)));
+/// assert_eq!(image.rows().count(), 2);
+/// ```
+///
+/// Pixels with a different channel order than the color type, such as BGRA,
+/// can be read from the raw rows, using any color type with the same number
+/// of channels for the view, and converted using [`Packed`](crate::Packed):
+///
+/// ```
+/// use palette::{Packed, Srgba};
+/// use palette::encoding::pixel::Strided;
+/// use palette::rgb::channels::Bgra;
+///
+/// let buffer = [255u8, 128, 0, 255, /* padding: */ 0, 0, 0, 0];
+/// let image = Strided::<Srgba<u8>, _>::new(&buffer, 1, 1, 8);
+///
+/// let pixel = image.raw_row(0);
+/// let packed = Packed::<Bgra>::from([pixel[0], pixel[1], pixel[2], pixel[3]]);
+/// assert_eq!(Srgba::from(packed), Srgba::new(0, 128, 255, 255));
+/// ```
+#[derive(Debug)]
+pub struct Strided<'a, C, T> {
+    data: &'a [T],
+    width: usize,
+    height: usize,
+    row_stride: usize,
+    color: PhantomData<C>,
+}
+
+impl<'a, C, T> Strided<'a, C, T>
+where
+    C: Pixel<T>,
+{
+    /// Create a view of `data`, with `height` rows of `width` pixels each,
+    /// where each row starts `row_stride` components after the previous.
+    ///
+    /// The last row doesn't need to be followed by any padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_stride` is zero or smaller than a row of pixels, or if
+    /// `data` is too short.
+    pub fn new(data: &'a [T], width: usize, height: usize, row_stride: usize) -> Self {
+        check_layout::<C, T>(data.len(), width, height, row_stride);
+
+        Strided {
+            data,
+            width,
+            height,
+            row_stride,
+            color: PhantomData,
+        }
+    }
+
+    /// The number of pixels in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance, in components, between the start of two rows.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    /// Get the components of row `y`, without the padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is outside the view.
+    pub fn raw_row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "the row index is out of bounds");
+        let start = y * self.row_stride;
+        &self.data[start..start + self.width * C::CHANNELS]
+    }
+
+    /// Get the pixels of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is outside the view.
+    pub fn row(&self, y: usize) -> &'a [C] {
+        C::from_raw_slice(self.raw_row(y))
+    }
+
+    /// Iterate over the rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [C]> + 'a
+    where
+        C: 'a,
+    {
+        let row_length = self.width * C::CHANNELS;
+
+        self.data
+            .chunks(self.row_stride)
+            .take(self.height)
+            .map(move |row| C::from_raw_slice(&row[..row_length]))
+    }
+
+    /// Get the pixel at column `x` of row `y`, or `None` if it's outside the
+    /// view.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a C> {
+        if x < self.width && y < self.height {
+            Some(&self.row(y)[x])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, C, T> Clone for Strided<'a, C, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C, T> Copy for Strided<'a, C, T> {}
+
+/// A mutable view of a buffer of raw pixel data, where each row may be
+/// followed by padding.
+///
+/// This is the mutable version of [`Strided`]. The padding is never modified.
+///
+/// ```
+/// use palette::Srgba;
+/// use palette::encoding::pixel::StridedMut;
+///
+/// let mut buffer = [0u8; 12];
+/// {
+///     let mut image = StridedMut::<Srgba<u8>, _>::new(&mut buffer, 1, 2, 8);
+///
+///     for row in image.rows_mut() {
+///         row[0] = Srgba::new(1, 2, 3, 255);
+///     }
+/// }
+///
+/// assert_eq!(buffer, [1, 2, 3, 255, 0, 0, 0, 0, 1, 2, 3, 255]);
+/// ```
+#[derive(Debug)]
+pub struct StridedMut<'a, C, T> {
+    data: &'a mut [T],
+    width: usize,
+    height: usize,
+    row_stride: usize,
+    color: PhantomData<C>,
+}
+
+impl<'a, C, T> StridedMut<'a, C, T>
+where
+    C: Pixel<T>,
+{
+    /// Create a mutable view of `data`, with `height` rows of `width` pixels
+    /// each, where each row starts `row_stride` components after the previous.
+    ///
+    /// The last row doesn't need to be followed by any padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_stride` is zero or smaller than a row of pixels, or if
+    /// `data` is too short.
+    pub fn new(data: &'a mut [T], width: usize, height: usize, row_stride: usize) -> Self {
+        check_layout::<C, T>(data.len(), width, height, row_stride);
+
+        StridedMut {
+            data,
+            width,
+            height,
+            row_stride,
+            color: PhantomData,
+        }
+    }
+
+    /// The number of pixels in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance, in components, between the start of two rows.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    /// Borrow the view as an immutable [`Strided`] view.
+    pub fn as_strided(&self) -> Strided<'_, C, T> {
+        Strided {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            row_stride: self.row_stride,
+            color: PhantomData,
+        }
+    }
+
+    /// Get the components of row `y`, without the padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is outside the view.
+    pub fn raw_row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "the row index is out of bounds");
+        let start = y * self.row_stride;
+        &mut self.data[start..start + self.width * C::CHANNELS]
+    }
+
+    /// Get the pixels of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is outside the view.
+    pub fn row_mut(&mut self, y: usize) -> &mut [C] {
+        C::from_raw_slice_mut(self.raw_row_mut(y))
+    }
+
+    /// Iterate over the rows, from top to bottom.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [C]> + '_ {
+        let row_length = self.width * C::CHANNELS;
+
+        self.data
+            .chunks_mut(self.row_stride)
+            .take(self.height)
+            .map(move |row| C::from_raw_slice_mut(&mut row[..row_length]))
+    }
+
+    /// Get the pixel at column `x` of row `y`, or `None` if it's outside the
+    /// view.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut C> {
+        if x < self.width && y < self.height {
+            Some(&mut self.row_mut(y)[x])
+        } else {
+            None
+        }
+    }
+}
+
+fn check_layout<C: Pixel<T>, T>(length: usize, width: usize, height: usize, row_stride: usize) {
+    let row_length = width * C::CHANNELS;
+
+    assert!(row_stride > 0, "the row stride can't be zero");
+    assert!(
+        row_stride >= row_length,
+        "the row stride is shorter than a row of pixels"
+    );
+
+    if height > 0 {
+        assert!(
+            length >= (height - 1) * row_stride + row_length,
+            "the buffer is too short for the image size"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Strided, StridedMut};
+    use crate::{LinSrgb, Srgb};
+
+    #[test]
+    fn rows() {
+        let buffer: Vec<u8> = (0..20).collect();
+        let image = Strided::<Srgb<u8>, _>::new(&buffer, 2, 3, 7);
+
+        let rows: Vec<_> = image.rows().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], &[Srgb::new(0, 1, 2), Srgb::new(3, 4, 5)]);
+        assert_eq!(rows[2], &[Srgb::new(14, 15, 16), Srgb::new(17, 18, 19)]);
+        assert_eq!(image.row(1), rows[1]);
+        assert_eq!(image.raw_row(1), &[7, 8, 9, 10, 11, 12]);
+
+        assert_eq!(image.get(1, 1), Some(&Srgb::new(10, 11, 12)));
+        assert_eq!(image.get(2, 1), None);
+        assert_eq!(image.get(0, 3), None);
+    }
+
+    #[test]
+    fn rows_mut() {
+        let mut buffer = [0.5f32; 10];
+        let mut image = StridedMut::<LinSrgb, _>::new(&mut buffer, 1, 2, 5);
+
+        *image.get_mut(0, 1).unwrap() = LinSrgb::new(1.0, 2.0, 3.0);
+        for row in image.rows_mut() {
+            row[0].red = 0.0;
+        }
+
+        assert_eq!(image.as_strided().row(0), &[LinSrgb::new(0.0, 0.5, 0.5)]);
+        assert_eq!(buffer, [0.0, 0.5, 0.5, 0.5, 0.5, 0.0, 2.0, 3.0, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn empty() {
+        let image = Strided::<Srgb<u8>, u8>::new(&[], 4, 0, 12);
+        assert_eq!(image.rows().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the buffer is too short for the image size")]
+    fn too_short() {
+        let buffer = [0u8; 11];
+        Strided::<Srgb<u8>, _>::new(&buffer, 2, 2, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "the row stride is shorter than a row of pixels")]
+    fn short_stride() {
+        let buffer = [0u8; 12];
+        Strided::<Srgb<u8>, _>::new(&buffer, 2, 2, 5);
+    }
+}
@@ -0,0 +1,91 @@
+//! The perceptual quantizer (PQ) encoding, from SMPTE ST 2084.
+
+use core::marker::PhantomData;
+
+use crate::encoding::TransferFn;
+use crate::float::Float;
+use crate::rgb::{RgbSpace, RgbStandard};
+use crate::{from_f64, FromF64};
+
+/// The luminance, in cd/m², that is represented by the linear value `1.0` in
+/// PQ encoded colors.
+pub const PQ_MAX_LUMINANCE: f64 = 10000.0;
+
+/// The perceptual quantizer (PQ) encoding, from SMPTE ST 2084.
+///
+/// PQ is used for high dynamic range video, such as HDR10 and Dolby Vision,
+/// and encodes absolute luminance. The linear value `1.0` represents
+/// [`PQ_MAX_LUMINANCE`] (10000 cd/m²), so linear colors need to be scaled to
+/// that range before they are encoded. The
+/// [`Absolute`](crate::hdr::Absolute) wrapper can take care of the scaling.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Pq<S>(PhantomData<S>);
+
+impl<S: RgbSpace> RgbStandard for Pq<S> {
+    type Space = S;
+    type TransferFn = PqFn;
+}
+
+/// The transfer function for PQ encoded colors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PqFn;
+
+impl TransferFn for PqFn {
+    fn into_linear<T: Float + FromF64>(x: T) -> T {
+        pq_decode(x, from_f64(M2))
+    }
+
+    fn from_linear<T: Float + FromF64>(x: T) -> T {
+        pq_encode(x, from_f64(M2))
+    }
+}
+
+pub(crate) const M1: f64 = 2610.0 / 16384.0;
+pub(crate) const M2: f64 = 2523.0 / 4096.0 * 128.0;
+pub(crate) const C1: f64 = 3424.0 / 4096.0;
+pub(crate) const C2: f64 = 2413.0 / 4096.0 * 32.0;
+pub(crate) const C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The PQ curve with a custom exponent, `m2`, which differs between PQ and
+/// Jzazbz.
+pub(crate) fn pq_encode<T: Float + FromF64>(x: T, m2: T) -> T {
+    let linear = x.max(T::zero()).powf(from_f64(M1));
+    let numerator = from_f64::<T>(C1) + from_f64::<T>(C2) * linear;
+    let denominator = T::one() + from_f64::<T>(C3) * linear;
+
+    (numerator / denominator).powf(m2)
+}
+
+/// The inverse of [`pq_encode`].
+pub(crate) fn pq_decode<T: Float + FromF64>(x: T, m2: T) -> T {
+    let encoded = x.max(T::zero()).powf(T::one() / m2);
+    let numerator = (encoded - from_f64(C1)).max(T::zero());
+    let denominator = from_f64::<T>(C2) - from_f64::<T>(C3) * encoded;
+
+    (numerator / denominator).powf(T::one() / from_f64(M1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PqFn, PQ_MAX_LUMINANCE};
+    use crate::encoding::TransferFn;
+
+    #[test]
+    fn reference_values() {
+        assert_relative_eq!(PqFn::from_linear(0.0f64), 0.0, epsilon = 1e-6);
+        assert_relative_eq!(PqFn::from_linear(1.0f64), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(
+            PqFn::from_linear(100.0 / PQ_MAX_LUMINANCE),
+            0.508078,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for &x in &[0.0f64, 0.0001, 0.01, 0.2, 0.5, 1.0] {
+            let encoded = PqFn::from_linear(x);
+            assert_relative_eq!(PqFn::into_linear(encoded), x, epsilon = 1e-10);
+        }
+    }
+}
@@ -57,3 +57,78 @@ impl TransferFn for Srgb {
         }
     }
 }
+
+include!(concat!(env!("OUT_DIR"), "/srgb_lut.rs"));
+
+/// Convert an 8 bit sRGB component into linear space, using a lookup table.
+#[inline]
+pub(crate) fn u8_into_linear<T: FromF64>(x: u8) -> T {
+    from_f64(SRGB_U8_TO_LINEAR[x as usize])
+}
+
+/// Convert the linear component `x` to an 8 bit sRGB component, rounded to
+/// the nearest value, using lookup tables.
+#[inline]
+pub(crate) fn u8_from_linear<T: Float + FromF64>(x: T) -> u8 {
+    // NaN is also treated as 0
+    if x.is_nan() || x <= T::zero() {
+        return 0;
+    }
+
+    if x >= T::one() {
+        return 255;
+    }
+
+    let buckets: T = from_f64(SRGB_LINEAR_TO_U8.len() as f64);
+    let bucket = (x * buckets).to_usize().unwrap_or(0);
+    let encoded = SRGB_LINEAR_TO_U8[bucket];
+
+    // Each bucket contains at most one rounding threshold
+    match SRGB_U8_THRESHOLDS.get(encoded as usize) {
+        Some(&threshold) if x > from_f64(threshold) => encoded + 1,
+        _ => encoded,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{u8_from_linear, u8_into_linear, Srgb};
+    use crate::encoding::TransferFn;
+
+    #[test]
+    fn u8_lookup_into_linear() {
+        for encoded in 0..=255u8 {
+            let expected: f64 = Srgb::into_linear(f64::from(encoded) / 255.0);
+            assert_relative_eq!(u8_into_linear::<f64>(encoded), expected, epsilon = 1e-12);
+            assert_relative_eq!(u8_into_linear::<f32>(encoded), expected as f32);
+        }
+    }
+
+    #[test]
+    fn u8_lookup_from_linear() {
+        for encoded in 0..=255u8 {
+            assert_eq!(u8_from_linear(u8_into_linear::<f32>(encoded)), encoded);
+            assert_eq!(u8_from_linear(u8_into_linear::<f64>(encoded)), encoded);
+        }
+
+        for step in 0..=10000 {
+            let linear = step as f64 / 10000.0;
+            let expected = (Srgb::from_linear(linear) * 255.0).round() as u8;
+            assert_eq!(u8_from_linear(linear), expected);
+        }
+
+        // Compare with counting the thresholds below each value
+        for step in 0..=100_000 {
+            let linear = step as f32 / 100_000.0;
+            let expected = super::SRGB_U8_THRESHOLDS
+                .iter()
+                .filter(|&&threshold| (threshold as f32) < linear)
+                .count();
+            assert_eq!(u8_from_linear(linear), expected as u8);
+        }
+
+        assert_eq!(u8_from_linear(-1.0f32), 0);
+        assert_eq!(u8_from_linear(2.0f32), 255);
+        assert_eq!(u8_from_linear(f32::NAN), 0);
+    }
+}
@@ -3,6 +3,11 @@
 //! This module will just re-export the currently used floating point trait.
 //! Both for use in derive macros and for anyone who don't want to add it as an
 //! additional dependency.
+//!
+//! The `Float` trait is provided by `num_traits` and is backed by either the
+//! standard library (the `std` feature) or by [`libm`](https://crates.io/crates/libm)
+//! (the `libm` feature). The latter makes the full set of float conversions,
+//! including transfer functions and `Lab`, available in `#![no_std]` builds.
 
 #[cfg(any(feature = "std", feature = "libm"))]
 pub use num_traits::Float;
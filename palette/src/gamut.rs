@@ -0,0 +1,244 @@
+//! Gamut mapping of colors that can't be represented in an RGB space.
+//!
+//! Colors that are outside the gamut of an RGB space, such as a very
+//! saturated L\*C\*h° color, are often made displayable by clamping each RGB
+//! component. That can change the hue of the color noticeably. The functions
+//! in this module reduce the chroma of the color instead, while keeping its
+//! lightness and hue, until it fits within the gamut:
+//!
+//! ```
+//! use palette::{Lch, Srgb};
+//! use palette::gamut::map_into_gamut;
+//!
+//! let color = Lch::new(50.0, 150.0, 30.0);
+//! let mapped: Srgb = map_into_gamut(color);
+//!
+//! assert!(mapped.red <= 1.0 && mapped.green >= 0.0 && mapped.blue >= 0.0);
+//! ```
+//!
+//! The algorithm is the binary search from CSS Color Module Level 4, adapted
+//! to CIE L\*C\*h°. It searches for the chroma where clamping the color changes
+//! it by less than a just noticeable difference, so the result is as
+//! colorful as possible without visibly shifting the hue.
+//!
+//! Whether a color needs to be mapped can be checked using
+//! [`IsWithinGamut`]:
+//!
+//! ```
+//! use palette::{Lab, Srgb};
+//! use palette::gamut::IsWithinGamut;
+//!
+//! assert!(Lab::new(50.0, 10.0, 10.0).is_within_gamut::<Srgb>());
+//! assert!(!Lab::new(50.0, 100.0, 10.0).is_within_gamut::<Srgb>());
+//! ```
+
+use crate::convert::FromColorUnclamped;
+use crate::rgb::{Rgb, RgbSpace, RgbStandard};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, Lab, Lch, Limited};
+
+/// Check if a color is within the gamut of another color type.
+///
+/// This is implemented for every color type. The check converts the color,
+/// without clamping, and checks if the result is
+/// [valid](crate::Limited::is_valid). Note that rounding errors may make
+/// colors on the boundary of the gamut, such as pure white, appear to be
+/// slightly outside it.
+pub trait IsWithinGamut: Sized + Clone {
+    /// Check if `self` can be represented as `C` without clamping.
+    ///
+    /// ```
+    /// use palette::{Hsv, Lch, LinSrgb, Srgb};
+    /// use palette::gamut::IsWithinGamut;
+    ///
+    /// assert!(Lch::new(50.0, 30.0, 120.0).is_within_gamut::<Srgb>());
+    /// assert!(!Lch::new(50.0, 130.0, 120.0).is_within_gamut::<LinSrgb>());
+    /// assert!(Srgb::new(0.8, 0.1, 0.4).is_within_gamut::<Hsv>());
+    /// ```
+    fn is_within_gamut<C>(&self) -> bool
+    where
+        C: FromColorUnclamped<Self> + Limited,
+    {
+        C::from_color_unclamped(self.clone()).is_valid()
+    }
+}
+
+impl<T: Clone> IsWithinGamut for T {}
+
+/// The difference, in CIE76 ΔE\*, that is considered just noticeable.
+const JUST_NOTICEABLE_DIFFERENCE: f64 = 2.0;
+
+/// The precision of the chroma search, and of how close to
+/// `JUST_NOTICEABLE_DIFFERENCE` a clamped color needs to be to be accepted.
+const EPSILON: f64 = 0.001;
+
+/// Convert `color` to the RGB standard `S`, and reduce its chroma, in CIE
+/// L\*C\*h°, until it's within the gamut of `S`.
+///
+/// The lightness and hue are kept, except for colors that are lighter than
+/// white or darker than black, which become white or black. Colors that are
+/// already within the gamut are only converted.
+///
+/// ```
+/// use palette::{FromColor, Lch, Srgb};
+/// use palette::gamut::map_into_gamut;
+///
+/// let color = Srgb::new(0.2, 0.6, 0.8);
+/// let mapped: Srgb = map_into_gamut(Lch::from_color(color));
+///
+/// assert_relative_eq!(mapped, color, epsilon = 0.0001);
+/// # use approx::assert_relative_eq;
+/// ```
+pub fn map_into_gamut<S, Wp, T, C>(color: C) -> Rgb<S, T>
+where
+    S: RgbStandard,
+    S::Space: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: FloatComponent,
+    C: IntoLch<Wp, T>,
+    Rgb<S, T>: FromColorUnclamped<Lch<Wp, T>>,
+    Lab<Wp, T>: FromColorUnclamped<Rgb<S, T>>,
+{
+    let mut current = color.into_lch();
+
+    if current.l >= from_f64(100.0) {
+        return Rgb::new(T::one(), T::one(), T::one());
+    }
+
+    if current.l <= T::zero() {
+        return Rgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    let rgb = Rgb::<S, T>::from_color_unclamped(current);
+    if rgb.is_valid() {
+        return rgb;
+    }
+
+    let just_noticeable: T = from_f64(JUST_NOTICEABLE_DIFFERENCE);
+    let epsilon: T = from_f64(EPSILON);
+
+    let mut clamped = rgb.clamp();
+    if difference(current, clamped) < just_noticeable {
+        return clamped;
+    }
+
+    let mut min = T::zero();
+    let mut max = current.chroma;
+    let mut min_in_gamut = true;
+
+    while max - min > epsilon {
+        current.chroma = (min + max) * from_f64(0.5);
+        let rgb = Rgb::<S, T>::from_color_unclamped(current);
+
+        if min_in_gamut && rgb.is_valid() {
+            min = current.chroma;
+            continue;
+        }
+
+        clamped = rgb.clamp();
+        let error = difference(current, clamped);
+
+        if error < just_noticeable {
+            if just_noticeable - error < epsilon {
+                return clamped;
+            }
+
+            min_in_gamut = false;
+            min = current.chroma;
+        } else {
+            max = current.chroma;
+        }
+    }
+
+    clamped
+}
+
+/// Colors that can be converted to CIE L\*C\*h° for gamut mapping.
+///
+/// This is implemented for every color that can be converted to `Lch`,
+/// including `Lch` itself.
+pub trait IntoLch<Wp: WhitePoint, T: FloatComponent> {
+    /// Convert the color to CIE L\*C\*h°, without clamping.
+    fn into_lch(self) -> Lch<Wp, T>;
+}
+
+impl<Wp, T, C> IntoLch<Wp, T> for C
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+    Lch<Wp, T>: FromColorUnclamped<C>,
+{
+    fn into_lch(self) -> Lch<Wp, T> {
+        Lch::from_color_unclamped(self)
+    }
+}
+
+/// The CIE76 color difference between `color` and `clamped`.
+fn difference<S, Wp, T>(color: Lch<Wp, T>, clamped: Rgb<S, T>) -> T
+where
+    S: RgbStandard,
+    Wp: WhitePoint,
+    T: FloatComponent,
+    Lab<Wp, T>: FromColorUnclamped<Rgb<S, T>>,
+{
+    let color = <Lab<Wp, T> as FromColorUnclamped<Lch<Wp, T>>>::from_color_unclamped(color);
+    let clamped = Lab::<Wp, T>::from_color_unclamped(clamped);
+
+    let l = color.l - clamped.l;
+    let a = color.a - clamped.a;
+    let b = color.b - clamped.b;
+
+    (l * l + a * a + b * b).sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{map_into_gamut, IsWithinGamut};
+    use crate::convert::FromColorUnclamped;
+    use crate::{FromColor, Lch, Limited, LinSrgb, Srgb};
+
+    #[test]
+    fn in_gamut() {
+        let color = Srgb::new(0.2, 0.6, 0.8);
+        let mapped: Srgb = map_into_gamut(color);
+        assert_relative_eq!(mapped, color, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn keeps_lightness_and_hue() {
+        let color = Lch::new(60.0, 130.0, 250.0);
+        let mapped: Srgb = map_into_gamut(color);
+        assert!(mapped.is_valid());
+
+        let mapped = Lch::from_color(mapped);
+        assert_relative_eq!(mapped.l, color.l, epsilon = 1.0);
+        assert!(mapped.chroma < color.chroma);
+
+        // Clamping each component shifts the hue much more
+        let clamped = Lch::from_color(Srgb::from_color_unclamped(color).clamp());
+        let mapped_shift = (mapped.hue - color.hue).to_degrees().abs();
+        let clamped_shift = (clamped.hue - color.hue).to_degrees().abs();
+        assert!(mapped_shift < clamped_shift / 2.0);
+    }
+
+    #[test]
+    fn within_gamut() {
+        let color = Lch::new(60.0, 130.0, 250.0);
+        assert!(!color.is_within_gamut::<Srgb>());
+
+        let mapped: Srgb = map_into_gamut(color);
+        assert!(mapped.is_within_gamut::<Srgb>());
+
+        assert!(Lch::new(60.0, 20.0, 250.0).is_within_gamut::<Srgb<f64>>());
+        assert!(!Srgb::new(1.2, 0.5, 0.5).is_within_gamut::<LinSrgb>());
+    }
+
+    #[test]
+    fn white_and_black() {
+        let white: LinSrgb = map_into_gamut(Lch::new(110.0, 50.0, 30.0));
+        assert_eq!(white, LinSrgb::new(1.0, 1.0, 1.0));
+
+        let black: LinSrgb = map_into_gamut(Lch::new(-5.0, 50.0, 30.0));
+        assert_eq!(black, LinSrgb::new(0.0, 0.0, 0.0));
+    }
+}
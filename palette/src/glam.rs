@@ -0,0 +1,75 @@
+//! Conversion between Palette colors and the vector types of `glam`.
+
+use ::glam::{DVec3, DVec4, Vec3, Vec4};
+
+use crate::rgb::{Rgb, RgbStandard};
+use crate::white_point::WhitePoint;
+use crate::{Alpha, Lab, Xyz, Yxy};
+
+macro_rules! impl_glam {
+    ($($color: ident <$param: ident: $bound: ident>),+) => {
+        $(
+            impl_glam!(@impl $color<$param: $bound>, f32, Vec3, Vec4);
+            impl_glam!(@impl $color<$param: $bound>, f64, DVec3, DVec4);
+        )+
+    };
+    (@impl $color: ident <$param: ident: $bound: ident>, $t: ty, $vec3: ident, $vec4: ident) => {
+        impl<$param: $bound> From<$color<$param, $t>> for $vec3 {
+            fn from(color: $color<$param, $t>) -> Self {
+                let [x, y, z]: [$t; 3] = color.into();
+                $vec3::new(x, y, z)
+            }
+        }
+
+        impl<$param: $bound> From<$vec3> for $color<$param, $t> {
+            fn from(vector: $vec3) -> Self {
+                vector.to_array().into()
+            }
+        }
+
+        impl<$param: $bound> From<Alpha<$color<$param, $t>, $t>> for $vec4 {
+            fn from(color: Alpha<$color<$param, $t>, $t>) -> Self {
+                let [x, y, z, w]: [$t; 4] = color.into();
+                $vec4::new(x, y, z, w)
+            }
+        }
+
+        impl<$param: $bound> From<$vec4> for Alpha<$color<$param, $t>, $t> {
+            fn from(vector: $vec4) -> Self {
+                vector.to_array().into()
+            }
+        }
+    };
+}
+
+impl_glam!(
+    Rgb<S: RgbStandard>,
+    Xyz<Wp: WhitePoint>,
+    Yxy<Wp: WhitePoint>,
+    Lab<Wp: WhitePoint>
+);
+
+#[cfg(test)]
+mod test {
+    use ::glam::{DVec3, Vec3, Vec4};
+
+    use crate::{Lab, LinSrgb, LinSrgba};
+
+    #[test]
+    fn rgb() {
+        let vector = Vec3::from(LinSrgb::new(0.1, 0.2, 0.3));
+        assert_eq!(vector, Vec3::new(0.1, 0.2, 0.3));
+        assert_eq!(LinSrgb::from(vector), LinSrgb::new(0.1, 0.2, 0.3));
+
+        let vector = Vec4::from(LinSrgba::new(0.1, 0.2, 0.3, 0.5));
+        assert_eq!(vector, Vec4::new(0.1, 0.2, 0.3, 0.5));
+        assert_eq!(LinSrgba::from(vector), LinSrgba::new(0.1, 0.2, 0.3, 0.5));
+    }
+
+    #[test]
+    fn lab_f64() {
+        let color = Lab::new(50.0f64, -10.0, 20.0);
+        let vector = DVec3::from(color) * 2.0;
+        assert_eq!(Lab::from(vector), Lab::new(100.0, -20.0, 40.0));
+    }
+}
@@ -0,0 +1,170 @@
+//! Colors with a memory layout for GPU buffers.
+//!
+//! Uniform and storage buffers have stricter layout rules than Rust. Both
+//! `vec3<f32>` and `vec4<f32>` in WGSL, and `vec3` and `vec4` in GLSL with
+//! the std140 and std430 layouts, are aligned to 16 bytes, and arrays of them
+//! have a stride of 16 bytes. A color such as `LinSrgb<f32>` is only 12 bytes
+//! and aligned to 4 bytes, so it can't be copied into a buffer as it is.
+//!
+//! [`GpuColor`] stores a color with up to four `f32` components as a 16 byte
+//! aligned `vec4<f32>`, with any unused component set to `0.0`. It can be
+//! copied directly into buffers, as well as slices of them:
+//!
+//! ```
+//! use palette::gpu::GpuColor;
+//! use palette::{LinSrgb, LinSrgba};
+//!
+//! // var<storage> lights: array<vec3<f32>>;
+//! let lights = [
+//!     GpuColor::new(LinSrgb::new(1.0, 0.9, 0.8)),
+//!     GpuColor::new(LinSrgb::new(0.2, 0.3, 1.0)),
+//! ];
+//! assert_eq!(GpuColor::slice_as_bytes(&lights).len(), 32);
+//!
+//! // var<uniform> tint: vec4<f32>;
+//! let tint = GpuColor::new(LinSrgba::new(1.0, 0.5, 0.0, 0.5));
+//! assert_eq!(tint.as_bytes().len(), 16);
+//! ```
+//!
+//! The bytes are in the native byte order, which is what graphics APIs
+//! expect when uploading buffers from the CPU.
+
+use core::marker::PhantomData;
+use core::{mem, slice};
+
+use crate::Pixel;
+
+/// A color with up to four `f32` components, stored as a 16 byte aligned
+/// `vec4<f32>`.
+///
+/// Colors with three components, such as `LinSrgb<f32>`, are padded with a
+/// fourth component that is always `0.0`, which matches the layout of a
+/// `vec3<f32>` that is followed by padding or is part of an array. Colors
+/// with fewer components are padded in the same way.
+///
+/// ```
+/// use palette::gpu::GpuColor;
+/// use palette::LinSrgb;
+///
+/// let color = GpuColor::new(LinSrgb::new(1.0, 0.5, 0.25));
+/// assert_eq!(color.components(), &[1.0, 0.5, 0.25, 0.0]);
+/// assert_eq!(color.color(), LinSrgb::new(1.0, 0.5, 0.25));
+///
+/// assert_eq!(std::mem::size_of_val(&color), 16);
+/// assert_eq!(std::mem::align_of_val(&color), 16);
+/// ```
+#[repr(C, align(16))]
+pub struct GpuColor<C> {
+    components: [f32; 4],
+    color: PhantomData<C>,
+}
+
+impl<C: Pixel<f32>> GpuColor<C> {
+    /// Store `color` as a `vec4<f32>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` has more than four components.
+    pub fn new(color: C) -> Self {
+        assert!(C::CHANNELS <= 4, "the color has more than four components");
+
+        let mut components = [0.0; 4];
+        components[..C::CHANNELS].copy_from_slice(color.as_raw::<[f32]>());
+
+        GpuColor {
+            components,
+            color: PhantomData,
+        }
+    }
+
+    /// Get the stored color.
+    pub fn color(&self) -> C
+    where
+        C: Copy,
+    {
+        *C::from_raw(&self.components[..])
+    }
+}
+
+impl<C> GpuColor<C> {
+    /// Get the components, including any padding.
+    pub fn components(&self) -> &[f32; 4] {
+        &self.components
+    }
+
+    /// View the color as the 16 bytes of a `vec4<f32>`.
+    pub fn as_bytes(&self) -> &[u8] {
+        Self::slice_as_bytes(slice::from_ref(self))
+    }
+
+    /// View a slice of colors as bytes, with a stride of 16 bytes.
+    pub fn slice_as_bytes(colors: &[Self]) -> &[u8] {
+        // Safe, since `GpuColor` is only `f32` values without any padding
+        // bytes, and `u8` has a smaller alignment.
+        unsafe { slice::from_raw_parts(colors.as_ptr() as *const u8, mem::size_of_val(colors)) }
+    }
+}
+
+impl<C: Pixel<f32>> From<C> for GpuColor<C> {
+    fn from(color: C) -> Self {
+        GpuColor::new(color)
+    }
+}
+
+impl<C> Copy for GpuColor<C> {}
+
+impl<C> Clone for GpuColor<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> PartialEq for GpuColor<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+impl<C> core::fmt::Debug for GpuColor<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("GpuColor").field(&self.components).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GpuColor;
+    use crate::white_point::D65;
+    use crate::{LinLuma, LinSrgba, Xyz};
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<GpuColor<Xyz>>(), 16);
+        assert_eq!(core::mem::align_of::<GpuColor<Xyz>>(), 16);
+        assert_eq!(core::mem::size_of::<[GpuColor<LinSrgba>; 3]>(), 48);
+    }
+
+    #[test]
+    fn components() {
+        let color = GpuColor::new(LinSrgba::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(color.components(), &[0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(color.color(), LinSrgba::new(0.1, 0.2, 0.3, 0.4));
+
+        let luma = GpuColor::new(LinLuma::<D65>::new(0.5));
+        assert_eq!(luma.components(), &[0.5, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn bytes() {
+        let colors = [
+            GpuColor::new(Xyz::new(1.0, 2.0, 3.0)),
+            GpuColor::new(Xyz::new(4.0, 5.0, 6.0)),
+        ];
+        let bytes = GpuColor::slice_as_bytes(&colors);
+
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes[16..20], 4.0f32.to_ne_bytes());
+        assert_eq!(bytes[12..16], 0.0f32.to_ne_bytes());
+        assert_eq!(colors[1].as_bytes(), &bytes[16..]);
+    }
+}
@@ -0,0 +1,310 @@
+use core::fmt;
+
+use crate::Srgb;
+
+/// An error that occurred while parsing a CSS gradient.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseCssGradientError {
+    /// The input doesn't follow the `linear-gradient(...)` syntax.
+    InvalidSyntax(&'static str),
+
+    /// A color in the gradient couldn't be parsed.
+    InvalidColor(String),
+
+    /// A color stop position couldn't be parsed. Only percentages are
+    /// supported.
+    InvalidPosition(String),
+}
+
+impl fmt::Display for ParseCssGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCssGradientError::InvalidSyntax(s) => write!(f, "{}", s),
+            ParseCssGradientError::InvalidColor(color) => {
+                write!(f, "could not parse '{}' as a color", color)
+            }
+            ParseCssGradientError::InvalidPosition(position) => write!(
+                f,
+                "could not parse '{}' as a color stop position, please use a percentage",
+                position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseCssGradientError {}
+
+/// Parse the color stops of a CSS `linear-gradient(...)`, with the positions
+/// resolved to the range `[0.0, 1.0]`, as described in CSS Images Module
+/// Level 3.
+pub(crate) fn parse_stops(input: &str) -> Result<Vec<(f32, Srgb)>, ParseCssGradientError> {
+    let input = input.trim();
+    let arguments = strip_prefix_ignore_case(input, "linear-gradient(")
+        .and_then(|input| input.strip_suffix(')'))
+        .ok_or(ParseCssGradientError::InvalidSyntax(
+            "expected a 'linear-gradient(...)' function",
+        ))?;
+
+    let mut arguments = split_top_level(arguments, |c| c == ',').peekable();
+
+    // The direction doesn't affect the colors
+    if let Some(&first) = arguments.peek() {
+        if is_direction(first) {
+            arguments.next();
+        }
+    }
+
+    let mut stops: Vec<(Option<f32>, Srgb)> = Vec::new();
+    for argument in arguments {
+        let mut tokens = split_top_level(argument, char::is_whitespace);
+        let color = tokens.next().ok_or(ParseCssGradientError::InvalidSyntax(
+            "expected a color stop",
+        ))?;
+
+        if parse_percentage(color).is_some() {
+            return Err(ParseCssGradientError::InvalidSyntax(
+                "interpolation hints are not supported",
+            ));
+        }
+
+        let color =
+            parse_color(color).ok_or_else(|| ParseCssGradientError::InvalidColor(color.into()))?;
+
+        let mut positions = 0;
+        for position in tokens {
+            positions += 1;
+            if positions > 2 {
+                return Err(ParseCssGradientError::InvalidSyntax(
+                    "a color stop can't have more than two positions",
+                ));
+            }
+
+            let position = parse_percentage(position)
+                .ok_or_else(|| ParseCssGradientError::InvalidPosition(position.into()))?;
+            stops.push((Some(position), color));
+        }
+
+        if positions == 0 {
+            stops.push((None, color));
+        }
+    }
+
+    if stops.len() < 2 {
+        return Err(ParseCssGradientError::InvalidSyntax(
+            "a gradient must have at least two color stops",
+        ));
+    }
+
+    Ok(resolve_positions(stops))
+}
+
+/// Fill in missing positions and make sure they are in increasing order.
+fn resolve_positions(mut stops: Vec<(Option<f32>, Srgb)>) -> Vec<(f32, Srgb)> {
+    let last = stops.len() - 1;
+    stops[0].0.get_or_insert(0.0);
+    stops[last].0.get_or_insert(1.0);
+
+    // A position can't be smaller than the ones before it
+    let mut max_position = f32::MIN;
+    for &mut (ref mut position, _) in &mut stops {
+        if let Some(position) = position {
+            max_position = max_position.max(*position);
+            *position = max_position;
+        }
+    }
+
+    // Missing positions are evenly spaced between the surrounding positions
+    let mut previous = 0;
+    for index in 1..stops.len() {
+        if let Some(to) = stops[index].0 {
+            let from = stops[previous].0.unwrap_or(0.0);
+            let steps = (index - previous) as f32;
+
+            for (step, stop) in stops[previous + 1..index].iter_mut().enumerate() {
+                stop.0 = Some(from + (to - from) * (step + 1) as f32 / steps);
+            }
+
+            previous = index;
+        }
+    }
+
+    stops
+        .into_iter()
+        .map(|(position, color)| (position.unwrap_or(0.0), color))
+        .collect()
+}
+
+fn is_direction(argument: &str) -> bool {
+    if strip_prefix_ignore_case(argument, "to ").is_some() {
+        return true;
+    }
+
+    ["deg", "grad", "rad", "turn"].iter().any(|unit| {
+        argument.len() > unit.len()
+            && argument[argument.len() - unit.len()..].eq_ignore_ascii_case(unit)
+            && argument[..argument.len() - unit.len()]
+                .parse::<f32>()
+                .is_ok()
+    })
+}
+
+fn parse_percentage(input: &str) -> Option<f32> {
+    input
+        .strip_suffix('%')
+        .and_then(|number| number.parse::<f32>().ok())
+        .filter(|percentage| percentage.is_finite())
+        .map(|percentage| percentage / 100.0)
+}
+
+fn parse_color(input: &str) -> Option<Srgb> {
+    #[cfg(feature = "parsing")]
+    {
+        input
+            .parse::<crate::css::CssColor>()
+            .ok()
+            .map(|color| color.into_srgba().color)
+    }
+
+    #[cfg(not(feature = "parsing"))]
+    {
+        parse_hex_or_named_color(input)
+    }
+}
+
+#[cfg(not(feature = "parsing"))]
+fn parse_hex_or_named_color(input: &str) -> Option<Srgb> {
+    if input.starts_with('#') {
+        return input
+            .parse::<Srgb<u8>>()
+            .ok()
+            .map(|color| color.into_format());
+    }
+
+    #[cfg(feature = "named_from_str")]
+    {
+        if let Some(color) = crate::named::from_str(input) {
+            return Some(color.into_format());
+        }
+    }
+
+    None
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    if input.len() >= prefix.len()
+        && input.is_char_boundary(prefix.len())
+        && input[..prefix.len()].eq_ignore_ascii_case(prefix)
+    {
+        Some(&input[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Split `input` at the characters that match `is_separator`, but not within
+/// parentheses. Empty parts are skipped and the parts are trimmed.
+fn split_top_level<'a, F>(input: &'a str, is_separator: F) -> impl Iterator<Item = &'a str>
+where
+    F: Fn(char) -> bool + 'a,
+{
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut chars = input.char_indices();
+
+    core::iter::from_fn(move || loop {
+        match chars.next() {
+            Some((_, '(')) => depth += 1,
+            Some((_, ')')) => depth = depth.saturating_sub(1),
+            Some((index, c)) if depth == 0 && is_separator(c) => {
+                let part = input[start..index].trim();
+                start = index + c.len_utf8();
+                if !part.is_empty() {
+                    return Some(part);
+                }
+            }
+            Some(_) => {}
+            None => {
+                let part = input[start..].trim();
+                start = input.len();
+                return if part.is_empty() { None } else { Some(part) };
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_stops, ParseCssGradientError};
+    use crate::Srgb;
+
+    #[test]
+    fn positions() {
+        let stops = parse_stops("linear-gradient(#f00, #0f0 40%, #00f, #fff, #000)").unwrap();
+        let positions: Vec<_> = stops.iter().map(|&(position, _)| position).collect();
+
+        assert_eq!(positions.len(), 5);
+        assert_relative_eq!(positions[0], 0.0);
+        assert_relative_eq!(positions[1], 0.4);
+        assert_relative_eq!(positions[2], 0.6);
+        assert_relative_eq!(positions[3], 0.8);
+        assert_relative_eq!(positions[4], 1.0);
+
+        // Positions can't decrease
+        let stops = parse_stops("linear-gradient(#f00 50%, #0f0 20%)").unwrap();
+        assert_relative_eq!(stops[0].0, 0.5);
+        assert_relative_eq!(stops[1].0, 0.5);
+    }
+
+    #[test]
+    fn directions_and_double_positions() {
+        let stops = parse_stops("linear-gradient(45deg, #ff0000 0% 50%, #0000ff)").unwrap();
+
+        assert_eq!(
+            stops,
+            vec![
+                (0.0, Srgb::new(1.0, 0.0, 0.0)),
+                (0.5, Srgb::new(1.0, 0.0, 0.0)),
+                (1.0, Srgb::new(0.0, 0.0, 1.0)),
+            ]
+        );
+
+        assert!(parse_stops("linear-gradient(to top left, #fff, #000)").is_ok());
+        assert!(parse_stops("LINEAR-GRADIENT(0.25turn, #fff, #000)").is_ok());
+    }
+
+    #[cfg(feature = "named_from_str")]
+    #[test]
+    fn named_colors() {
+        let stops = parse_stops("linear-gradient(Red, blue)").unwrap();
+        assert_eq!(stops[0], (0.0, Srgb::new(1.0, 0.0, 0.0)));
+        assert_eq!(stops[1], (1.0, Srgb::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse_stops("radial-gradient(#fff, #000)"),
+            Err(ParseCssGradientError::InvalidSyntax(
+                "expected a 'linear-gradient(...)' function"
+            ))
+        );
+        assert_eq!(
+            parse_stops("linear-gradient(#fff, #xyz)"),
+            Err(ParseCssGradientError::InvalidColor("#xyz".into()))
+        );
+        assert_eq!(
+            parse_stops("linear-gradient(#fff 10px, #000)"),
+            Err(ParseCssGradientError::InvalidPosition("10px".into()))
+        );
+        assert_eq!(
+            parse_stops("linear-gradient(#fff nan%, #000)"),
+            Err(ParseCssGradientError::InvalidPosition("nan%".into()))
+        );
+        assert_eq!(
+            parse_stops("linear-gradient(#fff, #000 inf%)"),
+            Err(ParseCssGradientError::InvalidPosition("inf%".into()))
+        );
+        assert!(parse_stops("linear-gradient(#fff)").is_err());
+        assert!(parse_stops("linear-gradient(#fff, 50%, #000)").is_err());
+    }
+}
@@ -0,0 +1,401 @@
+//! Easing functions for shaping the transitions of gradients.
+//!
+//! An easing function changes how fast a gradient transitions between two
+//! control points, similar to the timing functions of CSS transitions. It's
+//! applied to the relative position within each segment, before the colors are
+//! interpolated, and can be added to any gradient using
+//! [`Gradient::with_easing`](crate::gradient::Gradient::with_easing):
+//!
+//! ```
+//! use palette::{Gradient, LinSrgb};
+//! use palette::gradient::easing::Smoothstep;
+//!
+//! let gradient = Gradient::new(vec![
+//!     LinSrgb::new(1.0, 0.1, 0.1),
+//!     LinSrgb::new(0.1, 0.1, 1.0),
+//! ])
+//! .with_easing(Smoothstep);
+//!
+//! let colors: Vec<_> = gradient.take(10).collect();
+//! ```
+//!
+//! Any function or closure that takes and returns a factor can be used as an
+//! easing function, and a list of easing functions will apply one to each
+//! segment:
+//!
+//! ```
+//! use palette::{Gradient, LinSrgb};
+//!
+//! let gradient = Gradient::new(vec![
+//!     LinSrgb::new(1.0, 0.1, 0.1),
+//!     LinSrgb::new(0.1, 0.1, 1.0),
+//!     LinSrgb::new(0.1, 1.0, 0.1),
+//! ])
+//! .with_easing(vec![
+//!     (|x: f32| x * x) as fn(f32) -> f32,
+//!     |x: f32| x.sqrt(),
+//! ]);
+//!
+//! let colors: Vec<_> = gradient.take(10).collect();
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::{One, Zero};
+
+use crate::float::Float;
+use crate::gradient::interpolation::Interpolation;
+use crate::{from_f64, FromF64, Mix};
+
+/// A function that changes the rate of change within a gradient segment.
+pub trait Easing<T> {
+    /// Ease the relative position `factor`, in the range `[0.0, 1.0]`, within
+    /// the segment that starts at control point `segment`.
+    fn ease(&self, segment: usize, factor: T) -> T;
+}
+
+impl<T, F> Easing<T> for F
+where
+    F: Fn(T) -> T,
+{
+    fn ease(&self, _segment: usize, factor: T) -> T {
+        self(factor)
+    }
+}
+
+/// Use one easing function per segment. Segments without a corresponding
+/// easing function are not eased.
+impl<T, E> Easing<T> for [E]
+where
+    E: Easing<T>,
+{
+    fn ease(&self, segment: usize, factor: T) -> T {
+        match self.get(segment) {
+            Some(easing) => easing.ease(segment, factor),
+            None => factor,
+        }
+    }
+}
+
+/// Use one easing function per segment. Segments without a corresponding
+/// easing function are not eased.
+impl<T, E, const N: usize> Easing<T> for [E; N]
+where
+    E: Easing<T>,
+{
+    fn ease(&self, segment: usize, factor: T) -> T {
+        self[..].ease(segment, factor)
+    }
+}
+
+/// Use one easing function per segment. Segments without a corresponding
+/// easing function are not eased.
+#[cfg(feature = "alloc")]
+impl<T, E> Easing<T> for Vec<E>
+where
+    E: Easing<T>,
+{
+    fn ease(&self, segment: usize, factor: T) -> T {
+        (**self).ease(segment, factor)
+    }
+}
+
+/// Smooth Hermite easing, that starts and ends slowly. This is the same as
+/// the `smoothstep` function in GLSL.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Smoothstep;
+
+impl<T: Float + FromF64> Easing<T> for Smoothstep {
+    fn ease(&self, _segment: usize, factor: T) -> T {
+        factor * factor * (from_f64::<T>(3.0) - from_f64::<T>(2.0) * factor)
+    }
+}
+
+/// Cubic easing that starts slowly and ends quickly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CubicIn;
+
+impl<T: Float> Easing<T> for CubicIn {
+    fn ease(&self, _segment: usize, factor: T) -> T {
+        factor * factor * factor
+    }
+}
+
+/// Cubic easing that starts quickly and ends slowly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CubicOut;
+
+impl<T: Float> Easing<T> for CubicOut {
+    fn ease(&self, _segment: usize, factor: T) -> T {
+        let inverse = T::one() - factor;
+        T::one() - inverse * inverse * inverse
+    }
+}
+
+/// Cubic easing that starts and ends slowly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CubicInOut;
+
+impl<T: Float + FromF64> Easing<T> for CubicInOut {
+    fn ease(&self, _segment: usize, factor: T) -> T {
+        let half: T = from_f64(0.5);
+
+        if factor < half {
+            from_f64::<T>(4.0) * factor * factor * factor
+        } else {
+            let inverse = from_f64::<T>(2.0) * (T::one() - factor);
+            T::one() - inverse * inverse * inverse * half
+        }
+    }
+}
+
+/// A cubic Bézier curve from `(0, 0)` to `(1, 1)`, with the control points
+/// `(x1, y1)` and `(x2, y2)`. This is the same as the `cubic-bezier` timing
+/// function in CSS.
+///
+/// ```
+/// use palette::gradient::easing::{CubicBezier, Easing};
+///
+/// // The CSS `ease` timing function
+/// let ease = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+/// assert!(ease.ease(0, 0.5) > 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CubicBezier<T> {
+    /// The horizontal position of the first control point, in the range
+    /// `[0.0, 1.0]`.
+    pub x1: T,
+
+    /// The vertical position of the first control point.
+    pub y1: T,
+
+    /// The horizontal position of the second control point, in the range
+    /// `[0.0, 1.0]`.
+    pub x2: T,
+
+    /// The vertical position of the second control point.
+    pub y2: T,
+}
+
+impl<T> CubicBezier<T> {
+    /// Create a cubic Bézier easing function from its two control points.
+    pub fn new(x1: T, y1: T, x2: T, y2: T) -> CubicBezier<T> {
+        CubicBezier { x1, y1, x2, y2 }
+    }
+}
+
+impl<T: Float + FromF64> CubicBezier<T> {
+    /// Evaluate a one dimensional curve from 0 to 1, with the control values
+    /// `p1` and `p2`, at `t`.
+    fn curve(p1: T, p2: T, t: T) -> T {
+        let three: T = from_f64(3.0);
+        let inverse = T::one() - t;
+
+        three * inverse * inverse * t * p1 + three * inverse * t * t * p2 + t * t * t
+    }
+
+    /// The derivative of `curve` with respect to `t`.
+    fn curve_derivative(p1: T, p2: T, t: T) -> T {
+        let three: T = from_f64(3.0);
+        let six: T = from_f64(6.0);
+        let inverse = T::one() - t;
+
+        three * inverse * inverse * p1
+            + six * inverse * t * (p2 - p1)
+            + three * t * t * (T::one() - p2)
+    }
+
+    /// Find the curve parameter for the horizontal position `x`.
+    fn solve_x(&self, x: T) -> T {
+        let epsilon: T = from_f64(1e-7);
+
+        // Newton's method converges quickly in most cases...
+        let mut t = x;
+        for _ in 0..8 {
+            let error = Self::curve(self.x1, self.x2, t) - x;
+            if error.abs() < epsilon {
+                return t;
+            }
+
+            let derivative = Self::curve_derivative(self.x1, self.x2, t);
+            if derivative.abs() < epsilon {
+                break;
+            }
+
+            t = t - error / derivative;
+        }
+
+        // ...but bisection is used as a fallback.
+        let mut low = T::zero();
+        let mut high = T::one();
+        t = x;
+        for _ in 0..64 {
+            let value = Self::curve(self.x1, self.x2, t);
+            if (value - x).abs() < epsilon {
+                break;
+            }
+
+            if value < x {
+                low = t;
+            } else {
+                high = t;
+            }
+
+            t = (low + high) * from_f64(0.5);
+        }
+
+        t
+    }
+}
+
+impl<T: Float + FromF64> Easing<T> for CubicBezier<T> {
+    fn ease(&self, _segment: usize, factor: T) -> T {
+        if factor <= T::zero() || factor >= T::one() {
+            return factor;
+        }
+
+        let t = self.solve_x(factor);
+        Self::curve(self.y1, self.y2, t)
+    }
+}
+
+/// An interpolation method with an easing function applied to the position
+/// within each segment. It's created using
+/// [`Gradient::with_easing`](crate::gradient::Gradient::with_easing).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Eased<I, E> {
+    /// The underlying interpolation method.
+    pub interpolation: I,
+
+    /// The easing function.
+    pub easing: E,
+
+    /// Apply the easing function backwards, from the last segment to the
+    /// first, and from the end of each segment to its start. This is toggled
+    /// when the gradient is reversed, such as by
+    /// [`Gradient::reverse`](crate::gradient::Gradient::reverse), so the
+    /// easing is mirrored along with the control points.
+    #[cfg_attr(feature = "serializing", serde(default))]
+    pub reversed: bool,
+}
+
+impl<C, I, E> Interpolation<C> for Eased<I, E>
+where
+    C: Mix,
+    I: Interpolation<C>,
+    E: Easing<C::Scalar>,
+{
+    fn interpolate(&self, points: &[(C::Scalar, C)], index: usize, factor: C::Scalar) -> C {
+        let factor = if self.reversed {
+            let one = C::Scalar::one();
+            let last_segment = points.len() - 2;
+            one - self.easing.ease(last_segment - index, one - factor)
+        } else {
+            self.easing.ease(index, factor)
+        };
+
+        self.interpolation.interpolate(points, index, factor)
+    }
+
+    fn positions_transformed(&mut self, scale: C::Scalar, offset: C::Scalar) {
+        if scale < C::Scalar::zero() {
+            self.reversed = !self.reversed;
+        }
+
+        self.interpolation.positions_transformed(scale, offset);
+    }
+
+    fn position_changed(&self, point: &mut (C::Scalar, C)) {
+        self.interpolation.position_changed(point);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CubicBezier, CubicInOut, Easing, Smoothstep};
+    use crate::gradient::Gradient;
+    use crate::LinSrgb;
+
+    #[test]
+    fn end_points() {
+        assert_relative_eq!(Smoothstep.ease(0, 0.0f64), 0.0);
+        assert_relative_eq!(Smoothstep.ease(0, 1.0f64), 1.0);
+        assert_relative_eq!(CubicInOut.ease(0, 0.0f64), 0.0);
+        assert_relative_eq!(CubicInOut.ease(0, 0.5f64), 0.5);
+        assert_relative_eq!(CubicInOut.ease(0, 1.0f64), 1.0);
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        let linear = CubicBezier::new(0.0f64, 0.0, 1.0, 1.0);
+        assert_relative_eq!(linear.ease(0, 0.3), 0.3, epsilon = 0.00001);
+
+        // CSS `ease-in-out` is symmetric
+        let ease_in_out = CubicBezier::new(0.42f64, 0.0, 0.58, 1.0);
+        assert_relative_eq!(ease_in_out.ease(0, 0.5), 0.5, epsilon = 0.00001);
+        assert_relative_eq!(
+            ease_in_out.ease(0, 0.2),
+            1.0 - ease_in_out.ease(0, 0.8),
+            epsilon = 0.00001
+        );
+        assert!(ease_in_out.ease(0, 0.2) < 0.2);
+    }
+
+    #[test]
+    fn eased_gradient() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ])
+        .with_easing(|x: f64| x * x);
+
+        assert_relative_eq!(gradient.get(0.5), LinSrgb::new(0.25, 0.25, 0.25));
+        assert_relative_eq!(gradient.get(1.0), LinSrgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn per_segment_easing() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+            LinSrgb::new(0.0, 0.0, 0.0),
+        ])
+        .with_easing(vec![(|x: f64| x * x) as fn(f64) -> f64]);
+
+        // The first segment is eased, but not the second
+        assert_relative_eq!(gradient.get(0.25), LinSrgb::new(0.25, 0.25, 0.25));
+        assert_relative_eq!(gradient.get(0.75), LinSrgb::new(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn reversed_easing() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+            LinSrgb::new(0.0, 0.0, 0.0),
+        ])
+        .with_easing(vec![(|x: f64| x * x) as fn(f64) -> f64]);
+
+        // Reversing and scaling by -1 mirror the eased first segment, so it
+        // becomes the last segment and starts quickly instead of slowly
+        let reversed = gradient.clone().reverse();
+        let scaled = gradient.clone().scale(-1.0);
+
+        for &position in &[0.1, 0.25, 0.4, 0.6, 0.75, 0.9] {
+            assert_relative_eq!(reversed.get(1.0 - position), gradient.get(position));
+            assert_relative_eq!(scaled.get(-position), gradient.get(position));
+        }
+
+        // Reversing twice brings back the original easing
+        let restored = reversed.reverse();
+        assert_relative_eq!(restored.get(0.25), gradient.get(0.25));
+        assert_relative_eq!(restored.get(0.75), gradient.get(0.75));
+    }
+}
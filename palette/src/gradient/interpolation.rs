@@ -0,0 +1,479 @@
+//! Interpolation methods for gradients.
+//!
+//! A [`Gradient`](crate::gradient::Gradient) uses [`Linear`] interpolation by
+//! default, but any other method can be selected with
+//! [`Gradient::with_interpolation`](crate::gradient::Gradient::with_interpolation).
+//! The cubic methods are smooth across the control points, but require the
+//! color type to implement [`ComponentWise`](crate::ComponentWise), since
+//! they can't be expressed as a simple mix of two colors.
+//!
+//! ```
+//! use palette::{Gradient, LinSrgb};
+//! use palette::gradient::interpolation::CatmullRom;
+//!
+//! let gradient = Gradient::new(vec![
+//!     LinSrgb::new(1.0, 0.1, 0.1),
+//!     LinSrgb::new(0.1, 0.1, 1.0),
+//!     LinSrgb::new(0.1, 1.0, 0.1),
+//! ])
+//! .with_interpolation(CatmullRom);
+//!
+//! let colors: Vec<_> = gradient.take(10).collect();
+//! ```
+
+use crate::float::Float;
+use crate::{from_f64, ComponentWise, FromF64, HueInterpolation, Mix, MixHue};
+
+/// A method for interpolating between the control points of a gradient.
+pub trait Interpolation<C: Mix> {
+    /// Interpolate between `points[index]` and `points[index + 1]`, where
+    /// `factor` is the relative position between them, in the range `[0.0,
+    /// 1.0]`. The surrounding points may be used to calculate a smoother
+    /// transition.
+    fn interpolate(&self, points: &[(C::Scalar, C)], index: usize, factor: C::Scalar) -> C;
+
+    /// Update the interpolation after the position of every control point
+    /// has been changed to `position * scale + offset`, such as by
+    /// [`Gradient::shift`](crate::gradient::Gradient::shift). It does nothing
+    /// by default.
+    fn positions_transformed(&mut self, _scale: C::Scalar, _offset: C::Scalar) {}
+
+    /// Update a control point after its position has been changed by
+    /// [`Gradient::set_position`](crate::gradient::Gradient::set_position).
+    /// It does nothing by default.
+    fn position_changed(&self, _point: &mut (C::Scalar, C)) {}
+}
+
+/// Linear interpolation between each pair of control points. This is the
+/// default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Linear;
+
+impl<C: Mix> Interpolation<C> for Linear {
+    fn interpolate(&self, points: &[(C::Scalar, C)], index: usize, factor: C::Scalar) -> C {
+        points[index].1.mix(&points[index + 1].1, factor)
+    }
+}
+
+/// Linear interpolation, where the hue takes the path around the color wheel
+/// that is decided by the [`HueInterpolation`] method. This is only available
+/// for colors with a hue component.
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::{Gradient, Hsv, HueInterpolation};
+///
+/// // Go the long way around, through all the hues
+/// let rainbow = Gradient::new(vec![
+///     Hsv::new(0.0, 1.0, 1.0),
+///     Hsv::new(0.0, 1.0, 1.0),
+/// ])
+/// .with_interpolation(HueInterpolation::Longer);
+///
+/// assert_relative_eq!(rainbow.get(0.25).hue.to_positive_degrees(), 90.0);
+/// assert_relative_eq!(rainbow.get(0.5).hue.to_positive_degrees(), 180.0);
+/// ```
+impl<C: MixHue> Interpolation<C> for HueInterpolation {
+    fn interpolate(&self, points: &[(C::Scalar, C)], index: usize, factor: C::Scalar) -> C {
+        points[index].1.mix_hue(&points[index + 1].1, factor, *self)
+    }
+}
+
+/// Catmull-Rom spline interpolation.
+///
+/// The curve passes through every control point, and the tangents are
+/// calculated from the neighboring points. This gives smooth transitions, but
+/// may overshoot the control points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CatmullRom;
+
+impl<C> Interpolation<C> for CatmullRom
+where
+    C: Mix + ComponentWise<Scalar = <C as Mix>::Scalar>,
+    <C as Mix>::Scalar: FromF64,
+{
+    fn interpolate(
+        &self,
+        points: &[(<C as Mix>::Scalar, C)],
+        index: usize,
+        factor: <C as Mix>::Scalar,
+    ) -> C {
+        let last = points.len() - 1;
+        let tangent = |index: usize| {
+            let (from, to) = (index.saturating_sub(1), (index + 1).min(last));
+            let (from_position, ref from_color) = points[from];
+            let (to_position, ref to_color) = points[to];
+            let scale = inverse_width(to_position - from_position);
+
+            weighted_sum(to_color, scale, from_color, -scale)
+        };
+
+        hermite(points, index, factor, &tangent(index), &tangent(index + 1))
+    }
+}
+
+/// Monotone cubic interpolation.
+///
+/// The curve passes through every control point and is smooth, but each
+/// component is guaranteed to not overshoot the control points. This uses
+/// the method described by M. Steffen in "A simple method for monotonic
+/// interpolation in one dimension".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct MonotoneCubic;
+
+impl<C> Interpolation<C> for MonotoneCubic
+where
+    C: Mix + ComponentWise<Scalar = <C as Mix>::Scalar>,
+    <C as Mix>::Scalar: FromF64,
+{
+    fn interpolate(
+        &self,
+        points: &[(<C as Mix>::Scalar, C)],
+        index: usize,
+        factor: <C as Mix>::Scalar,
+    ) -> C {
+        let last = points.len() - 1;
+        let secant = |index: usize| {
+            let (from_position, ref from_color) = points[index];
+            let (to_position, ref to_color) = points[index + 1];
+            let width = to_position - from_position;
+            let scale = inverse_width(width);
+
+            (weighted_sum(to_color, scale, from_color, -scale), width)
+        };
+        let tangent = |index: usize| {
+            if index == 0 {
+                secant(0).0
+            } else if index == last {
+                secant(last - 1).0
+            } else {
+                let (previous, previous_width) = secant(index - 1);
+                let (next, next_width) = secant(index);
+
+                previous.component_wise(&next, |previous, next| {
+                    steffen_slope(previous, next, previous_width, next_width)
+                })
+            }
+        };
+
+        hermite(points, index, factor, &tangent(index), &tangent(index + 1))
+    }
+}
+
+/// Cubic B-spline interpolation.
+///
+/// The control points are used as the control points of a uniform cubic
+/// B-spline, which makes the curve very smooth. It passes through the first
+/// and the last control point, but only approaches the ones in between.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct BSpline;
+
+impl<C> Interpolation<C> for BSpline
+where
+    C: Mix + ComponentWise<Scalar = <C as Mix>::Scalar>,
+    <C as Mix>::Scalar: FromF64,
+{
+    fn interpolate(
+        &self,
+        points: &[(<C as Mix>::Scalar, C)],
+        index: usize,
+        factor: <C as Mix>::Scalar,
+    ) -> C {
+        let last = points.len() - 1;
+        let one: <C as Mix>::Scalar = from_f64(1.0);
+        let two: <C as Mix>::Scalar = from_f64(2.0);
+
+        // The curve is extended with mirrored points, to make it pass through
+        // the end points. The other points are borrowed, to avoid cloning
+        // them.
+        let mirrored_before;
+        let before = if index == 0 {
+            mirrored_before = weighted_sum(&points[0].1, two, &points[1].1, -one);
+            &mirrored_before
+        } else {
+            &points[index - 1].1
+        };
+        let mirrored_after;
+        let after = if index + 1 == last {
+            mirrored_after = weighted_sum(&points[last].1, two, &points[last - 1].1, -one);
+            &mirrored_after
+        } else {
+            &points[index + 2].1
+        };
+
+        let t = factor;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let sixth: <C as Mix>::Scalar = from_f64(1.0 / 6.0);
+        let three: <C as Mix>::Scalar = from_f64(3.0);
+        let four: <C as Mix>::Scalar = from_f64(4.0);
+        let six: <C as Mix>::Scalar = from_f64(6.0);
+
+        let inv = one - t;
+        let w0 = inv * inv * inv * sixth;
+        let w1 = (three * t3 - six * t2 + four) * sixth;
+        let w2 = (-three * t3 + three * t2 + three * t + one) * sixth;
+        let w3 = t3 * sixth;
+
+        let first = weighted_sum(before, w0, &points[index].1, w1);
+        let second = weighted_sum(&points[index + 1].1, w2, after, w3);
+        first.component_wise(&second, |a, b| a + b)
+    }
+}
+
+/// Calls a function with the position, instead of interpolating between the
+/// control points.
+///
+/// This is what makes the gradients from
+/// [`Gradient::from_fn`](crate::gradient::Gradient::from_fn) work, where the
+/// control points are only the ends of the domain. The position is
+/// reconstructed from the surrounding control points and the relative
+/// position between them, so easing functions still apply.
+///
+/// The function follows the control points when the gradient is moved,
+/// stretched or reversed, so it's called with the same positions as before
+/// the change. A control point that is moved on its own gets the color of the
+/// function at its new position instead.
+#[derive(Clone, Copy, Debug)]
+pub struct FromFn<F, T> {
+    function: F,
+    scale: T,
+    offset: T,
+}
+
+impl<F, T: Float> FromFn<F, T> {
+    /// Call `function` to get the color at each position.
+    pub fn new(function: F) -> Self {
+        FromFn {
+            function,
+            scale: T::one(),
+            offset: T::zero(),
+        }
+    }
+
+    /// Get the position that the function is called with for a position in
+    /// the gradient.
+    fn function_position(&self, position: T) -> T {
+        position * self.scale + self.offset
+    }
+}
+
+impl<C, F> Interpolation<C> for FromFn<F, C::Scalar>
+where
+    C: Mix,
+    F: Fn(C::Scalar) -> C,
+{
+    fn interpolate(&self, points: &[(C::Scalar, C)], index: usize, factor: C::Scalar) -> C {
+        let from = points[index].0;
+        let to = points[index + 1].0;
+        (self.function)(self.function_position(from + (to - from) * factor))
+    }
+
+    fn positions_transformed(&mut self, scale: C::Scalar, offset: C::Scalar) {
+        // Undo the transformation before calling the function
+        self.scale = self.scale / scale;
+        self.offset = self.offset - offset * self.scale;
+    }
+
+    fn position_changed(&self, point: &mut (C::Scalar, C)) {
+        point.1 = (self.function)(self.function_position(point.0));
+    }
+}
+
+/// Evaluate a cubic Hermite curve between `points[index]` and
+/// `points[index + 1]`, with the given tangents.
+fn hermite<C>(
+    points: &[(<C as Mix>::Scalar, C)],
+    index: usize,
+    factor: <C as Mix>::Scalar,
+    from_tangent: &C,
+    to_tangent: &C,
+) -> C
+where
+    C: Mix + ComponentWise<Scalar = <C as Mix>::Scalar>,
+    <C as Mix>::Scalar: FromF64,
+{
+    let (from_position, ref from_color) = points[index];
+    let (to_position, ref to_color) = points[index + 1];
+    let width = to_position - from_position;
+
+    let t = factor;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let two: <C as Mix>::Scalar = from_f64(2.0);
+    let three: <C as Mix>::Scalar = from_f64(3.0);
+
+    let h00 = two * t3 - three * t2 + from_f64(1.0);
+    let h10 = t3 - two * t2 + t;
+    let h01 = three * t2 - two * t3;
+    let h11 = t3 - t2;
+
+    let colors = weighted_sum(from_color, h00, to_color, h01);
+    let tangents = weighted_sum(from_tangent, width * h10, to_tangent, width * h11);
+    colors.component_wise(&tangents, |a, b| a + b)
+}
+
+/// Calculate `a * a_weight + b * b_weight` for each component.
+fn weighted_sum<C, T>(a: &C, a_weight: T, b: &C, b_weight: T) -> C
+where
+    C: ComponentWise<Scalar = T>,
+    T: Float,
+{
+    a.component_wise(b, |a, b| a * a_weight + b * b_weight)
+}
+
+/// Calculate the reciprocal of an interval's width, for turning color
+/// differences into slopes. Hard stops, where the width is zero, are treated
+/// as flat instead of having infinite slopes.
+fn inverse_width<T: Float>(width: T) -> T {
+    if width > T::zero() {
+        width.recip()
+    } else {
+        T::zero()
+    }
+}
+
+/// Calculate a slope that preserves monotonicity, from the slopes of the
+/// previous and next intervals, and their widths.
+fn steffen_slope<T: Float + FromF64>(previous: T, next: T, previous_width: T, next_width: T) -> T {
+    if previous * next <= T::zero() {
+        return T::zero();
+    }
+
+    let parabola = (previous * next_width + next * previous_width) / (previous_width + next_width);
+    let limit = previous
+        .abs()
+        .min(next.abs())
+        .min(parabola.abs() * from_f64(0.5));
+
+    previous.signum() * limit * from_f64(2.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BSpline, CatmullRom, MonotoneCubic};
+    use crate::gradient::Gradient;
+    use crate::{Hsv, HueInterpolation, LinSrgb};
+
+    fn points() -> Vec<(f64, LinSrgb<f64>)> {
+        vec![
+            (0.0, LinSrgb::new(0.0, 0.0, 1.0)),
+            (0.2, LinSrgb::new(1.0, 0.2, 0.5)),
+            (0.5, LinSrgb::new(0.9, 0.8, 0.0)),
+            (1.0, LinSrgb::new(0.0, 1.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_points() {
+        let gradient = Gradient::with_domain(points()).with_interpolation(CatmullRom);
+
+        for &(position, color) in &points() {
+            assert_relative_eq!(gradient.get(position), color, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn cubic_hard_stops() {
+        let points: Vec<(f64, LinSrgb<f64>)> = vec![
+            (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (0.5, LinSrgb::new(0.0, 1.0, 0.0)),
+            (0.5, LinSrgb::new(0.0, 0.0, 1.0)),
+            (1.0, LinSrgb::new(1.0, 1.0, 1.0)),
+        ];
+        let catmull_rom = Gradient::with_domain(points.clone()).with_interpolation(CatmullRom);
+        let monotone = Gradient::with_domain(points.clone()).with_interpolation(MonotoneCubic);
+
+        for &position in &[0.1, 0.25, 0.4, 0.6, 0.75, 0.9] {
+            for color in &[catmull_rom.get(position), monotone.get(position)] {
+                assert!(color.red.is_finite());
+                assert!(color.green.is_finite());
+                assert!(color.blue.is_finite());
+            }
+        }
+
+        assert_relative_eq!(monotone.get(0.5), points[1].1);
+        assert_relative_eq!(monotone.get(0.5001), points[2].1, epsilon = 0.001);
+    }
+
+    #[test]
+    fn monotone_passes_through_points() {
+        let gradient = Gradient::with_domain(points()).with_interpolation(MonotoneCubic);
+
+        for &(position, color) in &points() {
+            assert_relative_eq!(gradient.get(position), color, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn monotone_does_not_overshoot() {
+        let gradient = Gradient::with_domain(points()).with_interpolation(MonotoneCubic);
+
+        for color in gradient.take(100) {
+            assert!(color.red >= 0.0 && color.red <= 1.0);
+            assert!(color.green >= 0.0 && color.green <= 1.0);
+            assert!(color.blue >= 0.0 && color.blue <= 1.0);
+        }
+
+        // The green channel is monotonically increasing
+        let greens: Vec<_> = gradient.take(100).map(|color| color.green).collect();
+        for pair in greens.windows(2) {
+            assert!(pair[0] <= pair[1] + 0.000001);
+        }
+    }
+
+    #[test]
+    fn b_spline_end_points() {
+        let gradient = Gradient::with_domain(points()).with_interpolation(BSpline);
+
+        assert_relative_eq!(gradient.get(0.0), points()[0].1);
+        assert_relative_eq!(gradient.get(1.0), points()[3].1);
+        assert_relative_eq!(gradient.get(0.0001), points()[0].1, epsilon = 0.01);
+        assert_relative_eq!(gradient.get(0.9999), points()[3].1, epsilon = 0.01);
+    }
+
+    #[test]
+    fn two_points_are_linear() {
+        let linear = Gradient::new(vec![
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+        let catmull_rom = linear.clone().with_interpolation(CatmullRom);
+        let b_spline = linear.clone().with_interpolation(BSpline);
+
+        for ((a, b), c) in linear
+            .take(10)
+            .zip(catmull_rom.take(10))
+            .zip(b_spline.take(10))
+        {
+            assert_relative_eq!(a, b, epsilon = 0.000001);
+            assert_relative_eq!(a, c, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn hue_interpolation() {
+        let gradient = Gradient::new(vec![Hsv::new(30.0f64, 1.0, 1.0), Hsv::new(330.0, 1.0, 1.0)]);
+
+        let shorter = gradient
+            .clone()
+            .with_interpolation(HueInterpolation::Shorter);
+        let longer = gradient
+            .clone()
+            .with_interpolation(HueInterpolation::Longer);
+        let increasing = gradient
+            .clone()
+            .with_interpolation(HueInterpolation::Increasing);
+        let decreasing = gradient.with_interpolation(HueInterpolation::Decreasing);
+
+        assert_relative_eq!(shorter.get(0.25).hue.to_positive_degrees(), 15.0);
+        assert_relative_eq!(longer.get(0.25).hue.to_positive_degrees(), 105.0);
+        assert_relative_eq!(increasing.get(0.25).hue.to_positive_degrees(), 105.0);
+        assert_relative_eq!(decreasing.get(0.25).hue.to_positive_degrees(), 15.0);
+    }
+}
@@ -0,0 +1,165 @@
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::float::Float;
+use crate::{from_f64, FromF64, Mix};
+
+use super::interpolation::Interpolation;
+use super::{Gradient, Wrap};
+
+/// A gradient that is baked into a lookup table of `N` evenly spaced colors.
+///
+/// [`Gradient::get`] searches for the surrounding control points on every
+/// call, and may use an expensive interpolation method. A `GradientLut`
+/// samples the gradient once when it's created, and answers
+/// [`get`](GradientLut::get) by mixing the two closest samples, which takes
+/// the same time for any gradient. This is useful when the same gradient is
+/// sampled many times, such as for each pixel of a heatmap.
+///
+/// The result is an approximation of the gradient, where more samples give a
+/// closer approximation. Sharp transitions are softened, and any curvature
+/// between the samples is lost. The domain and wrap mode of the gradient are
+/// kept.
+///
+/// ```
+/// use palette::gradient::GradientLut;
+/// use palette::{Gradient, LinSrgb};
+///
+/// let gradient = Gradient::new(vec![
+///     LinSrgb::new(0.0, 0.0, 0.5),
+///     LinSrgb::new(1.0, 1.0, 0.0),
+///     LinSrgb::new(1.0, 0.0, 0.0),
+/// ]);
+/// // 257 samples includes the middle control point
+/// let lut = GradientLut::<_, 257>::new(&gradient);
+///
+/// let heatmap = [0.1, 0.5, 0.9];
+/// let colors: Vec<_> = heatmap.iter().map(|&value| lut.get(value)).collect();
+/// assert_eq!(colors[1], gradient.get(0.5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GradientLut<C: Mix, const N: usize> {
+    colors: [C; N],
+    min: C::Scalar,
+    max: C::Scalar,
+    wrap: Wrap,
+}
+
+impl<C, const N: usize> GradientLut<C, N>
+where
+    C: Mix + Clone,
+    C::Scalar: FromF64,
+{
+    /// Sample `N` evenly spaced colors from `gradient`, including both ends
+    /// of its domain. They are the same colors as from
+    /// [`Gradient::take`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn new<T, I>(gradient: &Gradient<C, T, I>) -> Self
+    where
+        T: AsRef<[(C::Scalar, C)]>,
+        I: Interpolation<C>,
+    {
+        assert!(N > 0, "a GradientLut must contain at least one color");
+
+        let (min, max) = gradient.domain();
+        let step = if N > 1 {
+            (max - min) / from_f64((N - 1) as f64)
+        } else {
+            C::Scalar::zero()
+        };
+
+        GradientLut {
+            colors: core::array::from_fn(|index| gradient.get(min + step * from_f64(index as f64))),
+            min,
+            max,
+            wrap: gradient.wrap(),
+        }
+    }
+
+    /// Get an approximation of the color at `i` in the gradient. It mixes the
+    /// two closest samples, and positions outside the domain are handled
+    /// according to the gradient's wrap mode.
+    pub fn get(&self, i: C::Scalar) -> C {
+        let i = self.wrap.wrap_position(i, (self.min, self.max));
+        let width = self.max - self.min;
+        if N == 1 || i <= self.min || width <= C::Scalar::zero() {
+            return self.colors[0].clone();
+        }
+        if i >= self.max {
+            return self.colors[N - 1].clone();
+        }
+
+        let position = (i - self.min) / width * from_f64((N - 1) as f64);
+        let index = position.floor().to_usize().unwrap_or(0).min(N - 2);
+        let factor = (position - from_f64(index as f64)).min(C::Scalar::one());
+
+        self.colors[index].mix(&self.colors[index + 1], factor)
+    }
+
+    /// Get the limits of the domain.
+    pub fn domain(&self) -> (C::Scalar, C::Scalar) {
+        (self.min, self.max)
+    }
+
+    /// Get the sampled colors.
+    pub fn colors(&self) -> &[C; N] {
+        &self.colors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GradientLut;
+    use crate::gradient::interpolation::CatmullRom;
+    use crate::gradient::{Gradient, Wrap};
+    use crate::LinSrgb;
+
+    #[test]
+    fn samples() {
+        let g = Gradient::with_domain(vec![
+            (-1.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (0.0, LinSrgb::new(0.0, 1.0, 0.0)),
+            (3.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ]);
+        let lut = GradientLut::<_, 5>::new(&g);
+
+        assert_eq!(lut.domain(), (-1.0, 3.0));
+        assert_eq!(&lut.colors()[..], &g.take(5).collect::<Vec<_>>()[..]);
+
+        // Linear gradients are exact at the samples and between the samples
+        // of the same segment
+        for &i in &[-2.0, -1.0, 0.0, 0.5, 1.0, 2.75, 3.0, 4.0] {
+            assert_relative_eq!(lut.get(i), g.get(i), epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn approximation() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ])
+        .with_interpolation(CatmullRom)
+        .with_wrap(Wrap::Mirror);
+        let lut = GradientLut::<_, 1024>::new(&g);
+
+        for i in -20..40 {
+            let i = i as f32 / 20.0;
+            assert_relative_eq!(lut.get(i), g.get(i), epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn single_sample() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+        let lut = GradientLut::<_, 1>::new(&g);
+        assert_eq!(lut.get(0.7), LinSrgb::new(1.0, 0.0, 0.0));
+    }
+}
@@ -1,32 +1,80 @@
 //! Types for interpolation between multiple colors.
 //!
-//! This module is only available if the `std` feature is enabled (this is the
-//! default).
-
-use std::cmp::max;
+//! The control points of a gradient can be stored in any type that can be
+//! referenced as a slice, such as a `Vec`, a fixed-size array or a borrowed
+//! slice. Gradients with the default `Vec` storage are only available if the
+//! `alloc` feature is enabled (it's enabled by `std`, which is the default),
+//! while arrays and slices can be used in `no_std` environments without an
+//! allocator:
+//!
+//! ```
+//! use palette::{Gradient, LinSrgb};
+//!
+//! const GRADIENT: Gradient<LinSrgb, [(f32, LinSrgb); 2]> = Gradient::from_array([
+//!     (0.0, LinSrgb::new(0.0, 0.0, 0.0)),
+//!     (1.0, LinSrgb::new(1.0, 0.5, 0.0)),
+//! ]);
+//!
+//! let orange = GRADIENT.get(1.0);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::max;
+use core::fmt;
 use core::marker::PhantomData;
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num_traits::{One, Zero};
-
+#[cfg(feature = "rayon")]
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+#[cfg(feature = "alloc")]
+use crate::convert::FromColorUnclamped;
+#[cfg(feature = "std")]
+use crate::Srgb;
+#[cfg(feature = "alloc")]
+use crate::bytes::{self, ColorBytes, ComponentBytes, FromBytesError};
 use crate::float::Float;
-use crate::Mix;
+use crate::{FromColor, Mix};
 use crate::{from_f64, FromF64};
 
+#[cfg(feature = "std")]
+pub use self::css::ParseCssGradientError;
+pub use self::lut::GradientLut;
+pub use self::patch::{Gradient2d, Interpolation2d};
+
+use self::easing::{Eased, Easing};
+use self::interpolation::{FromFn, Interpolation, Linear};
+
+#[cfg(feature = "std")]
+mod css;
+pub mod easing;
+pub mod interpolation;
+mod lut;
 #[cfg(feature = "named_gradients")]
 pub mod named;
+mod patch;
 
 impl<C,T> From<T> for Gradient<C,T>
 where
-    C: Mix + Clone,
+    C: Mix,
     T: AsRef<[(C::Scalar, C)]>
 {
     fn from(col: T) -> Self {
-        Gradient(col, PhantomData)
+        Gradient {
+            points: col,
+            interpolation: Linear,
+            wrap: Wrap::Clamp,
+            color: PhantomData,
+        }
     }
 }
 
-/// A linear interpolation between colors.
+/// An interpolation between colors.
 ///
 /// It's used to smoothly transition between a series of colors, that can be
 /// either evenly spaced or have customized positions. The gradient is
@@ -34,39 +82,324 @@ where
 /// number of evenly spaced points using the `take` method. Any point outside
 /// the domain of the gradient will have the same color as the closest control
 /// point.
+///
+/// The colors are linearly interpolated by default, but other methods can be
+/// selected using [`with_interpolation`](Gradient::with_interpolation). See
+/// the [`interpolation`](crate::gradient::interpolation) module for the
+/// available methods.
+///
+/// The control points can be any type that implements [`Mix`], including
+/// types that carry more data than a color. They only have to implement
+/// `Clone` to be sampled, and are only cloned when a position at or outside
+/// the ends of the domain is sampled, or by interpolation methods that need
+/// it.
+///
+/// ```
+/// use palette::{Gradient, LinSrgb, Mix};
+///
+/// // A color with a label, that isn't `Copy`
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Keyframe {
+///     color: LinSrgb,
+///     label: String,
+/// }
+///
+/// impl Mix for Keyframe {
+///     type Scalar = f32;
+///
+///     fn mix(&self, other: &Keyframe, factor: f32) -> Keyframe {
+///         let closest = if factor < 0.5 { self } else { other };
+///
+///         Keyframe {
+///             color: self.color.mix(&other.color, factor),
+///             label: closest.label.clone(),
+///         }
+///     }
+/// }
+///
+/// let animation = Gradient::new(vec![
+///     Keyframe { color: LinSrgb::new(1.0, 0.0, 0.0), label: "start".into() },
+///     Keyframe { color: LinSrgb::new(0.0, 0.0, 1.0), label: "end".into() },
+/// ]);
+///
+/// let frame = animation.get(0.75);
+/// assert_eq!(frame.color, LinSrgb::new(0.25, 0.0, 0.75));
+/// assert_eq!(frame.label, "end");
+/// ```
 #[derive(Clone, Debug)]
-pub struct Gradient<C, T = Vec<(<C as Mix>::Scalar, C)>>(T, PhantomData<C>)
+#[cfg_attr(feature = "serializing", derive(Serialize))]
+pub struct Gradient<
+    C,
+    #[cfg(feature = "alloc")] T = Vec<(<C as Mix>::Scalar, C)>,
+    #[cfg(not(feature = "alloc"))] T,
+    I = Linear,
+>
 where
-    C: Mix + Clone,
-    T: AsRef<[(C::Scalar, C)]>;
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    points: T,
+    #[cfg_attr(feature = "serializing", serde(skip_serializing_if = "is_unit"))]
+    interpolation: I,
+    wrap: Wrap,
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    color: PhantomData<C>,
+}
+
+/// The serialized form of a gradient, which is validated before it becomes a
+/// `Gradient`.
+#[cfg(feature = "serializing")]
+#[derive(Deserialize)]
+#[serde(rename = "Gradient")]
+struct GradientData<T, I> {
+    points: T,
+    #[serde(default)]
+    interpolation: I,
+    #[serde(default)]
+    wrap: Wrap,
+}
+
+#[cfg(feature = "serializing")]
+impl<'de, C, T, I> serde::Deserialize<'de> for Gradient<C, T, I>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]> + serde::Deserialize<'de>,
+    I: Default + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = GradientData::<T, I>::deserialize(deserializer)?;
+        check_points(data.points.as_ref()).map_err(serde::de::Error::custom)?;
+
+        Ok(Gradient {
+            points: data.points,
+            interpolation: data.interpolation,
+            wrap: data.wrap,
+            color: PhantomData,
+        })
+    }
+}
 
 impl<C,T> Gradient<C,T>
 where
-    C: Mix + Clone,
+    C: Mix,
     T: AsRef<[(C::Scalar, C)]>
+{
+    /// Create a gradient of colors with custom spacing and domain. There must
+    /// be at least one color and they must be ordered by their position
+    /// value.
+    ///
+    /// See [`try_with_domain`](Gradient::try_with_domain) for a version that
+    /// returns an error instead of panicking, or
+    /// [`with_domain_sorted`](Gradient::with_domain_sorted) for a version
+    /// that sorts the colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no colors, if a position is NaN, or if the colors
+    /// are not ordered by their positions.
+    pub fn with_domain(colors: T) -> Gradient<C, T> {
+        Gradient::try_with_domain(colors).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Create a gradient of colors with custom spacing and domain, or return
+    /// an error if there are no colors, if a position is NaN, or if the
+    /// colors are not ordered by their positions. Points with the same
+    /// position are allowed, and make a hard transition.
+    ///
+    /// ```
+    /// use palette::gradient::GradientError;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::try_with_domain(vec![
+    ///     (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(0.0, 0.0, 1.0)),
+    /// ]);
+    /// assert!(gradient.is_ok());
+    ///
+    /// let unordered = Gradient::try_with_domain(vec![
+    ///     (1.0, LinSrgb::new(1.0, 0.0, 0.0)),
+    ///     (0.0, LinSrgb::new(0.0, 0.0, 1.0)),
+    /// ]);
+    /// assert_eq!(unordered.unwrap_err(), GradientError::Unordered(1));
+    /// ```
+    pub fn try_with_domain(colors: T) -> Result<Gradient<C, T>, GradientError> {
+        check_points(colors.as_ref())?;
+
+        Ok(Gradient {
+            points: colors,
+            interpolation: Linear,
+            wrap: Wrap::Clamp,
+            color: PhantomData,
+        })
+    }
+}
+
+/// Check if `I` is a unit type, such as `Linear`, that has only one value. It
+/// doesn't have to be serialized, since it's recreated using `Default`.
+#[cfg(feature = "serializing")]
+fn is_unit<I>(_: &I) -> bool {
+    core::mem::size_of::<I>() == 0
+}
+
+/// Check that `points` is not empty, and that the positions are not NaN and
+/// are in increasing order.
+fn check_points<T: Float, C>(points: &[(T, C)]) -> Result<(), GradientError> {
+    if points.is_empty() {
+        return Err(GradientError::Empty);
+    }
+
+    for (index, &(position, _)) in points.iter().enumerate() {
+        if position.is_nan() {
+            return Err(GradientError::NanPosition(index));
+        }
+        if index > 0 && position < points[index - 1].0 {
+            return Err(GradientError::Unordered(index));
+        }
+    }
+
+    Ok(())
+}
+
+impl<C, const N: usize> Gradient<C, [(C::Scalar, C); N]>
+where
+    C: Mix,
+{
+    /// Create a gradient from a fixed-size array of control points, without
+    /// allocating. There must be at least one color and they are expected to
+    /// be ordered by their position value.
+    ///
+    /// This can be used to create constant gradients, and doesn't require the
+    /// `alloc` feature.
+    pub const fn from_array(points: [(C::Scalar, C); N]) -> Self {
+        assert!(N > 0, "a Gradient must contain at least one color");
+
+        Gradient {
+            points,
+            interpolation: Linear,
+            wrap: Wrap::Clamp,
+            color: PhantomData,
+        }
+    }
+}
+
+impl<C, F> Gradient<C, [(C::Scalar, C); 2], FromFn<F, C::Scalar>>
+where
+    C: Mix,
+    F: Fn(C::Scalar) -> C,
+{
+    /// Create a gradient from a function, that returns the color for each
+    /// position in `domain`, given as `(min, max)`.
+    ///
+    /// The gradient has the same API as any other gradient, but calls `f`
+    /// instead of interpolating between control points. The only control
+    /// points are the ends of the domain, with the colors `f(min)` and
+    /// `f(max)`, which are returned for positions outside the domain. This
+    /// makes it possible to use procedurally defined color ramps anywhere a
+    /// gradient is expected. It doesn't allocate.
+    ///
+    /// The function is moved together with the gradient by
+    /// [`shift`](Gradient::shift), [`scale`](Gradient::scale) and
+    /// [`reverse`](Gradient::reverse), while
+    /// [`set_position`](Gradient::set_position) extends or shortens the part
+    /// of the function that is used.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// // A ramp from black to white, with a gamma curve
+    /// let gradient = Gradient::from_fn((0.0, 10.0), |x: f32| {
+    ///     let value = (x / 10.0).powf(2.0);
+    ///     LinSrgb::new(value, value, value)
+    /// });
+    ///
+    /// assert_eq!(gradient.get(5.0), LinSrgb::new(0.25, 0.25, 0.25));
+    /// assert_eq!(gradient.get(20.0), LinSrgb::new(1.0, 1.0, 1.0));
+    /// assert_eq!(gradient.domain(), (0.0, 10.0));
+    ///
+    /// let colors: Vec<_> = gradient.slice(0.0..5.0).take(3).collect();
+    /// assert_eq!(colors[1], LinSrgb::new(0.0625, 0.0625, 0.0625));
+    /// ```
+    pub fn from_fn(domain: (C::Scalar, C::Scalar), f: F) -> Self {
+        let (min, max) = domain;
+
+        Gradient {
+            points: [(min, f(min)), (max, f(max))],
+            interpolation: FromFn::new(f),
+            wrap: Wrap::Clamp,
+            color: PhantomData,
+        }
+    }
+}
+
+impl<'a, C> Gradient<C, &'a [(C::Scalar, C)]>
+where
+    C: Mix,
+{
+    /// Create a gradient from a borrowed slice of control points, without
+    /// allocating. There must be at least one color and they are expected to
+    /// be ordered by their position value.
+    ///
+    /// This can be used to create constant gradients, and doesn't require the
+    /// `alloc` feature.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let points = [
+    ///     (0.0, LinSrgb::new(0.0, 0.0, 0.0)),
+    ///     (0.5, LinSrgb::new(1.0, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(1.0, 1.0, 0.0)),
+    /// ];
+    /// let gradient = Gradient::from_slice(&points);
+    ///
+    /// assert_eq!(gradient.get(0.5), LinSrgb::new(1.0, 0.0, 0.0));
+    /// ```
+    pub const fn from_slice(points: &'a [(C::Scalar, C)]) -> Self {
+        assert!(
+            !points.is_empty(),
+            "a Gradient must contain at least one color"
+        );
+
+        Gradient {
+            points,
+            interpolation: Linear,
+            wrap: Wrap::Clamp,
+            color: PhantomData,
+        }
+    }
+}
+
+impl<C, T, I> Gradient<C, T, I>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]>,
 {
     /// Get a color from the gradient. The color of the closest control point
     /// will be returned if `i` is outside the domain.
-    pub fn get(&self, i: C::Scalar) -> C {
-        let &(mut min, ref min_color) = self
-            .0
-            .as_ref()
-            .get(0)
+    pub fn get(&self, i: C::Scalar) -> C
+    where
+        C: Clone,
+        I: Interpolation<C>,
+    {
+        let points = self.points.as_ref();
+        let i = self.wrap_position(i);
+
+        let &(mut min, ref min_color) = points
+            .first()
             .expect("a Gradient must contain at least one color");
-        let mut min_color = min_color;
         let mut min_index = 0;
 
         if i <= min {
             return min_color.clone();
         }
 
-        let &(mut max, ref max_color) = self
-            .0
-            .as_ref()
+        let &(mut max, ref max_color) = points
             .last()
             .expect("a Gradient must contain at least one color");
-        let mut max_color = max_color;
-        let mut max_index = self.0.as_ref().len() - 1;
+        let mut max_index = points.len() - 1;
 
         if i >= max {
             return max_color.clone();
@@ -75,32 +408,111 @@ where
         while min_index < max_index - 1 {
             let index = min_index + (max_index - min_index) / 2;
 
-            let (p, ref color) = self.0.as_ref()[index];
+            let p = points[index].0;
 
             if i <= p {
                 max = p;
-                max_color = color;
                 max_index = index;
             } else {
                 min = p;
-                min_color = color;
                 min_index = index;
             }
         }
 
         let factor = (i - min) / (max - min);
 
-        min_color.mix(max_color, factor)
+        self.interpolation.interpolate(points, min_index, factor)
     }
 
-    /// Create a gradient of colors with custom spacing and domain. There must
-    /// be at least one color and they are expected to be ordered by their
-    /// position value.
-    pub fn with_domain(colors: T) -> Gradient<C, T> {
-        assert!(!colors.as_ref().is_empty());
+    /// Change the interpolation method of the gradient.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    /// use palette::gradient::interpolation::MonotoneCubic;
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.2, 0.8, 0.5),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ])
+    /// .with_interpolation(MonotoneCubic);
+    ///
+    /// let color = gradient.get(0.25);
+    /// ```
+    pub fn with_interpolation<J: Interpolation<C>>(self, interpolation: J) -> Gradient<C, T, J> {
+        Gradient {
+            points: self.points,
+            interpolation,
+            wrap: self.wrap,
+            color: PhantomData,
+        }
+    }
+
+    /// Get the interpolation method of the gradient.
+    pub fn interpolation(&self) -> &I {
+        &self.interpolation
+    }
+
+    /// Change what the gradient returns for positions outside its domain.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    /// use palette::gradient::Wrap;
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ])
+    /// .with_wrap(Wrap::Mirror);
+    ///
+    /// assert_eq!(gradient.get(1.25), LinSrgb::new(0.75, 0.75, 0.75));
+    /// ```
+    pub fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Get what the gradient returns for positions outside its domain.
+    pub fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    /// Map `i` into the domain, according to the wrap mode. Positions within
+    /// the domain are left as they are.
+    fn wrap_position(&self, i: C::Scalar) -> C::Scalar {
+        self.wrap.wrap_position(i, self.domain())
+    }
+
+    /// Apply an easing function to each segment of the gradient, to change
+    /// how fast it transitions between the control points. See the
+    /// [`easing`](crate::gradient::easing) module for the available easing
+    /// functions.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    /// use palette::gradient::easing::CubicInOut;
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ])
+    /// .with_easing(CubicInOut);
+    ///
+    /// assert!(gradient.get(0.25).red < 0.25);
+    /// ```
+    pub fn with_easing<E: Easing<C::Scalar>>(self, easing: E) -> Gradient<C, T, Eased<I, E>> {
+        let interpolation = Eased {
+            interpolation: self.interpolation,
+            easing,
+            reversed: false,
+        };
 
-        //Maybe sort the colors?
-        Gradient(colors, PhantomData)
+        Gradient {
+            points: self.points,
+            interpolation,
+            wrap: self.wrap,
+            color: PhantomData,
+        }
     }
 
     /// Take `n` evenly spaced colors from the gradient, as an iterator. The
@@ -131,7 +543,7 @@ where
     ///     assert_relative_eq!(c1, c2);
     /// }
     /// ```
-    pub fn take(&self, n: usize) -> Take<C,T> {
+    pub fn take(&self, n: usize) -> Take<'_, C, T, I> {
         let (min, max) = self.domain();
 
         Take {
@@ -144,8 +556,124 @@ where
         }
     }
 
+    /// Fill `buffer` with evenly spaced colors from the gradient. The colors
+    /// are the same as from [`take(buffer.len())`](Gradient::take), but are
+    /// written directly to the buffer, which makes it fast to refill an
+    /// existing buffer, such as a lookup table texture.
+    ///
+    /// Use [`slice`](Gradient::slice) to only fill the buffer with a part of
+    /// the domain.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// let mut buffer = [LinSrgb::new(0.0, 0.0, 0.0); 256];
+    /// gradient.fill_slice(&mut buffer);
+    /// assert_eq!(buffer[255], LinSrgb::new(1.0, 1.0, 1.0));
+    ///
+    /// gradient.slice(0.5..).fill_slice(&mut buffer);
+    /// assert_eq!(buffer[0], LinSrgb::new(0.5, 0.5, 0.5));
+    /// ```
+    pub fn fill_slice(&self, buffer: &mut [C])
+    where
+        C: Clone,
+        C::Scalar: FromF64,
+        I: Interpolation<C>,
+    {
+        fill_evenly(buffer, self.domain(), |i| self.get(i));
+    }
+
+    /// Take `n` colors from the gradient, spaced so that the difference
+    /// between each pair of consecutive colors is the same, according to
+    /// `metric`.
+    ///
+    /// The colors from [`take`](Gradient::take) are evenly spaced within the
+    /// domain, which may look uneven if some parts of the gradient change
+    /// faster than others. Using a perceptual color difference as the metric
+    /// will make the steps look more even, which is often desired for data
+    /// visualization.
+    ///
+    /// ```
+    /// use palette::{ColorDifference, FromColor, Gradient, Lab, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    /// ]);
+    ///
+    /// let colors = gradient.resample_uniform(5, |a, b| {
+    ///     Lab::from_color(*a).get_color_difference(&Lab::from_color(*b))
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn resample_uniform<F>(&self, n: usize, mut metric: F) -> Vec<C>
+    where
+        C: Clone,
+        I: Interpolation<C>,
+        C::Scalar: FromF64,
+        F: FnMut(&C, &C) -> C::Scalar,
+    {
+        let (min, max) = self.domain();
+
+        if n < 2 {
+            return self.take(n).collect();
+        }
+
+        // Measure the accumulated difference along the gradient, in small steps
+        let samples = (n * 16).max(256);
+        let step = (max - min) / from_f64((samples - 1) as f64);
+        let mut positions = Vec::with_capacity(samples);
+        let mut distances = Vec::with_capacity(samples);
+        let mut previous = self.get(min);
+        let mut total = C::Scalar::zero();
+
+        positions.push(min);
+        distances.push(total);
+
+        for i in 1..samples {
+            let position = min + step * from_f64(i as f64);
+            let color = self.get(position);
+            total = total + metric(&previous, &color).max(C::Scalar::zero());
+
+            positions.push(position);
+            distances.push(total);
+            previous = color;
+        }
+
+        if total <= C::Scalar::zero() {
+            return self.take(n).collect();
+        }
+
+        let mut colors = Vec::with_capacity(n);
+        let mut segment = 1;
+        for i in 0..n {
+            let target = total * from_f64(i as f64) / from_f64((n - 1) as f64);
+            while segment < samples - 1 && distances[segment] < target {
+                segment += 1;
+            }
+
+            let (from, to) = (distances[segment - 1], distances[segment]);
+            let factor = if to > from {
+                (target - from) / (to - from)
+            } else {
+                C::Scalar::zero()
+            };
+            let position =
+                positions[segment - 1] + (positions[segment] - positions[segment - 1]) * factor;
+
+            colors.push(self.get(position));
+        }
+
+        colors
+    }
+
     /// Slice this gradient to limit its domain.
-    pub fn slice<R: Into<Range<C::Scalar>>>(&self, range: R) -> Slice<C,T> {
+    pub fn slice<R: Into<Range<C::Scalar>>>(&self, range: R) -> Slice<'_, C, T, I> {
         Slice {
             gradient: self,
             range: range.into(),
@@ -155,46 +683,948 @@ where
     /// Get the limits of this gradient's domain.
     pub fn domain(&self) -> (C::Scalar, C::Scalar) {
         let &(min, _) = self
-            .0
+            .points
             .as_ref()
-            .get(0)
+            .first()
             .expect("a Gradient must contain at least one color");
         let &(max, _) = self
-            .0
+            .points
             .as_ref()
             .last()
             .expect("a Gradient must contain at least one color");
         (min, max)
     }
-}
 
-impl<C: Mix + Clone> Gradient<C> {
-    /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0].
-    /// There must be at least one color.
-    pub fn new<I: IntoIterator<Item = C>>(colors: I) -> Gradient<C>
-    where
-        C::Scalar: FromF64,
-    {
-        let mut points: Vec<_> = colors.into_iter().map(|c| (C::Scalar::zero(), c)).collect();
-        assert!(!points.is_empty());
-        let step_size = C::Scalar::one() / from_f64(max(points.len() - 1, 1) as f64);
+    /// Get the control points of the gradient, as `(position, color)`
+    /// pairs, ordered by their positions.
+    ///
+    /// Unlike [`points`](Gradient::points), this works for any kind of
+    /// storage, including borrowed slices.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let points = [
+    ///     (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+    ///     (1.0, LinSrgb::new(0.0, 0.0, 1.0)),
+    /// ];
+    /// let gradient = Gradient::from_slice(&points);
+    ///
+    /// assert_eq!(gradient.stops(), &points);
+    /// ```
+    pub fn stops(&self) -> &[(C::Scalar, C)] {
+        self.points.as_ref()
+    }
+
+    /// Iterate over the control points of the gradient, as `(position,
+    /// color)` pairs, ordered by their positions.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 1.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// let positions: Vec<f32> = gradient.iter().map(|&(position, _)| position).collect();
+    /// assert_eq!(positions, vec![0.0, 0.5, 1.0]);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, (C::Scalar, C)> {
+        self.points.as_ref().iter()
+    }
+}
+
+impl<'a, C, T, I> IntoIterator for &'a Gradient<C, T, I>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    type Item = &'a (C::Scalar, C);
+    type IntoIter = core::slice::Iter<'a, (C::Scalar, C)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Turns the gradient into an iterator over its control points, as
+/// `(position, color)` pairs, if they are stored in a type that can be
+/// iterated by value, such as a `Vec` or an array.
+impl<C, T, I> IntoIterator for Gradient<C, T, I>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]> + IntoIterator<Item = (C::Scalar, C)>,
+{
+    type Item = (C::Scalar, C);
+    type IntoIter = T::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.into_iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, T, I> Gradient<C, T, I>
+where
+    C: Mix + ColorBytes<<C as Mix>::Scalar>,
+    C::Scalar: ComponentBytes,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    /// Convert the control points and the wrap mode of the gradient to a
+    /// canonical byte representation, that can be used as a cache key. See
+    /// the [`bytes`](crate::bytes) module for how the colors are stored.
+    ///
+    /// The bytes start with the wrap mode as a single byte, followed by the
+    /// number of control points as a little endian `u32`, and then each
+    /// position and color. The interpolation method is not included.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0f32, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// let bytes = gradient.to_bytes();
+    /// assert_eq!(bytes.len(), 1 + 4 + 2 * 16);
+    ///
+    /// let decoded = Gradient::<LinSrgb>::from_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded.points(), gradient.points());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let points = self.points.as_ref();
+        let point_size = C::Scalar::SIZE + C::BYTES;
+        let mut bytes = alloc::vec![0; 5 + points.len() * point_size];
+
+        bytes[0] = match self.wrap {
+            Wrap::Clamp => 0,
+            Wrap::Repeat => 1,
+            Wrap::Mirror => 2,
+        };
+        (points.len() as u32).write_le_bytes(&mut bytes[1..5]);
+
+        let chunks = bytes[5..].chunks_exact_mut(point_size);
+        for ((position, color), chunk) in points.iter().zip(chunks) {
+            position.write_le_bytes(chunk);
+            color.write_bytes(&mut chunk[C::Scalar::SIZE..]);
+        }
+
+        bytes
+    }
+
+    /// Compute a hash of the control points and the wrap mode of the
+    /// gradient, that is the same on every platform. This is the
+    /// [`fingerprint`](crate::bytes::fingerprint) of
+    /// [`to_bytes`](Gradient::to_bytes).
+    pub fn fingerprint(&self) -> u64 {
+        bytes::fingerprint(&self.to_bytes())
+    }
+}
+
+impl<C, T, I> Gradient<C, T, I>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]> + AsMut<[(C::Scalar, C)]>,
+{
+    /// Get the control points of the gradient, as `(position, color)` pairs.
+    pub fn points(&self) -> &[(C::Scalar, C)] {
+        self.points.as_ref()
+    }
+
+    /// Replace the color of the control point at `index`.
+    pub fn set_color(&mut self, index: usize, color: C) {
+        self.points.as_mut()[index].1 = color;
+    }
+
+    /// Move the control point at `index` to a new position. The control
+    /// points are kept ordered by their positions, so the point may end up
+    /// with a different index. The new index is returned.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let mut gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 1.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// // Move the red point past the green point
+    /// assert_eq!(gradient.set_position(0, 0.75), 1);
+    /// assert_eq!(gradient.points()[1], (0.75, LinSrgb::new(1.0, 0.0, 0.0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if `position` is NaN.
+    pub fn set_position(&mut self, index: usize, position: C::Scalar) -> usize
+    where
+        I: Interpolation<C>,
+    {
+        assert!(!position.is_nan(), "the position of a control point can't be NaN");
+
+        let points = self.points.as_mut();
+        points[index].0 = position;
+        self.interpolation.position_changed(&mut points[index]);
+
+        let mut new_index = index;
+        while new_index > 0 && points[new_index - 1].0 > position {
+            new_index -= 1;
+        }
+        while new_index + 1 < points.len() && points[new_index + 1].0 < position {
+            new_index += 1;
+        }
+
+        if new_index < index {
+            points[new_index..=index].rotate_right(1);
+        } else {
+            points[index..=new_index].rotate_left(1);
+        }
+
+        new_index
+    }
+
+    /// Reverse the gradient within its domain, so the first color ends up at
+    /// the end, and vice versa. Any easing is mirrored too.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ])
+    /// .reverse();
+    ///
+    /// assert_eq!(gradient.get(0.0), LinSrgb::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn reverse(mut self) -> Self
+    where
+        I: Interpolation<C>,
+    {
+        let (min, max) = self.domain();
+        let points = self.points.as_mut();
+
+        points.reverse();
+        for &mut (ref mut position, _) in points {
+            *position = min + max - *position;
+        }
+
+        self.interpolation.positions_transformed(-C::Scalar::one(), min + max);
+        self
+    }
+
+    /// Move the gradient's domain by `offset`, by adding it to the position
+    /// of every control point.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ])
+    /// .shift(2.0);
+    ///
+    /// assert_eq!(gradient.domain(), (2.0, 3.0));
+    /// ```
+    pub fn shift(mut self, offset: C::Scalar) -> Self
+    where
+        I: Interpolation<C>,
+    {
+        for &mut (ref mut position, _) in self.points.as_mut() {
+            *position = *position + offset;
+        }
+
+        self.interpolation.positions_transformed(C::Scalar::one(), offset);
+        self
+    }
+
+    /// Stretch the gradient's domain by `factor`, by multiplying the
+    /// position of every control point with it. A negative factor also
+    /// reverses the order of the control points, to keep them ordered by
+    /// their positions, and mirrors any easing the same way as
+    /// [`reverse`](Gradient::reverse).
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// // Stretch the gradient to [0.0, 10.0] and move it to [-5.0, 5.0]
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ])
+    /// .scale(10.0)
+    /// .shift(-5.0);
+    ///
+    /// assert_eq!(gradient.domain(), (-5.0, 5.0));
+    /// assert_eq!(gradient.get(0.0), LinSrgb::new(0.5, 0.0, 0.5));
+    /// ```
+    pub fn scale(mut self, factor: C::Scalar) -> Self
+    where
+        I: Interpolation<C>,
+    {
+        let points = self.points.as_mut();
+        for &mut (ref mut position, _) in points.iter_mut() {
+            *position = *position * factor;
+        }
+
+        if factor < C::Scalar::zero() {
+            points.reverse();
+        }
+
+        self.interpolation.positions_transformed(factor, C::Scalar::zero());
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, I> Gradient<C, Vec<(C::Scalar, C)>, I>
+where
+    C: Mix,
+{
+    /// Add a control point to the gradient. The control points are kept
+    /// ordered by their positions, and the new point is placed after any
+    /// existing points with the same position. The index of the new point is
+    /// returned.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let mut gradient = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// assert_eq!(gradient.insert(0.5, LinSrgb::new(0.0, 1.0, 0.0)), 1);
+    /// assert_eq!(gradient.get(0.5), LinSrgb::new(0.0, 1.0, 0.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is NaN.
+    pub fn insert(&mut self, position: C::Scalar, color: C) -> usize {
+        assert!(!position.is_nan(), "the position of a control point can't be NaN");
+
+        let index = self
+            .points
+            .iter()
+            .position(|&(p, _)| p > position)
+            .unwrap_or(self.points.len());
+        self.points.insert(index, (position, color));
+
+        index
+    }
+
+    /// Append the control points of `other` to the end of the gradient. They
+    /// are moved so the domain of `other` starts where the domain of this
+    /// gradient ends, which makes a hard transition between the two
+    /// gradients if the colors at the seam are different. The interpolation
+    /// method and wrap mode of this gradient are kept, and used for the
+    /// points of `other` too. Only the end colors are added from a gradient
+    /// that was created with [`from_fn`](Gradient::from_fn).
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let red_to_green = Gradient::new(vec![
+    ///     LinSrgb::new(1.0, 0.0, 0.0),
+    ///     LinSrgb::new(0.0, 1.0, 0.0),
+    /// ]);
+    /// let green_to_blue = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 1.0, 0.0),
+    ///     LinSrgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// let gradient = red_to_green.concat(green_to_blue.scale(2.0));
+    /// assert_eq!(gradient.domain(), (0.0, 3.0));
+    /// assert_eq!(gradient.get(2.0), LinSrgb::new(0.0, 0.5, 0.5));
+    /// ```
+    pub fn concat<U, J>(mut self, other: Gradient<C, U, J>) -> Self
+    where
+        C: Clone,
+        U: AsRef<[(C::Scalar, C)]>,
+    {
+        let (_, end) = self.domain();
+        let (start, _) = other.domain();
+        let offset = end - start;
+
+        self.points.extend(
+            other
+                .points
+                .as_ref()
+                .iter()
+                .map(|(position, color)| (*position + offset, color.clone())),
+        );
+
+        self
+    }
+
+    /// Remove the control point at `index` and return it. This will panic if
+    /// `index` is out of bounds, or if it's the last remaining point, since a
+    /// gradient must contain at least one color.
+    pub fn remove(&mut self, index: usize) -> (C::Scalar, C) {
+        assert!(
+            self.points.len() > 1,
+            "a Gradient must contain at least one color"
+        );
+
+        self.points.remove(index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Mix> Gradient<C> {
+    /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0].
+    /// There must be at least one color.
+    ///
+    /// See [`try_new`](Gradient::try_new) for a version that returns an
+    /// error instead of panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no colors.
+    pub fn new<I: IntoIterator<Item = C>>(colors: I) -> Gradient<C>
+    where
+        C::Scalar: FromF64,
+    {
+        Gradient::try_new(colors).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0],
+    /// or return an error if there are no colors.
+    ///
+    /// ```
+    /// use palette::gradient::GradientError;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let colors: Vec<LinSrgb> = Vec::new();
+    /// assert_eq!(Gradient::try_new(colors).unwrap_err(), GradientError::Empty);
+    /// ```
+    pub fn try_new<I: IntoIterator<Item = C>>(colors: I) -> Result<Gradient<C>, GradientError>
+    where
+        C::Scalar: FromF64,
+    {
+        Gradient::try_new_with_domain(colors, C::Scalar::zero(), C::Scalar::one())
+    }
+
+    /// Create a gradient of colors that are evenly spaced over the domain
+    /// [`from`, `to`]. There must be at least one color, and `from` must not
+    /// be greater than `to`.
+    ///
+    /// This is useful for mapping data to colors, where the domain is the
+    /// range of the data.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let temperatures = [-12.0, 4.0, 31.0];
+    /// let min = temperatures.iter().cloned().fold(f32::INFINITY, f32::min);
+    /// let max = temperatures.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    ///
+    /// let gradient = Gradient::new_with_domain(
+    ///     vec![
+    ///         LinSrgb::new(0.0, 0.0, 1.0),
+    ///         LinSrgb::new(1.0, 1.0, 1.0),
+    ///         LinSrgb::new(1.0, 0.0, 0.0),
+    ///     ],
+    ///     min,
+    ///     max,
+    /// );
+    ///
+    /// assert_eq!(gradient.domain(), (-12.0, 31.0));
+    /// assert_eq!(gradient.points()[1].0, 9.5);
+    /// ```
+    ///
+    /// See [`try_new_with_domain`](Gradient::try_new_with_domain) for a
+    /// version that returns an error instead of panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no colors, if `from` or `to` is NaN, or if `from`
+    /// is greater than `to`.
+    pub fn new_with_domain<I: IntoIterator<Item = C>>(
+        colors: I,
+        from: C::Scalar,
+        to: C::Scalar,
+    ) -> Gradient<C>
+    where
+        C::Scalar: FromF64,
+    {
+        Gradient::try_new_with_domain(colors, from, to)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Create a gradient of colors that are evenly spaced over the domain
+    /// [`from`, `to`], or return an error if there are no colors, if `from`
+    /// or `to` is NaN, or if `from` is greater than `to`.
+    pub fn try_new_with_domain<I: IntoIterator<Item = C>>(
+        colors: I,
+        from: C::Scalar,
+        to: C::Scalar,
+    ) -> Result<Gradient<C>, GradientError>
+    where
+        C::Scalar: FromF64,
+    {
+        let mut points: Vec<_> = colors.into_iter().map(|c| (from, c)).collect();
+        if points.is_empty() {
+            return Err(GradientError::Empty);
+        }
+        check_points(&[(from, ()), (to, ())])?;
+
+        let step_size = (to - from) / from_f64(max(points.len() - 1, 1) as f64);
+        for (i, &mut (ref mut p, _)) in points.iter_mut().enumerate() {
+            *p = from + from_f64::<C::Scalar>(i as f64) * step_size;
+        }
+
+        Gradient::try_with_domain(points)
+    }
+
+    /// Create a gradient of colors with custom spacing and domain, where the
+    /// control points are sorted by their positions.
+    ///
+    /// Unlike [`with_domain`](Gradient::with_domain), the control points
+    /// don't have to be ordered, and invalid input is returned as an error
+    /// instead of causing a panic or an incorrect gradient. The control
+    /// points are sorted with a stable sort, so points with the same
+    /// position keep their order. Two points with the same position make a
+    /// hard transition, and if more than two points have the same position,
+    /// only the first and the last of them are kept, since the colors in
+    /// between can't be reached.
+    ///
+    /// ```
+    /// use palette::gradient::GradientError;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::with_domain_sorted(vec![
+    ///     (1.0, LinSrgb::new(0.0, 0.0, 1.0)),
+    ///     (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(gradient.get(0.0), LinSrgb::new(1.0, 0.0, 0.0));
+    ///
+    /// let invalid = Gradient::with_domain_sorted(vec![
+    ///     (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+    ///     (f32::NAN, LinSrgb::new(0.0, 0.0, 1.0)),
+    /// ]);
+    /// assert_eq!(invalid.unwrap_err(), GradientError::NanPosition(1));
+    /// ```
+    pub fn with_domain_sorted<I>(colors: I) -> Result<Gradient<C>, GradientError>
+    where
+        I: IntoIterator<Item = (C::Scalar, C)>,
+    {
+        let mut points: Vec<_> = colors.into_iter().collect();
+
+        if points.is_empty() {
+            return Err(GradientError::Empty);
+        }
+        if let Some(index) = points.iter().position(|&(position, _)| position.is_nan()) {
+            return Err(GradientError::NanPosition(index));
+        }
+
+        points.sort_by(|(a, _), (b, _)| a.partial_cmp(b).expect("NaN positions are rejected"));
+
+        // Remove the middle points of each run of three or more points with
+        // the same position.
+        let mut index = 1;
+        while index + 1 < points.len() {
+            if points[index - 1].0 == points[index].0 && points[index].0 == points[index + 1].0 {
+                points.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        Ok(Gradient {
+            points,
+            interpolation: Linear,
+            wrap: Wrap::Clamp,
+            color: PhantomData,
+        })
+    }
+
+    /// Create a gradient of evenly spaced colors with the domain [0.0, 1.0],
+    /// that interpolates in the color space `C`, instead of the color space
+    /// of the control points. There must be at least one color.
+    ///
+    /// The control points are converted to `C` when the gradient is created,
+    /// and the interpolated colors are converted back to the input type when
+    /// they are taken from the gradient. This makes it possible to have a
+    /// gradient of, for example, sRGB colors that are mixed in Lab space.
+    ///
+    /// ```
+    /// use palette::{Gradient, Lab, Srgb};
+    ///
+    /// let gradient = Gradient::<Lab>::new_in(vec![
+    ///     Srgb::new(1.0, 0.0, 0.0),
+    ///     Srgb::new(0.0, 0.0, 1.0),
+    /// ]);
+    ///
+    /// let purple: Srgb = gradient.get(0.5);
+    /// ```
+    pub fn new_in<I, U>(colors: I) -> Converted<U, C>
+    where
+        C::Scalar: FromF64,
+        I: IntoIterator<Item = U>,
+        C: FromColorUnclamped<U>,
+        U: FromColor<C>,
+    {
+        Converted {
+            gradient: Gradient::new(colors.into_iter().map(C::from_color_unclamped)),
+            color: PhantomData,
+        }
+    }
+
+    /// Create a gradient of colors with custom spacing and domain, that
+    /// interpolates in the color space `C`, instead of the color space of the
+    /// control points. There must be at least one color and they are expected
+    /// to be ordered by their position value.
+    ///
+    /// See [`new_in`](Gradient::new_in) for more details.
+    pub fn with_domain_in<I, U>(colors: I) -> Converted<U, C>
+    where
+        I: IntoIterator<Item = (C::Scalar, U)>,
+        C: FromColorUnclamped<U>,
+        U: FromColor<C>,
+    {
+        let points: Vec<_> = colors
+            .into_iter()
+            .map(|(position, color)| (position, C::from_color_unclamped(color)))
+            .collect();
+
+        Converted {
+            gradient: Gradient::with_domain(points),
+            color: PhantomData,
+        }
+    }
+
+    /// Read a gradient from the byte representation that is created by
+    /// [`to_bytes`](Gradient::to_bytes). It will use linear interpolation.
+    ///
+    /// The control points are checked the same way as in
+    /// [`try_with_domain`](Gradient::try_with_domain), so NaN or unordered
+    /// positions are returned as
+    /// [`FromBytesError::InvalidGradient`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Gradient<C>, FromBytesError>
+    where
+        C: ColorBytes<<C as Mix>::Scalar>,
+        C::Scalar: ComponentBytes,
+    {
+        if bytes.len() < 5 {
+            return Err(FromBytesError::Length {
+                length: bytes.len(),
+                expected: 5,
+            });
+        }
+
+        let wrap = match bytes[0] {
+            0 => Wrap::Clamp,
+            1 => Wrap::Repeat,
+            2 => Wrap::Mirror,
+            wrap => return Err(FromBytesError::InvalidWrap(wrap)),
+        };
+
+        let count = u32::read_le_bytes(&bytes[1..5]) as usize;
+        if count == 0 {
+            return Err(FromBytesError::EmptyGradient);
+        }
+
+        let point_size = C::Scalar::SIZE + C::BYTES;
+        let expected = count
+            .checked_mul(point_size)
+            .and_then(|size| size.checked_add(5))
+            .unwrap_or(usize::MAX);
+        if bytes.len() != expected {
+            return Err(FromBytesError::Length {
+                length: bytes.len(),
+                expected,
+            });
+        }
+
+        let points: Vec<_> = bytes[5..]
+            .chunks_exact(point_size)
+            .map(|chunk| {
+                let (position, color) = chunk.split_at(C::Scalar::SIZE);
+                C::from_bytes(color).map(|color| (C::Scalar::read_le_bytes(position), color))
+            })
+            .collect::<Result<_, _>>()?;
+        check_points(&points)?;
+
+        Ok(Gradient {
+            points,
+            interpolation: Linear,
+            wrap,
+            color: PhantomData,
+        })
+    }
+
+    /// Parse a CSS `linear-gradient(...)` into a gradient of sRGB colors,
+    /// that interpolates in the color space `C`.
+    ///
+    /// The color stops may have one or two percentage positions, and missing
+    /// positions are filled in the same way as in CSS. The direction is
+    /// accepted but ignored. Colors can be written in hexadecimal form, or as
+    /// names if the `"named_from_str"` feature is enabled. All of the formats
+    /// from the [`css`](crate::css) module are accepted if the `"parsing"`
+    /// feature is enabled, but any alpha values are ignored.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb, Srgb};
+    ///
+    /// let gradient = Gradient::<LinSrgb>::from_css(
+    ///     "linear-gradient(to right, #ff0000, #00ff00 25%, #0000ff)"
+    /// ).unwrap();
+    ///
+    /// let green: Srgb = gradient.get(0.25);
+    /// assert_eq!(green.into_format(), Srgb::new(0u8, 255, 0));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_css(input: &str) -> Result<Converted<Srgb, C>, ParseCssGradientError>
+    where
+        C: FromColorUnclamped<Srgb>,
+        C::Scalar: FromF64,
+        Srgb: FromColor<C>,
+    {
+        let stops = css::parse_stops(input)?;
+
+        Ok(Gradient::with_domain_in(
+            stops
+                .into_iter()
+                .map(|(position, color)| (from_f64(position as f64), color)),
+        ))
+    }
+}
+
+/// Decides what a gradient returns for positions outside its domain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum Wrap {
+    /// Use the color of the closest control point. This is the default.
+    #[default]
+    Clamp,
+
+    /// Repeat the gradient, so it starts over from the beginning after the
+    /// end of the domain.
+    Repeat,
+
+    /// Repeat the gradient, but reverse every other repetition, so it goes
+    /// back and forth between the ends of the domain.
+    Mirror,
+}
+
+impl Wrap {
+    /// Map `i` into the domain `(min, max)`, according to the wrap mode.
+    /// Positions within the domain are left as they are.
+    fn wrap_position<T: Float>(self, i: T, (min, max): (T, T)) -> T {
+        let width = max - min;
+
+        if (i >= min && i <= max) || width <= T::zero() {
+            return i;
+        }
+
+        match self {
+            Wrap::Clamp => i,
+            Wrap::Repeat => {
+                let offset = (i - min) % width;
+                if offset < T::zero() {
+                    min + offset + width
+                } else {
+                    min + offset
+                }
+            }
+            Wrap::Mirror => {
+                let period = width + width;
+                let mut offset = (i - min) % period;
+                if offset < T::zero() {
+                    offset = offset + period;
+                }
+
+                if offset > width {
+                    min + period - offset
+                } else {
+                    min + offset
+                }
+            }
+        }
+    }
+}
+
+/// The error type for the checked gradient constructors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientError {
+    /// There were no control points.
+    Empty,
+
+    /// The position of the control point at this index was NaN.
+    NanPosition(usize),
+
+    /// The position of the control point at this index was less than the
+    /// position of the previous control point.
+    Unordered(usize),
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GradientError::Empty => f.write_str("a Gradient must contain at least one color"),
+            GradientError::NanPosition(index) => {
+                write!(f, "the position of control point {} is NaN", index)
+            }
+            GradientError::Unordered(index) => write!(
+                f,
+                "the position of control point {} is less than the previous position",
+                index
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GradientError {}
+
+/// A gradient that interpolates in another color space than the color type it
+/// produces.
+///
+/// The control points are stored as colors of type `C`, where the
+/// interpolation happens, and the results are converted to `U` when they are
+/// taken from the gradient. It's created using
+/// [`Gradient::new_in`](Gradient::new_in) or
+/// [`Gradient::with_domain_in`](Gradient::with_domain_in).
+#[derive(Clone, Debug)]
+pub struct Converted<
+    U,
+    C,
+    #[cfg(feature = "alloc")] T = Vec<(<C as Mix>::Scalar, C)>,
+    #[cfg(not(feature = "alloc"))] T,
+    I = Linear,
+>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    gradient: Gradient<C, T, I>,
+    color: PhantomData<U>,
+}
+
+impl<U, C, T, I> Converted<U, C, T, I>
+where
+    C: Mix,
+    T: AsRef<[(C::Scalar, C)]>,
+    U: FromColor<C>,
+{
+    /// Get a color from the gradient, converted to `U`. The color of the
+    /// closest control point will be returned if `i` is outside the domain.
+    pub fn get(&self, i: C::Scalar) -> U
+    where
+        C: Clone,
+        I: Interpolation<C>,
+    {
+        U::from_color(self.gradient.get(i))
+    }
+
+    /// Take `n` evenly spaced colors from the gradient, as an iterator of
+    /// converted colors. See [`Gradient::take`](Gradient::take) for details.
+    pub fn take(&self, n: usize) -> ConvertedTake<'_, U, C, T, I>
+    where
+        C: Clone,
+        C::Scalar: FromF64,
+        I: Interpolation<C>,
+    {
+        self.gradient.take(n).map(U::from_color as fn(C) -> U)
+    }
+
+    /// Get the limits of this gradient's domain.
+    pub fn domain(&self) -> (C::Scalar, C::Scalar) {
+        self.gradient.domain()
+    }
+
+    /// Get the underlying gradient, which interpolates and produces colors of
+    /// type `C`.
+    pub fn gradient(&self) -> &Gradient<C, T, I> {
+        &self.gradient
+    }
+
+    /// Unwrap the underlying gradient, which interpolates and produces colors
+    /// of type `C`.
+    pub fn into_gradient(self) -> Gradient<C, T, I> {
+        self.gradient
+    }
+
+    /// Change the interpolation method of the underlying gradient.
+    pub fn with_interpolation<J: Interpolation<C>>(
+        self,
+        interpolation: J,
+    ) -> Converted<U, C, T, J> {
+        Converted {
+            gradient: self.gradient.with_interpolation(interpolation),
+            color: PhantomData,
+        }
+    }
 
-        for (i, &mut (ref mut p, _)) in points.iter_mut().enumerate() {
-            *p = from_f64::<C::Scalar>(i as f64) * step_size;
+    /// Change what the underlying gradient returns for positions outside its
+    /// domain.
+    pub fn with_wrap(self, wrap: Wrap) -> Self {
+        Converted {
+            gradient: self.gradient.with_wrap(wrap),
+            color: PhantomData,
         }
+    }
 
-        Gradient(points, PhantomData)
+    /// Apply an easing function to each segment of the underlying gradient.
+    pub fn with_easing<E: Easing<C::Scalar>>(self, easing: E) -> Converted<U, C, T, Eased<I, E>> {
+        Converted {
+            gradient: self.gradient.with_easing(easing),
+            color: PhantomData,
+        }
     }
 }
 
+/// An iterator over interpolated and converted colors.
+///
+/// This is returned from [`Converted::take`], and yields the colors from
+/// [`Take`] after converting them with [`FromColor`]. It's a type alias, so it
+/// can be named in struct fields and function signatures:
+///
+/// ```
+/// use palette::{Gradient, Lab, Srgb};
+/// use palette::gradient::{Converted, ConvertedTake};
+///
+/// fn steps(gradient: &Converted<Srgb, Lab>) -> ConvertedTake<'_, Srgb, Lab> {
+///     gradient.take(5)
+/// }
+///
+/// let gradient = Gradient::<Lab>::new_in(vec![
+///     Srgb::new(1.0, 0.0, 0.0),
+///     Srgb::new(0.0, 0.0, 1.0),
+/// ]);
+///
+/// assert_eq!(steps(&gradient).count(), 5);
+/// ```
+pub type ConvertedTake<
+    'a,
+    U,
+    C,
+    #[cfg(feature = "alloc")] T = Vec<(<C as Mix>::Scalar, C)>,
+    #[cfg(not(feature = "alloc"))] T,
+    I = Linear,
+> = core::iter::Map<Take<'a, C, T, I>, fn(C) -> U>;
+
 /// An iterator over interpolated colors.
-#[derive(Clone)]
-pub struct Take<'a, C, T = Vec<(<C as Mix>::Scalar, C)>>
+pub struct Take<
+    'a,
+    C,
+    #[cfg(feature = "alloc")] T = Vec<(<C as Mix>::Scalar, C)>,
+    #[cfg(not(feature = "alloc"))] T,
+    I = Linear,
+>
 where
-    C: Mix + Clone + 'a,
+    C: Mix + 'a,
     T: AsRef<[(C::Scalar, C)]>
 {
-    gradient: MaybeSlice<'a, C, T>,
+    gradient: MaybeSlice<'a, C, T, I>,
     from: C::Scalar,
     diff: C::Scalar,
     len: usize,
@@ -202,11 +1632,31 @@ where
     from_end: usize,
 }
 
-impl<'a, C, T> Iterator for Take<'a, C, T>
+// Implemented manually, since only the references and positions are cloned,
+// and `C`, `T` and `I` don't have to implement `Clone`.
+impl<'a, C, T, I> Clone for Take<'a, C, T, I>
+where
+    C: Mix + 'a,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn clone(&self) -> Self {
+        Take {
+            gradient: self.gradient.clone(),
+            from: self.from,
+            diff: self.diff,
+            len: self.len,
+            from_head: self.from_head,
+            from_end: self.from_end,
+        }
+    }
+}
+
+impl<'a, C, T, I> Iterator for Take<'a, C, T, I>
 where
     C::Scalar: FromF64,
     C: Mix + Clone,
-    T: AsRef<[(C::Scalar, C)]>
+    T: AsRef<[(C::Scalar, C)]>,
+    I: Interpolation<C>,
 {
     type Item = C;
 
@@ -235,18 +1685,20 @@ where
     }
 }
 
-impl<'a, C, T> ExactSizeIterator for Take<'a, C, T>
+impl<'a, C, T, I> ExactSizeIterator for Take<'a, C, T, I>
 where
     C::Scalar: FromF64,
     C: Mix + Clone,
-    T: AsRef<[(C::Scalar, C)]>
+    T: AsRef<[(C::Scalar, C)]>,
+    I: Interpolation<C>,
 {}
 
-impl<'a, C, T> DoubleEndedIterator for Take<'a, C, T>
+impl<'a, C, T, I> DoubleEndedIterator for Take<'a, C, T, I>
 where
     C::Scalar: FromF64,
     C: Mix + Clone,
-    T: AsRef<[(C::Scalar, C)]>
+    T: AsRef<[(C::Scalar, C)]>,
+    I: Interpolation<C>,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.from_head + self.from_end < self.len {
@@ -266,31 +1718,172 @@ where
     }
 }
 
+/// A parallel iterator over interpolated colors.
+///
+/// It's created by calling `into_par_iter` on a [`Take`] iterator, and splits
+/// the colors between `rayon`'s threads. Requires the `rayon` feature.
+///
+/// ```
+/// use palette::{Gradient, LinSrgb};
+/// use rayon::prelude::*;
+///
+/// let gradient = Gradient::new(vec![
+///     LinSrgb::new(0.0, 0.0, 0.0),
+///     LinSrgb::new(1.0, 0.5, 0.0),
+/// ]);
+///
+/// let colors: Vec<_> = gradient.take(1000).into_par_iter().collect();
+/// assert_eq!(colors, gradient.take(1000).collect::<Vec<_>>());
+/// ```
+#[cfg(feature = "rayon")]
+pub struct ParTake<'a, C, T, I>
+where
+    C: Mix + 'a,
+    T: AsRef<[(C::Scalar, C)]>
+{
+    take: Take<'a, C, T, I>,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, C, T, I> Clone for ParTake<'a, C, T, I>
+where
+    C: Mix + 'a,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn clone(&self) -> Self {
+        ParTake {
+            take: self.take.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, C, T, I> IntoParallelIterator for Take<'a, C, T, I>
+where
+    C::Scalar: FromF64 + Send + Sync,
+    C: Mix + Clone + Send + Sync,
+    T: AsRef<[(C::Scalar, C)]> + Sync,
+    I: Interpolation<C> + Sync,
+{
+    type Iter = ParTake<'a, C, T, I>;
+    type Item = C;
+
+    fn into_par_iter(self) -> ParTake<'a, C, T, I> {
+        ParTake { take: self }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, C, T, I> ParallelIterator for ParTake<'a, C, T, I>
+where
+    C::Scalar: FromF64 + Send + Sync,
+    C: Mix + Clone + Send + Sync,
+    T: AsRef<[(C::Scalar, C)]> + Sync,
+    I: Interpolation<C> + Sync,
+{
+    type Item = C;
+
+    fn drive_unindexed<R: UnindexedConsumer<C>>(self, consumer: R) -> R::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.take.len())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, C, T, I> IndexedParallelIterator for ParTake<'a, C, T, I>
+where
+    C::Scalar: FromF64 + Send + Sync,
+    C: Mix + Clone + Send + Sync,
+    T: AsRef<[(C::Scalar, C)]> + Sync,
+    I: Interpolation<C> + Sync,
+{
+    fn len(&self) -> usize {
+        self.take.len()
+    }
+
+    fn drive<R: Consumer<C>>(self, consumer: R) -> R::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<B: ProducerCallback<C>>(self, callback: B) -> B::Output {
+        callback.callback(self)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, C, T, I> Producer for ParTake<'a, C, T, I>
+where
+    C::Scalar: FromF64 + Send + Sync,
+    C: Mix + Clone + Send + Sync,
+    T: AsRef<[(C::Scalar, C)]> + Sync,
+    I: Interpolation<C> + Sync,
+{
+    type Item = C;
+    type IntoIter = Take<'a, C, T, I>;
+
+    fn into_iter(self) -> Take<'a, C, T, I> {
+        self.take
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        // Both halves share the same sample positions, and only differ in
+        // which of them they have left to take.
+        let split = self.take.from_head + index;
+
+        let head = Take {
+            gradient: self.take.gradient.clone(),
+            from: self.take.from,
+            diff: self.take.diff,
+            len: self.take.len,
+            from_head: self.take.from_head,
+            from_end: self.take.len - split,
+        };
+
+        let mut tail = self.take;
+        tail.from_head = split;
+
+        (ParTake { take: head }, ParTake { take: tail })
+    }
+}
+
 /// A slice of a Gradient that limits its domain.
-#[derive(Clone, Debug)]
-pub struct Slice<'a, C,T = Vec<(<C as Mix>::Scalar, C)>>
+#[derive(Debug)]
+pub struct Slice<
+    'a,
+    C,
+    #[cfg(feature = "alloc")] T = Vec<(<C as Mix>::Scalar, C)>,
+    #[cfg(not(feature = "alloc"))] T,
+    I = Linear,
+>
 where
-    C: Mix + Clone + 'a,
+    C: Mix + 'a,
     T: AsRef<[(C::Scalar, C)]>
 {
-    gradient: &'a Gradient<C,T>,
+    gradient: &'a Gradient<C, T, I>,
     range: Range<C::Scalar>,
 }
 
-impl<'a, C, T> Slice<'a, C, T>
+impl<'a, C, T, I> Slice<'a, C, T, I>
 where
-    C: Mix + Clone + 'a,
+    C: Mix + 'a,
     T: AsRef<[(C::Scalar, C)]>
 {
     /// Get a color from the gradient slice. The color of the closest domain
     /// limit will be returned if `i` is outside the domain.
-    pub fn get(&self, i: C::Scalar) -> C {
+    pub fn get(&self, i: C::Scalar) -> C
+    where
+        C: Clone,
+        I: Interpolation<C>,
+    {
         self.gradient.get(self.range.clamp(i))
     }
 
     /// Slice this gradient slice to further limit its domain. Ranges outside
     /// the domain will be clamped to the nearest domain limit.
-    pub fn slice<R: Into<Range<C::Scalar>>>(&self, range: R) -> Slice<C,T> {
+    pub fn slice<R: Into<Range<C::Scalar>>>(&self, range: R) -> Slice<'_, C, T, I> {
         Slice {
             gradient: self.gradient,
             range: self.range.constrain(&range.into()),
@@ -310,15 +1903,58 @@ where
             (self.range.from.unwrap_or(from), self.range.to.unwrap_or(to))
         }
     }
+
+    /// Fill `buffer` with evenly spaced colors from the gradient slice. See
+    /// [`Gradient::fill_slice`] for more details.
+    pub fn fill_slice(&self, buffer: &mut [C])
+    where
+        C: Clone,
+        C::Scalar: FromF64,
+        I: Interpolation<C>,
+    {
+        fill_evenly(buffer, self.domain(), |i| self.get(i));
+    }
 }
 
-impl<'a, C, T> Slice<'a, C, T>
+/// Fill `buffer` with the colors at evenly spaced positions from `min` to
+/// `max`, the same way as [`Take`] spaces them.
+fn fill_evenly<C, T, F>(buffer: &mut [C], (min, max): (T, T), get: F)
 where
-    C: Mix + Clone + 'a,
-    T: AsRef<[(C::Scalar, C)]> + Clone
+    T: Float + FromF64,
+    F: Fn(T) -> C,
+{
+    match buffer.len() {
+        0 => {}
+        1 => buffer[0] = get(min),
+        len => {
+            let step = (max - min) / from_f64((len - 1) as f64);
+            for (index, color) in buffer.iter_mut().enumerate() {
+                *color = get(min + step * from_f64(index as f64));
+            }
+        }
+    }
+}
+
+impl<'a, C, T, I> Clone for Slice<'a, C, T, I>
+where
+    C: Mix + 'a,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn clone(&self) -> Self {
+        Slice {
+            gradient: self.gradient,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<'a, C, T, I> Slice<'a, C, T, I>
+where
+    C: Mix + 'a,
+    T: AsRef<[(C::Scalar, C)]>,
 {
     /// Take `n` evenly spaced colors from the gradient slice, as an iterator.
-    pub fn take(&self, n: usize) -> Take<C, T> {
+    pub fn take(&self, n: usize) -> Take<'_, C, T, I> {
         let (min, max) = self.domain();
 
         Take {
@@ -381,8 +2017,8 @@ impl<T: Float> Range<T> {
     }
 }
 
-impl<T: Float> From<::std::ops::Range<T>> for Range<T> {
-    fn from(range: ::std::ops::Range<T>) -> Range<T> {
+impl<T: Float> From<::core::ops::Range<T>> for Range<T> {
+    fn from(range: ::core::ops::Range<T>) -> Range<T> {
         Range {
             from: Some(range.start),
             to: Some(range.end),
@@ -390,8 +2026,8 @@ impl<T: Float> From<::std::ops::Range<T>> for Range<T> {
     }
 }
 
-impl<T: Float> From<::std::ops::RangeFrom<T>> for Range<T> {
-    fn from(range: ::std::ops::RangeFrom<T>) -> Range<T> {
+impl<T: Float> From<::core::ops::RangeFrom<T>> for Range<T> {
+    fn from(range: ::core::ops::RangeFrom<T>) -> Range<T> {
         Range {
             from: Some(range.start),
             to: None,
@@ -399,8 +2035,8 @@ impl<T: Float> From<::std::ops::RangeFrom<T>> for Range<T> {
     }
 }
 
-impl<T: Float> From<::std::ops::RangeTo<T>> for Range<T> {
-    fn from(range: ::std::ops::RangeTo<T>) -> Range<T> {
+impl<T: Float> From<::core::ops::RangeTo<T>> for Range<T> {
+    fn from(range: ::core::ops::RangeTo<T>) -> Range<T> {
         Range {
             from: None,
             to: Some(range.end),
@@ -408,8 +2044,8 @@ impl<T: Float> From<::std::ops::RangeTo<T>> for Range<T> {
     }
 }
 
-impl<T: Float> From<::std::ops::RangeFull> for Range<T> {
-    fn from(_range: ::std::ops::RangeFull) -> Range<T> {
+impl<T: Float> From<::core::ops::RangeFull> for Range<T> {
+    fn from(_range: ::core::ops::RangeFull) -> Range<T> {
         Range {
             from: None,
             to: None,
@@ -502,20 +2138,20 @@ where
     }
 }
 
-#[derive(Clone)]
-enum MaybeSlice<'a, C, T = Vec<(<C as Mix>::Scalar, C)>>
+enum MaybeSlice<'a, C, T, I>
 where
-    C: Mix + Clone + 'a,
+    C: Mix + 'a,
     T: AsRef<[(C::Scalar, C)]>
 {
-    NotSlice(&'a Gradient<C, T>),
-    Slice(Slice<'a, C, T>),
+    NotSlice(&'a Gradient<C, T, I>),
+    Slice(Slice<'a, C, T, I>),
 }
 
-impl<'a, C, T> MaybeSlice<'a, C, T>
+impl<'a, C, T, I> MaybeSlice<'a, C, T, I>
 where
     C: Mix + Clone + 'a,
-    T: AsRef<[(C::Scalar, C)]>
+    T: AsRef<[(C::Scalar, C)]>,
+    I: Interpolation<C>,
 {
     fn get(&self, i: C::Scalar) -> C {
         match *self {
@@ -525,10 +2161,24 @@ where
     }
 }
 
+impl<'a, C, T, I> Clone for MaybeSlice<'a, C, T, I>
+where
+    C: Mix + 'a,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            MaybeSlice::NotSlice(g) => MaybeSlice::NotSlice(g),
+            MaybeSlice::Slice(ref s) => MaybeSlice::Slice(s.clone()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{Gradient, Range};
-    use crate::LinSrgb;
+    use super::interpolation::Interpolation;
+    use super::{Gradient, GradientError, Range, Wrap};
+    use crate::{FromColor, Lab, LinSrgb, Srgb};
 
     #[test]
     fn range_clamp() {
@@ -597,6 +2247,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn without_clone() {
+        use crate::Mix;
+
+        // Only implements `Mix`, and counts how many times it was mixed
+        #[derive(Debug, PartialEq)]
+        struct Counted(f32, Vec<usize>);
+
+        impl Mix for Counted {
+            type Scalar = f32;
+
+            fn mix(&self, other: &Counted, factor: f32) -> Counted {
+                Counted(self.0 + (other.0 - self.0) * factor, vec![self.1.len() + 1])
+            }
+        }
+
+        let mut g = Gradient::with_domain(vec![
+            (0.0, Counted(0.0, vec![])),
+            (1.0, Counted(2.0, vec![])),
+        ]);
+        g.insert(0.5, Counted(1.5, vec![]));
+        let g = g.reverse();
+
+        assert_eq!(g.domain(), (0.0, 1.0));
+        assert_eq!(g.stops()[1], (0.5, Counted(1.5, vec![])));
+        assert_eq!(g.iter().count(), 3);
+        assert_eq!(g.interpolation().interpolate(g.points(), 0, 0.5), Counted(1.75, vec![1]));
+    }
+
     #[test]
     fn inclusive_take() {
         let g = Gradient::new(vec![
@@ -615,4 +2294,493 @@ mod test {
         assert_relative_eq!(v1[0], LinSrgb::new(1.0, 1.0, 0.0));
         assert_relative_eq!(v1[4], LinSrgb::new(0.0, 0.0, 1.0));
     }
+
+    #[test]
+    fn converted_gradient() {
+        let g = Gradient::<Lab>::new_in(vec![Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 0.0, 1.0)]);
+
+        assert_relative_eq!(g.get(0.0), Srgb::new(1.0, 0.0, 0.0), epsilon = 0.0001);
+        assert_relative_eq!(g.get(1.0), Srgb::new(0.0, 0.0, 1.0), epsilon = 0.0001);
+
+        let lab = Gradient::new(vec![
+            Lab::from_color(Srgb::new(1.0, 0.0, 0.0)),
+            Lab::from_color(Srgb::new(0.0, 0.0, 1.0)),
+        ]);
+        for (converted, expected) in g.take(5).zip(lab.take(5)) {
+            assert_relative_eq!(converted, Srgb::from_color(expected));
+        }
+    }
+
+    #[test]
+    fn array_and_slice_gradients() {
+        const POINTS: [(f64, LinSrgb<f64>); 3] = [
+            (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (0.5, LinSrgb::new(0.0, 1.0, 0.0)),
+            (1.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ];
+        const ARRAY: Gradient<LinSrgb<f64>, [(f64, LinSrgb<f64>); 3]> =
+            Gradient::from_array(POINTS);
+        const SLICE: Gradient<LinSrgb<f64>, &[(f64, LinSrgb<f64>)]> =
+            Gradient::from_slice(&POINTS);
+        let vec = Gradient::with_domain(POINTS.to_vec());
+
+        for (a, (s, v)) in ARRAY.take(10).zip(SLICE.take(10).zip(vec.take(10))) {
+            assert_relative_eq!(a, v);
+            assert_relative_eq!(s, v);
+        }
+    }
+
+    #[test]
+    fn wrap_modes() {
+        let g = Gradient::with_domain(vec![
+            (1.0, LinSrgb::new(0.0f64, 0.0, 0.0)),
+            (3.0, LinSrgb::new(1.0, 1.0, 1.0)),
+        ]);
+
+        let clamp = g.clone();
+        assert_relative_eq!(clamp.get(-2.0), LinSrgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(clamp.get(3.5), LinSrgb::new(1.0, 1.0, 1.0));
+
+        let repeat = g.clone().with_wrap(Wrap::Repeat);
+        assert_relative_eq!(repeat.get(3.0), LinSrgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(repeat.get(3.5), LinSrgb::new(0.25, 0.25, 0.25));
+        assert_relative_eq!(repeat.get(0.5), LinSrgb::new(0.75, 0.75, 0.75));
+        assert_relative_eq!(repeat.get(-3.5), LinSrgb::new(0.75, 0.75, 0.75));
+
+        let mirror = g.with_wrap(Wrap::Mirror);
+        assert_relative_eq!(mirror.get(3.5), LinSrgb::new(0.75, 0.75, 0.75));
+        assert_relative_eq!(mirror.get(5.5), LinSrgb::new(0.25, 0.25, 0.25));
+        assert_relative_eq!(mirror.get(0.5), LinSrgb::new(0.25, 0.25, 0.25));
+        assert_relative_eq!(mirror.get(-1.5), LinSrgb::new(0.75, 0.75, 0.75));
+    }
+
+    #[test]
+    fn edit_points() {
+        let mut g = Gradient::new(vec![
+            LinSrgb::new(1.0f64, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+
+        assert_eq!(g.insert(0.25, LinSrgb::new(1.0, 1.0, 1.0)), 1);
+        assert_eq!(g.insert(1.0, LinSrgb::new(0.0, 0.0, 0.0)), 4);
+        assert_eq!(g.set_position(4, 0.0), 1);
+        assert_eq!(g.set_position(0, 0.75), 3);
+        assert_eq!(g.remove(3), (0.75, LinSrgb::new(1.0, 0.0, 0.0)));
+
+        let positions: Vec<_> = g.points().iter().map(|&(p, _)| p).collect();
+        assert_eq!(positions, vec![0.0, 0.25, 0.5, 1.0]);
+
+        let g = g.reverse();
+        let positions: Vec<_> = g.points().iter().map(|&(p, _)| p).collect();
+        assert_eq!(positions, vec![0.0, 0.5, 0.75, 1.0]);
+        assert_eq!(g.points()[0].1, LinSrgb::new(0.0, 0.0, 1.0));
+        assert_eq!(g.points()[2].1, LinSrgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the position of a control point can't be NaN")]
+    fn insert_nan() {
+        let mut g = Gradient::new(vec![LinSrgb::new(1.0, 0.0, 0.0)]);
+        g.insert(f32::NAN, LinSrgb::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the position of a control point can't be NaN")]
+    fn set_position_nan() {
+        let mut g = Gradient::new(vec![LinSrgb::new(1.0, 0.0, 0.0)]);
+        g.set_position(0, f32::NAN);
+    }
+
+    #[test]
+    fn resample_uniform() {
+        use crate::ColorDifference;
+
+        let g = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+        let difference = |a: &LinSrgb<f64>, b: &LinSrgb<f64>| {
+            Lab::from_color(*a).get_color_difference(&Lab::from_color(*b))
+        };
+
+        let colors = g.resample_uniform(6, difference);
+        assert_eq!(colors.len(), 6);
+        assert_relative_eq!(colors[0], LinSrgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(colors[5], LinSrgb::new(1.0, 1.0, 1.0), epsilon = 0.0001);
+
+        let steps: Vec<_> = colors
+            .windows(2)
+            .map(|pair| difference(&pair[0], &pair[1]))
+            .collect();
+        for &step in &steps {
+            assert_relative_eq!(step, steps[0], max_relative = 0.1);
+        }
+
+        // Evenly spaced colors are not perceptually even in linear RGB
+        let taken: Vec<_> = g.take(6).collect();
+        assert!(difference(&taken[0], &taken[1]) > steps[0] * 1.5);
+
+        assert!(g.resample_uniform(0, difference).is_empty());
+        assert_eq!(g.resample_uniform(1, difference).len(), 1);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        use crate::{Hsv, HueInterpolation};
+
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0f32, 0.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ])
+        .with_wrap(Wrap::Repeat);
+        let serialized = ::serde_json::to_string(&g).unwrap();
+
+        assert_eq!(
+            serialized,
+            r#"{"points":[[0.0,{"red":1.0,"green":0.0,"blue":0.0}],[1.0,{"red":0.0,"green":0.0,"blue":1.0}]],"wrap":"Repeat"}"#
+        );
+
+        let g = Gradient::new(vec![Hsv::new(0.0f32, 1.0, 1.0), Hsv::new(0.0, 1.0, 1.0)])
+            .with_interpolation(HueInterpolation::Longer);
+        let serialized = ::serde_json::to_string(&g).unwrap();
+        assert!(serialized.contains(r#""interpolation":"Longer""#));
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Gradient<LinSrgb> = ::serde_json::from_str(
+            r#"{"points":[[0.0,{"red":1.0,"green":0.0,"blue":0.0}],[1.0,{"red":0.0,"green":0.0,"blue":1.0}]]}"#,
+        )
+        .unwrap();
+
+        let expected = Gradient::new(vec![
+            LinSrgb::new(1.0f32, 0.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+        assert_eq!(deserialized.points(), expected.points());
+        assert_eq!(deserialized.wrap(), Wrap::Clamp);
+
+        let empty: Result<Gradient<LinSrgb>, _> = ::serde_json::from_str(r#"{"points":[]}"#);
+        assert!(empty.is_err());
+    }
+
+    #[test]
+    fn from_fn() {
+        let g = Gradient::from_fn((-1.0, 1.0), |x: f64| LinSrgb::new(x, -x, x * x));
+        assert_relative_eq!(g.get(0.5), LinSrgb::new(0.5, -0.5, 0.25));
+        assert_relative_eq!(g.get(-2.0), LinSrgb::new(-1.0, 1.0, 1.0));
+        assert_eq!(g.stops().len(), 2);
+
+        let g = g.with_wrap(Wrap::Repeat);
+        assert_relative_eq!(g.get(1.5), LinSrgb::new(-0.5, 0.5, 0.25));
+
+        let colors: Vec<_> = g.take(5).collect();
+        assert_relative_eq!(colors[1], LinSrgb::new(-0.5, 0.5, 0.25));
+        assert_relative_eq!(colors[4], LinSrgb::new(1.0, -1.0, 1.0));
+    }
+
+    #[test]
+    fn moved_from_fn() {
+        let g = Gradient::from_fn((0.0, 1.0), |x: f64| LinSrgb::new(x, 1.0 - x, 0.0));
+
+        // The function moves with the ends, so there are no jumps at the ends
+        let shifted = g.clone().shift(2.0);
+        assert_eq!(shifted.domain(), (2.0, 3.0));
+        assert_relative_eq!(shifted.get(1.5), LinSrgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(shifted.get(2.0), LinSrgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(shifted.get(2.001), LinSrgb::new(0.001, 0.999, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(shifted.get(2.999), LinSrgb::new(0.999, 0.001, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(shifted.get(3.0), LinSrgb::new(1.0, 0.0, 0.0));
+
+        let reversed = g.reverse();
+        assert_eq!(reversed.domain(), (0.0, 1.0));
+        assert_relative_eq!(reversed.get(0.0), LinSrgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(reversed.get(0.001), LinSrgb::new(0.999, 0.001, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(reversed.get(0.999), LinSrgb::new(0.001, 0.999, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(reversed.get(1.0), LinSrgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(reversed.get(1.5), LinSrgb::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn scaled_from_fn() {
+        let g = Gradient::from_fn((0.0, 1.0), |x: f64| LinSrgb::new(x, 1.0 - x, 0.0));
+
+        let stretched = g.clone().scale(4.0);
+        assert_eq!(stretched.domain(), (0.0, 4.0));
+        assert_relative_eq!(stretched.get(1.0), LinSrgb::new(0.25, 0.75, 0.0));
+        assert_relative_eq!(stretched.get(3.996), LinSrgb::new(0.999, 0.001, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(stretched.get(4.0), LinSrgb::new(1.0, 0.0, 0.0));
+
+        // A negative factor flips the function together with the ends
+        let flipped = g.clone().scale(-2.0);
+        assert_eq!(flipped.domain(), (-2.0, 0.0));
+        assert_relative_eq!(flipped.get(-2.0), LinSrgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(flipped.get(-1.998), LinSrgb::new(0.999, 0.001, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(flipped.get(-0.5), LinSrgb::new(0.25, 0.75, 0.0));
+        assert_relative_eq!(flipped.get(-0.002), LinSrgb::new(0.001, 0.999, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(flipped.get(0.0), LinSrgb::new(0.0, 1.0, 0.0));
+
+        let moved = g.clone().shift(1.0).scale(2.0);
+        assert_eq!(moved.domain(), (2.0, 4.0));
+        assert_relative_eq!(moved.get(3.0), LinSrgb::new(0.5, 0.5, 0.0));
+
+        // Moving an end changes how much of the function is used
+        let mut extended = g;
+        assert_eq!(extended.set_position(1, 2.0), 1);
+        assert_relative_eq!(extended.get(1.999), LinSrgb::new(1.999, -0.999, 0.0), epsilon = 1e-9);
+        assert_relative_eq!(extended.get(2.0), LinSrgb::new(2.0, -1.0, 0.0));
+        assert_relative_eq!(extended.get(3.0), LinSrgb::new(2.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn iterate_points() {
+        let points = [
+            (0.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (0.25, LinSrgb::new(0.0, 1.0, 0.0)),
+            (1.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ];
+
+        let g = Gradient::from_array(points);
+        assert_eq!(g.iter().count(), 3);
+        assert_eq!((&g).into_iter().collect::<Vec<_>>(), points.iter().collect::<Vec<_>>());
+        assert_eq!(g.into_iter().collect::<Vec<_>>(), points.to_vec());
+
+        let g = Gradient::with_domain(points.to_vec());
+        let mut visited = Vec::new();
+        for &(position, _) in &g {
+            visited.push(position);
+        }
+        assert_eq!(visited, vec![0.0, 0.25, 1.0]);
+        assert_eq!(g.into_iter().last(), Some(points[2]));
+
+        let g = Gradient::from_slice(&points);
+        assert_eq!(g.stops(), &points);
+    }
+
+    #[test]
+    fn domain_transforms() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+
+        let shifted = g.clone().shift(-1.0);
+        assert_eq!(shifted.domain(), (-1.0, 0.0));
+        assert_eq!(shifted.get(-0.5), g.get(0.5));
+
+        let flipped = g.clone().scale(-2.0);
+        assert_eq!(flipped.domain(), (-2.0, 0.0));
+        assert_eq!(flipped.get(-2.0), LinSrgb::new(0.0, 0.0, 1.0));
+        assert_eq!(flipped.get(-0.5), g.get(0.25));
+
+        let concatenated = g.clone().concat(g.clone().shift(5.0));
+        assert_eq!(concatenated.points().len(), 6);
+        assert_eq!(concatenated.domain(), (0.0, 2.0));
+        assert_eq!(concatenated.get(1.5), g.get(0.5));
+
+        // The seam has a hard transition, without NaN values
+        let seam = concatenated.get(1.0);
+        assert!(seam == LinSrgb::new(0.0, 0.0, 1.0) || seam == LinSrgb::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn fill_slice() {
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+
+        for &n in &[0, 1, 2, 7, 100] {
+            let mut buffer = vec![LinSrgb::default(); n];
+            g.fill_slice(&mut buffer);
+            assert_eq!(buffer, g.take(n).collect::<Vec<_>>());
+
+            g.slice(0.2..0.7).fill_slice(&mut buffer);
+            assert_eq!(buffer, g.slice(0.2..0.7).take(n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn new_with_domain() {
+        let colors = vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ];
+
+        let g = Gradient::new_with_domain(colors.clone(), 0.0, 10.0);
+        let positions: Vec<_> = g.points().iter().map(|&(position, _)| position).collect();
+        assert_eq!(positions, vec![0.0, 5.0, 10.0]);
+
+        let g = Gradient::new_with_domain(colors.clone(), -1.0, 1.0);
+        assert_eq!(g.get(0.0), colors[1]);
+
+        let g = Gradient::new_with_domain(vec![colors[0]], 2.0, 3.0);
+        assert_eq!(g.domain(), (2.0, 2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reversed_new_with_domain() {
+        Gradient::new_with_domain(vec![LinSrgb::new(1.0, 0.0, 0.0)], 1.0, 0.0);
+    }
+
+    #[test]
+    fn with_domain_sorted() {
+        let red = LinSrgb::new(1.0, 0.0, 0.0);
+        let green = LinSrgb::new(0.0, 1.0, 0.0);
+        let blue = LinSrgb::new(0.0, 0.0, 1.0);
+
+        let g = Gradient::with_domain_sorted(vec![
+            (1.0, blue),
+            (0.5, red),
+            (0.0, red),
+            (0.5, green),
+            (0.5, blue),
+            (0.5, green),
+        ])
+        .unwrap();
+        assert_eq!(
+            g.points(),
+            &[(0.0, red), (0.5, red), (0.5, green), (1.0, blue)]
+        );
+        assert_eq!(g.get(0.25), red);
+        assert_eq!(g.get(0.75), LinSrgb::new(0.0, 0.5, 0.5));
+
+        assert_eq!(
+            Gradient::<LinSrgb>::with_domain_sorted(vec![]).unwrap_err(),
+            GradientError::Empty
+        );
+        assert_eq!(
+            Gradient::with_domain_sorted(vec![(0.0, red), (1.0, blue), (f32::NAN, green)])
+                .unwrap_err(),
+            GradientError::NanPosition(2)
+        );
+    }
+
+    #[test]
+    fn checked_constructors() {
+        let red = LinSrgb::new(1.0, 0.0, 0.0);
+        let blue = LinSrgb::new(0.0, 0.0, 1.0);
+
+        assert!(Gradient::try_with_domain(vec![(0.0, red), (0.0, blue), (1.0, red)]).is_ok());
+        assert_eq!(
+            Gradient::<LinSrgb>::try_with_domain(vec![]).unwrap_err(),
+            GradientError::Empty
+        );
+        assert_eq!(
+            Gradient::try_with_domain([(0.0, red), (f32::NAN, blue)]).unwrap_err(),
+            GradientError::NanPosition(1)
+        );
+        assert_eq!(
+            Gradient::try_with_domain(&[(0.0, red), (1.0, blue), (0.5, red)][..]).unwrap_err(),
+            GradientError::Unordered(2)
+        );
+
+        assert_eq!(
+            Gradient::<LinSrgb>::try_new(vec![]).unwrap_err(),
+            GradientError::Empty
+        );
+        assert_eq!(
+            Gradient::try_new_with_domain(vec![red, blue], 1.0, 0.0).unwrap_err(),
+            GradientError::Unordered(1)
+        );
+        assert_eq!(
+            Gradient::try_new_with_domain(vec![red], 1.0, 0.0).unwrap_err(),
+            GradientError::Unordered(1)
+        );
+        assert_eq!(
+            Gradient::try_new_with_domain(vec![red], 0.0, f32::NAN).unwrap_err(),
+            GradientError::NanPosition(1)
+        );
+        assert_eq!(
+            Gradient::try_new_with_domain(vec![red, blue], 0.0, 2.0)
+                .unwrap()
+                .domain(),
+            (0.0, 2.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the position of control point 1 is less than the previous position")]
+    fn unordered_with_domain() {
+        Gradient::with_domain(vec![
+            (1.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (0.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ]);
+    }
+
+    #[test]
+    fn bytes() {
+        use crate::bytes::FromBytesError;
+
+        let g = Gradient::with_domain(vec![
+            (-1.0, LinSrgb::new(1.0, 0.0, 0.0)),
+            (2.0, LinSrgb::new(0.0, 0.0, 1.0)),
+        ])
+        .with_wrap(Wrap::Mirror);
+
+        let bytes = g.to_bytes();
+        let decoded = Gradient::<LinSrgb>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.points(), g.points());
+        assert_eq!(decoded.wrap(), Wrap::Mirror);
+        assert_eq!(decoded.fingerprint(), g.fingerprint());
+        assert_ne!(g.clone().with_wrap(Wrap::Clamp).fingerprint(), g.fingerprint());
+
+        assert_eq!(
+            Gradient::<LinSrgb>::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            FromBytesError::Length {
+                length: bytes.len() - 1,
+                expected: bytes.len()
+            }
+        );
+        assert_eq!(
+            Gradient::<LinSrgb>::from_bytes(&[3, 1, 0, 0, 0]).unwrap_err(),
+            FromBytesError::InvalidWrap(3)
+        );
+        assert_eq!(
+            Gradient::<LinSrgb>::from_bytes(&[0, 0, 0, 0, 0]).unwrap_err(),
+            FromBytesError::EmptyGradient
+        );
+
+        // Swap the positions of the two control points
+        let mut unordered = bytes.clone();
+        unordered[5..9].copy_from_slice(&2.0f32.to_le_bytes());
+        unordered[21..25].copy_from_slice(&(-1.0f32).to_le_bytes());
+        assert_eq!(
+            Gradient::<LinSrgb>::from_bytes(&unordered).unwrap_err(),
+            FromBytesError::InvalidGradient(GradientError::Unordered(1))
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_take() {
+        use rayon::prelude::*;
+
+        let g = Gradient::new(vec![
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+        ]);
+
+        for &n in &[0, 1, 2, 7, 1000] {
+            let parallel: Vec<_> = g.take(n).into_par_iter().with_max_len(3).collect();
+            assert_eq!(parallel, g.take(n).collect::<Vec<_>>());
+
+            let parallel: Vec<_> = g.slice(0.2..0.8).take(n).into_par_iter().rev().collect();
+            assert_eq!(parallel, g.slice(0.2..0.8).take(n).rev().collect::<Vec<_>>());
+        }
+
+        let mut partial = g.take(10);
+        partial.next();
+        partial.next_back();
+        let parallel: Vec<_> = partial.clone().into_par_iter().with_max_len(2).collect();
+        assert_eq!(parallel, partial.collect::<Vec<_>>());
+    }
 }
@@ -0,0 +1,185 @@
+use num_traits::{One, Zero};
+
+use crate::{clamp, Mix};
+
+/// A two-dimensional gradient, between the colors of the four corners of a
+/// square.
+///
+/// The square has the domain `[0.0, 1.0]` on both axes, where `x` goes from
+/// left to right and `y` goes from top to bottom. The colors are mixed in the
+/// color space `C`, so convert the corners to the color space that should be
+/// used for the interpolation, such as [`Lab`](crate::Lab) for perceptually
+/// even transitions, or [`LinSrgb`](crate::LinSrgb) for physically correct
+/// light mixing. This makes it useful for mesh gradients, where each patch
+/// of the mesh is a `Gradient2d`, and for coloring surfaces, such as
+/// heatmaps.
+///
+/// ```
+/// use palette::gradient::Gradient2d;
+/// use palette::{FromColor, IntoColor, Lab, Srgb};
+///
+/// let patch = Gradient2d::new(
+///     Lab::from_color(Srgb::new(1.0, 0.0, 0.0)),
+///     Lab::from_color(Srgb::new(0.0, 1.0, 0.0)),
+///     Lab::from_color(Srgb::new(0.0, 0.0, 1.0)),
+///     Lab::from_color(Srgb::new(1.0, 1.0, 1.0)),
+/// );
+///
+/// let center: Srgb = patch.get(0.5, 0.5).into_color();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradient2d<C> {
+    top_left: C,
+    top_right: C,
+    bottom_left: C,
+    bottom_right: C,
+    interpolation: Interpolation2d,
+}
+
+impl<C> Gradient2d<C> {
+    /// Create a gradient between four corner colors, with bilinear
+    /// interpolation.
+    pub const fn new(top_left: C, top_right: C, bottom_left: C, bottom_right: C) -> Self {
+        Gradient2d {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            interpolation: Interpolation2d::Bilinear,
+        }
+    }
+
+    /// Change the interpolation method of the gradient.
+    pub fn with_interpolation(mut self, interpolation: Interpolation2d) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Get the interpolation method of the gradient.
+    pub fn interpolation(&self) -> Interpolation2d {
+        self.interpolation
+    }
+
+    /// Get the corner colors, in the order top left, top right, bottom left
+    /// and bottom right.
+    pub fn corners(&self) -> [&C; 4] {
+        [
+            &self.top_left,
+            &self.top_right,
+            &self.bottom_left,
+            &self.bottom_right,
+        ]
+    }
+}
+
+impl<C: Mix + Clone> Gradient2d<C> {
+    /// Get the color at the position `(x, y)`. Positions outside the square
+    /// are clamped to its edges.
+    ///
+    /// ```
+    /// use palette::gradient::{Gradient2d, Interpolation2d};
+    /// use palette::LinSrgb;
+    ///
+    /// let black = LinSrgb::new(0.0, 0.0, 0.0);
+    /// let white = LinSrgb::new(1.0, 1.0, 1.0);
+    /// let patch = Gradient2d::new(black, white, white, black);
+    ///
+    /// assert_eq!(patch.get(0.0, 0.0), black);
+    /// assert_eq!(patch.get(0.5, 0.0), LinSrgb::new(0.5, 0.5, 0.5));
+    /// assert_eq!(patch.get(0.5, 0.5), LinSrgb::new(0.5, 0.5, 0.5));
+    ///
+    /// // The black diagonal stays black with barycentric interpolation
+    /// let patch = patch.with_interpolation(Interpolation2d::Barycentric);
+    /// assert_eq!(patch.get(0.5, 0.5), black);
+    /// ```
+    pub fn get(&self, x: C::Scalar, y: C::Scalar) -> C {
+        let x = clamp(x, C::Scalar::zero(), C::Scalar::one());
+        let y = clamp(y, C::Scalar::zero(), C::Scalar::one());
+
+        match self.interpolation {
+            Interpolation2d::Bilinear => {
+                let top = self.top_left.mix(&self.top_right, x);
+                let bottom = self.bottom_left.mix(&self.bottom_right, x);
+                top.mix(&bottom, y)
+            }
+            Interpolation2d::Barycentric => {
+                // The weights of the triangle corners are `1 - x`, `x - y`
+                // and `y` in the upper triangle, which is the same as mixing
+                // the two corners on the right first.
+                if x >= y {
+                    if x == C::Scalar::zero() {
+                        return self.top_left.clone();
+                    }
+
+                    let right = self.top_right.mix(&self.bottom_right, y / x);
+                    self.top_left.mix(&right, x)
+                } else {
+                    let bottom = self.bottom_left.mix(&self.bottom_right, x / y);
+                    self.top_left.mix(&bottom, y)
+                }
+            }
+        }
+    }
+}
+
+/// The interpolation methods of a [`Gradient2d`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation2d {
+    /// Mix the colors along the top and bottom edges, and then mix the
+    /// results. This is smooth, and treats all corners the same. This is the
+    /// default.
+    #[default]
+    Bilinear,
+
+    /// Split the square into two triangles along the diagonal from the top
+    /// left to the bottom right corner, and mix the three corners of each
+    /// triangle by their barycentric weights. This is how GPUs shade
+    /// triangles, so it matches meshes that are rendered as triangles.
+    Barycentric,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Gradient2d, Interpolation2d};
+    use crate::LinSrgb;
+
+    #[test]
+    fn corners_and_edges() {
+        let corners = [
+            LinSrgb::new(1.0, 0.0, 0.0),
+            LinSrgb::new(0.0, 1.0, 0.0),
+            LinSrgb::new(0.0, 0.0, 1.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ];
+        let patch = Gradient2d::new(corners[0], corners[1], corners[2], corners[3]);
+
+        for &interpolation in &[Interpolation2d::Bilinear, Interpolation2d::Barycentric] {
+            let patch = patch.with_interpolation(interpolation);
+
+            assert_eq!(patch.get(0.0, 0.0), corners[0]);
+            assert_eq!(patch.get(1.0, 0.0), corners[1]);
+            assert_eq!(patch.get(0.0, 1.0), corners[2]);
+            assert_eq!(patch.get(1.0, 1.0), corners[3]);
+            assert_eq!(patch.get(-1.0, 2.0), corners[2]);
+
+            // The edges are linear for both methods
+            assert_relative_eq!(patch.get(0.5, 0.0), LinSrgb::new(0.5, 0.5, 0.0));
+            assert_relative_eq!(patch.get(1.0, 0.25), LinSrgb::new(0.25, 1.0, 0.25));
+        }
+    }
+
+    #[test]
+    fn barycentric() {
+        let zero = LinSrgb::new(0.0, 0.0, 0.0);
+        let one = LinSrgb::new(1.0, 1.0, 1.0);
+        let patch =
+            Gradient2d::new(zero, zero, one, zero).with_interpolation(Interpolation2d::Barycentric);
+
+        // Only the lower triangle is affected by the bottom left corner
+        assert_eq!(patch.get(0.75, 0.5), zero);
+        assert_relative_eq!(patch.get(0.25, 0.75), LinSrgb::new(0.5, 0.5, 0.5));
+
+        let bilinear = patch.with_interpolation(Interpolation2d::Bilinear);
+        assert_relative_eq!(bilinear.get(0.75, 0.5), LinSrgb::new(0.125, 0.125, 0.125));
+    }
+}
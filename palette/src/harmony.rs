@@ -0,0 +1,342 @@
+//! Color harmonies, based on the hue of a color.
+//!
+//! Color harmonies are sets of colors with hues at fixed angles from each
+//! other, such as the complementary color on the opposite side of the color
+//! wheel. The [`Harmony`] trait is implemented for all colors with a hue, and
+//! keeps the other components, such as the lightness and chroma, unchanged:
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//!
+//! use palette::{Harmony, Lch};
+//!
+//! let color = Lch::new(60.0, 40.0, 30.0);
+//! let [second, third] = color.triadic();
+//!
+//! assert_relative_eq!(second, Lch::new(60.0, 40.0, 150.0));
+//! assert_relative_eq!(third, Lch::new(60.0, 40.0, 270.0));
+//! ```
+//!
+//! The harmonies are only as uniform as the color space they are calculated
+//! in. Perceptual color spaces, such as [`Lch`](crate::Lch), give more
+//! balanced results than [`Hsl`](crate::Hsl) and [`Hsv`](crate::Hsv).
+//!
+//! Monochromatic scales, made of lighter and darker variants of a single
+//! color, can be made with [`monochromatic`].
+
+use core::ops::Sub;
+
+use crate::{from_f64, FromF64, GetHue, Hue, Shade};
+
+/// Calculate color harmonies by rotating the hue of a color.
+///
+/// The returned colors don't include the original color, and are ordered by
+/// increasing hue angle from it. All angles are in degrees.
+pub trait Harmony: Hue + Sized {
+    /// The color on the opposite side of the color wheel, rotated by 180
+    /// degrees.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    ///
+    /// use palette::{Harmony, Hsl};
+    ///
+    /// let color = Hsl::new(30.0, 1.0, 0.5);
+    /// assert_relative_eq!(color.complementary(), Hsl::new(210.0, 1.0, 0.5));
+    /// ```
+    fn complementary(&self) -> Self;
+
+    /// The two colors next to the complementary color, rotated by 150 and 210
+    /// degrees.
+    fn split_complementary(&self) -> [Self; 2];
+
+    /// The two colors that form an equilateral triangle with this color,
+    /// rotated by 120 and 240 degrees.
+    fn triadic(&self) -> [Self; 2];
+
+    /// The three colors that form a square with this color, rotated by 90, 180
+    /// and 270 degrees.
+    fn tetradic(&self) -> [Self; 3];
+
+    /// The two closest neighbors on the color wheel, rotated by -30 and 30
+    /// degrees.
+    fn analogous(&self) -> [Self; 2];
+
+    /// A series of `count` analogous colors, where the hue of each color is
+    /// `step` degrees from the previous.
+    ///
+    /// Unlike the other harmonies, the series includes this color, which is
+    /// in the middle of it. It's placed just after the middle if `count` is
+    /// even.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    ///
+    /// use palette::{Harmony, Hsv};
+    ///
+    /// let color = Hsv::new(120.0, 1.0, 1.0);
+    /// let hues: Vec<_> = color
+    ///     .analogous_series(15.0, 5)
+    ///     .map(|color| color.hue.to_positive_degrees())
+    ///     .collect();
+    ///
+    /// assert_relative_eq!(&hues[..], &[90.0, 105.0, 120.0, 135.0, 150.0][..]);
+    /// ```
+    fn analogous_series<H: Into<Self::Hue>>(&self, step: H, count: usize) -> Analogous<Self>;
+}
+
+impl<C> Harmony for C
+where
+    C: Hue + Clone,
+    C::Hue: FromF64 + Sub<Output = C::Hue> + Clone,
+{
+    fn complementary(&self) -> Self {
+        self.shift_hue(C::Hue::from_f64(180.0))
+    }
+
+    fn split_complementary(&self) -> [Self; 2] {
+        [
+            self.shift_hue(C::Hue::from_f64(150.0)),
+            self.shift_hue(C::Hue::from_f64(210.0)),
+        ]
+    }
+
+    fn triadic(&self) -> [Self; 2] {
+        [
+            self.shift_hue(C::Hue::from_f64(120.0)),
+            self.shift_hue(C::Hue::from_f64(240.0)),
+        ]
+    }
+
+    fn tetradic(&self) -> [Self; 3] {
+        [
+            self.shift_hue(C::Hue::from_f64(90.0)),
+            self.shift_hue(C::Hue::from_f64(180.0)),
+            self.shift_hue(C::Hue::from_f64(270.0)),
+        ]
+    }
+
+    fn analogous(&self) -> [Self; 2] {
+        [
+            self.shift_hue(C::Hue::from_f64(-30.0)),
+            self.shift_hue(C::Hue::from_f64(30.0)),
+        ]
+    }
+
+    fn analogous_series<H: Into<Self::Hue>>(&self, step: H, count: usize) -> Analogous<Self> {
+        let step = step.into();
+        let backwards = C::Hue::from_f64(0.0) - step.clone();
+
+        let mut next = self.clone();
+        for _ in 0..count / 2 {
+            next = next.shift_hue(backwards.clone());
+        }
+
+        Analogous {
+            next,
+            step,
+            remaining: count,
+        }
+    }
+}
+
+/// An iterator over a series of analogous colors.
+///
+/// See [`Harmony::analogous_series`] for more details.
+#[derive(Clone, Debug)]
+pub struct Analogous<C: GetHue> {
+    next: C,
+    step: C::Hue,
+    remaining: usize,
+}
+
+impl<C> Iterator for Analogous<C>
+where
+    C: Hue + Clone,
+    C::Hue: Clone,
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<C> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let following = self.next.shift_hue(self.step.clone());
+        Some(core::mem::replace(&mut self.next, following))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<C> ExactSizeIterator for Analogous<C>
+where
+    C: Hue + Clone,
+    C::Hue: Clone,
+{
+}
+
+/// Create a scale of `count` lighter and darker variants of `color`, ordered
+/// from lightest to darkest.
+///
+/// This is similar to the 50-900 color ramps of many design systems. `color`
+/// is in the middle of the scale, or just after the middle if `count` is even.
+/// The lighter and darker colors are spread evenly between `color` and white
+/// or black, using [`lighten_relative`](Shade::lighten_relative) and
+/// [`darken_relative`](Shade::darken_relative), but never reach pure white
+/// or black.
+///
+/// The steps are perceptually even when `color` is in a perceptual color
+/// space, such as [`Lch`](crate::Lch) or [`Lab`](crate::Lab), which is
+/// recommended.
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::harmony::monochromatic;
+/// use palette::Lch;
+///
+/// let color = Lch::new(40.0, 30.0, 250.0);
+/// let lightness: Vec<_> = monochromatic(color, 7).map(|color| color.l).collect();
+///
+/// assert_relative_eq!(&lightness[..], &[85.0, 70.0, 55.0, 40.0, 30.0, 20.0, 10.0][..]);
+/// ```
+pub fn monochromatic<C>(color: C, count: usize) -> Monochromatic<C>
+where
+    C: Shade,
+    C::Scalar: FromF64,
+{
+    Monochromatic {
+        color,
+        index: 0,
+        count,
+    }
+}
+
+/// An iterator over a monochromatic scale, from lightest to darkest.
+///
+/// See [`monochromatic`] for more details.
+#[derive(Clone, Debug)]
+pub struct Monochromatic<C> {
+    color: C,
+    index: usize,
+    count: usize,
+}
+
+impl<C> Iterator for Monochromatic<C>
+where
+    C: Shade,
+    C::Scalar: FromF64,
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<C> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let middle = self.count / 2;
+        let index = self.index;
+        self.index += 1;
+
+        let color = if index < middle {
+            let steps = (middle + 1) as f64;
+            self.color
+                .lighten_relative(from_f64((middle - index) as f64 / steps))
+        } else {
+            let steps = (self.count - middle) as f64;
+            self.color
+                .darken_relative(from_f64((index - middle) as f64 / steps))
+        };
+
+        Some(color)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<C> ExactSizeIterator for Monochromatic<C>
+where
+    C: Shade,
+    C::Scalar: FromF64,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::{monochromatic, Harmony};
+    use crate::{Hsl, Hwba, Lab, Lch};
+
+    #[test]
+    fn fixed_harmonies() {
+        let color = Hsl::new(60.0, 0.5, 0.25);
+
+        assert_relative_eq!(color.complementary(), Hsl::new(240.0, 0.5, 0.25));
+
+        let [first, second] = color.split_complementary();
+        assert_relative_eq!(first, Hsl::new(210.0, 0.5, 0.25));
+        assert_relative_eq!(second, Hsl::new(270.0, 0.5, 0.25));
+
+        let [first, second] = color.triadic();
+        assert_relative_eq!(first, Hsl::new(180.0, 0.5, 0.25));
+        assert_relative_eq!(second, Hsl::new(300.0, 0.5, 0.25));
+
+        let [first, second, third] = color.tetradic();
+        assert_relative_eq!(first, Hsl::new(150.0, 0.5, 0.25));
+        assert_relative_eq!(second, Hsl::new(240.0, 0.5, 0.25));
+        assert_relative_eq!(third, Hsl::new(330.0, 0.5, 0.25));
+
+        let [first, second] = color.analogous();
+        assert_relative_eq!(first, Hsl::new(30.0, 0.5, 0.25));
+        assert_relative_eq!(second, Hsl::new(90.0, 0.5, 0.25));
+    }
+
+    #[test]
+    fn alpha_is_kept() {
+        let color = Hwba::new(10.0, 0.2, 0.3, 0.5);
+        assert_relative_eq!(color.complementary(), Hwba::new(190.0, 0.2, 0.3, 0.5));
+    }
+
+    #[test]
+    fn analogous_series() {
+        let color = Lch::new(50.0, 30.0, 0.0);
+
+        let series: Vec<_> = color.analogous_series(20.0, 4).collect();
+        assert_eq!(series.len(), 4);
+        assert_relative_eq!(series[0], Lch::new(50.0, 30.0, -40.0));
+        assert_relative_eq!(series[1], Lch::new(50.0, 30.0, -20.0));
+        assert_relative_eq!(series[2], color);
+        assert_relative_eq!(series[3], Lch::new(50.0, 30.0, 20.0));
+
+        assert_eq!(color.analogous_series(20.0, 0).count(), 0);
+        assert_eq!(
+            color.analogous_series(20.0, 1).collect::<Vec<_>>(),
+            vec![color]
+        );
+    }
+
+    #[test]
+    fn monochromatic_scale() {
+        let color = Lab::new(40.0, 10.0, -20.0);
+        let scale: Vec<_> = monochromatic(color, 10).collect();
+
+        assert_eq!(scale.len(), 10);
+        assert_relative_eq!(scale[5], color);
+        assert_relative_eq!(scale[0], Lab::new(90.0, 10.0, -20.0));
+        assert_relative_eq!(scale[9], Lab::new(8.0, 10.0, -20.0));
+
+        for pair in scale.windows(2) {
+            assert!(pair[0].l > pair[1].l);
+            assert_eq!((pair[0].a, pair[0].b), (color.a, color.b));
+        }
+
+        assert_eq!(monochromatic(color, 0).count(), 0);
+        assert_eq!(monochromatic(color, 1).collect::<Vec<_>>(), vec![color]);
+    }
+}
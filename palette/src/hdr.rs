@@ -0,0 +1,291 @@
+//! Absolute luminance, for high dynamic range colors.
+//!
+//! Most color types in palette are relative, meaning that a luminance of
+//! `1.0` is the brightest white of the display or the scene, whatever that
+//! happens to be. High dynamic range (HDR) formats, such as PQ encoded video,
+//! instead describe the actual luminance in candela per square meter (cd/m²,
+//! or "nits"). The [`Absolute`] wrapper makes the difference explicit, by
+//! pairing a relative color with the luminance of its white:
+//!
+//! ```
+//! use palette::encoding::Srgb;
+//! use palette::hdr::Absolute;
+//! use palette::Xyz;
+//!
+//! // The white of a display with a peak luminance of 1000 cd/m².
+//! let white = Absolute::new(Xyz::new(0.95047, 1.0, 1.08883), 1000.0);
+//! assert_eq!(white.luminance(), 1000.0);
+//!
+//! let encoded = white.into_pq::<Srgb>();
+//! assert!(encoded.red > 0.75 && encoded.red < 0.76);
+//! ```
+//!
+//! Absolute colors can also be converted to and from [`Jzazbz`], which is a
+//! perceptually uniform color space for HDR colors.
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::pq::{pq_decode, pq_encode, PQ_MAX_LUMINANCE};
+use crate::encoding::{Linear, Pq};
+use crate::rgb::{Rgb, RgbSpace};
+use crate::white_point::{WhitePoint, D65};
+use crate::{from_f64, FloatComponent, Xyz};
+
+/// A color with an absolute luminance.
+///
+/// The luminance of `color` is relative, where `1.0` is the white point, and
+/// `peak` is the luminance of that white point in cd/m². A color where `Y` is
+/// `0.5` and `peak` is `200.0` has an absolute luminance of 100 cd/m².
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Absolute<C, T = f32> {
+    /// The relative color.
+    pub color: C,
+
+    /// The luminance of the white point, in cd/m².
+    pub peak: T,
+}
+
+impl<C, T> Absolute<C, T> {
+    /// Pair `color` with the luminance of its white point, `peak`, in cd/m².
+    pub fn new(color: C, peak: T) -> Self {
+        Absolute { color, peak }
+    }
+}
+
+impl<Wp, T> Absolute<Xyz<Wp, T>, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    /// Create an absolute color from XYZ values in cd/m², where `peak` is
+    /// the luminance of the white point.
+    ///
+    /// ```
+    /// use palette::hdr::Absolute;
+    /// use palette::Xyz;
+    ///
+    /// let color = Absolute::from_nits(Xyz::new(60.0, 64.0, 70.0), 256.0);
+    /// assert_eq!(color.color.y, 0.25);
+    /// assert_eq!(color.into_nits(), Xyz::new(60.0, 64.0, 70.0));
+    /// ```
+    pub fn from_nits(color: Xyz<Wp, T>, peak: T) -> Self {
+        Absolute::new(color / peak, peak)
+    }
+
+    /// Convert into XYZ values in cd/m².
+    pub fn into_nits(self) -> Xyz<Wp, T> {
+        self.color * self.peak
+    }
+
+    /// The absolute luminance, in cd/m².
+    pub fn luminance(&self) -> T {
+        self.color.y * self.peak
+    }
+
+    /// Convert into PQ encoded RGB in the RGB space `S`.
+    ///
+    /// Colors that are brighter than 10000 cd/m², or outside the RGB space,
+    /// are not clamped.
+    pub fn into_pq<S>(self) -> Rgb<Pq<S>, T>
+    where
+        S: RgbSpace<WhitePoint = Wp>,
+    {
+        let scale = self.peak / from_f64(PQ_MAX_LUMINANCE);
+        let linear = Rgb::<Linear<S>, T>::from_color_unclamped(self.color) * scale;
+        Rgb::from_linear(linear)
+    }
+
+    /// Convert from PQ encoded RGB, where `peak` is the luminance of the
+    /// white point in cd/m². This is the inverse of
+    /// [`into_pq`](Self::into_pq).
+    pub fn from_pq<S>(color: Rgb<Pq<S>, T>, peak: T) -> Self
+    where
+        S: RgbSpace<WhitePoint = Wp>,
+    {
+        let nits = color.into_linear() * from_f64::<T>(PQ_MAX_LUMINANCE);
+        Self::from_nits(Xyz::from_color_unclamped(nits), peak)
+    }
+}
+
+impl<T> Absolute<Xyz<D65, T>, T>
+where
+    T: FloatComponent,
+{
+    /// Convert into the Jzazbz color space.
+    pub fn into_jzazbz(self) -> Jzazbz<T> {
+        Jzazbz::from_nits(self.into_nits())
+    }
+
+    /// Convert from the Jzazbz color space, where `peak` is the luminance of
+    /// the white point in cd/m². This is the inverse of
+    /// [`into_jzazbz`](Self::into_jzazbz).
+    pub fn from_jzazbz(color: Jzazbz<T>, peak: T) -> Self {
+        Self::from_nits(color.into_nits(), peak)
+    }
+}
+
+/// The Jzazbz color space, by Safdar et al.
+///
+/// Jzazbz is a perceptually uniform color space, similar to
+/// [`Lab`](crate::Lab), but made for high dynamic range colors. It's based on
+/// the absolute luminance of the color, so it's converted to and from
+/// [`Absolute`] XYZ with the D65 white point. `jz` goes from `0.0` for black
+/// to about `1.0` for 10000 cd/m².
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Jzazbz<T = f32> {
+    /// The lightness of the color.
+    pub jz: T,
+
+    /// The position between red and green.
+    pub az: T,
+
+    /// The position between yellow and blue.
+    pub bz: T,
+}
+
+impl<T: FloatComponent> Jzazbz<T> {
+    /// Create a Jzazbz color.
+    pub fn new(jz: T, az: T, bz: T) -> Self {
+        Jzazbz { jz, az, bz }
+    }
+
+    fn from_nits(color: Xyz<D65, T>) -> Self {
+        let b: T = from_f64(JZ_B);
+        let g: T = from_f64(JZ_G);
+        let x = b * color.x - (b - T::one()) * color.z;
+        let y = g * color.y - (g - T::one()) * color.x;
+        let z = color.z;
+
+        let [l, m, s] = multiply(&XYZ_TO_LMS, [x, y, z]);
+        let scale: T = from_f64(PQ_MAX_LUMINANCE);
+        let p: T = from_f64(JZ_P);
+        let lms = [
+            pq_encode(l / scale, p),
+            pq_encode(m / scale, p),
+            pq_encode(s / scale, p),
+        ];
+
+        let [iz, az, bz] = multiply(&LMS_TO_IAB, lms);
+        let d: T = from_f64(JZ_D);
+        let jz = (T::one() + d) * iz / (T::one() + d * iz) - from_f64(JZ_D0);
+
+        Jzazbz::new(jz, az, bz)
+    }
+
+    fn into_nits(self) -> Xyz<D65, T> {
+        let d: T = from_f64(JZ_D);
+        let jz = self.jz + from_f64(JZ_D0);
+        let iz = jz / (T::one() + d - d * jz);
+
+        let [l, m, s] = multiply(&IAB_TO_LMS, [iz, self.az, self.bz]);
+        let scale: T = from_f64(PQ_MAX_LUMINANCE);
+        let p: T = from_f64(JZ_P);
+        let lms = [
+            pq_decode(l, p) * scale,
+            pq_decode(m, p) * scale,
+            pq_decode(s, p) * scale,
+        ];
+
+        let [x, y, z] = multiply(&LMS_TO_XYZ, lms);
+        let b: T = from_f64(JZ_B);
+        let g: T = from_f64(JZ_G);
+        let x = (x + (b - T::one()) * z) / b;
+        let y = (y + (g - T::one()) * x) / g;
+
+        Xyz::new(x, y, z)
+    }
+}
+
+const JZ_B: f64 = 1.15;
+const JZ_G: f64 = 0.66;
+const JZ_D: f64 = -0.56;
+const JZ_D0: f64 = 1.629_549_953_282_156_6e-11;
+const JZ_P: f64 = 1.7 * 2523.0 / 32.0;
+
+const XYZ_TO_LMS: [[f64; 3]; 3] = [
+    [0.41478972, 0.579999, 0.0146480],
+    [-0.2015100, 1.120649, 0.0531008],
+    [-0.0166008, 0.264800, 0.6684799],
+];
+
+const LMS_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        1.924_226_435_787_606_7,
+        -1.004_792_312_595_365_5,
+        0.037_651_404_030_618,
+    ],
+    [
+        0.350_316_762_094_999_07,
+        0.726_481_193_931_655_2,
+        -0.065_384_422_948_085,
+    ],
+    [
+        -0.090_982_810_982_847_58,
+        -0.312_728_290_523_074,
+        1.522_766_561_305_260_6,
+    ],
+];
+
+const LMS_TO_IAB: [[f64; 3]; 3] = [
+    [0.5, 0.5, 0.0],
+    [3.524000, -4.066708, 0.542708],
+    [0.199076, 1.096799, -1.295875],
+];
+
+const IAB_TO_LMS: [[f64; 3]; 3] = [
+    [1.0, 0.138_605_043_271_539_3, 0.058_047_316_156_118_876],
+    [1.0, -0.138_605_043_271_539_3, -0.058_047_316_156_118_876],
+    [1.0, -0.096_019_242_026_318_95, -0.811_891_896_056_039],
+];
+
+fn multiply<T: FloatComponent>(matrix: &[[f64; 3]; 3], [a, b, c]: [T; 3]) -> [T; 3] {
+    let row =
+        |[x, y, z]: [f64; 3]| from_f64::<T>(x) * a + from_f64::<T>(y) * b + from_f64::<T>(z) * c;
+    [row(matrix[0]), row(matrix[1]), row(matrix[2])]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Absolute, Jzazbz};
+    use crate::encoding::Srgb;
+    use crate::Xyz;
+
+    #[test]
+    fn pq_round_trip() {
+        let color = Absolute::new(Xyz::new(0.3f64, 0.4, 0.2), 600.0);
+        let encoded = color.into_pq::<Srgb>();
+        let decoded = Absolute::from_pq(encoded, 600.0);
+
+        assert_relative_eq!(decoded.color, color.color, epsilon = 1e-10);
+        assert_eq!(decoded.peak, 600.0);
+    }
+
+    #[test]
+    fn pq_white() {
+        let white = Absolute::new(Xyz::new(0.95047f64, 1.0, 1.08883), 100.0);
+        let encoded = white.into_pq::<Srgb>();
+
+        assert_relative_eq!(encoded.red, 0.508078, epsilon = 1e-4);
+        assert_relative_eq!(encoded.green, 0.508078, epsilon = 1e-4);
+        assert_relative_eq!(encoded.blue, 0.508078, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn jzazbz_round_trip() {
+        let color = Absolute::new(Xyz::new(0.3f64, 0.4, 0.2), 1000.0);
+        let jzazbz = color.into_jzazbz();
+        let decoded = Absolute::from_jzazbz(jzazbz, 1000.0);
+
+        assert_relative_eq!(decoded.color, color.color, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn jzazbz_lightness() {
+        let black = Absolute::new(Xyz::new(0.0f64, 0.0, 0.0), 100.0).into_jzazbz();
+        assert_eq!(black, Jzazbz::new(0.0, 0.0, 0.0));
+
+        let dim = Absolute::new(Xyz::new(0.95047f64, 1.0, 1.08883), 100.0).into_jzazbz();
+        let bright = Absolute::new(Xyz::new(0.95047f64, 1.0, 1.08883), 1000.0).into_jzazbz();
+        assert!(dim.jz < bright.jz);
+        assert!(dim.az.abs() < 0.01 && dim.bz.abs() < 0.01);
+    }
+}
@@ -10,14 +10,16 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
-use crate::convert::FromColorUnclamped;
+use crate::blend::PreAlpha;
+use crate::convert::{FromColor, FromColorUnclamped};
 use crate::encoding::pixel::RawPixel;
-use crate::encoding::Srgb;
+use crate::encoding::{Linear, Srgb};
 use crate::float::Float;
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Component, FloatComponent, FromF64, GetHue, Hsv, Hue,
-    Limited, Mix, Pixel, RelativeContrast, RgbHue, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, ApcaContrast, Blend, Component, FloatComponent, FromF64, GetHue, Hsv,
+    Hue, HueInterpolation, Hwb, InvalidComponents, Limited, Mix, MixHue, Pixel, RelativeLuminance,
+    RgbHue, Saturate, Shade,
 };
 
 /// Linear HSL with an alpha component. See the [`Hsla` implementation in
@@ -42,7 +44,7 @@ pub type Hsla<S = Srgb, T = f32> = Alpha<Hsl<S, T>, T>;
     rgb_standard = "S",
     white_point = "<S::Space as RgbSpace>::WhitePoint",
     component = "T",
-    skip_derives(Rgb, Hsv, Hsl)
+    skip_derives(Rgb, Hsv, Hwb, Hsl)
 )]
 #[repr(C)]
 pub struct Hsl<S = Srgb, T = f32>
@@ -127,6 +129,48 @@ where
         Self::with_wp(hue, saturation, lightness)
     }
 
+    /// Convert `color` into HSL, but keep the hue and saturation of
+    /// `previous` where the conversion leaves them undefined.
+    ///
+    /// Gray colors have no hue and black and white have neither hue nor
+    /// saturation, so converting them into HSL gives them a hue of `0.0`. This
+    /// makes animations and gradients that pass through gray jump to red when
+    /// they are converted back and forth. This function keeps the hue of
+    /// `previous` if the saturation of the result is `0.0`, and also its
+    /// saturation if the lightness is `0.0` or `1.0`.
+    ///
+    /// Conversions between HSL, [HSV](crate::Hsv) and [HWB](crate::Hwb) are
+    /// direct and keep the hue, so this is only needed when converting from
+    /// other color spaces.
+    ///
+    /// ```
+    /// use palette::{FromColor, Hsl, Srgb};
+    ///
+    /// let previous = Hsl::new(120.0, 1.0, 0.8);
+    /// let white = Srgb::new(1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(Hsl::from_color(white), Hsl::new(0.0, 0.0, 1.0));
+    /// assert_eq!(
+    ///     Hsl::from_color_preserving_hue(white, previous),
+    ///     Hsl::new(120.0, 1.0, 1.0)
+    /// );
+    /// ```
+    pub fn from_color_preserving_hue<C>(color: C, previous: Self) -> Self
+    where
+        Self: FromColor<C>,
+    {
+        let mut hsl = Self::from_color(color);
+
+        if hsl.lightness <= T::zero() || hsl.lightness >= T::one() {
+            hsl.hue = previous.hue;
+            hsl.saturation = previous.saturation;
+        } else if hsl.saturation <= T::zero() {
+            hsl.hue = previous.hue;
+        }
+
+        hsl
+    }
+
     #[inline]
     fn reinterpret_as<St: RgbStandard>(self) -> Hsl<St, T> {
         Hsl {
@@ -296,6 +340,32 @@ where
     }
 }
 
+impl<S, T> FromColorUnclamped<Hwb<S, T>> for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn from_color_unclamped(hwb: Hwb<S, T>) -> Self {
+        let value = T::one() - hwb.blackness;
+        let lightness = (value + hwb.whiteness) / from_f64(2.0);
+
+        // avoid divide by zero
+        let denom = lightness.min(T::one() - lightness);
+        let saturation = if denom.is_normal() {
+            (value - lightness) / denom
+        } else {
+            T::zero()
+        };
+
+        Hsl {
+            hue: hwb.hue,
+            saturation,
+            lightness,
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S: RgbStandard, T: FloatComponent, H: Into<RgbHue<T>>> From<(H, T, T)> for Hsl<S, T> {
     fn from(components: (H, T, T)) -> Self {
         Self::from_components(components)
@@ -324,6 +394,35 @@ impl<S: RgbStandard, T: FloatComponent, A: Component> Into<(RgbHue<T>, T, T, A)>
     }
 }
 
+impl<S: RgbStandard, T: FloatComponent> From<[T; 3]> for Hsl<S, T> {
+    fn from([hue, saturation, lightness]: [T; 3]) -> Self {
+        Self::from_components((hue, saturation, lightness))
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<Hsl<S, T>> for [T; 3] {
+    fn from(color: Hsl<S, T>) -> Self {
+        [color.hue.to_degrees(), color.saturation, color.lightness]
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<[T; 4]> for Alpha<Hsl<S, T>, T> {
+    fn from([hue, saturation, lightness, alpha]: [T; 4]) -> Self {
+        Self::from_components((hue, saturation, lightness, alpha))
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<Alpha<Hsl<S, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Hsl<S, T>, T>) -> Self {
+        [
+            color.hue.to_degrees(),
+            color.saturation,
+            color.lightness,
+            color.alpha,
+        ]
+    }
+}
+
 impl<S, T> Limited for Hsl<S, T>
 where
     T: FloatComponent,
@@ -345,6 +444,38 @@ where
         self.saturation = clamp(self.saturation, T::zero(), T::one());
         self.lightness = clamp(self.lightness, T::zero(), T::one());
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check(
+                "saturation",
+                self.saturation >= T::zero() && self.saturation <= T::one(),
+            )
+            .check(
+                "lightness",
+                self.lightness >= T::zero() && self.lightness <= T::one(),
+            )
+    }
+}
+
+/// Blending is performed in linear RGB, with the same RGB space as the color.
+impl<S, T> Blend for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    type Color = Rgb<Linear<S::Space>, T>;
+
+    fn into_premultiplied(self) -> PreAlpha<Rgb<Linear<S::Space>, T>, T> {
+        Rgb::<S, T>::from_color_unclamped(self)
+            .into_linear()
+            .into_premultiplied()
+    }
+
+    fn from_premultiplied(color: PreAlpha<Rgb<Linear<S::Space>, T>, T>) -> Self {
+        let color = Rgb::<Linear<S::Space>, T>::from_premultiplied(color);
+        Self::from_color_unclamped(Rgb::<S, T>::from_linear(color))
+    }
 }
 
 impl<S, T> Mix for Hsl<S, T>
@@ -355,8 +486,18 @@ where
     type Scalar = T;
 
     fn mix(&self, other: &Hsl<S, T>, factor: T) -> Hsl<S, T> {
+        self.mix_hue(other, factor, HueInterpolation::Shorter)
+    }
+}
+
+impl<S, T> MixHue for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn mix_hue(&self, other: &Hsl<S, T>, factor: T, method: HueInterpolation) -> Hsl<S, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let hue_diff: T = method.adjust_difference((other.hue - self.hue).to_degrees());
 
         Hsl {
             hue: self.hue + factor * hue_diff,
@@ -382,6 +523,23 @@ where
             standard: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Hsl<S, T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.lightness
+        } else {
+            self.lightness
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Hsl {
+            hue: self.hue,
+            saturation: self.saturation,
+            lightness: (self.lightness + delta).max(T::zero()),
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> GetHue for Hsl<S, T>
@@ -439,6 +597,15 @@ where
             standard: PhantomData,
         }
     }
+
+    fn saturate_fixed(&self, amount: T) -> Hsl<S, T> {
+        Hsl {
+            hue: self.hue,
+            saturation: self.saturation + amount,
+            lightness: self.lightness,
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> Default for Hsl<S, T>
@@ -649,20 +816,29 @@ where
     }
 }
 
-impl<S, T> RelativeContrast for Hsl<S, T>
+impl<S, T> RelativeLuminance for Hsl<S, T>
 where
     T: FloatComponent,
     S: RgbStandard,
+{
+    type WhitePoint = <S::Space as RgbSpace>::WhitePoint;
+    type Scalar = T;
+}
+
+impl<S, T> ApcaContrast for Hsl<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard<Space = Srgb>,
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn get_apca_contrast(&self, background: &Self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
+        let text = Rgb::<S, T>::from_color(*self);
+        let background = Rgb::<S, T>::from_color(*background);
 
-        contrast_ratio(xyz1.y, xyz2.y)
+        text.get_apca_contrast(&background)
     }
 }
 
@@ -841,6 +1017,45 @@ mod test {
         assert_relative_eq!(Hsl::<Srgb>::max_lightness(), 1.0);
     }
 
+    #[test]
+    fn arrays() {
+        use crate::Hsla;
+
+        let color = Hsl::new(-90.0f64, 0.5, 0.25);
+        let array: [f64; 3] = color.into();
+        assert_eq!(array, [-90.0, 0.5, 0.25]);
+        assert_eq!(Hsl::from(array), color);
+
+        let color = Hsla::new(270.0f64, 0.5, 0.25, 0.75);
+        let array: [f64; 4] = color.into();
+        assert_eq!(array, [-90.0, 0.5, 0.25, 0.75]);
+        assert_eq!(Hsla::from(array), Hsla::new(-90.0, 0.5, 0.25, 0.75));
+    }
+
+    #[test]
+    fn hwb_conversion() {
+        use crate::Hwb;
+
+        for &saturation in &[0.0, 0.3, 1.0] {
+            for &lightness in &[0.0, 0.2, 0.5, 0.8, 1.0] {
+                let hsl = Hsl::new(200.0f64, saturation, lightness);
+                let hwb = Hwb::from_color(hsl);
+
+                assert_relative_eq!(hwb, Hwb::from_color(Hsv::from_color(hsl)), epsilon = 1e-12);
+                assert_relative_eq!(
+                    Srgb::from_color(hwb),
+                    Srgb::from_color(hsl),
+                    epsilon = 1e-12
+                );
+                assert_relative_eq!(hwb.hue, hsl.hue);
+
+                if saturation > 0.0 && lightness > 0.0 && lightness < 1.0 {
+                    assert_relative_eq!(Hsl::from_color(hwb), hsl, epsilon = 1e-12);
+                }
+            }
+        }
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
@@ -905,4 +1120,25 @@ mod test {
         assert_uniform_distribution!(green);
         assert_uniform_distribution!(blue);
     }
+
+    #[test]
+    fn clamped_adjustments() {
+        use crate::{Saturate, Shade};
+
+        let color = Hsl::new(0.0, 0.75, 0.75);
+
+        assert_relative_eq!(color.lighten_clamped(0.5), Hsl::new(0.0, 0.75, 1.0));
+        assert_relative_eq!(color.darken_clamped(1.0), Hsl::new(0.0, 0.75, 0.0));
+        assert_relative_eq!(
+            color.lighten_relative_clamped(0.5),
+            color.lighten_relative(0.5)
+        );
+        assert_relative_eq!(color.saturate_clamped(1.0), Hsl::new(0.0, 1.0, 0.75));
+        assert_relative_eq!(color.saturate_fixed_clamped(0.5), Hsl::new(0.0, 1.0, 0.75));
+        assert_relative_eq!(
+            color.desaturate_fixed_clamped(1.0),
+            Hsl::new(0.0, 0.0, 0.75)
+        );
+        assert_relative_eq!(color.desaturate_clamped(0.5), color.desaturate(0.5));
+    }
 }
@@ -10,14 +10,16 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
-use crate::convert::FromColorUnclamped;
+use crate::blend::PreAlpha;
+use crate::convert::{FromColor, FromColorUnclamped};
 use crate::encoding::pixel::RawPixel;
-use crate::encoding::Srgb;
+use crate::encoding::{Linear, Srgb};
 use crate::float::Float;
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Component, FloatComponent, FromColor, FromF64, GetHue,
-    Hsl, Hue, Hwb, Limited, Mix, Pixel, RelativeContrast, RgbHue, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, ApcaContrast, Blend, Component, FloatComponent, FromF64, GetHue, Hsl,
+    Hue, HueInterpolation, Hwb, InvalidComponents, Limited, Mix, MixHue, Pixel, RelativeLuminance,
+    RgbHue, Saturate, Shade,
 };
 
 /// Linear HSV with an alpha component. See the [`Hsva` implementation in
@@ -125,6 +127,48 @@ where
         Self::with_wp(hue, saturation, value)
     }
 
+    /// Convert `color` into HSV, but keep the hue and saturation of
+    /// `previous` where the conversion leaves them undefined.
+    ///
+    /// Gray colors have no hue and black has neither hue nor saturation, so
+    /// converting them into HSV gives them a hue of `0.0`. This makes
+    /// animations and gradients that pass through gray or black jump to red
+    /// when they are converted back and forth. This function keeps the hue of
+    /// `previous` if the saturation of the result is `0.0`, and also its
+    /// saturation if the value is `0.0`.
+    ///
+    /// Conversions between HSV, [HSL](crate::Hsl) and [HWB](crate::Hwb) are
+    /// direct and keep the hue, so this is only needed when converting from
+    /// other color spaces.
+    ///
+    /// ```
+    /// use palette::{FromColor, Hsv, Srgb};
+    ///
+    /// let previous = Hsv::new(120.0, 1.0, 0.2);
+    /// let black = Srgb::new(0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Hsv::from_color(black), Hsv::new(0.0, 0.0, 0.0));
+    /// assert_eq!(
+    ///     Hsv::from_color_preserving_hue(black, previous),
+    ///     Hsv::new(120.0, 1.0, 0.0)
+    /// );
+    /// ```
+    pub fn from_color_preserving_hue<C>(color: C, previous: Self) -> Self
+    where
+        Self: FromColor<C>,
+    {
+        let mut hsv = Self::from_color(color);
+
+        if hsv.value <= T::zero() {
+            hsv.hue = previous.hue;
+            hsv.saturation = previous.saturation;
+        } else if hsv.saturation <= T::zero() {
+            hsv.hue = previous.hue;
+        }
+
+        hsv
+    }
+
     #[inline]
     fn reinterpret_as<St: RgbStandard>(self) -> Hsv<St, T> {
         Hsv {
@@ -333,6 +377,35 @@ impl<S: RgbStandard, T: FloatComponent, A: Component> Into<(RgbHue<T>, T, T, A)>
     }
 }
 
+impl<S: RgbStandard, T: FloatComponent> From<[T; 3]> for Hsv<S, T> {
+    fn from([hue, saturation, value]: [T; 3]) -> Self {
+        Self::from_components((hue, saturation, value))
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<Hsv<S, T>> for [T; 3] {
+    fn from(color: Hsv<S, T>) -> Self {
+        [color.hue.to_degrees(), color.saturation, color.value]
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<[T; 4]> for Alpha<Hsv<S, T>, T> {
+    fn from([hue, saturation, value, alpha]: [T; 4]) -> Self {
+        Self::from_components((hue, saturation, value, alpha))
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<Alpha<Hsv<S, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Hsv<S, T>, T>) -> Self {
+        [
+            color.hue.to_degrees(),
+            color.saturation,
+            color.value,
+            color.alpha,
+        ]
+    }
+}
+
 impl<S, T> Limited for Hsv<S, T>
 where
     T: FloatComponent,
@@ -354,6 +427,35 @@ where
         self.saturation = clamp(self.saturation, T::zero(), T::one());
         self.value = clamp(self.value, T::zero(), T::one());
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check(
+                "saturation",
+                self.saturation >= T::zero() && self.saturation <= T::one(),
+            )
+            .check("value", self.value >= T::zero() && self.value <= T::one())
+    }
+}
+
+/// Blending is performed in linear RGB, with the same RGB space as the color.
+impl<S, T> Blend for Hsv<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    type Color = Rgb<Linear<S::Space>, T>;
+
+    fn into_premultiplied(self) -> PreAlpha<Rgb<Linear<S::Space>, T>, T> {
+        Rgb::<S, T>::from_color_unclamped(self)
+            .into_linear()
+            .into_premultiplied()
+    }
+
+    fn from_premultiplied(color: PreAlpha<Rgb<Linear<S::Space>, T>, T>) -> Self {
+        let color = Rgb::<Linear<S::Space>, T>::from_premultiplied(color);
+        Self::from_color_unclamped(Rgb::<S, T>::from_linear(color))
+    }
 }
 
 impl<S, T> Mix for Hsv<S, T>
@@ -364,8 +466,18 @@ where
     type Scalar = T;
 
     fn mix(&self, other: &Hsv<S, T>, factor: T) -> Hsv<S, T> {
+        self.mix_hue(other, factor, HueInterpolation::Shorter)
+    }
+}
+
+impl<S, T> MixHue for Hsv<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn mix_hue(&self, other: &Hsv<S, T>, factor: T, method: HueInterpolation) -> Hsv<S, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let hue_diff: T = method.adjust_difference((other.hue - self.hue).to_degrees());
 
         Hsv {
             hue: self.hue + factor * hue_diff,
@@ -391,6 +503,23 @@ where
             standard: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Hsv<S, T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.value
+        } else {
+            self.value
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Hsv {
+            hue: self.hue,
+            saturation: self.saturation,
+            value: (self.value + delta).max(T::zero()),
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> GetHue for Hsv<S, T>
@@ -448,6 +577,15 @@ where
             standard: PhantomData,
         }
     }
+
+    fn saturate_fixed(&self, amount: T) -> Hsv<S, T> {
+        Hsv {
+            hue: self.hue,
+            saturation: self.saturation + amount,
+            value: self.value,
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> Default for Hsv<S, T>
@@ -658,18 +796,29 @@ where
     }
 }
 
-impl<S, T> RelativeContrast for Hsv<S, T>
+impl<S, T> RelativeLuminance for Hsv<S, T>
 where
     T: FloatComponent,
     S: RgbStandard,
 {
+    type WhitePoint = <S::Space as RgbSpace>::WhitePoint;
     type Scalar = T;
+}
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
+impl<S, T> ApcaContrast for Hsv<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard<Space = Srgb>,
+{
+    type Scalar = T;
+
+    fn get_apca_contrast(&self, background: &Self) -> T {
+        use crate::FromColor;
 
-        contrast_ratio(xyz1.y, xyz2.y)
+        let text = Rgb::<S, T>::from_color(*self);
+        let background = Rgb::<S, T>::from_color(*background);
+
+        text.get_apca_contrast(&background)
     }
 }
 
@@ -856,6 +1005,19 @@ mod test {
         assert_relative_eq!(Hsv::<Srgb>::max_value(), 1.0,);
     }
 
+    #[test]
+    fn rgb_round_trip_preserving_hue() {
+        let gray = Hsv::new(200.0, 0.0, 0.5);
+        let black = Hsv::new(200.0, 0.7, 0.0);
+        let color = Hsv::new(200.0, 0.7, 0.5);
+
+        let round_trip = |hsv| Hsv::from_color_preserving_hue(Srgb::from_color(hsv), hsv);
+
+        assert_relative_eq!(round_trip(gray), gray);
+        assert_relative_eq!(round_trip(black), black);
+        assert_relative_eq!(round_trip(color), color, epsilon = 1e-5);
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
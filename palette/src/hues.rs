@@ -1,5 +1,5 @@
 use core::cmp::PartialEq;
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[cfg(feature = "random")]
 use rand::distributions::uniform::{SampleBorrow, SampleUniform, Uniform, UniformSampler};
@@ -9,7 +9,7 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::float::Float;
-use crate::{from_f64, FromF64};
+use crate::{clamp, from_f64, FromF64};
 
 macro_rules! make_hues {
     ($($(#[$doc:meta])+ struct $name:ident;)+) => ($(
@@ -73,6 +73,27 @@ macro_rules! make_hues {
             pub fn to_raw_radians(self) -> T {
                 self.0.to_radians()
             }
+
+            /// Get the shortest distance to `other` around the color circle,
+            /// in degrees and in the range `[0, 180]`.
+            #[inline]
+            pub fn abs_diff(self, other: $name<T>) -> T {
+                normalize_angle(self.0 - other.0).abs()
+            }
+
+            /// Interpolate towards `other` by `factor`, with the hue following
+            /// the path decided by `method`.
+            ///
+            /// `factor` is clamped to `[0, 1]`, where `0.0` gives `self` and
+            /// `1.0` gives `other`. This is the same interpolation as
+            /// [`MixHue`](crate::MixHue) does for the hue of a color.
+            #[inline]
+            pub fn lerp(self, other: $name<T>, factor: T, method: HueInterpolation) -> $name<T> {
+                let factor = clamp(factor, T::zero(), T::one());
+                let difference = method.adjust_difference(normalize_angle(other.0 - self.0));
+
+                $name(self.0 + factor * difference)
+            }
         }
 
         impl<T: Float> From<T> for $name<T> {
@@ -82,6 +103,13 @@ macro_rules! make_hues {
             }
         }
 
+        impl<T: Float + FromF64> FromF64 for $name<T> {
+            #[inline]
+            fn from_f64(degrees: f64) -> $name<T> {
+                $name(from_f64(degrees))
+            }
+        }
+
         impl Into<f64> for $name<f64> {
             #[inline]
             fn into(self) -> f64 {
@@ -247,6 +275,47 @@ macro_rules! make_hues {
             }
         }
 
+        impl<T: Float> Mul<T> for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn mul(self, other: T) -> $name<T> {
+                $name(self.0 * other)
+            }
+        }
+
+        impl<T: Float + MulAssign> MulAssign<T> for $name<T> {
+            #[inline]
+            fn mul_assign(&mut self, other: T) {
+                self.0 *= other;
+            }
+        }
+
+        impl<T: Float> Div<T> for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn div(self, other: T) -> $name<T> {
+                $name(self.0 / other)
+            }
+        }
+
+        impl<T: Float + DivAssign> DivAssign<T> for $name<T> {
+            #[inline]
+            fn div_assign(&mut self, other: T) {
+                self.0 /= other;
+            }
+        }
+
+        impl<T: Float> Neg for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn neg(self) -> $name<T> {
+                $name(-self.0)
+            }
+        }
+
         #[cfg(feature = "random")]
         impl<T> Distribution<$name<T>> for Standard
         where
@@ -275,6 +344,64 @@ make_hues! {
     struct RgbHue;
 }
 
+/// A method for interpolating between two hues, as in the
+/// `hue-interpolation-method` of CSS Color Module Level 4.
+///
+/// It's used by [`MixHue`](crate::MixHue) to decide which way around the
+/// color wheel the hue should go.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum HueInterpolation {
+    /// Take the shortest path between the hues. This is what
+    /// [`Mix`](crate::Mix) does, and the default.
+    #[default]
+    Shorter,
+
+    /// Take the longest path between the hues.
+    Longer,
+
+    /// Always go in the direction of increasing hue angles.
+    Increasing,
+
+    /// Always go in the direction of decreasing hue angles.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Adjust a hue difference, in degrees and normalized to `(-180, 180]`,
+    /// to follow this interpolation method.
+    pub(crate) fn adjust_difference<T: Float + FromF64>(self, difference: T) -> T {
+        let c360: T = from_f64(360.0);
+
+        match self {
+            HueInterpolation::Shorter => difference,
+            HueInterpolation::Longer => {
+                if difference > T::zero() && difference < from_f64(180.0) {
+                    difference - c360
+                } else if difference <= T::zero() {
+                    difference + c360
+                } else {
+                    difference
+                }
+            }
+            HueInterpolation::Increasing => {
+                if difference < T::zero() {
+                    difference + c360
+                } else {
+                    difference
+                }
+            }
+            HueInterpolation::Decreasing => {
+                if difference > T::zero() {
+                    difference - c360
+                } else {
+                    difference
+                }
+            }
+        }
+    }
+}
+
 #[inline]
 fn normalize_angle<T: Float + FromF64>(deg: T) -> T {
     let c360 = from_f64(360.0);
@@ -430,9 +557,25 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::{normalize_angle, normalize_angle_positive};
+    use super::{normalize_angle, normalize_angle_positive, HueInterpolation};
     use crate::RgbHue;
 
+    #[test]
+    fn hue_interpolation_difference() {
+        let cases = [
+            (HueInterpolation::Shorter, [-90.0, 0.0, 90.0, 180.0]),
+            (HueInterpolation::Longer, [270.0, 360.0, -270.0, 180.0]),
+            (HueInterpolation::Increasing, [270.0, 0.0, 90.0, 180.0]),
+            (HueInterpolation::Decreasing, [-90.0, 0.0, -270.0, -180.0]),
+        ];
+
+        for &(method, expected) in &cases {
+            for (&difference, &expected) in [-90.0f64, 0.0, 90.0, 180.0].iter().zip(&expected) {
+                assert_relative_eq!(method.adjust_difference(difference), expected);
+            }
+        }
+    }
+
     #[test]
     fn normalize_angle_0_360() {
         let inp = [
@@ -526,6 +669,43 @@ mod test {
         }
     }
 
+    #[test]
+    fn arithmetic() {
+        let hue = RgbHue::from_degrees(100.0f64);
+
+        assert_relative_eq!((hue * 2.0).to_raw_degrees(), 200.0);
+        assert_relative_eq!((hue / 4.0).to_raw_degrees(), 25.0);
+        assert_relative_eq!((-hue).to_raw_degrees(), -100.0);
+
+        let mut hue = hue;
+        hue *= 3.0;
+        hue /= 2.0;
+        assert_relative_eq!(hue.to_raw_degrees(), 150.0);
+    }
+
+    #[test]
+    fn abs_diff() {
+        let a = RgbHue::from_degrees(350.0f64);
+
+        assert_relative_eq!(a.abs_diff(10.0.into()), 20.0);
+        assert_relative_eq!(a.abs_diff((-10.0).into()), 0.0);
+        assert_relative_eq!(a.abs_diff(170.0.into()), 180.0);
+        assert_relative_eq!(RgbHue::from(10.0).abs_diff(a), 20.0);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = RgbHue::from_degrees(350.0f64);
+        let b = RgbHue::from_degrees(30.0);
+
+        assert_relative_eq!(a.lerp(b, 0.5, HueInterpolation::Shorter), 10.0.into());
+        assert_relative_eq!(a.lerp(b, 0.5, HueInterpolation::Longer), 190.0.into());
+        assert_relative_eq!(a.lerp(b, 0.5, HueInterpolation::Decreasing), 190.0.into());
+        assert_relative_eq!(b.lerp(a, 0.5, HueInterpolation::Increasing), 190.0.into());
+        assert_relative_eq!(a.lerp(b, 0.0, HueInterpolation::Longer), a);
+        assert_relative_eq!(a.lerp(b, 2.0, HueInterpolation::Shorter), b);
+    }
+
     #[cfg(feature = "serializing")]
     #[test]
     fn serialize() {
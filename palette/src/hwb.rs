@@ -10,14 +10,16 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
-use crate::convert::FromColorUnclamped;
+use crate::blend::PreAlpha;
+use crate::convert::{FromColor, FromColorUnclamped};
 use crate::encoding::pixel::RawPixel;
-use crate::encoding::Srgb;
+use crate::encoding::{Linear, Srgb};
 use crate::float::Float;
-use crate::rgb::{RgbSpace, RgbStandard};
+use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::{
-    clamp, contrast_ratio, Alpha, Component, FloatComponent, FromF64, GetHue, Hsv, Hue, Limited,
-    Mix, Pixel, RelativeContrast, RgbHue, Shade, Xyz,
+    clamp, Alpha, ApcaContrast, Blend, Component, FloatComponent, FromF64, GetHue, Hsl, Hsv, Hue,
+    HueInterpolation, InvalidComponents, Limited, Mix, MixHue, Pixel, RelativeLuminance, RgbHue,
+    Shade,
 };
 
 /// Linear HWB with an alpha component. See the [`Hwba` implementation in
@@ -40,7 +42,7 @@ pub type Hwba<S = Srgb, T = f32> = Alpha<Hwb<S, T>, T>;
     rgb_standard = "S",
     white_point = "<S::Space as RgbSpace>::WhitePoint",
     component = "T",
-    skip_derives(Hsv, Hwb)
+    skip_derives(Hsv, Hsl, Hwb)
 )]
 #[repr(C)]
 pub struct Hwb<S = Srgb, T = f32>
@@ -130,6 +132,44 @@ where
         Self::with_wp(hue, whiteness, blackness)
     }
 
+    /// Convert `color` into HWB, but keep the hue of `previous` where the
+    /// conversion leaves it undefined.
+    ///
+    /// Gray colors have no hue, so converting them into HWB gives them a hue
+    /// of `0.0`. This makes animations and gradients that pass through gray
+    /// jump to red when they are converted back and forth. This function keeps
+    /// the hue of `previous` if the sum of the whiteness and blackness of the
+    /// result is `1.0` or more.
+    ///
+    /// Conversions between HWB, [HSV](crate::Hsv) and [HSL](crate::Hsl) are
+    /// direct and keep the hue, so this is only needed when converting from
+    /// other color spaces.
+    ///
+    /// ```
+    /// use palette::{FromColor, Hwb, Srgb};
+    ///
+    /// let previous = Hwb::new(120.0, 0.3, 0.4);
+    /// let gray = Srgb::new(0.5, 0.5, 0.5);
+    ///
+    /// assert_eq!(Hwb::from_color(gray), Hwb::new(0.0, 0.5, 0.5));
+    /// assert_eq!(
+    ///     Hwb::from_color_preserving_hue(gray, previous),
+    ///     Hwb::new(120.0, 0.5, 0.5)
+    /// );
+    /// ```
+    pub fn from_color_preserving_hue<C>(color: C, previous: Self) -> Self
+    where
+        Self: FromColor<C>,
+    {
+        let mut hwb = Self::from_color(color);
+
+        if hwb.whiteness + hwb.blackness >= T::one() {
+            hwb.hue = previous.hue;
+        }
+
+        hwb
+    }
+
     #[inline]
     fn reinterpret_as<St: RgbStandard>(self) -> Hwb<St, T> {
         Hwb {
@@ -237,6 +277,23 @@ where
     }
 }
 
+impl<S, T> FromColorUnclamped<Hsl<S, T>> for Hwb<S, T>
+where
+    S: RgbStandard,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Hsl<S, T>) -> Self {
+        let x = color.saturation * color.lightness.min(T::one() - color.lightness);
+
+        Hwb {
+            hue: color.hue,
+            whiteness: color.lightness - x,
+            blackness: T::one() - color.lightness - x,
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S: RgbStandard, T: FloatComponent, H: Into<RgbHue<T>>> From<(H, T, T)> for Hwb<S, T> {
     fn from(components: (H, T, T)) -> Self {
         Self::from_components(components)
@@ -265,6 +322,35 @@ impl<S: RgbStandard, T: FloatComponent, A: Component> Into<(RgbHue<T>, T, T, A)>
     }
 }
 
+impl<S: RgbStandard, T: FloatComponent> From<[T; 3]> for Hwb<S, T> {
+    fn from([hue, whiteness, blackness]: [T; 3]) -> Self {
+        Self::from_components((hue, whiteness, blackness))
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<Hwb<S, T>> for [T; 3] {
+    fn from(color: Hwb<S, T>) -> Self {
+        [color.hue.to_degrees(), color.whiteness, color.blackness]
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<[T; 4]> for Alpha<Hwb<S, T>, T> {
+    fn from([hue, whiteness, blackness, alpha]: [T; 4]) -> Self {
+        Self::from_components((hue, whiteness, blackness, alpha))
+    }
+}
+
+impl<S: RgbStandard, T: FloatComponent> From<Alpha<Hwb<S, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Hwb<S, T>, T>) -> Self {
+        [
+            color.hue.to_degrees(),
+            color.whiteness,
+            color.blackness,
+            color.alpha,
+        ]
+    }
+}
+
 impl<S, T> Limited for Hwb<S, T>
 where
     T: FloatComponent,
@@ -292,6 +378,40 @@ where
             self.blackness = self.blackness / sum;
         }
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        let sum_is_valid = self.whiteness + self.blackness <= T::one();
+
+        InvalidComponents::new()
+            .check(
+                "whiteness",
+                self.whiteness >= T::zero() && self.whiteness <= T::one() && sum_is_valid,
+            )
+            .check(
+                "blackness",
+                self.blackness >= T::zero() && self.blackness <= T::one() && sum_is_valid,
+            )
+    }
+}
+
+/// Blending is performed in linear RGB, with the same RGB space as the color.
+impl<S, T> Blend for Hwb<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    type Color = Rgb<Linear<S::Space>, T>;
+
+    fn into_premultiplied(self) -> PreAlpha<Rgb<Linear<S::Space>, T>, T> {
+        Rgb::<S, T>::from_color_unclamped(self)
+            .into_linear()
+            .into_premultiplied()
+    }
+
+    fn from_premultiplied(color: PreAlpha<Rgb<Linear<S::Space>, T>, T>) -> Self {
+        let color = Rgb::<Linear<S::Space>, T>::from_premultiplied(color);
+        Self::from_color_unclamped(Rgb::<S, T>::from_linear(color))
+    }
 }
 
 impl<S, T> Mix for Hwb<S, T>
@@ -302,8 +422,18 @@ where
     type Scalar = T;
 
     fn mix(&self, other: &Hwb<S, T>, factor: T) -> Hwb<S, T> {
+        self.mix_hue(other, factor, HueInterpolation::Shorter)
+    }
+}
+
+impl<S, T> MixHue for Hwb<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+{
+    fn mix_hue(&self, other: &Hwb<S, T>, factor: T, method: HueInterpolation) -> Hwb<S, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let hue_diff: T = method.adjust_difference((other.hue - self.hue).to_degrees());
 
         Hwb {
             hue: self.hue + factor * hue_diff,
@@ -329,6 +459,29 @@ where
             standard: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Hwb<S, T> {
+        let difference_whiteness = if factor >= T::zero() {
+            T::one() - self.whiteness
+        } else {
+            self.whiteness
+        };
+        let delta_whiteness = difference_whiteness.max(T::zero()) * factor;
+
+        let difference_blackness = if factor >= T::zero() {
+            self.blackness
+        } else {
+            T::one() - self.blackness
+        };
+        let delta_blackness = difference_blackness.max(T::zero()) * factor;
+
+        Hwb {
+            hue: self.hue,
+            whiteness: (self.whiteness + delta_whiteness).max(T::zero()),
+            blackness: (self.blackness - delta_blackness).max(T::zero()),
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> GetHue for Hwb<S, T>
@@ -608,20 +761,29 @@ where
     }
 }
 
-impl<S, T> RelativeContrast for Hwb<S, T>
+impl<S, T> RelativeLuminance for Hwb<S, T>
 where
     T: FloatComponent,
     S: RgbStandard,
+{
+    type WhitePoint = <S::Space as RgbSpace>::WhitePoint;
+    type Scalar = T;
+}
+
+impl<S, T> ApcaContrast for Hwb<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard<Space = Srgb>,
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
+    fn get_apca_contrast(&self, background: &Self) -> T {
         use crate::FromColor;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
+        let text = Rgb::<S, T>::from_color(*self);
+        let background = Rgb::<S, T>::from_color(*background);
 
-        contrast_ratio(xyz1.y, xyz2.y)
+        text.get_apca_contrast(&background)
     }
 }
 
@@ -796,6 +958,23 @@ mod test {
         assert_relative_eq!(expected, calc_a);
     }
 
+    #[test]
+    fn invalid_components() {
+        let valid = Hwb::new(240.0, 0.3, 0.1);
+        assert!(valid.invalid_components().is_empty());
+
+        let negative = Hwb::new(240.0, -0.1, 0.5);
+        assert_eq!(
+            negative.invalid_components().iter().collect::<Vec<_>>(),
+            ["whiteness"]
+        );
+
+        let over_one = Hwb::new(240.0, 0.6, 0.6);
+        let (clamped, invalid) = over_one.clamp_and_report();
+        assert_relative_eq!(clamped, Hwb::new(240.0, 0.5, 0.5));
+        assert_eq!(invalid.to_string(), "whiteness, blackness");
+    }
+
     raw_pixel_conversion_tests!(Hwb<crate::encoding::Srgb>: hue, whiteness, blackness);
     raw_pixel_conversion_fail_tests!(Hwb<crate::encoding::Srgb>: hue, whiteness, blackness);
 
@@ -836,4 +1015,16 @@ mod test {
         min: Hwb::new(0.0f32, 0.0, 0.0),
         max: Hwb::new(360.0, 1.0, 1.0)
     }
+
+    #[test]
+    fn lighten_and_darken() {
+        use crate::Shade;
+
+        let color = Hwb::new(0.0, 0.2, 0.4);
+
+        assert_relative_eq!(color.lighten_relative(0.5), Hwb::new(0.0, 0.6, 0.2));
+        assert_relative_eq!(color.darken_relative(0.5), Hwb::new(0.0, 0.1, 0.7));
+        assert_relative_eq!(color.lighten(0.1), Hwb::new(0.0, 0.3, 0.3));
+        assert_relative_eq!(color.darken(0.1), Hwb::new(0.0, 0.1, 0.5));
+    }
 }
@@ -0,0 +1,270 @@
+//! Conversion between Palette colors and the pixel types of the `image`
+//! crate.
+//!
+//! The `image` pixel types `Rgb`, `Rgba`, `Luma` and `LumaA` can be converted
+//! to and from the corresponding Palette colors using `From` and `Into`. The
+//! pixels are assumed to have the same RGB standard or luma standard as the
+//! Palette color, which is usually sRGB for images:
+//!
+//! ```
+//! use palette::Srgba;
+//!
+//! let pixel = image::Rgba([255u8, 128, 0, 255]);
+//! let color = Srgba::from(pixel);
+//! assert_eq!(color, Srgba::new(255, 128, 0, 255));
+//!
+//! let pixel: image::Rgba<u8> = color.into();
+//! assert_eq!(pixel, image::Rgba([255, 128, 0, 255]));
+//! ```
+//!
+//! The pixels of a whole `ImageBuffer` can also be accessed as Palette colors
+//! without copying them, using [`as_colors`] and [`as_colors_mut`]:
+//!
+//! ```
+//! use palette::Srgb;
+//! use palette::image::as_colors_mut;
+//!
+//! let mut image = image::RgbImage::new(4, 4);
+//!
+//! for color in as_colors_mut::<Srgb<u8>, _, _>(&mut image) {
+//!     *color = Srgb::new(255, 0, 0);
+//! }
+//!
+//! assert_eq!(image.get_pixel(3, 3), &image::Rgb([255, 0, 0]));
+//! ```
+
+use core::ops::{Deref, DerefMut};
+
+use ::image::{ImageBuffer, Primitive};
+
+use crate::luma::{Luma, LumaStandard};
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{Alpha, Component, Pixel};
+
+/// Palette colors with the same channels, in the same order, as the `image`
+/// pixel type `P`.
+///
+/// This is implemented for the RGB and luma types, with or without alpha,
+/// and makes it possible to view the pixels of an `ImageBuffer` as colors.
+pub trait ImagePixel<P: ::image::Pixel>: Pixel<P::Subpixel> {}
+
+impl<S, T> ImagePixel<::image::Rgb<T>> for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component + Primitive + 'static,
+{
+}
+
+impl<S, T> ImagePixel<::image::Rgba<T>> for Alpha<Rgb<S, T>, T>
+where
+    S: RgbStandard,
+    T: Component + Primitive + 'static,
+{
+}
+
+impl<S, T> ImagePixel<::image::Luma<T>> for Luma<S, T>
+where
+    S: LumaStandard,
+    T: Component + Primitive + 'static,
+{
+}
+
+impl<S, T> ImagePixel<::image::LumaA<T>> for Alpha<Luma<S, T>, T>
+where
+    S: LumaStandard,
+    T: Component + Primitive + 'static,
+{
+}
+
+/// View the pixels of `image` as a slice of colors, without copying them.
+///
+/// The colors are in the same order as the pixels, row by row.
+///
+/// ```
+/// use palette::Srgba;
+/// use palette::image::as_colors;
+///
+/// let image = image::RgbaImage::from_pixel(2, 2, image::Rgba([10, 20, 30, 255]));
+/// let colors: &[Srgba<u8>] = as_colors(&image);
+///
+/// assert_eq!(colors.len(), 4);
+/// assert_eq!(colors[3], Srgba::new(10, 20, 30, 255));
+/// ```
+pub fn as_colors<C, P, Container>(image: &ImageBuffer<P, Container>) -> &[C]
+where
+    C: ImagePixel<P>,
+    P: ::image::Pixel + 'static,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    C::from_raw_slice(&(**image)[..subpixel_count(image)])
+}
+
+/// View the pixels of `image` as a mutable slice of colors, without copying
+/// them.
+///
+/// The colors are in the same order as the pixels, row by row, and any
+/// changes to them are made to the image.
+pub fn as_colors_mut<C, P, Container>(image: &mut ImageBuffer<P, Container>) -> &mut [C]
+where
+    C: ImagePixel<P>,
+    P: ::image::Pixel + 'static,
+    Container: Deref<Target = [P::Subpixel]> + DerefMut,
+{
+    let length = subpixel_count(image);
+    C::from_raw_slice_mut(&mut (**image)[..length])
+}
+
+/// The number of subpixels that belong to the pixels of `image`. The container
+/// may be longer than that, but the rest of it isn't part of the image.
+fn subpixel_count<P, Container>(image: &ImageBuffer<P, Container>) -> usize
+where
+    P: ::image::Pixel + 'static,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    let (width, height) = image.dimensions();
+    width as usize * height as usize * P::CHANNEL_COUNT as usize
+}
+
+impl<S, T> From<::image::Rgb<T>> for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component + Primitive,
+{
+    fn from(pixel: ::image::Rgb<T>) -> Self {
+        let [red, green, blue] = pixel.0;
+        Rgb::new(red, green, blue)
+    }
+}
+
+impl<S, T> From<Rgb<S, T>> for ::image::Rgb<T>
+where
+    S: RgbStandard,
+    T: Component + Primitive,
+{
+    fn from(color: Rgb<S, T>) -> Self {
+        ::image::Rgb([color.red, color.green, color.blue])
+    }
+}
+
+impl<S, T> From<::image::Rgba<T>> for Alpha<Rgb<S, T>, T>
+where
+    S: RgbStandard,
+    T: Component + Primitive,
+{
+    fn from(pixel: ::image::Rgba<T>) -> Self {
+        let [red, green, blue, alpha] = pixel.0;
+        Alpha {
+            color: Rgb::new(red, green, blue),
+            alpha,
+        }
+    }
+}
+
+impl<S, T> From<Alpha<Rgb<S, T>, T>> for ::image::Rgba<T>
+where
+    S: RgbStandard,
+    T: Component + Primitive,
+{
+    fn from(color: Alpha<Rgb<S, T>, T>) -> Self {
+        ::image::Rgba([
+            color.color.red,
+            color.color.green,
+            color.color.blue,
+            color.alpha,
+        ])
+    }
+}
+
+impl<S, T> From<::image::Luma<T>> for Luma<S, T>
+where
+    S: LumaStandard,
+    T: Component + Primitive,
+{
+    fn from(pixel: ::image::Luma<T>) -> Self {
+        Luma::new(pixel.0[0])
+    }
+}
+
+impl<S, T> From<Luma<S, T>> for ::image::Luma<T>
+where
+    S: LumaStandard,
+    T: Component + Primitive,
+{
+    fn from(color: Luma<S, T>) -> Self {
+        ::image::Luma([color.luma])
+    }
+}
+
+impl<S, T> From<::image::LumaA<T>> for Alpha<Luma<S, T>, T>
+where
+    S: LumaStandard,
+    T: Component + Primitive,
+{
+    fn from(pixel: ::image::LumaA<T>) -> Self {
+        let [luma, alpha] = pixel.0;
+        Alpha {
+            color: Luma::new(luma),
+            alpha,
+        }
+    }
+}
+
+impl<S, T> From<Alpha<Luma<S, T>, T>> for ::image::LumaA<T>
+where
+    S: LumaStandard,
+    T: Component + Primitive,
+{
+    fn from(color: Alpha<Luma<S, T>, T>) -> Self {
+        ::image::LumaA([color.color.luma, color.alpha])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{as_colors, as_colors_mut};
+    use crate::{LinSrgb, Srgb, SrgbLuma, SrgbLumaa, Srgba};
+
+    #[test]
+    fn pixels() {
+        let rgb = Srgb::new(1u8, 2, 3);
+        assert_eq!(Srgb::from(::image::Rgb::from(rgb)), rgb);
+
+        let rgba = Srgba::new(1u16, 2, 3, 4);
+        assert_eq!(Srgba::from(::image::Rgba::from(rgba)), rgba);
+
+        let luma = SrgbLuma::new(0.5f32);
+        assert_eq!(SrgbLuma::from(::image::Luma::from(luma)), luma);
+
+        let lumaa = SrgbLumaa::new(10u8, 20);
+        assert_eq!(SrgbLumaa::from(::image::LumaA::from(lumaa)), lumaa);
+    }
+
+    #[test]
+    fn image_buffer() {
+        let mut image = ::image::RgbImage::new(3, 2);
+        image.put_pixel(1, 1, ::image::Rgb([10, 20, 30]));
+
+        {
+            let colors: &[Srgb<u8>] = as_colors(&image);
+            assert_eq!(colors.len(), 6);
+            assert_eq!(colors[4], Srgb::new(10, 20, 30));
+        }
+
+        for color in as_colors_mut::<Srgb<u8>, _, _>(&mut image) {
+            *color = Srgb::from_linear_u8(LinSrgb::new(1.0f32, 0.0, 0.5));
+        }
+
+        assert_eq!(image.get_pixel(2, 0), &::image::Rgb([255, 0, 188]));
+
+        let gray = ::image::GrayImage::from_raw(2, 1, vec![5, 6]).unwrap();
+        let colors: &[SrgbLuma<u8>] = as_colors(&gray);
+        assert_eq!(colors, &[SrgbLuma::new(5), SrgbLuma::new(6)]);
+    }
+
+    #[test]
+    fn longer_container() {
+        let mut image = ::image::RgbImage::from_raw(1, 1, vec![1, 2, 3, 4, 5, 6, 7]).unwrap();
+        assert_eq!(as_colors::<Srgb<u8>, _, _>(&image), &[Srgb::new(1, 2, 3)]);
+        assert_eq!(as_colors_mut::<Srgb<u8>, _, _>(&mut image).len(), 1);
+    }
+}
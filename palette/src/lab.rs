@@ -8,14 +8,17 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
+use crate::blend::PreAlpha;
 use crate::color_difference::ColorDifference;
 use crate::color_difference::{get_ciede_difference, LabColorDiff};
 use crate::convert::FromColorUnclamped;
 use crate::encoding::pixel::RawPixel;
+use crate::encoding::{Linear, Srgb};
+use crate::rgb::Rgb;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Component, ComponentWise, FloatComponent, GetHue,
-    LabHue, Lch, Limited, Mix, Pixel, RelativeContrast, Shade, Xyz,
+    clamp, from_f64, Alpha, Blend, Component, ComponentWise, ComponentWiseMap, FloatComponent,
+    GetHue, InvalidComponents, LabHue, Lch, Limited, Mix, Pixel, RelativeLuminance, Shade, Xyz,
 };
 
 /// CIE L\*a\*b\* (CIELAB) with an alpha component. See the [`Laba`
@@ -121,6 +124,35 @@ where
         Self::with_wp(l, a, b)
     }
 
+    /// Create a color from the percentages of the CSS `lab()` function, where
+    /// `100%` is `100.0` for `l` and `125.0` for `a` and `b`.
+    ///
+    /// The numbers of `lab()` have the same scale as `Lab`, so they can be
+    /// passed to [`with_wp`](Self::with_wp) as they are. Note that CSS uses
+    /// the D50 white point for `lab()`.
+    ///
+    /// ```
+    /// use palette::white_point::D50;
+    /// use palette::Lab;
+    ///
+    /// // lab(50% 40% -20%)
+    /// let color = Lab::<D50>::from_css_percentages(50.0, 40.0, -20.0);
+    /// assert_eq!(color, Lab::with_wp(50.0, 50.0, -25.0));
+    /// assert_eq!(color.into_css_percentages(), (50.0, 40.0, -20.0));
+    /// ```
+    pub fn from_css_percentages(l: T, a: T, b: T) -> Self {
+        let ab_scale: T = from_f64(1.25);
+        Self::with_wp(l, a * ab_scale, b * ab_scale)
+    }
+
+    /// Convert into the percentages of the CSS `lab()` function, where
+    /// `100%` is `100.0` for `l` and `125.0` for `a` and `b`. This is the
+    /// inverse of [`from_css_percentages`](Self::from_css_percentages).
+    pub fn into_css_percentages(self) -> (T, T, T) {
+        let ab_scale: T = from_f64(1.25);
+        (self.l, self.a / ab_scale, self.b / ab_scale)
+    }
+
     /// Return the `l` value minimum.
     pub fn min_l() -> T {
         T::zero()
@@ -279,6 +311,30 @@ impl<Wp: WhitePoint, T: FloatComponent, A: Component> Into<(T, T, T, A)> for Alp
     }
 }
 
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 3]> for Lab<Wp, T> {
+    fn from([l, a, b]: [T; 3]) -> Self {
+        Self::from_components((l, a, b))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Lab<Wp, T>> for [T; 3] {
+    fn from(color: Lab<Wp, T>) -> Self {
+        [color.l, color.a, color.b]
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 4]> for Alpha<Lab<Wp, T>, T> {
+    fn from([l, a, b, alpha]: [T; 4]) -> Self {
+        Self::from_components((l, a, b, alpha))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Alpha<Lab<Wp, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Lab<Wp, T>, T>) -> Self {
+        [color.l, color.a, color.b, color.alpha]
+    }
+}
+
 impl<Wp, T> Limited for Lab<Wp, T>
 where
     T: FloatComponent,
@@ -302,6 +358,31 @@ where
         self.a = clamp(self.a, from_f64(-128.0), from_f64(127.0));
         self.b = clamp(self.b, from_f64(-128.0), from_f64(127.0));
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check("l", self.l >= T::zero() && self.l <= from_f64(100.0))
+            .check("a", self.a >= from_f64(-128.0) && self.a <= from_f64(127.0))
+            .check("b", self.b >= from_f64(-128.0) && self.b <= from_f64(127.0))
+    }
+}
+
+/// Blending is performed in linear RGB, with the sRGB primaries and the same
+/// white point as the color.
+impl<Wp, T> Blend for Lab<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Color = Rgb<Linear<(Srgb, Wp)>, T>;
+
+    fn into_premultiplied(self) -> PreAlpha<Rgb<Linear<(Srgb, Wp)>, T>, T> {
+        Rgb::<Linear<(Srgb, Wp)>, T>::from_color_unclamped(self).into_premultiplied()
+    }
+
+    fn from_premultiplied(color: PreAlpha<Rgb<Linear<(Srgb, Wp)>, T>, T>) -> Self {
+        Self::from_color_unclamped(Rgb::<Linear<(Srgb, Wp)>, T>::from_premultiplied(color))
+    }
 }
 
 impl<Wp, T> Mix for Lab<Wp, T>
@@ -338,6 +419,23 @@ where
             white_point: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Lab<Wp, T> {
+        let difference = if factor >= T::zero() {
+            Self::max_l() - self.l
+        } else {
+            self.l
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Lab {
+            l: (self.l + delta).max(T::zero()),
+            a: self.a,
+            b: self.b,
+            white_point: PhantomData,
+        }
+    }
 }
 
 impl<Wp, T> GetHue for Lab<Wp, T>
@@ -354,6 +452,10 @@ where
             Some(LabHue::from_radians(self.b.atan2(self.a)))
         }
     }
+
+    fn has_hue(&self) -> bool {
+        self.a != T::zero() || self.b != T::zero()
+    }
 }
 
 impl<Wp, T> ColorDifference for Lab<Wp, T>
@@ -410,6 +512,33 @@ where
     }
 }
 
+impl<Wp, T, U> ComponentWiseMap<U> for Lab<Wp, T>
+where
+    T: FloatComponent,
+    U: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Lab<Wp, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(&self, other: &Lab<Wp, T>, mut f: F) -> Lab<Wp, U> {
+        Lab {
+            l: f(self.l, other.l),
+            a: f(self.a, other.a),
+            b: f(self.b, other.b),
+            white_point: PhantomData,
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> Lab<Wp, U> {
+        Lab {
+            l: f(self.l),
+            a: f(self.a),
+            b: f(self.b),
+            white_point: PhantomData,
+        }
+    }
+}
+
 impl<Wp, T> Default for Lab<Wp, T>
 where
     T: FloatComponent,
@@ -674,21 +803,13 @@ where
     }
 }
 
-impl<Wp, T> RelativeContrast for Lab<Wp, T>
+impl<Wp, T> RelativeLuminance for Lab<Wp, T>
 where
     Wp: WhitePoint,
     T: FloatComponent,
 {
+    type WhitePoint = Wp;
     type Scalar = T;
-
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        use crate::FromColor;
-
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
-    }
 }
 
 #[cfg(feature = "random")]
@@ -860,4 +981,17 @@ mod test {
         min: Lab::new(0.0f32, -128.0, -128.0),
         max: Lab::new(100.0, 127.0, 127.0)
     }
+
+    #[test]
+    fn lighten_and_darken() {
+        use crate::Shade;
+
+        let color = Lab::<D65, f64>::new(60.0, 20.0, -10.0);
+
+        assert_relative_eq!(color.lighten_relative(0.5), Lab::new(80.0, 20.0, -10.0));
+        assert_relative_eq!(color.darken_relative(0.5), Lab::new(30.0, 20.0, -10.0));
+        assert_relative_eq!(color.lighten_relative(1.0), Lab::new(100.0, 20.0, -10.0));
+        assert_relative_eq!(color.lighten(0.1), Lab::new(70.0, 20.0, -10.0));
+        assert_relative_eq!(color.darken(0.1), Lab::new(50.0, 20.0, -10.0));
+    }
 }
@@ -8,14 +8,18 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
+use crate::blend::PreAlpha;
 use crate::color_difference::ColorDifference;
 use crate::color_difference::{get_ciede_difference, LabColorDiff};
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
 use crate::encoding::pixel::RawPixel;
+use crate::encoding::{Linear, Srgb};
+use crate::rgb::Rgb;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Component, FloatComponent, FromColor, GetHue, Hue, Lab,
-    LabHue, Limited, Mix, Pixel, RelativeContrast, Saturate, Shade, Xyz,
+    clamp, from_f64, Alpha, Blend, Component, FloatComponent, GetHue, Hue, HueInterpolation,
+    InvalidComponents, Lab, LabHue, Limited, Mix, MixHue, Pixel, RelativeLuminance, Saturate,
+    Shade, Xyz,
 };
 
 /// CIE L\*C\*h° with an alpha component. See the [`Lcha` implementation in
@@ -121,6 +125,34 @@ where
         Self::with_wp(l, chroma, hue)
     }
 
+    /// Create a color from the percentages of the CSS `lch()` function, where
+    /// `100%` is `100.0` for `l` and `150.0` for `chroma`. The hue is in
+    /// degrees.
+    ///
+    /// The numbers of `lch()` have the same scale as `Lch`, so they can be
+    /// passed to [`with_wp`](Self::with_wp) as they are. Note that CSS uses
+    /// the D50 white point for `lch()`.
+    ///
+    /// ```
+    /// use palette::white_point::D50;
+    /// use palette::Lch;
+    ///
+    /// // lch(50% 40% 120)
+    /// let color = Lch::<D50>::from_css_percentages(50.0, 40.0, 120.0);
+    /// assert_eq!(color, Lch::with_wp(50.0, 60.0, 120.0));
+    /// assert_eq!(color.into_css_percentages(), (50.0, 40.0, 120.0.into()));
+    /// ```
+    pub fn from_css_percentages<H: Into<LabHue<T>>>(l: T, chroma: T, hue: H) -> Self {
+        Self::with_wp(l, chroma * from_f64(1.5), hue)
+    }
+
+    /// Convert into the percentages of the CSS `lch()` function, where
+    /// `100%` is `100.0` for `l` and `150.0` for `chroma`. This is the
+    /// inverse of [`from_css_percentages`](Self::from_css_percentages).
+    pub fn into_css_percentages(self) -> (T, T, LabHue<T>) {
+        (self.l, self.chroma / from_f64(1.5), self.hue)
+    }
+
     /// Return the `l` value minimum.
     pub fn min_l() -> T {
         T::zero()
@@ -256,6 +288,30 @@ impl<Wp: WhitePoint, T: FloatComponent, A: Component> Into<(T, T, LabHue<T>, A)>
     }
 }
 
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 3]> for Lch<Wp, T> {
+    fn from([l, chroma, hue]: [T; 3]) -> Self {
+        Self::from_components((l, chroma, hue))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Lch<Wp, T>> for [T; 3] {
+    fn from(color: Lch<Wp, T>) -> Self {
+        [color.l, color.chroma, color.hue.to_degrees()]
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 4]> for Alpha<Lch<Wp, T>, T> {
+    fn from([l, chroma, hue, alpha]: [T; 4]) -> Self {
+        Self::from_components((l, chroma, hue, alpha))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Alpha<Lch<Wp, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Lch<Wp, T>, T>) -> Self {
+        [color.l, color.chroma, color.hue.to_degrees(), color.alpha]
+    }
+}
+
 impl<Wp, T> Limited for Lch<Wp, T>
 where
     T: FloatComponent,
@@ -275,6 +331,30 @@ where
         self.l = clamp(self.l, T::zero(), from_f64(100.0));
         self.chroma = self.chroma.max(T::zero())
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check("l", self.l >= T::zero() && self.l <= from_f64(100.0))
+            .check("chroma", self.chroma >= T::zero())
+    }
+}
+
+/// Blending is performed in linear RGB, with the sRGB primaries and the same
+/// white point as the color.
+impl<Wp, T> Blend for Lch<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Color = Rgb<Linear<(Srgb, Wp)>, T>;
+
+    fn into_premultiplied(self) -> PreAlpha<Rgb<Linear<(Srgb, Wp)>, T>, T> {
+        Rgb::<Linear<(Srgb, Wp)>, T>::from_color_unclamped(self).into_premultiplied()
+    }
+
+    fn from_premultiplied(color: PreAlpha<Rgb<Linear<(Srgb, Wp)>, T>, T>) -> Self {
+        Self::from_color_unclamped(Rgb::<Linear<(Srgb, Wp)>, T>::from_premultiplied(color))
+    }
 }
 
 impl<Wp, T> Mix for Lch<Wp, T>
@@ -285,8 +365,18 @@ where
     type Scalar = T;
 
     fn mix(&self, other: &Lch<Wp, T>, factor: T) -> Lch<Wp, T> {
+        self.mix_hue(other, factor, HueInterpolation::Shorter)
+    }
+}
+
+impl<Wp, T> MixHue for Lch<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+{
+    fn mix_hue(&self, other: &Lch<Wp, T>, factor: T, method: HueInterpolation) -> Lch<Wp, T> {
         let factor = clamp(factor, T::zero(), T::one());
-        let hue_diff: T = (other.hue - self.hue).to_degrees();
+        let hue_diff: T = method.adjust_difference((other.hue - self.hue).to_degrees());
         Lch {
             l: self.l + factor * (other.l - self.l),
             chroma: self.chroma + factor * (other.chroma - self.chroma),
@@ -311,6 +401,23 @@ where
             white_point: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Lch<Wp, T> {
+        let difference = if factor >= T::zero() {
+            Self::max_l() - self.l
+        } else {
+            self.l
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Lch {
+            l: (self.l + delta).max(T::zero()),
+            chroma: self.chroma,
+            hue: self.hue,
+            white_point: PhantomData,
+        }
+    }
 }
 
 impl<Wp, T> GetHue for Lch<Wp, T>
@@ -415,6 +522,15 @@ where
             white_point: PhantomData,
         }
     }
+
+    fn saturate_fixed(&self, amount: T) -> Lch<Wp, T> {
+        Lch {
+            l: self.l,
+            chroma: self.chroma + amount * Self::max_chroma(),
+            hue: self.hue,
+            white_point: PhantomData,
+        }
+    }
 }
 
 impl<Wp, T> Default for Lch<Wp, T>
@@ -565,19 +681,13 @@ where
     }
 }
 
-impl<Wp, T> RelativeContrast for Lch<Wp, T>
+impl<Wp, T> RelativeLuminance for Lch<Wp, T>
 where
     Wp: WhitePoint,
     T: FloatComponent,
 {
+    type WhitePoint = Wp;
     type Scalar = T;
-
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
-
-        contrast_ratio(xyz1.y, xyz2.y)
-    }
 }
 
 #[cfg(feature = "random")]
@@ -731,4 +841,16 @@ mod test {
         min: Lch::new(0.0f32, 0.0, 0.0),
         max: Lch::new(100.0, 128.0, 360.0)
     }
+
+    #[test]
+    fn saturate_and_desaturate() {
+        use crate::Saturate;
+
+        let color = Lch::<D65, f64>::new(50.0, 64.0, 120.0);
+
+        assert_relative_eq!(color.saturate(0.5), Lch::new(50.0, 96.0, 120.0));
+        assert_relative_eq!(color.desaturate(0.5), Lch::new(50.0, 32.0, 120.0));
+        assert_relative_eq!(color.saturate_fixed(0.25), Lch::new(50.0, 96.0, 120.0));
+        assert_relative_eq!(color.desaturate_fixed(0.25), Lch::new(50.0, 32.0, 120.0));
+    }
 }
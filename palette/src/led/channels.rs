@@ -0,0 +1,37 @@
+//! Channel orderings for LED drivers.
+use super::LedChannels;
+use crate::rgb;
+use crate::Component;
+
+macro_rules! impl_led_channels {
+    ($($(#[$attr:meta])* $name:ident: [$first:ident, $second:ident, $third:ident];)+) => {
+        $(
+            $(#[$attr])*
+            ///
+            /// See [LedChannels](crate::led::LedChannels) for more details.
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct $name;
+
+            impl LedChannels for $name {
+                fn split_rgb<S: rgb::RgbStandard, T: Component>(color: rgb::Rgb<S, T>) -> [T; 3] {
+                    [color.$first, color.$second, color.$third]
+                }
+            }
+        )+
+    };
+}
+
+impl_led_channels! {
+    /// Channels sent in RGB order.
+    Rgb: [red, green, blue];
+    /// Channels sent in RBG order.
+    Rbg: [red, blue, green];
+    /// Channels sent in GRB order, as used by WS2812 and SK6812 LEDs.
+    Grb: [green, red, blue];
+    /// Channels sent in GBR order.
+    Gbr: [green, blue, red];
+    /// Channels sent in BRG order.
+    Brg: [blue, red, green];
+    /// Channels sent in BGR order, as used by APA102 LEDs.
+    Bgr: [blue, green, red];
+}
@@ -0,0 +1,234 @@
+//! Color correction and byte output for addressable LEDs.
+//!
+//! LED strips, such as WS2812 and APA102, are driven with one PWM duty cycle
+//! per channel, which is linear in the emitted light. Colors that are picked
+//! or animated in sRGB have to be decoded to linear light before they are
+//! sent to the LEDs, or the dark colors will look too bright and washed out.
+//! LEDs are also often too bright to look at in full and have channels of
+//! different strength, so the colors are usually scaled down as well.
+//!
+//! [`LedCorrection`] performs these steps and converts the colors to 8 bit
+//! components, in the channel order the LEDs expect:
+//!
+//! ```
+//! use palette::led::{channels::Grb, LedCorrection};
+//! use palette::Srgb;
+//!
+//! let correction = LedCorrection::new()
+//!     .with_channel_scale(1.0, 0.7, 0.9)
+//!     .with_brightness_limit(0.5);
+//!
+//! let strip = [Srgb::new(1.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0)];
+//! let mut buffer = [0u8; 6];
+//! correction.write_bytes::<Grb>(&strip, &mut buffer);
+//!
+//! assert_eq!(buffer, [0, 128, 0, 89, 128, 115]);
+//! ```
+
+use crate::rgb::{LinSrgb, Rgb, RgbStandard, Srgb};
+use crate::{clamp, FloatComponent, FromComponent};
+
+pub mod channels;
+
+/// Splits RGB colors into arrays of components with some channel ordering.
+/// Channels may be ordered as `Rgb`, `Rbg`, `Grb`, `Gbr`, `Brg`, or `Bgr`.
+///
+/// ```
+/// use palette::led::{channels::Grb, LedChannels};
+/// use palette::Srgb;
+///
+/// let bytes = Grb::split_rgb(Srgb::new(255u8, 128, 0));
+/// assert_eq!(bytes, [128, 255, 0]);
+/// ```
+pub trait LedChannels {
+    /// Split the components of `color` into an array.
+    fn split_rgb<S: RgbStandard, T: crate::Component>(color: Rgb<S, T>) -> [T; 3];
+}
+
+/// Converts sRGB colors to 8 bit linear RGB for driving LEDs.
+///
+/// The colors are decoded with the sRGB transfer function by default, or with
+/// a power function if a gamma value is set. The decoded components are then
+/// multiplied by the channel scale and the brightness limit, and rounded to
+/// `u8`. Components outside the `[0.0, 1.0]` range are clamped before
+/// decoding.
+///
+/// ```
+/// use palette::led::LedCorrection;
+/// use palette::{LinSrgb, Srgb};
+///
+/// let correction = LedCorrection::new().with_gamma(2.8);
+/// let color = correction.apply(Srgb::new(1.0, 0.5, 0.0));
+///
+/// assert_eq!(color, LinSrgb::new(255, 37, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LedCorrection<T = f32> {
+    gamma: Option<T>,
+    channel_scale: [T; 3],
+    brightness_limit: T,
+}
+
+impl<T: FloatComponent> LedCorrection<T> {
+    /// Create a correction that only decodes the colors with the sRGB
+    /// transfer function.
+    pub fn new() -> Self {
+        LedCorrection {
+            gamma: None,
+            channel_scale: [T::one(); 3],
+            brightness_limit: T::one(),
+        }
+    }
+
+    /// Decode the colors with `x^gamma`, instead of the sRGB transfer
+    /// function. Values around `2.2` to `2.8` are commonly used for LEDs.
+    pub fn with_gamma(mut self, gamma: T) -> Self {
+        self.gamma = Some(gamma);
+        self
+    }
+
+    /// Scale each channel after decoding, for balancing the white point of
+    /// LEDs with channels of different strength. The scales are expected to
+    /// be in the `[0.0, 1.0]` range.
+    pub fn with_channel_scale(mut self, red: T, green: T, blue: T) -> Self {
+        self.channel_scale = [red, green, blue];
+        self
+    }
+
+    /// Limit the brightness of all channels to `limit`, in the `[0.0, 1.0]`
+    /// range, for reducing the power draw or glare of the LEDs.
+    pub fn with_brightness_limit(mut self, limit: T) -> Self {
+        self.brightness_limit = clamp(limit, T::zero(), T::one());
+        self
+    }
+
+    /// Decode, scale and round `color` to 8 bit linear RGB.
+    pub fn apply(&self, color: Srgb<T>) -> LinSrgb<u8>
+    where
+        u8: FromComponent<T>,
+    {
+        let color = Srgb::new(
+            clamp(color.red, T::zero(), T::one()),
+            clamp(color.green, T::zero(), T::one()),
+            clamp(color.blue, T::zero(), T::one()),
+        );
+
+        let linear = match self.gamma {
+            Some(gamma) => LinSrgb::new(
+                color.red.powf(gamma),
+                color.green.powf(gamma),
+                color.blue.powf(gamma),
+            ),
+            None => color.into_linear(),
+        };
+
+        let [red_scale, green_scale, blue_scale] = self.channel_scale;
+        LinSrgb::new(
+            linear.red * red_scale * self.brightness_limit,
+            linear.green * green_scale * self.brightness_limit,
+            linear.blue * blue_scale * self.brightness_limit,
+        )
+        .into_format()
+    }
+
+    /// Decode, scale and round `color`, and split it into bytes in the
+    /// channel order `C`.
+    ///
+    /// ```
+    /// use palette::led::{channels::Grb, LedCorrection};
+    /// use palette::Srgb;
+    ///
+    /// let bytes = LedCorrection::new().apply_bytes::<Grb>(Srgb::new(1.0, 0.0, 0.5));
+    /// assert_eq!(bytes, [0, 255, 55]);
+    /// ```
+    pub fn apply_bytes<C: LedChannels>(&self, color: Srgb<T>) -> [u8; 3]
+    where
+        u8: FromComponent<T>,
+    {
+        C::split_rgb(self.apply(color))
+    }
+
+    /// Decode, scale and round `colors` into `buffer`, three bytes per color
+    /// in the channel order `C`. Stops when either `colors` or `buffer` runs
+    /// out, and returns the number of colors that were written.
+    pub fn write_bytes<C: LedChannels>(&self, colors: &[Srgb<T>], buffer: &mut [u8]) -> usize
+    where
+        u8: FromComponent<T>,
+    {
+        let mut written = 0;
+
+        for (&color, bytes) in colors.iter().zip(buffer.chunks_exact_mut(3)) {
+            bytes.copy_from_slice(&self.apply_bytes::<C>(color));
+            written += 1;
+        }
+
+        written
+    }
+}
+
+impl<T: FloatComponent> Default for LedCorrection<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::channels::{Bgr, Grb, Rgb};
+    use super::{LedChannels, LedCorrection};
+    use crate::{LinSrgb, Srgb};
+
+    #[test]
+    fn srgb_decoding() {
+        let correction = LedCorrection::<f32>::new();
+
+        for &value in &[0u8, 1, 10, 64, 128, 200, 255] {
+            let color = Srgb::new(value, value, value).into_format();
+            let expected = Srgb::new(value, value, value)
+                .into_linear::<f32>()
+                .into_format();
+            assert_eq!(correction.apply(color), expected);
+        }
+    }
+
+    #[test]
+    fn clamps_input() {
+        let correction = LedCorrection::<f64>::new().with_gamma(2.0);
+        let color = correction.apply(Srgb::new(2.0, -1.0, 0.5));
+
+        assert_eq!(color, LinSrgb::new(255, 0, 64));
+    }
+
+    #[test]
+    fn scale_and_brightness() {
+        let correction = LedCorrection::<f32>::new()
+            .with_channel_scale(1.0, 0.5, 0.0)
+            .with_brightness_limit(0.5);
+        let color = correction.apply(Srgb::new(1.0, 1.0, 1.0));
+
+        assert_eq!(color, LinSrgb::new(128, 64, 0));
+    }
+
+    #[test]
+    fn channel_orders() {
+        let color = Srgb::new(1u8, 2, 3);
+
+        assert_eq!(Rgb::split_rgb(color), [1, 2, 3]);
+        assert_eq!(Grb::split_rgb(color), [2, 1, 3]);
+        assert_eq!(Bgr::split_rgb(color), [3, 2, 1]);
+    }
+
+    #[test]
+    fn write_bytes_stops_at_shortest() {
+        let correction = LedCorrection::<f32>::new();
+        let colors = [Srgb::new(1.0, 0.0, 0.0); 3];
+
+        let mut short_buffer = [0u8; 7];
+        assert_eq!(correction.write_bytes::<Rgb>(&colors, &mut short_buffer), 2);
+        assert_eq!(short_buffer, [255, 0, 0, 255, 0, 0, 0]);
+
+        let mut long_buffer = [0u8; 12];
+        assert_eq!(correction.write_bytes::<Rgb>(&colors, &mut long_buffer), 3);
+        assert_eq!(long_buffer[9..], [0, 0, 0]);
+    }
+}
@@ -213,6 +213,9 @@
 #[cfg(any(feature = "std", test))]
 extern crate core;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg_attr(test, macro_use)]
 extern crate approx;
 
@@ -228,14 +231,17 @@ extern crate serde;
 #[cfg(all(test, feature = "serializing"))]
 extern crate serde_json;
 
+use core::fmt;
+
 use float::Float;
 
 use luma::Luma;
 
 pub use alpha::{Alpha, WithAlpha};
 pub use blend::Blend;
-#[cfg(feature = "std")]
+pub use chromaticity::Chromaticity;
 pub use gradient::Gradient;
+pub use harmony::Harmony;
 
 pub use hsl::{Hsl, Hsla};
 pub use hsv::{Hsv, Hsva};
@@ -245,15 +251,46 @@ pub use lch::{Lch, Lcha};
 pub use luma::{GammaLuma, GammaLumaa, LinLuma, LinLumaa, SrgbLuma, SrgbLumaa};
 pub use rgb::{GammaSrgb, GammaSrgba, LinSrgb, LinSrgba, Packed, RgbChannels, Srgb, Srgba};
 pub use xyz::{Xyz, Xyza};
+
+/// Create an [`Srgb<u8>`](crate::Srgb) or [`Srgba<u8>`](crate::Srgba) from a
+/// hex color code, that is checked at compile time.
+///
+/// The code can have an optional `#` prefix, and 3, 4, 6 or 8 digits, like
+/// in CSS. Codes with 4 or 8 digits include an alpha component and become
+/// `Srgba<u8>`. The result is a constant expression.
+///
+/// ```
+/// use palette::{hex, Srgb, Srgba};
+///
+/// const DODGER_BLUE: Srgb<u8> = hex!("#1e90ff");
+/// assert_eq!(DODGER_BLUE, Srgb::new(30, 144, 255));
+///
+/// assert_eq!(hex!("f80"), Srgb::new(255, 136, 0));
+/// assert_eq!(hex!("#1e90ff80"), Srgba::new(30, 144, 255, 128));
+/// ```
+///
+/// Invalid codes are compile errors, instead of runtime errors:
+///
+/// ```compile_fail
+/// let color = palette::hex!("#1e90fg");
+/// ```
+pub use palette_derive::hex;
 pub use yxy::{Yxy, Yxya};
 
-pub use color_difference::ColorDifference;
+pub use color_difference::{find_nearest, ColorDifference, NearestColor};
 pub use component::*;
 pub use convert::{FromColor, IntoColor};
 pub use encoding::pixel::Pixel;
-pub use hues::{LabHue, RgbHue};
+pub use hues::{HueInterpolation, LabHue, RgbHue};
 pub use matrix::Mat3;
-pub use relative_contrast::{contrast_ratio, RelativeContrast};
+#[cfg(all(feature = "random", feature = "alloc"))]
+pub use random_sampling::random_distinct;
+#[cfg(feature = "random")]
+pub use random_sampling::InGamut;
+pub use relative_contrast::{
+    apca_contrast, contrast_ratio, ApcaContrast, RelativeContrast, RelativeLuminance,
+};
+pub use tone_map::{Exposure, ToneMap};
 
 //Helper macro for checking ranges and clamping.
 #[cfg(test)]
@@ -410,15 +447,42 @@ macro_rules! assert_ranges {
 mod macros;
 
 pub mod blend;
-#[cfg(feature = "std")]
+pub mod bytes;
+pub mod css;
+#[cfg(feature = "alloc")]
+pub mod dither;
+pub mod gamut;
+pub mod gpu;
 pub mod gradient;
+pub mod harmony;
+pub mod hdr;
 
+#[cfg(feature = "image")]
+pub mod image;
+pub mod led;
 #[cfg(feature = "named")]
 pub mod named;
+pub mod profile;
+#[cfg(feature = "alloc")]
+pub mod quantize;
+#[cfg(feature = "serializing")]
+pub mod serializing;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "alloc")]
+pub mod sort;
+#[cfg(feature = "spectral")]
+pub mod spectral;
+pub mod tone_map;
 
 #[cfg(feature = "random")]
 mod random_sampling;
 
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
 mod alpha;
 mod hsl;
 mod hsv;
@@ -433,6 +497,7 @@ mod yxy;
 mod hues;
 
 pub mod chromatic_adaptation;
+mod chromaticity;
 mod color_difference;
 mod component;
 pub mod convert;
@@ -443,7 +508,6 @@ pub mod white_point;
 
 pub mod float;
 
-#[doc(hidden)]
 pub mod matrix;
 
 fn clamp<T: PartialOrd>(v: T, min: T, max: T) -> T {
@@ -457,6 +521,21 @@ fn clamp<T: PartialOrd>(v: T, min: T, max: T) -> T {
 }
 
 /// A trait for clamping and checking if colors are within their ranges.
+///
+/// The out of range components can be listed by name, which is useful for
+/// telling users which part of a color is invalid:
+///
+/// ```
+/// use palette::{Limited, Srgb};
+///
+/// let color = Srgb::new(1.2, 0.5, -0.1);
+/// let (clamped, invalid) = color.clamp_and_report();
+///
+/// assert_eq!(clamped, Srgb::new(1.0, 0.5, 0.0));
+/// assert_eq!(invalid.iter().collect::<Vec<_>>(), ["red", "blue"]);
+/// assert!(invalid.contains("red"));
+/// assert!(!invalid.contains("green"));
+/// ```
 pub trait Limited {
     /// Check if the color's components are within the expected ranges.
     fn is_valid(&self) -> bool;
@@ -467,6 +546,102 @@ pub trait Limited {
 
     /// Clamp the color's components to the nearest valid values.
     fn clamp_self(&mut self);
+
+    /// List the components that are outside their expected ranges, by name.
+    ///
+    /// The result is empty if and only if the color is valid.
+    ///
+    /// The default implementation can't tell the components apart, so it
+    /// reports the whole color as a single component called `"color"` when
+    /// it's invalid. All colors in Palette override it with the actual
+    /// component names.
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new().check("color", self.is_valid())
+    }
+
+    /// Return a new color where the components has been clamped to the nearest
+    /// valid values, together with the components that were clamped.
+    fn clamp_and_report(&self) -> (Self, InvalidComponents)
+    where
+        Self: Sized,
+    {
+        (self.clamp(), self.invalid_components())
+    }
+
+    /// Clamp the color's components to the nearest valid values, and return
+    /// the components that were clamped.
+    fn clamp_self_and_report(&mut self) -> InvalidComponents {
+        let invalid = self.invalid_components();
+        self.clamp_self();
+        invalid
+    }
+}
+
+/// The names of the components of a color that are outside their expected
+/// ranges. See [`Limited::invalid_components`].
+///
+/// The names are the same as the field names, such as `"red"` or
+/// `"saturation"`, and are listed in the same order as the fields, with
+/// `"alpha"` last.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidComponents {
+    names: [&'static str; 4],
+    len: usize,
+}
+
+impl InvalidComponents {
+    /// Create an empty list, where every component is valid.
+    pub fn new() -> Self {
+        InvalidComponents::default()
+    }
+
+    /// Add `name` to the list, unless the component `is_valid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list already has four components.
+    pub fn check(mut self, name: &'static str, is_valid: bool) -> Self {
+        if !is_valid {
+            assert!(self.len < self.names.len(), "too many invalid components");
+            self.names[self.len] = name;
+            self.len += 1;
+        }
+
+        self
+    }
+
+    /// Check if every component is valid.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of invalid components.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the component called `name` is invalid.
+    pub fn contains(&self, name: &str) -> bool {
+        self.iter().any(|invalid| invalid == name)
+    }
+
+    /// Iterate over the names of the invalid components.
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.names[..self.len].iter().cloned()
+    }
+}
+
+impl fmt::Display for InvalidComponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, name) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// A trait for linear color interpolation.
@@ -495,6 +670,73 @@ pub trait Mix {
     fn mix(&self, other: &Self, factor: Self::Scalar) -> Self;
 }
 
+/// A trait for interpolating colors with a hue component, using a chosen
+/// [`HueInterpolation`] method.
+///
+/// [`Mix`] always takes the shortest path between two hues, while this makes
+/// it possible to take the longer path, or to only go in one direction around
+/// the color wheel.
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::{Hsv, HueInterpolation, MixHue};
+///
+/// let a = Hsv::new(30.0, 1.0, 1.0);
+/// let b = Hsv::new(330.0, 1.0, 1.0);
+///
+/// let shorter = a.mix_hue(&b, 0.5, HueInterpolation::Shorter);
+/// let longer = a.mix_hue(&b, 0.5, HueInterpolation::Longer);
+///
+/// assert_relative_eq!(shorter.hue.to_positive_degrees(), 0.0);
+/// assert_relative_eq!(longer.hue.to_positive_degrees(), 180.0);
+/// ```
+pub trait MixHue: Mix {
+    /// Mix the color with an other color, by `factor`, with the hue following
+    /// the path decided by `method`.
+    ///
+    /// `factor` should be between `0.0` and `1.0`, where `0.0` will result in
+    /// the same color as `self` and `1.0` will result in the same color as
+    /// `other`.
+    fn mix_hue(&self, other: &Self, factor: Self::Scalar, method: HueInterpolation) -> Self;
+}
+
+/// A trait for interpolating the encoded, non-linear, components of a color.
+///
+/// [`Mix`] is only implemented for linear RGB and luma, since interpolating
+/// encoded values doesn't blend the light physically. The result is often
+/// darker and less saturated in the middle. It is, however, what CSS, the
+/// canvas API and many image editors do, so `mix_encoded` can be used when
+/// the result has to match them. It's sometimes called "gamma-space lerp".
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::{Mix, NonlinearMix, Srgb};
+///
+/// let a = Srgb::new(1.0, 0.0, 0.0);
+/// let b = Srgb::new(0.0, 0.0, 1.0);
+///
+/// // The same as the CSS `linear-gradient(red, blue)` at 50%.
+/// assert_relative_eq!(a.mix_encoded(&b, 0.5), Srgb::new(0.5, 0.0, 0.5));
+///
+/// // Mixing the light is brighter in the middle.
+/// let linear = Srgb::from_linear(a.into_linear().mix(&b.into_linear(), 0.5));
+/// assert!(linear.red > 0.7);
+/// ```
+pub trait NonlinearMix {
+    /// The type of the mixing factor.
+    type Scalar: Float;
+
+    /// Mix the encoded components of the color with an other color, by
+    /// `factor`.
+    ///
+    /// `factor` should be between `0.0` and `1.0`, where `0.0` will result in
+    /// the same color as `self` and `1.0` will result in the same color as
+    /// `other`.
+    fn mix_encoded(&self, other: &Self, factor: Self::Scalar) -> Self;
+}
+
 /// The `Shade` trait allows a color to be lightened or darkened.
 ///
 /// ```
@@ -507,6 +749,44 @@ pub trait Mix {
 ///
 /// assert_relative_eq!(a.lighten(0.1), b.darken(0.1));
 /// ```
+///
+/// The methods come in two kinds, and the `_fixed` suffix means an absolute
+/// amount, the same as for [`Saturate`]:
+///
+/// * [`lighten_fixed`](Shade::lighten_fixed) and
+///   [`darken_fixed`](Shade::darken_fixed) add or subtract a fixed amount,
+///   regardless of the current lightness. The result may need to be clamped.
+///   [`lighten`](Shade::lighten) and [`darken`](Shade::darken) keep their
+///   original behavior, which is the same.
+/// * [`lighten_relative`](Shade::lighten_relative) and
+///   [`darken_relative`](Shade::darken_relative) move the lightness a fraction
+///   of the way towards the maximum or the minimum lightness, so
+///   `lighten_relative(0.5)` makes a color with 50% lightness 75% light, and a
+///   color with 80% lightness 90% light. This changes dark and light colors by
+///   a similar perceived step, and keeps the result in range.
+///
+/// The lightness is the lightness, value, luminance or intensity component of
+/// the color, or each component for RGB. The amounts and factors are always
+/// between `0.0` and `1.0`, even for colors where the lightness goes up to
+/// `100.0`.
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::{Hsl, Shade};
+///
+/// let color = Hsl::new(0.0, 1.0, 0.8);
+///
+/// assert_relative_eq!(color.lighten_fixed(0.1), Hsl::new(0.0, 1.0, 0.9));
+/// assert_relative_eq!(color.darken_fixed(0.5), Hsl::new(0.0, 1.0, 0.3));
+/// assert_relative_eq!(color.lighten_relative(0.5), Hsl::new(0.0, 1.0, 0.9));
+/// assert_relative_eq!(color.darken_relative(0.5), Hsl::new(0.0, 1.0, 0.4));
+/// ```
+///
+/// Each method also has a `_clamped` variant, such as
+/// [`lighten_clamped`](Shade::lighten_clamped), for colors that implement
+/// [`Limited`]. They clamp the result to the valid range of the color,
+/// instead of requiring a separate call to `clamp`.
 pub trait Shade: Sized {
     /// The type of the lighten/darken amount.
     type Scalar: Float;
@@ -518,6 +798,79 @@ pub trait Shade: Sized {
     fn darken(&self, amount: Self::Scalar) -> Self {
         self.lighten(-amount)
     }
+
+    /// Lighten the color by `amount`, regardless of its current lightness.
+    /// This is the same as [`lighten`](Shade::lighten), but with a name that
+    /// tells it apart from [`lighten_relative`](Shade::lighten_relative).
+    fn lighten_fixed(&self, amount: Self::Scalar) -> Self {
+        self.lighten(amount)
+    }
+
+    /// Darken the color by `amount`, regardless of its current lightness.
+    /// This is the same as [`darken`](Shade::darken), but with a name that
+    /// tells it apart from [`darken_relative`](Shade::darken_relative).
+    fn darken_fixed(&self, amount: Self::Scalar) -> Self {
+        self.darken(amount)
+    }
+
+    /// Scale the lightness of the color towards the maximum lightness by
+    /// `factor`, a value ranging from `0.0` to `1.0`.
+    ///
+    /// The default implementation can't see the current lightness, so it
+    /// falls back to [`lighten_fixed`](Shade::lighten_fixed). All colors in
+    /// Palette override it, and other implementations should do the same.
+    fn lighten_relative(&self, factor: Self::Scalar) -> Self {
+        self.lighten_fixed(factor)
+    }
+
+    /// Scale the lightness of the color towards the minimum lightness by
+    /// `factor`, a value ranging from `0.0` to `1.0`.
+    fn darken_relative(&self, factor: Self::Scalar) -> Self {
+        self.lighten_relative(-factor)
+    }
+
+    /// Same as [`lighten`](Shade::lighten), but the result is clamped to the
+    /// valid range of the color.
+    ///
+    /// ```
+    /// use palette::{Hsl, Shade};
+    ///
+    /// let color = Hsl::new(0.0, 1.0, 0.8);
+    /// assert_eq!(color.lighten_clamped(0.5), Hsl::new(0.0, 1.0, 1.0));
+    /// ```
+    fn lighten_clamped(&self, amount: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.lighten(amount).clamp()
+    }
+
+    /// Same as [`darken`](Shade::darken), but the result is clamped to the
+    /// valid range of the color.
+    fn darken_clamped(&self, amount: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.darken(amount).clamp()
+    }
+
+    /// Same as [`lighten_relative`](Shade::lighten_relative), but the result
+    /// is clamped to the valid range of the color.
+    fn lighten_relative_clamped(&self, factor: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.lighten_relative(factor).clamp()
+    }
+
+    /// Same as [`darken_relative`](Shade::darken_relative), but the result is
+    /// clamped to the valid range of the color.
+    fn darken_relative_clamped(&self, factor: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.darken_relative(factor).clamp()
+    }
 }
 
 /// A trait for colors where a hue may be calculated.
@@ -551,6 +904,31 @@ pub trait GetHue {
     /// Colors in the gray scale has no well defined hue and should preferably
     /// return `None`.
     fn get_hue(&self) -> Option<Self::Hue>;
+
+    /// Calculate a hue, or return `default` if the color doesn't have one.
+    ///
+    /// ```
+    /// use palette::{GetHue, LinSrgb, RgbHue};
+    ///
+    /// let gray = LinSrgb::new(0.5f32, 0.5, 0.5);
+    /// assert_eq!(gray.get_hue_or(120.0), RgbHue::from_degrees(120.0));
+    /// ```
+    fn get_hue_or<H: Into<Self::Hue>>(&self, default: H) -> Self::Hue {
+        self.get_hue().unwrap_or_else(|| default.into())
+    }
+
+    /// Check if the color has a well defined hue, which is when `get_hue`
+    /// returns `Some`.
+    ///
+    /// ```
+    /// use palette::{GetHue, Hsv};
+    ///
+    /// assert!(Hsv::new(60.0, 0.5, 0.5).has_hue());
+    /// assert!(!Hsv::new(60.0, 0.0, 0.5).has_hue());
+    /// ```
+    fn has_hue(&self) -> bool {
+        self.get_hue().is_some()
+    }
 }
 
 /// A trait for colors where the hue can be manipulated without conversion.
@@ -565,6 +943,27 @@ pub trait Hue: GetHue {
 /// A trait for colors where the saturation (or chroma) can be manipulated
 /// without conversion.
 ///
+/// The methods come in two variants:
+///
+/// * [`saturate`](Saturate::saturate) and
+///   [`desaturate`](Saturate::desaturate) are relative. They scale the
+///   saturation by `1.0 + factor` or `1.0 - factor`, so `desaturate(0.5)`
+///   halves the saturation.
+/// * [`saturate_fixed`](Saturate::saturate_fixed) and
+///   [`desaturate_fixed`](Saturate::desaturate_fixed) are absolute. They add
+///   or subtract a fraction of the maximum saturation, regardless of the
+///   current saturation, which is useful for moving it in constant steps. The
+///   result may need to be clamped.
+///
+/// Each method also has a `_clamped` variant, such as
+/// [`saturate_clamped`](Saturate::saturate_clamped), for colors that
+/// implement [`Limited`], which clamps the result to the valid range of the
+/// color.
+///
+/// The fractions are always between `0.0` and `1.0`, even for colors where the
+/// saturation goes above `1.0`, such as the chroma of [`Lch`], which has a
+/// maximum of `128.0`.
+///
 /// ```
 /// use approx::assert_relative_eq;
 ///
@@ -574,18 +973,78 @@ pub trait Hue: GetHue {
 /// let b = Hsv::new(0.0, 1.0, 1.0);
 ///
 /// assert_relative_eq!(a.saturate(1.0), b.desaturate(0.5));
+/// assert_relative_eq!(a.saturate_fixed(0.25), b.desaturate_fixed(0.5));
 /// ```
 pub trait Saturate: Sized {
-    /// The type of the (de)saturation factor.
+    /// The type of the (de)saturation modifier.
     type Scalar: Float;
 
     /// Increase the saturation by `factor`.
     fn saturate(&self, factor: Self::Scalar) -> Self;
 
+    /// Increase the saturation by `amount`, a value ranging from `0.0` to
+    /// `1.0` that is a fraction of the maximum saturation.
+    ///
+    /// The default implementation can't see the current saturation, so it
+    /// falls back to [`saturate`](Saturate::saturate). All colors in Palette
+    /// override it, and other implementations should do the same.
+    fn saturate_fixed(&self, amount: Self::Scalar) -> Self {
+        self.saturate(amount)
+    }
+
     /// Decrease the saturation by `factor`.
     fn desaturate(&self, factor: Self::Scalar) -> Self {
         self.saturate(-factor)
     }
+
+    /// Decrease the saturation by `amount`, a value ranging from `0.0` to
+    /// `1.0` that is a fraction of the maximum saturation.
+    fn desaturate_fixed(&self, amount: Self::Scalar) -> Self {
+        self.saturate_fixed(-amount)
+    }
+
+    /// Same as [`saturate`](Saturate::saturate), but the result is clamped to
+    /// the valid range of the color.
+    ///
+    /// ```
+    /// use palette::{Hsv, Saturate};
+    ///
+    /// let color = Hsv::new(0.0, 0.8, 1.0);
+    /// assert_eq!(color.saturate_clamped(0.5), Hsv::new(0.0, 1.0, 1.0));
+    /// ```
+    fn saturate_clamped(&self, factor: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.saturate(factor).clamp()
+    }
+
+    /// Same as [`saturate_fixed`](Saturate::saturate_fixed), but the result is
+    /// clamped to the valid range of the color.
+    fn saturate_fixed_clamped(&self, amount: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.saturate_fixed(amount).clamp()
+    }
+
+    /// Same as [`desaturate`](Saturate::desaturate), but the result is clamped
+    /// to the valid range of the color.
+    fn desaturate_clamped(&self, factor: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.desaturate(factor).clamp()
+    }
+
+    /// Same as [`desaturate_fixed`](Saturate::desaturate_fixed), but the
+    /// result is clamped to the valid range of the color.
+    fn desaturate_fixed_clamped(&self, amount: Self::Scalar) -> Self
+    where
+        Self: Limited,
+    {
+        self.desaturate_fixed(amount).clamp()
+    }
 }
 
 /// Perform a unary or binary operation on each component of a color.
@@ -602,6 +1061,168 @@ pub trait ComponentWise {
 
     /// Perform a unary operation on this color.
     fn component_wise_self<F: FnMut(Self::Scalar) -> Self::Scalar>(&self, f: F) -> Self;
+
+    /// Get the smallest component of this color.
+    ///
+    /// ```
+    /// use palette::{ComponentWise, LinSrgb};
+    ///
+    /// assert_eq!(LinSrgb::new(0.5, 0.25, 1.0).min_component(), 0.25);
+    /// ```
+    fn min_component(&self) -> Self::Scalar
+    where
+        Self: Sized,
+        Self::Scalar: PartialOrd + Copy,
+    {
+        let mut min = None;
+        self.component_wise_self(|component| {
+            min = match min {
+                Some(current) if component < current => Some(component),
+                None => Some(component),
+                current => current,
+            };
+            component
+        });
+
+        min.expect("the color has no components")
+    }
+
+    /// Get the largest component of this color.
+    ///
+    /// This can be used for normalizing the exposure of a linear color, by
+    /// dividing it by its largest component:
+    ///
+    /// ```
+    /// use palette::{ComponentWise, LinSrgb};
+    ///
+    /// let color = LinSrgb::new(2.0, 4.0, 1.0);
+    /// assert_eq!(color.max_component(), 4.0);
+    /// assert_eq!(color / color.max_component(), LinSrgb::new(0.5, 1.0, 0.25));
+    /// ```
+    fn max_component(&self) -> Self::Scalar
+    where
+        Self: Sized,
+        Self::Scalar: PartialOrd + Copy,
+    {
+        let mut max = None;
+        self.component_wise_self(|component| {
+            max = match max {
+                Some(current) if component > current => Some(component),
+                None => Some(component),
+                current => current,
+            };
+            component
+        });
+
+        max.expect("the color has no components")
+    }
+
+    /// Clamp each component of this color to the range from `min` to `max`.
+    ///
+    /// ```
+    /// use palette::{ComponentWise, LinSrgb};
+    ///
+    /// let color = LinSrgb::new(-0.5, 0.5, 1.5);
+    /// assert_eq!(color.clamp_components(0.0, 1.0), LinSrgb::new(0.0, 0.5, 1.0));
+    /// ```
+    fn clamp_components(&self, min: Self::Scalar, max: Self::Scalar) -> Self
+    where
+        Self: Sized,
+        Self::Scalar: PartialOrd + Copy,
+    {
+        self.component_wise_self(|component| clamp(component, min, max))
+    }
+}
+
+/// Perform a unary or binary operation on each component of a color, where
+/// the result has the component type `U`.
+///
+/// This is the same as [`ComponentWise`], except that the operations can
+/// change the component type. It makes it possible to write code that
+/// converts or transforms the components of any color, without knowing its
+/// color space:
+///
+/// ```
+/// use palette::{ComponentWiseMap, IntoComponent, Srgb, Srgba};
+///
+/// fn into_float<C: ComponentWiseMap<f32, Scalar = u8>>(color: &C) -> C::Output {
+///     color.component_wise_map(|component| component.into_component())
+/// }
+///
+/// assert_eq!(into_float(&Srgb::new(255u8, 0, 0)), Srgb::new(1.0, 0.0, 0.0));
+///
+/// // The average of two colors, with more precision than the inputs
+/// let color = Srgba::new(255u8, 255, 0, 255);
+/// let other = Srgba::new(0u8, 255, 0, 0);
+/// let average = color.component_wise_zip(&other, |a, b| (f32::from(a) + f32::from(b)) / 510.0);
+/// assert_eq!(average, Srgba::new(0.5, 1.0, 0.0, 0.5));
+/// ```
+pub trait ComponentWiseMap<U>: ComponentWise {
+    /// The same color type as `Self`, but with `U` as its component type.
+    type Output;
+
+    /// Perform a binary operation on this and an other color.
+    fn component_wise_zip<F: FnMut(Self::Scalar, Self::Scalar) -> U>(
+        &self,
+        other: &Self,
+        f: F,
+    ) -> Self::Output;
+
+    /// Perform a unary operation on this color.
+    fn component_wise_map<F: FnMut(Self::Scalar) -> U>(&self, f: F) -> Self::Output;
+}
+
+/// Addition and subtraction that saturate at the limits of integer
+/// components, instead of overflowing.
+///
+/// The `Add` and `Sub` implementations for integer components panic on
+/// overflow in debug builds and wrap around in release builds. These
+/// operations clamp each component to the range of its type instead, the same
+/// way as `u8::saturating_add` and `u8::saturating_sub`. `Rhs` is either
+/// another color or a single component value that is applied to all
+/// components.
+///
+/// ```
+/// use palette::{LinSrgb, SaturatingArithmetic};
+///
+/// let color = LinSrgb::new(200u8, 100, 20);
+///
+/// assert_eq!(color.saturating_add(LinSrgb::new(100, 100, 100)), LinSrgb::new(255, 200, 120));
+/// assert_eq!(color.saturating_sub(50), LinSrgb::new(150, 50, 0));
+/// ```
+pub trait SaturatingArithmetic<Rhs = Self> {
+    /// Add `other` to this color, saturating at the maximum value of each
+    /// component.
+    fn saturating_add(self, other: Rhs) -> Self;
+
+    /// Subtract `other` from this color, saturating at the minimum value of
+    /// each component.
+    fn saturating_sub(self, other: Rhs) -> Self;
+}
+
+/// Addition and subtraction that fail if any integer component overflows.
+///
+/// This is similar to [`SaturatingArithmetic`], but returns `None` instead of
+/// clamping the result, the same way as `u8::checked_add` and
+/// `u8::checked_sub`.
+///
+/// ```
+/// use palette::{CheckedArithmetic, LinSrgb};
+///
+/// let color = LinSrgb::new(200u8, 100, 20);
+///
+/// assert_eq!(color.checked_add(50), Some(LinSrgb::new(250, 150, 70)));
+/// assert_eq!(color.checked_add(LinSrgb::new(100, 0, 0)), None);
+/// assert_eq!(color.checked_sub(21), None);
+/// ```
+pub trait CheckedArithmetic<Rhs = Self>: Sized {
+    /// Add `other` to this color, or return `None` if any component would
+    /// overflow.
+    fn checked_add(self, other: Rhs) -> Option<Self>;
+
+    /// Subtract `other` from this color, or return `None` if any component
+    /// would overflow.
+    fn checked_sub(self, other: Rhs) -> Option<Self>;
 }
 
 /// A trait for infallible conversion from `f64`. The conversion may be lossy.
@@ -624,6 +1245,14 @@ impl FromF64 for f64 {
     }
 }
 
+#[cfg(feature = "half")]
+impl FromF64 for half::f16 {
+    #[inline]
+    fn from_f64(c: f64) -> Self {
+        half::f16::from_f64(c)
+    }
+}
+
 /// A convenience function to convert a constant number to Float Type
 #[inline]
 fn from_f64<T: FromF64>(c: f64) -> T {
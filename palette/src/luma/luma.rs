@@ -1,9 +1,11 @@
 use core::any::TypeId;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::{CheckedAdd, CheckedSub, Saturating};
 #[cfg(feature = "random")]
 use rand::distributions::uniform::{SampleBorrow, SampleUniform, Uniform, UniformSampler};
 #[cfg(feature = "random")]
@@ -13,13 +15,17 @@ use rand::Rng;
 
 use crate::blend::PreAlpha;
 use crate::convert::FromColorUnclamped;
+use crate::encoding::limited_range;
 use crate::encoding::linear::LinearFn;
 use crate::encoding::pixel::RawPixel;
-use crate::encoding::{Linear, Srgb, TransferFn};
+use crate::encoding::{srgb, Linear, Srgb, TransferFn};
 use crate::luma::LumaStandard;
+use crate::relative_contrast::apca_screen_luminance;
+use crate::white_point::D65;
 use crate::{
-    clamp, contrast_ratio, Alpha, Blend, Component, ComponentWise, FloatComponent, FromComponent,
-    Limited, Mix, Pixel, RelativeContrast, Shade, Xyz, Yxy,
+    apca_contrast, clamp, Alpha, ApcaContrast, Blend, CheckedArithmetic, Component, ComponentWise,
+    ComponentWiseMap, FloatComponent, FromComponent, InvalidComponents, Limited, Mix, NonlinearMix,
+    Pixel, RelativeLuminance, SaturatingArithmetic, Shade, Xyz, Yxy,
 };
 
 /// Luminance with an alpha component. See the [`Lumaa` implementation
@@ -79,7 +85,7 @@ where
     S: LumaStandard,
 {
     /// Create a luminance color.
-    pub fn new(luma: T) -> Luma<S, T> {
+    pub const fn new(luma: T) -> Luma<S, T> {
         Luma {
             luma,
             standard: PhantomData,
@@ -166,6 +172,102 @@ where
     }
 }
 
+/// Fast conversion between 8 bit sRGB and linear luminance.
+///
+/// These use lookup tables instead of evaluating the transfer function, which
+/// makes them much faster when converting large amounts of colors, such as
+/// the pixels of an image. The tables only cover 8 bit components, so other
+/// component types, such as `u16`, are still converted with `into_format`
+/// followed by the floating point `into_linear` and `from_linear`.
+///
+/// ```
+/// use palette::{LinLuma, SrgbLuma};
+///
+/// let linear: LinLuma = SrgbLuma::new(128u8).into_linear();
+/// assert_eq!(SrgbLuma::from_linear_u8(linear).luma, 128);
+/// ```
+impl<S> Luma<S, u8>
+where
+    S: LumaStandard<TransferFn = Srgb>,
+{
+    /// Convert the color to linear luminance.
+    #[inline]
+    pub fn into_linear<T: FloatComponent>(self) -> Luma<Linear<S::WhitePoint>, T> {
+        Luma::new(srgb::u8_into_linear(self.luma))
+    }
+
+    /// Convert linear luminance to 8 bit nonlinear luminance, rounded to the
+    /// nearest value. This is the same as `from_linear` followed by
+    /// `into_format`.
+    #[inline]
+    pub fn from_linear_u8<T: FloatComponent>(color: Luma<Linear<S::WhitePoint>, T>) -> Self {
+        Luma::new(srgb::u8_from_linear(color.luma))
+    }
+}
+
+/// Conversion between full and limited range.
+///
+/// ```
+/// use palette::SrgbLuma;
+///
+/// let limited = SrgbLuma::new(255u8).into_limited_range();
+/// assert_eq!(limited.luma, 235);
+/// assert_eq!(limited.into_full_range().luma, 255);
+/// ```
+impl<S: LumaStandard> Luma<S, u8> {
+    /// Convert the full range component to limited range, where black is `16`
+    /// and white is `235`.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    pub fn into_limited_range(self) -> Self {
+        Luma::new(limited_range::into_limited(self.luma.into(), 8) as u8)
+    }
+
+    /// Convert a limited range component, where black is `16` and white is
+    /// `235`, to full range.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    pub fn into_full_range(self) -> Self {
+        Luma::new(limited_range::from_limited(self.luma.into(), 8) as u8)
+    }
+}
+
+/// Conversion between full and limited range for higher bit depths.
+///
+/// ```
+/// use palette::SrgbLuma;
+///
+/// let limited = SrgbLuma::new(4095u16).into_limited_range(12);
+/// assert_eq!(limited.luma, 3760);
+/// assert_eq!(limited.into_full_range(12).luma, 4095);
+/// ```
+impl<S: LumaStandard> Luma<S, u16> {
+    /// Convert the full range component, with the given bit depth, to limited
+    /// range, where black is `16 * 2^(bit_depth - 8)` and white is
+    /// `235 * 2^(bit_depth - 8)`.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` is not between 8 and 16.
+    pub fn into_limited_range(self, bit_depth: u32) -> Self {
+        Luma::new(limited_range::into_limited(self.luma, bit_depth))
+    }
+
+    /// Convert a limited range component, with the given bit depth, to full
+    /// range.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` is not between 8 and 16.
+    pub fn into_full_range(self, bit_depth: u32) -> Self {
+        Luma::new(limited_range::from_limited(self.luma, bit_depth))
+    }
+}
+
 ///<span id="Lumaa"></span>[`Lumaa`](crate::luma::Lumaa) implementations.
 impl<S, T, A> Alpha<Luma<S, T>, A>
 where
@@ -174,7 +276,7 @@ where
     S: LumaStandard,
 {
     /// Create a luminance color with transparency.
-    pub fn new(luma: T, alpha: A) -> Self {
+    pub const fn new(luma: T, alpha: A) -> Self {
         Alpha {
             color: Luma::new(luma),
             alpha,
@@ -318,6 +420,30 @@ impl<S: LumaStandard, T: Component, A: Component> Into<(T, A)> for Alpha<Luma<S,
     }
 }
 
+impl<S: LumaStandard, T: Component> From<[T; 1]> for Luma<S, T> {
+    fn from([luma]: [T; 1]) -> Self {
+        Self::from_components((luma,))
+    }
+}
+
+impl<S: LumaStandard, T: Component> From<Luma<S, T>> for [T; 1] {
+    fn from(color: Luma<S, T>) -> Self {
+        [color.luma]
+    }
+}
+
+impl<S: LumaStandard, T: Component> From<[T; 2]> for Alpha<Luma<S, T>, T> {
+    fn from([luma, alpha]: [T; 2]) -> Self {
+        Self::from_components((luma, alpha))
+    }
+}
+
+impl<S: LumaStandard, T: Component> From<Alpha<Luma<S, T>, T>> for [T; 2] {
+    fn from(color: Alpha<Luma<S, T>, T>) -> Self {
+        [color.luma, color.alpha]
+    }
+}
+
 impl<S, T> Limited for Luma<S, T>
 where
     T: Component,
@@ -336,6 +462,13 @@ where
     fn clamp_self(&mut self) {
         self.luma = clamp(self.luma, T::zero(), T::max_intensity());
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new().check(
+            "luma",
+            self.luma >= T::zero() && self.luma <= T::max_intensity(),
+        )
+    }
 }
 
 impl<S, T> Mix for Luma<S, T>
@@ -355,6 +488,23 @@ where
     }
 }
 
+impl<S, T> NonlinearMix for Luma<S, T>
+where
+    T: FloatComponent,
+    S: LumaStandard,
+{
+    type Scalar = T;
+
+    fn mix_encoded(&self, other: &Luma<S, T>, factor: T) -> Luma<S, T> {
+        let factor = clamp(factor, T::zero(), T::one());
+
+        Luma {
+            luma: self.luma + factor * (other.luma - self.luma),
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Shade for Luma<S, T>
 where
     T: FloatComponent,
@@ -368,6 +518,21 @@ where
             standard: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Luma<S, T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.luma
+        } else {
+            self.luma
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Luma {
+            luma: (self.luma + delta).max(T::zero()),
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> Blend for Luma<S, T>
@@ -378,15 +543,14 @@ where
     type Color = Luma<S, T>;
 
     fn into_premultiplied(self) -> PreAlpha<Luma<S, T>, T> {
-        Lumaa {
+        PreAlpha {
             color: self,
             alpha: T::one(),
         }
-        .into_premultiplied()
     }
 
     fn from_premultiplied(color: PreAlpha<Luma<S, T>, T>) -> Self {
-        Lumaa::from_premultiplied(color).color
+        Lumaa::from(color).color
     }
 }
 
@@ -412,6 +576,29 @@ where
     }
 }
 
+impl<S, T, U> ComponentWiseMap<U> for Luma<S, T>
+where
+    T: Component,
+    U: Component,
+    S: LumaStandard,
+{
+    type Output = Luma<S, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(&self, other: &Luma<S, T>, mut f: F) -> Luma<S, U> {
+        Luma {
+            luma: f(self.luma, other.luma),
+            standard: PhantomData,
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> Luma<S, U> {
+        Luma {
+            luma: f(self.luma),
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Default for Luma<S, T>
 where
     T: Component,
@@ -526,6 +713,86 @@ where
     }
 }
 
+impl<S, T> SaturatingArithmetic<Luma<S, T>> for Luma<S, T>
+where
+    S: LumaStandard<TransferFn = LinearFn>,
+    T: Component + Saturating,
+{
+    fn saturating_add(self, other: Luma<S, T>) -> Self {
+        Luma {
+            luma: self.luma.saturating_add(other.luma),
+            standard: PhantomData,
+        }
+    }
+
+    fn saturating_sub(self, other: Luma<S, T>) -> Self {
+        Luma {
+            luma: self.luma.saturating_sub(other.luma),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> SaturatingArithmetic<T> for Luma<S, T>
+where
+    S: LumaStandard<TransferFn = LinearFn>,
+    T: Component + Saturating,
+{
+    fn saturating_add(self, other: T) -> Self {
+        Luma {
+            luma: self.luma.saturating_add(other),
+            standard: PhantomData,
+        }
+    }
+
+    fn saturating_sub(self, other: T) -> Self {
+        Luma {
+            luma: self.luma.saturating_sub(other),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> CheckedArithmetic<Luma<S, T>> for Luma<S, T>
+where
+    S: LumaStandard<TransferFn = LinearFn>,
+    T: Component + CheckedAdd + CheckedSub,
+{
+    fn checked_add(self, other: Luma<S, T>) -> Option<Self> {
+        Some(Luma {
+            luma: self.luma.checked_add(&other.luma)?,
+            standard: PhantomData,
+        })
+    }
+
+    fn checked_sub(self, other: Luma<S, T>) -> Option<Self> {
+        Some(Luma {
+            luma: self.luma.checked_sub(&other.luma)?,
+            standard: PhantomData,
+        })
+    }
+}
+
+impl<S, T> CheckedArithmetic<T> for Luma<S, T>
+where
+    S: LumaStandard<TransferFn = LinearFn>,
+    T: Component + CheckedAdd + CheckedSub,
+{
+    fn checked_add(self, other: T) -> Option<Self> {
+        Some(Luma {
+            luma: self.luma.checked_add(&other)?,
+            standard: PhantomData,
+        })
+    }
+
+    fn checked_sub(self, other: T) -> Option<Self> {
+        Some(Luma {
+            luma: self.luma.checked_sub(&other)?,
+            standard: PhantomData,
+        })
+    }
+}
+
 impl<S, T> Mul<Luma<S, T>> for Luma<S, T>
 where
     T: Component + Mul,
@@ -675,6 +942,23 @@ where
     }
 }
 
+impl<S, T> Eq for Luma<S, T>
+where
+    T: Component + Eq,
+    S: LumaStandard + PartialEq,
+{
+}
+
+impl<S, T> Hash for Luma<S, T>
+where
+    T: Component + Hash,
+    S: LumaStandard,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.luma.hash(state);
+    }
+}
+
 impl<S, T> AbsDiffEq for Luma<S, T>
 where
     T: Component + AbsDiffEq,
@@ -749,18 +1033,29 @@ where
     }
 }
 
-impl<S, T> RelativeContrast for Luma<S, T>
+impl<S, T> RelativeLuminance for Luma<S, T>
 where
     T: FloatComponent,
     S: LumaStandard,
+{
+    type WhitePoint = S::WhitePoint;
+    type Scalar = T;
+}
+
+impl<S, T> ApcaContrast for Luma<S, T>
+where
+    T: FloatComponent,
+    S: LumaStandard<WhitePoint = D65>,
 {
     type Scalar = T;
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        let luma1 = self.into_linear();
-        let luma2 = other.into_linear();
+    fn get_apca_contrast(&self, background: &Self) -> T {
+        let luminance = |color: &Self| {
+            let luma = Luma::<Srgb, T>::from_linear(color.into_linear());
+            apca_screen_luminance(luma.luma, luma.luma, luma.luma)
+        };
 
-        contrast_ratio(luma1.luma, luma2.luma)
+        apca_contrast(luminance(self), luminance(background))
     }
 }
 
@@ -939,4 +1234,23 @@ mod test {
         min: Luma::new(0.0f32),
         max: Luma::new(1.0)
     }
+
+    #[test]
+    fn saturating_and_checked_arithmetic() {
+        use crate::white_point::D65;
+        use crate::{CheckedArithmetic, SaturatingArithmetic};
+
+        type LinLuma = crate::LinLuma<D65, u8>;
+        type LinLumaa = crate::LinLumaa<D65, u8>;
+
+        let luma = LinLuma::new(100);
+        assert_eq!(luma.saturating_add(200), LinLuma::new(255));
+        assert_eq!(luma.saturating_sub(LinLuma::new(200)), LinLuma::new(0));
+        assert_eq!(luma.checked_add(155), Some(LinLuma::new(255)));
+        assert_eq!(luma.checked_sub(LinLuma::new(101)), None);
+
+        let lumaa = LinLumaa::new(100, 200);
+        assert_eq!(lumaa.saturating_add(100), LinLumaa::new(200, 255));
+        assert_eq!(lumaa.checked_add(LinLumaa::new(0, 56)), None);
+    }
 }
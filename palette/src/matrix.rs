@@ -1,5 +1,27 @@
 //! This module provides simple matrix operations on 3x3 matrices to aid in
 //! chromatic adaptation and conversion calculations.
+//!
+//! The matrices are represented as [`Mat3`], which is a plain array with the
+//! elements in row major order. They are used for converting between linear
+//! RGB and XYZ, and for adapting XYZ colors to a different white point, and
+//! can be combined into custom transforms:
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::encoding::Srgb;
+//! use palette::matrix::{multiply_3x3, multiply_xyz, rgb_to_xyz_matrix, xyz_to_rgb_matrix};
+//! use palette::Xyz;
+//!
+//! // Convert from linear sRGB to XYZ and back, in one step
+//! let round_trip = multiply_3x3(
+//!     &xyz_to_rgb_matrix::<Srgb, f64>(),
+//!     &rgb_to_xyz_matrix::<Srgb, f64>(),
+//! );
+//!
+//! let color = Xyz::new(0.2, 0.4, 0.6);
+//! let result: Xyz<_, f64> = multiply_xyz(&round_trip, &color);
+//! assert_relative_eq!(result, color, epsilon = 0.000001);
+//! ```
 
 use core::marker::PhantomData;
 
@@ -7,10 +29,10 @@ use crate::convert::IntoColorUnclamped;
 use crate::encoding::Linear;
 use crate::float::Float;
 use crate::rgb::{Primaries, Rgb, RgbSpace};
-use crate::white_point::WhitePoint;
-use crate::{FloatComponent, Xyz};
+use crate::white_point::{WhitePoint, D65};
+use crate::{Chromaticity, FloatComponent, Xyz};
 
-/// A 9 element array representing a 3x3 matrix.
+/// A 9 element array representing a 3x3 matrix, in row major order.
 pub type Mat3<T> = [T; 9];
 
 /// Multiply the 3x3 matrix with an XYZ color.
@@ -132,31 +154,98 @@ pub fn matrix_inverse<T: Float>(a: &Mat3<T>) -> Mat3<T> {
     ]
 }
 
-/// Generates the Srgb to Xyz transformation matrix for a given white point.
+/// Generates the linear RGB to Xyz transformation matrix for the RGB space
+/// `S`.
 #[inline]
 pub fn rgb_to_xyz_matrix<S: RgbSpace, T: FloatComponent>() -> Mat3<T> {
     let r: Xyz<S::WhitePoint, T> = S::Primaries::red().into_color_unclamped();
     let g: Xyz<S::WhitePoint, T> = S::Primaries::green().into_color_unclamped();
     let b: Xyz<S::WhitePoint, T> = S::Primaries::blue().into_color_unclamped();
 
+    primaries_to_xyz_matrix(r, g, b, &S::WhitePoint::get_xyz())
+}
+
+/// Generates the Xyz to linear RGB transformation matrix for the RGB space
+/// `S`.
+///
+/// This is the inverse of [`rgb_to_xyz_matrix`].
+#[inline]
+pub fn xyz_to_rgb_matrix<S: RgbSpace, T: FloatComponent>() -> Mat3<T> {
+    matrix_inverse(&rgb_to_xyz_matrix::<S, T>())
+}
+
+/// Generates the linear RGB to Xyz transformation matrix for the
+/// chromaticities of the `red`, `green` and `blue` primaries, and the `white`
+/// point, where white has a luminance of 1.
+///
+/// This is the run time equivalent of [`rgb_to_xyz_matrix`], for when the
+/// primaries aren't known at compile time.
+///
+/// # Panics
+///
+/// Panics if the primaries don't span a color space, such as when two of them
+/// are the same.
+pub fn rgb_to_xyz_matrix_from_primaries<T: FloatComponent>(
+    red: Chromaticity<T>,
+    green: Chromaticity<T>,
+    blue: Chromaticity<T>,
+    white: Chromaticity<T>,
+) -> Mat3<T> {
+    // The white point type doesn't change the result
+    primaries_to_xyz_matrix::<D65, T>(
+        red.into_xyz(T::one()),
+        green.into_xyz(T::one()),
+        blue.into_xyz(T::one()),
+        &white.into_xyz(T::one()),
+    )
+}
+
+/// Generates the Xyz to linear RGB transformation matrix for the
+/// chromaticities of the `red`, `green` and `blue` primaries, and the `white`
+/// point, where white has a luminance of 1.
+///
+/// This is the inverse of [`rgb_to_xyz_matrix_from_primaries`].
+///
+/// # Panics
+///
+/// Panics if the primaries don't span a color space, such as when two of them
+/// are the same.
+pub fn xyz_to_rgb_matrix_from_primaries<T: FloatComponent>(
+    red: Chromaticity<T>,
+    green: Chromaticity<T>,
+    blue: Chromaticity<T>,
+    white: Chromaticity<T>,
+) -> Mat3<T> {
+    matrix_inverse(&rgb_to_xyz_matrix_from_primaries(red, green, blue, white))
+}
+
+/// Generates the linear RGB to Xyz transformation matrix for the primaries
+/// `r`, `g` and `b`, where RGB white is scaled to `white`.
+#[inline]
+pub(crate) fn primaries_to_xyz_matrix<Wp: WhitePoint, T: FloatComponent>(
+    r: Xyz<Wp, T>,
+    g: Xyz<Wp, T>,
+    b: Xyz<Wp, T>,
+    white: &Xyz<Wp, T>,
+) -> Mat3<T> {
     // Destructuring has some performance benefits, don't change unless measured
     let [t0, t1, t2, t3, t4, t5, t6, t7, t8] = mat3_from_primaries(r, g, b);
 
-    let s_matrix: Rgb<Linear<S>, T> = multiply_xyz_to_rgb(
+    let s_matrix: Xyz<Wp, T> = multiply_xyz(
         &matrix_inverse(&[t0, t1, t2, t3, t4, t5, t6, t7, t8]),
-        &S::WhitePoint::get_xyz(),
+        white,
     );
 
     [
-        t0 * s_matrix.red,
-        t1 * s_matrix.green,
-        t2 * s_matrix.blue,
-        t3 * s_matrix.red,
-        t4 * s_matrix.green,
-        t5 * s_matrix.blue,
-        t6 * s_matrix.red,
-        t7 * s_matrix.green,
-        t8 * s_matrix.blue,
+        t0 * s_matrix.x,
+        t1 * s_matrix.y,
+        t2 * s_matrix.z,
+        t3 * s_matrix.x,
+        t4 * s_matrix.y,
+        t5 * s_matrix.z,
+        t6 * s_matrix.x,
+        t7 * s_matrix.y,
+        t8 * s_matrix.z,
     ]
 }
 
@@ -172,12 +261,15 @@ fn mat3_from_primaries<T: FloatComponent, Wp: WhitePoint>(r: Xyz<Wp, T>, g: Xyz<
 
 #[cfg(test)]
 mod test {
-    use super::{matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix};
+    use super::{
+        matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix,
+        rgb_to_xyz_matrix_from_primaries, xyz_to_rgb_matrix, xyz_to_rgb_matrix_from_primaries,
+    };
     use crate::chromatic_adaptation::AdaptInto;
     use crate::encoding::{Linear, Srgb};
-    use crate::rgb::Rgb;
-    use crate::white_point::D50;
-    use crate::Xyz;
+    use crate::rgb::{Primaries, Rgb};
+    use crate::white_point::{D50, D65};
+    use crate::{Chromaticity, Xyz};
 
     #[test]
     fn matrix_multiply_3x3() {
@@ -243,6 +335,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn xyz_to_rgb_is_inverse() {
+        let computed = multiply_3x3(
+            &xyz_to_rgb_matrix::<Srgb, f64>(),
+            &rgb_to_xyz_matrix::<Srgb, f64>(),
+        );
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        for (e, c) in identity.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.000001)
+        }
+    }
+
     #[test]
     fn d65_to_d50() {
         let input: Rgb<Linear<Srgb>> = Rgb::new(1.0, 1.0, 1.0);
@@ -251,4 +355,24 @@ mod test {
         let computed: Rgb<Linear<(Srgb, D50)>> = input.adapt_into();
         assert_relative_eq!(expected, computed, epsilon = 0.000001);
     }
+
+    #[test]
+    fn matrix_from_primaries() {
+        let red = Chromaticity::from(Srgb::red::<D65, f64>());
+        let green = Chromaticity::from(Srgb::green::<D65, f64>());
+        let blue = Chromaticity::from(Srgb::blue::<D65, f64>());
+        let white = Chromaticity::from_white_point::<D65>();
+
+        let expected = rgb_to_xyz_matrix::<Srgb, f64>();
+        let computed = rgb_to_xyz_matrix_from_primaries(red, green, blue, white);
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.000001)
+        }
+
+        let expected = matrix_inverse(&expected);
+        let computed = xyz_to_rgb_matrix_from_primaries(red, green, blue, white);
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.000001)
+        }
+    }
 }
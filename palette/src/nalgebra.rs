@@ -0,0 +1,73 @@
+//! Conversion between Palette colors and the vector types of `nalgebra`.
+
+use ::nalgebra::{Scalar, Vector3, Vector4};
+
+use crate::rgb::{Rgb, RgbStandard};
+use crate::white_point::WhitePoint;
+use crate::{Alpha, Component, FloatComponent, Lab, Xyz, Yxy};
+
+macro_rules! impl_nalgebra {
+    ($($color: ident <$param: ident: $bound: ident, $component: ident>),+) => {
+        $(
+            impl<$param: $bound, T: $component + Scalar> From<$color<$param, T>> for Vector3<T> {
+                fn from(color: $color<$param, T>) -> Self {
+                    <[T; 3]>::from(color).into()
+                }
+            }
+
+            impl<$param: $bound, T: $component + Scalar> From<Vector3<T>> for $color<$param, T> {
+                fn from(vector: Vector3<T>) -> Self {
+                    <[T; 3]>::from(vector).into()
+                }
+            }
+
+            impl<$param: $bound, T: $component + Scalar> From<Alpha<$color<$param, T>, T>>
+                for Vector4<T>
+            {
+                fn from(color: Alpha<$color<$param, T>, T>) -> Self {
+                    <[T; 4]>::from(color).into()
+                }
+            }
+
+            impl<$param: $bound, T: $component + Scalar> From<Vector4<T>>
+                for Alpha<$color<$param, T>, T>
+            {
+                fn from(vector: Vector4<T>) -> Self {
+                    <[T; 4]>::from(vector).into()
+                }
+            }
+        )+
+    };
+}
+
+impl_nalgebra!(
+    Rgb<S: RgbStandard, Component>,
+    Xyz<Wp: WhitePoint, FloatComponent>,
+    Yxy<Wp: WhitePoint, FloatComponent>,
+    Lab<Wp: WhitePoint, FloatComponent>
+);
+
+#[cfg(test)]
+mod test {
+    use ::nalgebra::{Vector3, Vector4};
+
+    use crate::{Lab, LinSrgb, Srgba};
+
+    #[test]
+    fn rgb() {
+        let vector = Vector3::from(LinSrgb::new(0.1f32, 0.2, 0.3));
+        assert_eq!(vector, Vector3::new(0.1, 0.2, 0.3));
+        assert_eq!(LinSrgb::from(vector), LinSrgb::new(0.1, 0.2, 0.3));
+
+        let vector = Vector4::from(Srgba::new(10u8, 20, 30, 255));
+        assert_eq!(vector, Vector4::new(10, 20, 30, 255));
+        assert_eq!(Srgba::from(vector), Srgba::new(10, 20, 30, 255));
+    }
+
+    #[test]
+    fn lab() {
+        let color = Lab::new(50.0f64, -10.0, 20.0);
+        let vector = Vector3::from(color) * 2.0;
+        assert_eq!(Lab::from(vector), Lab::new(100.0, -20.0, 40.0));
+    }
+}
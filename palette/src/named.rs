@@ -1,9 +1,10 @@
 //! A collection of named color constants. Can be toggled with the `"named"` and
 //! `"named_from_str"` Cargo features.
 //!
-//! They are taken from the [SVG keyword
-//! colors](https://www.w3.org/TR/SVG/types.html#ColorKeywords) (same as in
-//! CSS3) and they can be used as if they were pixel values:
+//! They are the 148 [named colors from CSS Color Module Level
+//! 4](https://www.w3.org/TR/css-color-4/#named-colors), which are the [SVG
+//! keyword colors](https://www.w3.org/TR/SVG/types.html#ColorKeywords) plus
+//! `rebeccapurple`, and they can be used as if they were pixel values:
 //!
 //! ```
 //! use palette::Srgb;
@@ -21,11 +22,24 @@
 
 include!(concat!(env!("OUT_DIR"), "/named.rs"));
 
-/// Get a SVG/CSS3 color by name. Can be toggled with the `"named_from_str"`
-/// Cargo feature.
+/// Get a CSS color by name. Can be toggled with the `"named_from_str"` Cargo
+/// feature.
 ///
-/// The names are the same as the constants, but lower case.
+/// The names are the same as the constants, and they are matched without
+/// regard to ASCII case, like in CSS.
+///
+/// ```
+/// use palette::named;
+///
+/// assert_eq!(named::from_str("rebeccapurple"), Some(named::REBECCAPURPLE));
+/// assert_eq!(named::from_str("RebeccaPurple"), Some(named::REBECCAPURPLE));
+/// assert_eq!(named::from_str("transparent"), None);
+/// ```
 #[cfg(feature = "named_from_str")]
 pub fn from_str(name: &str) -> Option<crate::Srgb<u8>> {
-    COLORS.get(name).cloned()
+    if name.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        COLORS.get(&*name.to_ascii_lowercase()).cloned()
+    } else {
+        COLORS.get(name).cloned()
+    }
 }
@@ -0,0 +1,627 @@
+//! Display profiles, made from RGB primaries and tone curves.
+//!
+//! The RGB types in this library have their primaries, white point and
+//! transfer function fixed at compile time, through the [`RgbStandard`]
+//! trait. That's not an option when the color space is only known at run
+//! time, such as for the display profile of a monitor, or the embedded
+//! profile of an image. A [`Profile`] describes such a color space with the
+//! same model as an ICC matrix/TRC profile:
+//!
+//! * a matrix that converts linear RGB to the profile connection space (PCS),
+//!   which is [`Xyz`] relative to the D50 white point, and
+//! * one tone reproduction curve (TRC) per channel, that converts the encoded
+//!   RGB values to linear RGB.
+//!
+//! Colors are converted between two profiles by going through the PCS, with
+//! any chromatic adaptation built into the matrices. Reading ICC files is out
+//! of scope, but the `rXYZ`, `gXYZ` and `bXYZ` tags of a matrix/TRC profile
+//! can be given to [`Profile::from_colorants`], and its `rTRC`, `gTRC` and
+//! `bTRC` tags can be represented as [`ToneCurve`]s. Colors in the color space
+//! of a profile can also be represented as [`DynRgb`], which can be converted
+//! to and from the other color types.
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::profile::{Profile, ToneCurve};
+//! use palette::white_point::D65;
+//! use palette::Chromaticity;
+//!
+//! let srgb = Profile::<f64>::srgb();
+//! let display_p3 = Profile::from_primaries(
+//!     [
+//!         Chromaticity::new(0.680, 0.320),
+//!         Chromaticity::new(0.265, 0.690),
+//!         Chromaticity::new(0.150, 0.060),
+//!     ],
+//!     Chromaticity::from_white_point::<D65>(),
+//!     [ToneCurve::srgb(), ToneCurve::srgb(), ToneCurve::srgb()],
+//! );
+//!
+//! let (red, green, blue) = srgb.transform_to(&display_p3).apply((1.0, 0.0, 0.0));
+//! assert_relative_eq!(red, 0.9175, epsilon = 0.0001);
+//! assert_relative_eq!(green, 0.2003, epsilon = 0.0001);
+//! assert_relative_eq!(blue, 0.1386, epsilon = 0.0001);
+//! ```
+//!
+//! [`RgbStandard`]: crate::rgb::RgbStandard
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::chromatic_adaptation::{adaptation_matrix, AdaptInto, Method, TransformMatrix};
+use crate::convert::FromColorUnclamped;
+use crate::from_f64;
+use crate::matrix::{
+    matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix_from_primaries, Mat3,
+};
+use crate::white_point::{WhitePoint, D50, D65};
+use crate::{
+    clamp, Alpha, Chromaticity, Component, FloatComponent, InvalidComponents, Limited, WithAlpha,
+    Xyz,
+};
+
+/// A tone reproduction curve, that converts an encoded color channel to and
+/// from linear light.
+///
+/// The curves are defined for values between 0 and 1. Values below 0 are
+/// mirrored, to not lose colors that are out of gamut for a profile.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ToneCurve<T> {
+    /// The encoded values are already linear.
+    Linear,
+
+    /// A pure power function, where the linear value is `x^gamma`.
+    Gamma(T),
+
+    /// A power function with a linear segment, as in ICC parametric curves
+    /// of type 4, and the sRGB transfer function.
+    ///
+    /// The linear value is `(a * x + b)^gamma + e` when `x >= d`, and
+    /// `c * x + f` when `x < d`.
+    Parametric {
+        /// The exponent of the power function.
+        gamma: T,
+        /// The scale of the encoded value in the power function.
+        a: T,
+        /// The offset of the encoded value in the power function.
+        b: T,
+        /// The slope of the linear segment.
+        c: T,
+        /// The encoded value where the power function starts.
+        d: T,
+        /// The offset of the power function.
+        e: T,
+        /// The offset of the linear segment.
+        f: T,
+    },
+
+    /// Linear values, sampled at evenly spaced encoded values between 0 and
+    /// 1, as in ICC curves with a lookup table. Values in between the samples
+    /// are linearly interpolated. The table should be increasing.
+    #[cfg(feature = "alloc")]
+    Table(Vec<T>),
+}
+
+impl<T: FloatComponent> ToneCurve<T> {
+    /// The sRGB transfer function, as a parametric curve.
+    pub fn srgb() -> Self {
+        ToneCurve::Parametric {
+            gamma: from_f64(2.4),
+            a: from_f64(1.0 / 1.055),
+            b: from_f64(0.055 / 1.055),
+            c: from_f64(1.0 / 12.92),
+            d: from_f64(0.04045),
+            e: T::zero(),
+            f: T::zero(),
+        }
+    }
+
+    /// Convert the encoded value `x` into linear space.
+    pub fn into_linear(&self, x: T) -> T {
+        if x < T::zero() {
+            return -self.into_linear(-x);
+        }
+
+        match *self {
+            ToneCurve::Linear => x,
+            ToneCurve::Gamma(gamma) => x.powf(gamma),
+            ToneCurve::Parametric {
+                gamma,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            } => {
+                if x >= d {
+                    (a * x + b).powf(gamma) + e
+                } else {
+                    c * x + f
+                }
+            }
+            #[cfg(feature = "alloc")]
+            ToneCurve::Table(ref table) => interpolate(table, x),
+        }
+    }
+
+    /// Convert the linear value `x` into the encoded space.
+    pub fn from_linear(&self, x: T) -> T {
+        if x < T::zero() {
+            return -self.from_linear(-x);
+        }
+
+        match *self {
+            ToneCurve::Linear => x,
+            ToneCurve::Gamma(gamma) => x.powf(gamma.recip()),
+            ToneCurve::Parametric {
+                gamma,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            } => {
+                if x >= c * d + f {
+                    ((x - e).max(T::zero()).powf(gamma.recip()) - b) / a
+                } else if c > T::zero() {
+                    (x - f) / c
+                } else {
+                    T::zero()
+                }
+            }
+            #[cfg(feature = "alloc")]
+            ToneCurve::Table(ref table) => interpolate_inverse(table, x),
+        }
+    }
+}
+
+/// Look up `x` in a table of evenly spaced samples between 0 and 1.
+#[cfg(feature = "alloc")]
+fn interpolate<T: FloatComponent>(table: &[T], x: T) -> T {
+    match table.len() {
+        0 => x,
+        1 => table[0],
+        len => {
+            let last = len - 1;
+            let position = x.min(T::one()) * from_f64(last as f64);
+            let index = position.to_usize().unwrap_or(0).min(last - 1);
+            let fraction = position - from_f64(index as f64);
+
+            table[index] + (table[index + 1] - table[index]) * fraction
+        }
+    }
+}
+
+/// Find the value between 0 and 1 that `interpolate` maps to `y`.
+#[cfg(feature = "alloc")]
+fn interpolate_inverse<T: FloatComponent>(table: &[T], y: T) -> T {
+    if table.len() < 2 {
+        return y;
+    }
+
+    let last = table.len() - 1;
+    let index = table
+        .iter()
+        .skip(1)
+        .position(|&value| value >= y)
+        .unwrap_or(last - 1);
+    let (low, high) = (table[index], table[index + 1]);
+    let fraction = if high > low {
+        ((y - low) / (high - low)).min(T::one())
+    } else {
+        T::zero()
+    };
+
+    (from_f64::<T>(index as f64) + fraction) / from_f64(last as f64)
+}
+
+/// A matrix/TRC display profile.
+///
+/// See the [module level documentation](self) for more details.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile<T> {
+    to_pcs: Mat3<T>,
+    from_pcs: Mat3<T>,
+    curves: [ToneCurve<T>; 3],
+}
+
+impl<T: FloatComponent> Profile<T> {
+    /// Create a profile from the chromaticities of its red, green and blue
+    /// primaries and its white point, as well as its red,
+    /// green and blue tone curves.
+    ///
+    /// The colors are adapted to and from the D50 white point of the PCS
+    /// with the Bradford method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the primaries don't span a color space, such as when two of
+    /// them are the same.
+    pub fn from_primaries(
+        primaries: [Chromaticity<T>; 3],
+        white: Chromaticity<T>,
+        curves: [ToneCurve<T>; 3],
+    ) -> Self {
+        let [red, green, blue] = primaries;
+        let to_xyz = rgb_to_xyz_matrix_from_primaries(red, green, blue, white);
+
+        // The white point type is only a placeholder until the colors have
+        // been adapted to D50.
+        let cone_response = TransformMatrix::<D50, D50, T>::get_cone_response(&Method::Bradford);
+        let d50: Xyz<D50, T> = D50::get_xyz();
+        let adapt = adaptation_matrix(&cone_response, &white.into_xyz::<D50>(T::one()), &d50);
+
+        Self::from_matrix(multiply_3x3(&adapt, &to_xyz), curves)
+    }
+
+    /// Create a profile from the PCS colors of its red, green and blue
+    /// primaries, also known as colorants, as well as its red, green and blue
+    /// tone curves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the colorants don't span a color space, such as when two of
+    /// them are the same.
+    pub fn from_colorants(
+        red: Xyz<D50, T>,
+        green: Xyz<D50, T>,
+        blue: Xyz<D50, T>,
+        curves: [ToneCurve<T>; 3],
+    ) -> Self {
+        #[rustfmt::skip]
+        let to_pcs = [
+            red.x, green.x, blue.x,
+            red.y, green.y, blue.y,
+            red.z, green.z, blue.z,
+        ];
+
+        Self::from_matrix(to_pcs, curves)
+    }
+
+    /// A profile for the sRGB color space.
+    pub fn srgb() -> Self {
+        Self::from_primaries(
+            [
+                Chromaticity::new(from_f64(0.64), from_f64(0.33)),
+                Chromaticity::new(from_f64(0.30), from_f64(0.60)),
+                Chromaticity::new(from_f64(0.15), from_f64(0.06)),
+            ],
+            Chromaticity::from_white_point::<D65>(),
+            [ToneCurve::srgb(), ToneCurve::srgb(), ToneCurve::srgb()],
+        )
+    }
+
+    fn from_matrix(to_pcs: Mat3<T>, curves: [ToneCurve<T>; 3]) -> Self {
+        Profile {
+            to_pcs,
+            from_pcs: matrix_inverse(&to_pcs),
+            curves,
+        }
+    }
+
+    /// Get the red, green and blue tone curves of the profile.
+    pub fn curves(&self) -> &[ToneCurve<T>; 3] {
+        &self.curves
+    }
+
+    /// Convert an encoded `(red, green, blue)` color to the PCS.
+    pub fn to_pcs(&self, (red, green, blue): (T, T, T)) -> Xyz<D50, T> {
+        let [red_curve, green_curve, blue_curve] = &self.curves;
+        let linear: Xyz<D50, T> = Xyz::with_wp(
+            red_curve.into_linear(red),
+            green_curve.into_linear(green),
+            blue_curve.into_linear(blue),
+        );
+
+        multiply_xyz(&self.to_pcs, &linear)
+    }
+
+    /// Convert a PCS color to an encoded `(red, green, blue)` color. The
+    /// result may be out of gamut for the profile.
+    pub fn from_pcs(&self, color: Xyz<D50, T>) -> (T, T, T) {
+        let [red_curve, green_curve, blue_curve] = &self.curves;
+        let linear: Xyz<D50, T> = multiply_xyz(&self.from_pcs, &color);
+
+        (
+            red_curve.from_linear(linear.x),
+            green_curve.from_linear(linear.y),
+            blue_curve.from_linear(linear.z),
+        )
+    }
+
+    /// Create a transform from this profile to `destination`, for converting
+    /// many colors at once.
+    pub fn transform_to<'a>(&'a self, destination: &'a Profile<T>) -> ProfileTransform<'a, T> {
+        ProfileTransform {
+            matrix: multiply_3x3(&destination.from_pcs, &self.to_pcs),
+            source: &self.curves,
+            destination: &destination.curves,
+        }
+    }
+}
+
+/// Converts colors from one [`Profile`] to another.
+///
+/// The conversion matrices of both profiles are combined into one, so the
+/// PCS colors are never computed.
+#[derive(Clone, Debug)]
+pub struct ProfileTransform<'a, T> {
+    matrix: Mat3<T>,
+    source: &'a [ToneCurve<T>; 3],
+    destination: &'a [ToneCurve<T>; 3],
+}
+
+impl<'a, T: FloatComponent> ProfileTransform<'a, T> {
+    /// Convert an encoded `(red, green, blue)` color from the source profile
+    /// to the destination profile. The result may be out of gamut for the
+    /// destination profile.
+    pub fn apply(&self, (red, green, blue): (T, T, T)) -> (T, T, T) {
+        let [red_in, green_in, blue_in] = self.source;
+        let [red_out, green_out, blue_out] = self.destination;
+
+        let linear: Xyz<D50, T> = Xyz::with_wp(
+            red_in.into_linear(red),
+            green_in.into_linear(green),
+            blue_in.into_linear(blue),
+        );
+        let linear: Xyz<D50, T> = multiply_xyz(&self.matrix, &linear);
+
+        (
+            red_out.from_linear(linear.x),
+            green_out.from_linear(linear.y),
+            blue_out.from_linear(linear.z),
+        )
+    }
+}
+
+/// An RGB color in a color space that is defined at run time, by a
+/// [`Profile`].
+///
+/// This is the dynamic counterpart of [`Rgb`](crate::rgb::Rgb), for when the
+/// primaries, white point and transfer functions are read from display
+/// metadata or an ICC profile. It can be converted to and from [`Xyz`], with
+/// any white point, which connects it to the rest of the color types:
+///
+/// ```
+/// use palette::convert::FromColorUnclamped;
+/// use palette::profile::{DynRgb, Profile};
+/// use palette::{FromColor, Lab, Xyz};
+///
+/// let profile = Profile::<f32>::srgb();
+/// let color = DynRgb::new(1.0, 0.5, 0.0, &profile);
+///
+/// let lab: Lab = Lab::from_color(Xyz::from_color_unclamped(color));
+/// let color = DynRgb::from_xyz(Xyz::from_color(lab), &profile);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct DynRgb<'a, T> {
+    /// The amount of red light, where 0.0 is no red light and 1.0 is the
+    /// highest displayable amount.
+    pub red: T,
+
+    /// The amount of green light, where 0.0 is no green light and 1.0 is the
+    /// highest displayable amount.
+    pub green: T,
+
+    /// The amount of blue light, where 0.0 is no blue light and 1.0 is the
+    /// highest displayable amount.
+    pub blue: T,
+
+    /// The profile that defines the color space.
+    pub profile: &'a Profile<T>,
+}
+
+impl<'a, T: Copy> Copy for DynRgb<'a, T> {}
+
+impl<'a, T: Copy> Clone for DynRgb<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: FloatComponent> DynRgb<'a, T> {
+    /// Create an RGB color in the color space of `profile`.
+    pub fn new(red: T, green: T, blue: T, profile: &'a Profile<T>) -> Self {
+        DynRgb {
+            red,
+            green,
+            blue,
+            profile,
+        }
+    }
+
+    /// Convert an XYZ color to the color space of `profile`, by adapting it
+    /// to the D50 white point of the PCS. The result may be out of gamut.
+    pub fn from_xyz<Wp: WhitePoint>(color: Xyz<Wp, T>, profile: &'a Profile<T>) -> Self {
+        let (red, green, blue) = profile.from_pcs(color.adapt_into());
+        DynRgb::new(red, green, blue, profile)
+    }
+
+    /// Convert to a `(red, green, blue)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Convert to the color space of `profile`.
+    pub fn into_profile<'b>(self, profile: &'b Profile<T>) -> DynRgb<'b, T> {
+        let (red, green, blue) = self
+            .profile
+            .transform_to(profile)
+            .apply(self.into_components());
+        DynRgb::new(red, green, blue, profile)
+    }
+}
+
+impl<'a, Wp, T> FromColorUnclamped<DynRgb<'a, T>> for Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: DynRgb<'a, T>) -> Self {
+        color.profile.to_pcs(color.into_components()).adapt_into()
+    }
+}
+
+impl<'a, T, A> WithAlpha<A> for DynRgb<'a, T>
+where
+    A: Component,
+{
+    type Color = Self;
+    type WithAlpha = Alpha<Self, A>;
+
+    fn with_alpha(self, alpha: A) -> Self::WithAlpha {
+        Alpha { color: self, alpha }
+    }
+
+    fn without_alpha(self) -> Self::Color {
+        self
+    }
+
+    fn split(self) -> (Self::Color, A) {
+        (self, A::max_intensity())
+    }
+}
+
+impl<'a, T: FloatComponent> Limited for DynRgb<'a, T> {
+    #[rustfmt::skip]
+    fn is_valid(&self) -> bool {
+        self.red >= T::zero() && self.red <= T::one() &&
+        self.green >= T::zero() && self.green <= T::one() &&
+        self.blue >= T::zero() && self.blue <= T::one()
+    }
+
+    fn clamp(&self) -> Self {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.red = clamp(self.red, T::zero(), T::one());
+        self.green = clamp(self.green, T::zero(), T::one());
+        self.blue = clamp(self.blue, T::zero(), T::one());
+    }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check("red", self.red >= T::zero() && self.red <= T::one())
+            .check("green", self.green >= T::zero() && self.green <= T::one())
+            .check("blue", self.blue >= T::zero() && self.blue <= T::one())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynRgb, Profile, ToneCurve};
+    use crate::chromatic_adaptation::AdaptFrom;
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::{WhitePoint, D50};
+    use crate::{Chromaticity, Srgb, Xyz};
+
+    #[test]
+    fn srgb_matches_srgb_type() {
+        let profile = Profile::<f64>::srgb();
+
+        for &(red, green, blue) in &[(1.0, 1.0, 1.0), (1.0, 0.5, 0.0), (0.2, 0.4, 0.8)] {
+            let expected =
+                Xyz::<D50, f64>::adapt_from(Xyz::from_color_unclamped(Srgb::new(red, green, blue)));
+            let pcs = profile.to_pcs((red, green, blue));
+            assert_relative_eq!(pcs, expected, epsilon = 0.0001);
+
+            let (red2, green2, blue2) = profile.from_pcs(pcs);
+            assert_relative_eq!(red, red2, epsilon = 0.000001);
+            assert_relative_eq!(green, green2, epsilon = 0.000001);
+            assert_relative_eq!(blue, blue2, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn white_is_d50() {
+        let profile = Profile::<f64>::from_primaries(
+            [
+                Chromaticity::new(0.7347, 0.2653),
+                Chromaticity::new(0.1596, 0.8404),
+                Chromaticity::new(0.0366, 0.0001),
+            ],
+            Chromaticity::new(0.3457, 0.3585),
+            [
+                ToneCurve::Gamma(1.8),
+                ToneCurve::Gamma(1.8),
+                ToneCurve::Gamma(1.8),
+            ],
+        );
+
+        let white: Xyz<D50, f64> = D50::get_xyz();
+        assert_relative_eq!(profile.to_pcs((1.0, 1.0, 1.0)), white, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn out_of_gamut_round_trip() {
+        let srgb = Profile::<f64>::srgb();
+        let linear = Profile::from_primaries(
+            [
+                Chromaticity::new(0.708, 0.292),
+                Chromaticity::new(0.170, 0.797),
+                Chromaticity::new(0.131, 0.046),
+            ],
+            Chromaticity::new(0.3127, 0.3290),
+            [ToneCurve::Linear, ToneCurve::Linear, ToneCurve::Linear],
+        );
+
+        let (red, green, blue) = linear.transform_to(&srgb).apply((0.0, 1.0, 0.0));
+        assert!(red < 0.0 && blue < 0.0);
+
+        let (red, green, blue) = srgb.transform_to(&linear).apply((red, green, blue));
+        assert_relative_eq!(red, 0.0, epsilon = 0.000001);
+        assert_relative_eq!(green, 1.0, epsilon = 0.000001);
+        assert_relative_eq!(blue, 0.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn parametric_curve_round_trip() {
+        let curve = ToneCurve::<f64>::srgb();
+
+        for &x in &[0.0, 0.01, 0.04045, 0.2, 0.5, 1.0] {
+            assert_relative_eq!(
+                curve.from_linear(curve.into_linear(x)),
+                x,
+                epsilon = 0.000001
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn table_curve_round_trip() {
+        let curve = ToneCurve::<f64>::Table(vec![0.0, 0.1, 0.3, 0.6, 1.0]);
+
+        assert_relative_eq!(curve.into_linear(0.125), 0.05);
+        assert_relative_eq!(curve.into_linear(0.625), 0.45);
+        assert_relative_eq!(curve.into_linear(1.0), 1.0);
+
+        for &x in &[0.0, 0.1, 0.25, 0.4, 0.8, 1.0] {
+            assert_relative_eq!(
+                curve.from_linear(curve.into_linear(x)),
+                x,
+                epsilon = 0.000001
+            );
+        }
+    }
+
+    #[test]
+    fn dyn_rgb_xyz_round_trip() {
+        let profile = Profile::<f64>::srgb();
+        let color = DynRgb::new(0.2, 0.4, 0.8, &profile);
+
+        let xyz = Xyz::from_color_unclamped(color);
+        let expected = Xyz::from_color_unclamped(Srgb::new(0.2, 0.4, 0.8));
+        assert_relative_eq!(xyz, expected, epsilon = 0.0001);
+
+        let (red, green, blue) = DynRgb::from_xyz(xyz, &profile).into_components();
+        assert_relative_eq!(red, 0.2, epsilon = 0.000001);
+        assert_relative_eq!(green, 0.4, epsilon = 0.000001);
+        assert_relative_eq!(blue, 0.8, epsilon = 0.000001);
+    }
+}
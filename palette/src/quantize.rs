@@ -0,0 +1,397 @@
+//! Color quantization, for reducing a set of colors to a small palette.
+//!
+//! The quantizers work on [`Lab`] colors, where the Euclidean distance
+//! between two colors roughly matches how different they look. Colors in
+//! other color spaces can be converted to `Lab` before quantizing them, and
+//! the palette can be converted back afterwards:
+//!
+//! ```
+//! use palette::quantize::{k_means, remap};
+//! use palette::{FromColor, Lab, Srgb};
+//!
+//! let pixels = [
+//!     Srgb::new(250u8, 10, 10),
+//!     Srgb::new(240, 20, 15),
+//!     Srgb::new(10, 10, 240),
+//!     Srgb::new(15, 20, 250),
+//! ];
+//!
+//! let colors: Vec<Lab> = pixels
+//!     .iter()
+//!     .map(|pixel| Lab::from_color(pixel.into_format::<f32>()))
+//!     .collect();
+//!
+//! let palette = k_means(&colors, 2, 10);
+//! let indices = remap(&colors, &palette);
+//!
+//! assert_eq!(palette.len(), 2);
+//! assert_eq!(indices[0], indices[1]);
+//! assert_eq!(indices[2], indices[3]);
+//! assert_ne!(indices[0], indices[2]);
+//!
+//! let reds: Srgb<u8> = Srgb::from_color(palette[indices[0]]).into_format();
+//! assert!(reds.red > 200);
+//! ```
+//!
+//! [`median_cut`] is fast and deterministic, but may not find the best
+//! palette for colors that are unevenly distributed. [`k_means`] refines the
+//! palette from `median_cut` in a number of iterations, which is slower but
+//! gives a palette that matches the colors more closely.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::color_difference::find_nearest;
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, Lab};
+
+/// Reduce `colors` to a palette of at most `max_colors` colors, using the
+/// median cut algorithm.
+///
+/// The colors are divided into boxes, by repeatedly splitting the box with
+/// the widest range of `l`, `a` or `b` values at its median, until there are
+/// `max_colors` boxes or no box can be split further. The palette consists of
+/// the average color of each box.
+///
+/// The palette has fewer than `max_colors` colors if there are fewer unique
+/// colors than that, and is empty if `colors` is empty.
+///
+/// ```
+/// use palette::quantize::median_cut;
+/// use palette::Lab;
+///
+/// let colors = [
+///     Lab::new(10.0, 0.0, 0.0),
+///     Lab::new(20.0, 0.0, 0.0),
+///     Lab::new(80.0, 0.0, 0.0),
+///     Lab::new(90.0, 0.0, 0.0),
+/// ];
+///
+/// let palette = median_cut(&colors, 2);
+/// assert_eq!(palette, vec![Lab::new(15.0, 0.0, 0.0), Lab::new(85.0, 0.0, 0.0)]);
+/// ```
+pub fn median_cut<Wp, T>(colors: &[Lab<Wp, T>], max_colors: usize) -> Vec<Lab<Wp, T>>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    if colors.is_empty() || max_colors == 0 {
+        return Vec::new();
+    }
+
+    let mut colors = colors.to_vec();
+
+    // The boxes are ranges of `colors`, which is reordered when they are split
+    let mut boxes = Vec::with_capacity(max_colors.min(colors.len()));
+    boxes.push(0..colors.len());
+
+    while boxes.len() < max_colors {
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(index, range)| (index, widest_channel(&colors[range.clone()])))
+            .filter(|&(_, (_, width))| width > T::zero())
+            .fold(
+                None,
+                |widest: Option<(usize, (usize, T))>, current| match widest {
+                    Some((_, (_, width))) if width >= (current.1).1 => widest,
+                    _ => Some(current),
+                },
+            );
+
+        let (box_index, (channel, _)) = match widest {
+            Some(widest) => widest,
+            None => break,
+        };
+
+        let range = boxes[box_index].clone();
+        let slice = &mut colors[range.clone()];
+        slice.sort_by(|a, b| {
+            let (a, b) = (channels(a)[channel], channels(b)[channel]);
+            a.partial_cmp(&b).unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        // Split at the boundary between two different values that is closest
+        // to the median, so identical colors end up in the same box
+        let is_boundary =
+            |index: usize| channels(&slice[index - 1])[channel] < channels(&slice[index])[channel];
+        let median = slice.len() / 2;
+        let mut split = median;
+
+        for offset in 0..slice.len() {
+            if median + offset < slice.len() && is_boundary(median + offset) {
+                split = median + offset;
+                break;
+            }
+
+            if offset < median && is_boundary(median - offset) {
+                split = median - offset;
+                break;
+            }
+        }
+
+        boxes[box_index] = range.start..range.start + split;
+        boxes.push(range.start + split..range.end);
+    }
+
+    boxes
+        .into_iter()
+        .map(|range| average(&colors[range]))
+        .collect()
+}
+
+/// Reduce `colors` to a palette of at most `max_colors` colors, using k-means
+/// clustering.
+///
+/// The palette from [`median_cut`] is refined by repeatedly assigning each
+/// color to its nearest palette color and moving the palette colors to the
+/// average of their assigned colors. This stops when the assignments don't
+/// change, or after `max_iterations` iterations.
+///
+/// ```
+/// use palette::quantize::k_means;
+/// use palette::Lab;
+///
+/// let colors = [
+///     Lab::new(10.0, 0.0, 0.0),
+///     Lab::new(20.0, 0.0, 0.0),
+///     Lab::new(30.0, 0.0, 0.0),
+///     Lab::new(90.0, 0.0, 0.0),
+/// ];
+///
+/// let palette = k_means(&colors, 2, 10);
+/// assert_eq!(palette, vec![Lab::new(20.0, 0.0, 0.0), Lab::new(90.0, 0.0, 0.0)]);
+/// ```
+pub fn k_means<Wp, T>(
+    colors: &[Lab<Wp, T>],
+    max_colors: usize,
+    max_iterations: usize,
+) -> Vec<Lab<Wp, T>>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let mut palette = median_cut(colors, max_colors);
+    if palette.is_empty() {
+        return palette;
+    }
+
+    let mut indices = vec![usize::MAX; colors.len()];
+    let mut sums = vec![(Lab::with_wp(T::zero(), T::zero(), T::zero()), 0usize); palette.len()];
+
+    for _ in 0..max_iterations {
+        let mut changed = false;
+
+        for (color, index) in colors.iter().zip(&mut indices) {
+            let nearest = nearest_index(color, &palette);
+            if nearest != *index {
+                *index = nearest;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+
+        for sum in &mut sums {
+            *sum = (Lab::with_wp(T::zero(), T::zero(), T::zero()), 0);
+        }
+
+        for (color, &index) in colors.iter().zip(&indices) {
+            let (sum, count) = &mut sums[index];
+            *sum = *sum + *color;
+            *count += 1;
+        }
+
+        // Palette colors without any assigned colors are left where they are
+        for (color, &(sum, count)) in palette.iter_mut().zip(&sums) {
+            if count > 0 {
+                *color = sum / from_f64::<T>(count as f64);
+            }
+        }
+    }
+
+    palette
+}
+
+/// Find the index of the nearest color in `palette` for each color in
+/// `colors`.
+///
+/// This creates the index buffer for an image with indexed colors, after
+/// finding its palette with [`median_cut`] or [`k_means`]. The distance is
+/// the Euclidean distance in `Lab`, the same as the quantizers use.
+///
+/// # Panics
+///
+/// Panics if `palette` is empty and `colors` is not.
+pub fn remap<Wp, T>(colors: &[Lab<Wp, T>], palette: &[Lab<Wp, T>]) -> Vec<usize>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    colors
+        .iter()
+        .map(|color| nearest_index(color, palette))
+        .collect()
+}
+
+fn nearest_index<Wp, T>(color: &Lab<Wp, T>, palette: &[Lab<Wp, T>]) -> usize
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let (index, _) = find_nearest(color, palette, distance_squared).expect("the palette is empty");
+    index
+}
+
+fn distance_squared<Wp, T>(a: &Lab<Wp, T>, b: &Lab<Wp, T>) -> T
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let (l, a, b) = (a.l - b.l, a.a - b.a, a.b - b.b);
+    l * l + a * a + b * b
+}
+
+fn channels<Wp, T>(color: &Lab<Wp, T>) -> [T; 3]
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    [color.l, color.a, color.b]
+}
+
+/// Returns the index and width of the channel with the widest range.
+fn widest_channel<Wp, T>(colors: &[Lab<Wp, T>]) -> (usize, T)
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let mut min = channels(&colors[0]);
+    let mut max = min;
+
+    for color in &colors[1..] {
+        for (channel, &value) in channels(color).iter().enumerate() {
+            min[channel] = min[channel].min(value);
+            max[channel] = max[channel].max(value);
+        }
+    }
+
+    (0..3)
+        .map(|channel| (channel, max[channel] - min[channel]))
+        .fold((0, T::zero()), |widest, current| {
+            if current.1 > widest.1 {
+                current
+            } else {
+                widest
+            }
+        })
+}
+
+fn average<Wp, T>(colors: &[Lab<Wp, T>]) -> Lab<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let sum = colors.iter().fold(
+        Lab::with_wp(T::zero(), T::zero(), T::zero()),
+        |sum, &color| sum + color,
+    );
+
+    sum / from_f64::<T>(colors.len() as f64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{k_means, median_cut, remap};
+    use crate::white_point::D65;
+    use crate::Lab;
+
+    fn gradient() -> Vec<Lab<D65, f64>> {
+        (0..100)
+            .map(|i| Lab::new(i as f64, (i % 10) as f64, 0.0))
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let colors: [Lab; 0] = [];
+        assert!(median_cut(&colors, 4).is_empty());
+        assert!(k_means(&colors, 4, 10).is_empty());
+        assert!(remap(&colors, &[]).is_empty());
+        assert!(median_cut(&[Lab::new(1.0, 2.0, 3.0)], 0).is_empty());
+    }
+
+    #[test]
+    fn fewer_unique_colors() {
+        let colors = [
+            Lab::new(50.0, 10.0, 10.0),
+            Lab::new(50.0, 10.0, 10.0),
+            Lab::new(20.0, 0.0, 0.0),
+        ];
+
+        let palette = median_cut(&colors, 16);
+        assert_eq!(
+            palette,
+            vec![Lab::new(20.0, 0.0, 0.0), Lab::new(50.0, 10.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn median_cut_size() {
+        let colors = gradient();
+
+        for &max_colors in &[1, 2, 5, 16, 100] {
+            let palette = median_cut(&colors, max_colors);
+            assert_eq!(palette.len(), max_colors);
+        }
+
+        assert_eq!(median_cut(&colors, 1), vec![Lab::new(49.5, 4.5, 0.0)]);
+    }
+
+    #[test]
+    fn k_means_improves_palette() {
+        let colors: Vec<Lab<D65, f64>> = [0.0, 10.0, 20.0, 30.0, 100.0]
+            .iter()
+            .map(|&l| Lab::new(l, 0.0, 0.0))
+            .collect();
+
+        let error = |palette: &[Lab<D65, f64>]| -> f64 {
+            let indices = remap(&colors, palette);
+            colors
+                .iter()
+                .zip(indices)
+                .map(|(color, index)| (color.l - palette[index].l).powi(2))
+                .sum()
+        };
+
+        let median_cut_palette = median_cut(&colors, 2);
+        let k_means_palette = k_means(&colors, 2, 10);
+
+        assert_eq!(
+            median_cut_palette,
+            vec![Lab::new(5.0, 0.0, 0.0), Lab::new(50.0, 0.0, 0.0)]
+        );
+        assert_eq!(
+            k_means_palette,
+            vec![Lab::new(15.0, 0.0, 0.0), Lab::new(100.0, 0.0, 0.0)]
+        );
+        assert!(error(&k_means_palette) < error(&median_cut_palette));
+
+        // Stopping early gives the median cut palette
+        assert_eq!(k_means(&colors, 2, 0), median_cut_palette);
+    }
+
+    #[test]
+    fn remap_to_nearest() {
+        let palette = [Lab::new(0.0, 0.0, 0.0), Lab::new(100.0, 0.0, 0.0)];
+        let colors = [
+            Lab::new(10.0, 5.0, 5.0),
+            Lab::new(90.0, -5.0, 0.0),
+            Lab::new(40.0, 0.0, 0.0),
+        ];
+
+        assert_eq!(remap(&colors, &palette), vec![0, 1, 0]);
+    }
+}
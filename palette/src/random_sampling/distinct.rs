@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// The number of rejected samples in a row before giving up on finding
+/// another color.
+const MAX_ATTEMPTS: usize = 1000;
+
+/// Sample up to `count` random colors from `distribution`, where every pair of
+/// colors is at least `min_distance` apart, according to `metric`.
+///
+/// This is useful for picking colors that are easy to tell apart, such as the
+/// category colors of a chart. Candidate colors that are too close to an
+/// already picked color are rejected, similar to Poisson disk sampling, so
+/// the metric should be perceptual for the distance to be meaningful. A good
+/// choice is to sample [`Lab`](crate::Lab) colors with
+/// [`InGamut`](crate::InGamut), and use the
+/// [`ColorDifference`](crate::ColorDifference) of `Lab` as the metric.
+///
+/// Fewer than `count` colors are returned if no more colors can be found,
+/// after a large number of rejected candidates in a row. That happens when
+/// `min_distance` is too large for `count` colors to fit.
+///
+/// ```no_run
+/// use palette::{random_distinct, ColorDifference, FromColor, InGamut, Lab, Srgb};
+/// use rand::Rng;
+///
+/// fn chart_colors<R: Rng>(rng: &mut R) -> Vec<Srgb> {
+///     let colors = random_distinct(
+///         rng,
+///         &InGamut::<palette::encoding::Srgb>::new(),
+///         8,
+///         20.0,
+///         |a: &Lab, b: &Lab| a.get_color_difference(b),
+///     );
+///
+///     colors.into_iter().map(Srgb::from_color).collect()
+/// }
+/// ```
+pub fn random_distinct<C, D, R, T, F>(
+    rng: &mut R,
+    distribution: &D,
+    count: usize,
+    min_distance: T,
+    mut metric: F,
+) -> Vec<C>
+where
+    D: Distribution<C>,
+    R: Rng + ?Sized,
+    T: PartialOrd,
+    F: FnMut(&C, &C) -> T,
+{
+    let mut colors = Vec::with_capacity(count);
+    let mut attempts = 0;
+
+    while colors.len() < count && attempts < MAX_ATTEMPTS {
+        let candidate = distribution.sample(rng);
+        let is_distinct = colors
+            .iter()
+            .all(|color| metric(color, &candidate) >= min_distance);
+
+        if is_distinct {
+            colors.push(candidate);
+            attempts = 0;
+        } else {
+            attempts += 1;
+        }
+    }
+
+    colors
+}
+
+#[cfg(test)]
+mod test {
+    use super::random_distinct;
+    use crate::random_sampling::InGamut;
+    use crate::{ColorDifference, Lab};
+
+    #[test]
+    fn colors_are_distinct() {
+        let mut rng = rand_mt::Mt::new(1234); // We want the same seed on every run to avoid random fails
+        let distribution = InGamut::<crate::encoding::Srgb>::new();
+
+        let colors = random_distinct(&mut rng, &distribution, 10, 15.0, |a: &Lab, b: &Lab| {
+            a.get_color_difference(b)
+        });
+
+        assert_eq!(colors.len(), 10);
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert!(a.get_color_difference(b) >= 15.0);
+            }
+        }
+    }
+
+    #[test]
+    fn too_many_colors() {
+        let mut rng = rand_mt::Mt::new(1234); // We want the same seed on every run to avoid random fails
+        let distribution = InGamut::<crate::encoding::Srgb>::new();
+
+        let colors = random_distinct(&mut rng, &distribution, 1000, 50.0, |a: &Lab, b: &Lab| {
+            a.get_color_difference(b)
+        });
+
+        assert!(!colors.is_empty());
+        assert!(colors.len() < 1000);
+    }
+}
@@ -0,0 +1,192 @@
+use core::marker::PhantomData;
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Srgb;
+use crate::rgb::{Rgb, RgbSpace, RgbStandard};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, Alpha, Component, FloatComponent, Hsl, Hsv, Hwb, Lab, Lch, Limited, Xyz};
+
+/// A distribution that samples colors uniformly within the gamut of the RGB
+/// standard `S`.
+///
+/// The [`Standard`] distribution samples [`Hsl`], [`Hsv`] and [`Hwb`] colors
+/// uniformly within their cones, and [`Lch`] colors uniformly within a
+/// cylinder. That's not the same as uniformly within the colors they
+/// represent, and random palettes tend to over-represent dark or saturated
+/// colors. `InGamut` samples them uniformly with respect to the RGB cube of
+/// `S` instead, and samples [`Lab`] and [`Lch`] colors uniformly within the
+/// volume the gamut of `S` takes up in L\*a\*b\*.
+///
+/// Lab and Lch colors are sampled by rejecting colors outside the gamut,
+/// which takes a few attempts on average. Colors with an alpha component get
+/// a uniformly distributed alpha, as with [`Standard`].
+///
+/// ```no_run
+/// use palette::{Hsl, InGamut, Lch};
+/// use rand::Rng;
+///
+/// fn random_colors<R: Rng>(rng: &mut R) -> (Vec<Hsl>, Vec<Lch>) {
+///     let in_gamut = InGamut::<palette::encoding::Srgb>::new();
+///     let hsl = (0..10).map(|_| rng.sample(in_gamut)).collect();
+///     let lch = (0..10).map(|_| rng.sample(in_gamut)).collect();
+///
+///     (hsl, lch)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct InGamut<S = Srgb> {
+    standard: PhantomData<S>,
+}
+
+impl<S: RgbStandard> InGamut<S> {
+    /// Create a distribution for the gamut of `S`.
+    pub fn new() -> Self {
+        InGamut {
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S: RgbStandard> Default for InGamut<S> {
+    fn default() -> Self {
+        InGamut::new()
+    }
+}
+
+impl<S, T> Distribution<Hsl<S, T>> for InGamut<S>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Hsl<S, T> {
+        Hsl::from_color_unclamped(rng.gen::<Rgb<S, T>>())
+    }
+}
+
+impl<S, T> Distribution<Hsv<S, T>> for InGamut<S>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Hsv<S, T> {
+        Hsv::from_color_unclamped(rng.gen::<Rgb<S, T>>())
+    }
+}
+
+impl<S, T> Distribution<Hwb<S, T>> for InGamut<S>
+where
+    T: FloatComponent,
+    S: RgbStandard,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Hwb<S, T> {
+        Hwb::from_color_unclamped(rng.gen::<Rgb<S, T>>())
+    }
+}
+
+impl<S, Wp, T> Distribution<Lab<Wp, T>> for InGamut<S>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+    S: RgbStandard,
+    S::Space: RgbSpace<WhitePoint = Wp>,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Lab<Wp, T> {
+        loop {
+            let lab = Lab::with_wp(
+                rng.gen::<T>() * from_f64(100.0),
+                rng.gen::<T>() * from_f64(256.0) - from_f64(128.0),
+                rng.gen::<T>() * from_f64(256.0) - from_f64(128.0),
+            );
+
+            let rgb = Rgb::<S, T>::from_color_unclamped(Xyz::from_color_unclamped(lab));
+            if rgb.is_valid() {
+                return lab;
+            }
+        }
+    }
+}
+
+impl<S, Wp, T> Distribution<Lch<Wp, T>> for InGamut<S>
+where
+    T: FloatComponent,
+    Wp: WhitePoint,
+    S: RgbStandard,
+    S::Space: RgbSpace<WhitePoint = Wp>,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Lch<Wp, T> {
+        Lch::from_color_unclamped(Distribution::<Lab<Wp, T>>::sample(self, rng))
+    }
+}
+
+impl<S, C, T> Distribution<Alpha<C, T>> for InGamut<S>
+where
+    T: Component,
+    InGamut<S>: Distribution<C>,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Alpha<C, T> {
+        Alpha {
+            color: Distribution::<C>::sample(self, rng),
+            alpha: rng.gen(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::Rng;
+
+    use super::InGamut;
+    use crate::convert::FromColorUnclamped;
+    use crate::{Hsl, Hsv, Lch, Lcha, Limited, Srgb};
+
+    #[test]
+    fn hsl_and_hsv_are_uniform_in_rgb() {
+        const BINS: usize = crate::random_sampling::test_utils::BINS;
+        const SAMPLES: usize = crate::random_sampling::test_utils::SAMPLES;
+
+        let mut red = [0; BINS];
+        let mut blue = [0; BINS];
+
+        let mut rng = rand_mt::Mt::new(1234); // We want the same seed on every run to avoid random fails
+        let distribution = InGamut::new();
+
+        for _ in 0..SAMPLES {
+            let hsl: Hsl = rng.sample(distribution);
+            let hsv: Hsv = rng.sample(distribution);
+            let hsl = Srgb::from_color_unclamped(hsl);
+            let hsv = Srgb::from_color_unclamped(hsv);
+
+            red[((hsl.red * BINS as f32) as usize).min(BINS - 1)] += 1;
+            blue[((hsv.blue * BINS as f32) as usize).min(BINS - 1)] += 1;
+        }
+
+        assert_uniform_distribution!(red);
+        assert_uniform_distribution!(blue);
+    }
+
+    #[test]
+    fn lch_is_in_gamut() {
+        let mut rng = rand_mt::Mt::new(1234); // We want the same seed on every run to avoid random fails
+        let distribution = InGamut::<crate::encoding::Srgb>::new();
+
+        for _ in 0..1000 {
+            let color: Lch = rng.sample(distribution);
+            let rgb = Srgb::from_color_unclamped(color);
+            assert!(rgb.is_valid(), "{:?} is out of gamut", rgb);
+
+            let color: Lcha = rng.sample(distribution);
+            let rgb = Srgb::from_color_unclamped(color.color);
+            assert!(rgb.is_valid(), "{:?} is out of gamut", rgb);
+            assert!(color.alpha >= 0.0 && color.alpha <= 1.0);
+        }
+    }
+}
@@ -1,6 +1,12 @@
 mod cone;
+#[cfg(feature = "alloc")]
+mod distinct;
+mod in_gamut;
 
 pub use self::cone::*;
+#[cfg(feature = "alloc")]
+pub use self::distinct::random_distinct;
+pub use self::in_gamut::InGamut;
 
 #[cfg(test)]
 pub(crate) mod test_utils {
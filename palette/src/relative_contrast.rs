@@ -1,7 +1,8 @@
 use core::ops::{Add, Div};
 
 use crate::component::Component;
-use crate::{from_f64, FromF64};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, FloatComponent, FromF64, IntoColor, Xyz};
 
 /// A trait for calculating relative contrast between two colors.
 ///
@@ -40,6 +41,25 @@ use crate::{from_f64, FromF64};
 /// color both in sRGB linear space. A higher contrast ratio is generally
 /// desireable.
 ///
+/// `RelativeContrast` is implemented for all colors that implement
+/// [`RelativeLuminance`], which is every color type that can be converted to
+/// [`Xyz`]. The luminance is the `y` component of the converted color, so the
+/// contrast ratio is the same in every color space:
+///
+/// ```rust
+/// use approx::assert_relative_eq;
+///
+/// use palette::{FromColor, Lch, RelativeContrast, Srgb};
+///
+/// let background = Srgb::new(0.2f32, 0.2, 0.2);
+/// let foreground = Srgb::new(0.85f32, 0.85, 0.9);
+/// let ratio = background.get_contrast_ratio(&foreground);
+///
+/// let background = Lch::from_color(background);
+/// let foreground = Lch::from_color(foreground);
+/// assert_relative_eq!(background.get_contrast_ratio(&foreground), ratio, epsilon = 0.0001);
+/// ```
+///
 /// For more details, visit the following links:
 ///
 /// [Success Criterion 1.4.3 Contrast (Minimum) (Level AA)](https://www.w3.org/WAI/WCAG21/Understanding/contrast-minimum)
@@ -81,6 +101,70 @@ pub trait RelativeContrast {
     }
 }
 
+impl<C> RelativeContrast for C
+where
+    C: RelativeLuminance + IntoColor<Xyz<C::WhitePoint, C::Scalar>> + Copy,
+{
+    type Scalar = C::Scalar;
+
+    fn get_contrast_ratio(&self, other: &Self) -> Self::Scalar {
+        contrast_ratio(self.relative_luminance(), other.relative_luminance())
+    }
+}
+
+/// A color with a relative luminance, that can be calculated by converting it
+/// to [`Xyz`].
+///
+/// The associated types are the white point and component type of the
+/// converted color. This is implemented for all color types that can be
+/// converted to `Xyz`, and gives them a [`RelativeContrast`] implementation.
+/// Custom color types can implement it in the same way:
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// use palette::convert::FromColorUnclamped;
+/// use palette::white_point::D65;
+/// use palette::{RelativeContrast, RelativeLuminance, Xyz};
+///
+/// #[derive(Clone, Copy)]
+/// struct Gray(f32);
+///
+/// impl FromColorUnclamped<Gray> for Xyz<D65, f32> {
+///     fn from_color_unclamped(gray: Gray) -> Self {
+///         Xyz::new(0.95047 * gray.0, gray.0, 1.08883 * gray.0)
+///     }
+/// }
+///
+/// impl RelativeLuminance for Gray {
+///     type WhitePoint = D65;
+///     type Scalar = f32;
+/// }
+///
+/// assert_relative_eq!(Gray(0.5).relative_luminance(), 0.5);
+/// assert_relative_eq!(Gray(1.0).get_contrast_ratio(&Gray(0.0)), 21.0, epsilon = 0.0001);
+/// ```
+pub trait RelativeLuminance {
+    /// The white point of the converted color.
+    type WhitePoint: WhitePoint;
+
+    /// The component type of the converted color, and of the luminance.
+    type Scalar: FloatComponent;
+
+    /// Calculate the relative luminance of the color, which is the `y`
+    /// component of the color when converted to [`Xyz`].
+    ///
+    /// The luminance is relative to the white point, where 0 is black and 1
+    /// is white. Colors outside the valid range of `Xyz` are clamped first.
+    fn relative_luminance(&self) -> Self::Scalar
+    where
+        Self: IntoColor<Xyz<Self::WhitePoint, Self::Scalar>> + Copy,
+    {
+        let xyz: Xyz<Self::WhitePoint, Self::Scalar> = (*self).into_color();
+        xyz.y
+    }
+}
+
 /// Calculate the ratio between two `luma` values.
 pub fn contrast_ratio<T>(luma1: T, luma2: T) -> T
 where
@@ -93,12 +177,152 @@ where
     }
 }
 
+/// A trait for calculating the APCA lightness contrast between a text color
+/// and a background color.
+///
+/// The Accessible Perceptual Contrast Algorithm (APCA) is the contrast method
+/// in the working draft of W3C's WCAG 3. It's based on how the perceived
+/// contrast depends on the lightness of the colors and on which one is the
+/// darker, and it ranks many color pairs better than the contrast ratio of
+/// [`RelativeContrast`], which overstates the contrast of dark colors.
+///
+/// The lightness contrast, `Lc`, is roughly between -108 and 106. It's
+/// positive for dark text on a light background, negative for light text on
+/// a dark background, and 0 when the colors are too similar to be readable.
+/// The sign only tells the polarity, and the `has_*` methods compare the
+/// absolute value to a minimum:
+///
+/// ```
+/// use palette::{ApcaContrast, Srgb};
+///
+/// let text = Srgb::new(0x88u8, 0x88, 0x88).into_format::<f32>();
+/// let background = Srgb::new(1.0, 1.0, 1.0);
+///
+/// let contrast = text.get_apca_contrast(&background);
+/// assert!(contrast > 63.0 && contrast < 63.1);
+/// assert!(text.has_apca_content_text_contrast(&background));
+/// assert!(!text.has_apca_body_text_contrast(&background));
+/// ```
+///
+/// APCA is made for colors on sRGB displays, so it's only implemented for
+/// colors in the sRGB color space. The screen luminance of the colors is
+/// estimated with the simple 2.4 exponent that APCA specifies, instead of the
+/// sRGB transfer function, to match its reference implementation.
+///
+/// The minimums are from the simplified guidelines for APCA, and depend on
+/// the size and weight of the text. Like with the WCAG 2 contrast ratio,
+/// they're recommendations and not a replacement for looking at the colors.
+/// WCAG 3 is still a draft, so both the algorithm and the guidelines may
+/// change.
+///
+/// For more details, visit the [APCA
+/// documentation](https://git.apcacontrast.com/documentation/APCA_in_a_Nutshell).
+pub trait ApcaContrast {
+    /// The type of the lightness contrast.
+    type Scalar: FromF64 + PartialOrd;
+
+    /// Calculate the lightness contrast of text in this color, on a
+    /// background in the `background` color.
+    ///
+    /// Unlike the WCAG 2 contrast ratio, the result depends on which color is
+    /// the text and which is the background.
+    fn get_apca_contrast(&self, background: &Self) -> Self::Scalar;
+
+    /// Verify the contrast is enough for body text, and other columns of
+    /// text. Contrast is at least Lc 75.
+    fn has_apca_body_text_contrast(&self, background: &Self) -> bool {
+        has_apca_contrast(self.get_apca_contrast(background), 75.0)
+    }
+
+    /// Verify the contrast is enough for content text that isn't body text,
+    /// such as shorter text at a larger size. Contrast is at least Lc 60.
+    fn has_apca_content_text_contrast(&self, background: &Self) -> bool {
+        has_apca_contrast(self.get_apca_contrast(background), 60.0)
+    }
+
+    /// Verify the contrast is enough for large and bold text, such as
+    /// headlines. Contrast is at least Lc 45.
+    fn has_apca_large_text_contrast(&self, background: &Self) -> bool {
+        has_apca_contrast(self.get_apca_contrast(background), 45.0)
+    }
+
+    /// Verify the contrast is enough for graphical objects and other non-text
+    /// elements that need to be understood. Contrast is at least Lc 30.
+    fn has_apca_graphics_contrast(&self, background: &Self) -> bool {
+        has_apca_contrast(self.get_apca_contrast(background), 30.0)
+    }
+}
+
+fn has_apca_contrast<T: FromF64 + PartialOrd>(contrast: T, minimum: f64) -> bool {
+    contrast >= from_f64(minimum) || contrast <= from_f64(-minimum)
+}
+
+/// Calculate the APCA lightness contrast between the estimated screen
+/// luminance of a text color and a background color.
+///
+/// The screen luminance is estimated from the nonlinear sRGB components, by
+/// raising each of them to the power of 2.4 and weighting them with the
+/// luminance of the sRGB primaries. See [`ApcaContrast`] for more details.
+pub fn apca_contrast<T: FloatComponent>(text_luminance: T, background_luminance: T) -> T {
+    // The constants of APCA version 0.0.98G
+    let soft_clamp = |luminance: T| {
+        let black_threshold = from_f64(0.022);
+        if luminance > black_threshold {
+            luminance
+        } else {
+            luminance + (black_threshold - luminance).powf(from_f64(1.414))
+        }
+    };
+
+    let text = soft_clamp(text_luminance.max(T::zero()));
+    let background = soft_clamp(background_luminance.max(T::zero()));
+
+    if (background - text).abs() < from_f64(0.0005) {
+        return T::zero();
+    }
+
+    let scale = from_f64::<T>(1.14);
+    let contrast = if background > text {
+        // Dark text on a light background
+        let contrast = (background.powf(from_f64(0.56)) - text.powf(from_f64(0.57))) * scale;
+
+        if contrast < from_f64(0.1) {
+            T::zero()
+        } else {
+            contrast - from_f64(0.027)
+        }
+    } else {
+        // Light text on a dark background
+        let contrast = (background.powf(from_f64(0.65)) - text.powf(from_f64(0.62))) * scale;
+
+        if contrast > from_f64(-0.1) {
+            T::zero()
+        } else {
+            contrast + from_f64(0.027)
+        }
+    };
+
+    contrast * from_f64(100.0)
+}
+
+/// Estimate the screen luminance of nonlinear sRGB components, the way APCA
+/// does it.
+pub(crate) fn apca_screen_luminance<T: FloatComponent>(red: T, green: T, blue: T) -> T {
+    let exponent = from_f64(2.4);
+    let linearize = |component: T| component.max(T::zero()).powf(exponent);
+
+    linearize(red) * from_f64(0.2126729)
+        + linearize(green) * from_f64(0.7151522)
+        + linearize(blue) * from_f64(0.0721750)
+}
+
 #[cfg(test)]
 mod test {
     use core::str::FromStr;
 
-    use crate::RelativeContrast;
-    use crate::Srgb;
+    use crate::white_point::{D50, D65};
+    use crate::{ApcaContrast, RelativeContrast, RelativeLuminance, SrgbLuma};
+    use crate::{FromColor, Hsl, Hwb, Lab, Lch, LinLuma, Srgb, Xyz, Yxy};
 
     #[test]
     fn relative_contrast() {
@@ -138,4 +362,66 @@ mod test {
         assert_relative_eq!(c1.get_contrast_ratio(&white), 1.22, epsilon = 0.01);
         assert_relative_eq!(c1.get_contrast_ratio(&black), 17.11, epsilon = 0.01);
     }
+
+    #[test]
+    fn all_color_types() {
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        let color = Srgb::from_str("#600").unwrap().into_format();
+        let ratio = color.get_contrast_ratio(&white);
+
+        macro_rules! assert_same_ratio {
+            ($($ty: ty),+) => {
+                $(
+                    let converted_color = <$ty>::from_color(color);
+                    let converted_white = <$ty>::from_color(white);
+                    assert_relative_eq!(
+                        converted_color.get_contrast_ratio(&converted_white),
+                        ratio,
+                        epsilon = 0.0001
+                    );
+                )+
+            };
+        }
+
+        assert_same_ratio!(Hsl, Hwb, Lab, Lch, Xyz, Yxy);
+
+        // The luminance is relative to the white point
+        let d50_white: Lab<D50> = Lab::with_wp(100.0, 0.0, 0.0);
+        assert_relative_eq!(d50_white.relative_luminance(), 1.0, epsilon = 0.0001);
+
+        let gray: LinLuma<D65, f64> = LinLuma::new(0.25);
+        let luma_white: LinLuma<D65, f64> = LinLuma::new(1.0);
+        assert_relative_eq!(gray.relative_luminance(), 0.25);
+        assert_relative_eq!(gray.get_contrast_ratio(&luma_white), 3.5);
+    }
+
+    #[test]
+    fn apca_contrast() {
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+
+        assert_relative_eq!(black.get_apca_contrast(&white), 106.04, epsilon = 0.01);
+        assert_relative_eq!(white.get_apca_contrast(&black), -107.88, epsilon = 0.01);
+        assert_eq!(white.get_apca_contrast(&white), 0.0);
+
+        let gray = Srgb::new(0x88u8, 0x88, 0x88).into_format();
+        assert_relative_eq!(gray.get_apca_contrast(&white), 63.06, epsilon = 0.01);
+        assert_relative_eq!(white.get_apca_contrast(&gray), -68.54, epsilon = 0.01);
+
+        assert!(black.has_apca_body_text_contrast(&white));
+        assert!(white.has_apca_body_text_contrast(&black));
+        assert!(!gray.has_apca_body_text_contrast(&white));
+        assert!(gray.has_apca_content_text_contrast(&white));
+        assert!(gray.has_apca_large_text_contrast(&white));
+        assert!(gray.has_apca_graphics_contrast(&white));
+
+        // Gray is the same as the equivalent RGB color
+        let luma_gray = SrgbLuma::new(0x88u8).into_format::<f64>();
+        let luma_white = SrgbLuma::new(1.0f64);
+        assert_relative_eq!(
+            luma_gray.get_apca_contrast(&luma_white),
+            gray.get_apca_contrast(&white),
+            epsilon = 0.001
+        );
+    }
 }
@@ -5,9 +5,11 @@ use crate::white_point::WhitePoint;
 use crate::{Component, FloatComponent, FromComponent, Yxy};
 
 pub use self::packed::{channels, Packed, RgbChannels};
-pub use self::rgb::{Rgb, Rgba};
+pub use self::packed_formats::{Rgb10A2, Rgb555, Rgb565};
+pub use self::rgb::{FromHexError, Rgb, Rgba};
 
 mod packed;
+mod packed_formats;
 mod rgb;
 
 /// Nonlinear sRGB.
@@ -25,6 +27,58 @@ pub type GammaSrgb<T = f32> = Rgb<Gamma<encoding::Srgb>, T>;
 /// Gamma 2.2 encoded sRGB with an alpha component.
 pub type GammaSrgba<T = f32> = Rgba<Gamma<encoding::Srgb>, T>;
 
+/// Create an [`Srgb<u8>`](crate::Srgb) constant from a hex color code in
+/// the `0xRRGGBB` format.
+///
+/// The color is created at compile time, so it can be used in constants and
+/// static tables, and it's a compile error if the code has more than six
+/// hexadecimal digits.
+///
+/// ```
+/// use palette::{srgb, Srgb};
+///
+/// const THEME: [Srgb<u8>; 2] = [srgb!(0x1E90FF), srgb!(0xFF6347)];
+/// assert_eq!(THEME[1], Srgb::new(255, 99, 71));
+/// ```
+///
+/// ```compile_fail
+/// let color = palette::srgb!(0x1E90FF00);
+/// ```
+#[macro_export]
+macro_rules! srgb {
+    ($color: expr) => {{
+        const COLOR: $crate::Srgb<u8> = {
+            let color: u32 = $color;
+            assert!(
+                color <= 0xFF_FFFF,
+                "the color code has more than six digits"
+            );
+            $crate::Srgb::<u8>::from_rgb_u32(color)
+        };
+        COLOR
+    }};
+}
+
+/// Create an [`Srgba<u8>`](crate::Srgba) constant from a hex color code in
+/// the `0xRRGGBBAA` format.
+///
+/// The color is created at compile time, so it can be used in constants and
+/// static tables.
+///
+/// ```
+/// use palette::{srgba, Srgba};
+///
+/// const OVERLAY: Srgba<u8> = srgba!(0x1E90FF80);
+/// assert_eq!(OVERLAY, Srgba::new(30, 144, 255, 128));
+/// ```
+#[macro_export]
+macro_rules! srgba {
+    ($color: expr) => {{
+        const COLOR: $crate::Srgba<u8> = $crate::Srgba::<u8>::from_rgba_u32($color);
+        COLOR
+    }};
+}
+
 /// An RGB space and a transfer function.
 pub trait RgbStandard: 'static {
     /// The RGB color space.
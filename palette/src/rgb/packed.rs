@@ -103,6 +103,36 @@ impl<C: RgbChannels> From<u32> for Packed<C> {
     }
 }
 
+impl<C: RgbChannels> From<Packed<C>> for u32 {
+    fn from(packed: Packed<C>) -> Self {
+        packed.color
+    }
+}
+
+/// Convert from the channel bytes, in the order of `C`, as they are laid out
+/// in memory in interleaved formats.
+///
+/// ```
+/// use palette::{Packed, Srgba};
+/// use palette::rgb::channels::Bgra;
+///
+/// let color: Srgba<u8> = Packed::<Bgra>::from([0u8, 128, 255, 64]).into();
+/// assert_eq!(color, Srgba::new(255, 128, 0, 64));
+/// ```
+impl<C: RgbChannels> From<[u8; 4]> for Packed<C> {
+    fn from(bytes: [u8; 4]) -> Self {
+        Self::from(u32::from_be_bytes(bytes))
+    }
+}
+
+/// Convert into the channel bytes, in the order of `C`, as they are laid out
+/// in memory in interleaved formats.
+impl<C: RgbChannels> From<Packed<C>> for [u8; 4] {
+    fn from(packed: Packed<C>) -> Self {
+        packed.color.to_be_bytes()
+    }
+}
+
 impl<S, C> From<Rgb<S, u8>> for Packed<C>
 where
     S: RgbStandard,
@@ -330,4 +360,40 @@ mod test {
         assert_eq!(0xFFFF_FF80, u32::from(Srgb::new(255u8, 255, 128)));
         assert_eq!(0x7FFF_FF80, u32::from(Srgba::new(127u8, 255u8, 255, 128)));
     }
+
+    #[test]
+    fn packed_to_u32() {
+        let packed: Packed<Argb> = Srgba::new(0x11u8, 0x22, 0x33, 0x44).into();
+        assert_eq!(u32::from(packed), 0x4411_2233);
+
+        let packed: Packed<Bgra> = Srgb::new(0x11u8, 0x22, 0x33).into();
+        assert_eq!(u32::from(packed), 0x3322_11FF);
+    }
+
+    #[test]
+    fn bytes() {
+        let color = Srgba::new(0x11u8, 0x22, 0x33, 0x44);
+
+        assert_eq!(
+            <[u8; 4]>::from(Packed::<Rgba>::from(color)),
+            [0x11, 0x22, 0x33, 0x44]
+        );
+        assert_eq!(
+            <[u8; 4]>::from(Packed::<Argb>::from(color)),
+            [0x44, 0x11, 0x22, 0x33]
+        );
+        assert_eq!(
+            <[u8; 4]>::from(Packed::<Bgra>::from(color)),
+            [0x33, 0x22, 0x11, 0x44]
+        );
+        assert_eq!(
+            <[u8; 4]>::from(Packed::<Abgr>::from(color)),
+            [0x44, 0x33, 0x22, 0x11]
+        );
+
+        let unpacked: Srgba<u8> = Packed::<Bgra>::from([0x33, 0x22, 0x11, 0x44]).into();
+        assert_eq!(unpacked, color);
+        let unpacked: Srgba<u8> = Packed::<Argb>::from([0x44, 0x11, 0x22, 0x33]).into();
+        assert_eq!(unpacked, color);
+    }
 }
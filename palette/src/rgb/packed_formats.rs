@@ -0,0 +1,262 @@
+use crate::rgb::{Rgb, RgbStandard, Rgba};
+use crate::{Component, FromComponent, IntoComponent, Pixel};
+
+/// RGB color packed into 16 bits, with 5 bits for red, 6 bits for green and
+/// 5 bits for blue.
+///
+/// Red is stored in the most significant bits, as `0bRRRRRGGGGGGBBBBB`. This
+/// is a common format for framebuffers of embedded displays.
+///
+/// Colors with any RGB standard and component type can be converted to and
+/// from `Rgb565`. The components are scaled and rounded to the nearest
+/// representable value, and converting an `Rgb565` to `Rgb<S, u8>` and back
+/// gives the same value.
+///
+/// ```
+/// use palette::Srgb;
+/// use palette::rgb::Rgb565;
+///
+/// let packed = Rgb565::from(Srgb::new(255u8, 128, 0));
+/// assert_eq!(packed.color, 0b11111_100000_00000);
+///
+/// let color: Srgb<u8> = packed.into();
+/// assert_eq!(color, Srgb::new(255, 130, 0));
+/// ```
+///
+/// `Rgb565` implements [`Pixel`](crate::Pixel), so a `&[u16]` buffer can be
+/// viewed as a slice of `Rgb565` colors.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Pixel)]
+#[palette(palette_internal)]
+#[repr(C)]
+pub struct Rgb565 {
+    /// The packed color.
+    pub color: u16,
+}
+
+/// RGB color packed into 16 bits, with 5 bits for each of red, green and
+/// blue.
+///
+/// Red is stored in the most significant bits, after one unused bit, as
+/// `0bXRRRRRGGGGGBBBBB`. The unused bit is always 0 when converting from
+/// other colors, and ignored when converting to them.
+///
+/// See [`Rgb565`] for more details about conversions.
+///
+/// ```
+/// use palette::Srgb;
+/// use palette::rgb::Rgb555;
+///
+/// let packed = Rgb555::from(Srgb::new(1.0, 0.5, 0.0));
+/// assert_eq!(packed.color, 0b0_11111_10000_00000);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Pixel)]
+#[palette(palette_internal)]
+#[repr(C)]
+pub struct Rgb555 {
+    /// The packed color.
+    pub color: u16,
+}
+
+/// RGBA color packed into 32 bits, with 10 bits for each of red, green and
+/// blue, and 2 bits for alpha.
+///
+/// Red is stored in the least significant bits and alpha in the most
+/// significant bits, as `0bAABBBBBBBBBBGGGGGGGGGGRRRRRRRRRR`. This matches
+/// the `R10G10B10A2` and `A2B10G10R10` texture formats of graphics APIs.
+///
+/// See [`Rgb565`] for more details about conversions. Converting from an
+/// `Rgb` color, without alpha, makes the color fully opaque, and converting
+/// into one ignores the alpha bits.
+///
+/// ```
+/// use palette::{Srgb, Srgba};
+/// use palette::rgb::Rgb10A2;
+///
+/// let packed = Rgb10A2::from(Srgba::new(1.0, 0.0, 0.5, 1.0));
+/// assert_eq!(packed.color, 0b11_1000000000_0000000000_1111111111);
+///
+/// let color: Srgb<u16> = packed.into();
+/// assert_eq!(color, Srgb::new(65535, 0, 32800));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Pixel)]
+#[palette(palette_internal)]
+#[repr(C)]
+pub struct Rgb10A2 {
+    /// The packed color.
+    pub color: u32,
+}
+
+/// Scale and round a component to an integer with `bits` bits.
+fn pack<T>(component: T, bits: u32) -> u32
+where
+    T: Component + IntoComponent<f64>,
+{
+    let max = ((1u32 << bits) - 1) as f64;
+    let value: f64 = component.into_component();
+    let value = if value > 1.0 {
+        1.0
+    } else if value > 0.0 {
+        value
+    } else {
+        // This also catches NaN
+        0.0
+    };
+
+    (value * max + 0.5) as u32
+}
+
+/// Scale an integer with `bits` bits to a component.
+fn unpack<T>(value: u32, bits: u32) -> T
+where
+    T: Component + FromComponent<f64>,
+{
+    let max = (1u32 << bits) - 1;
+    T::from_component(f64::from(value & max) / f64::from(max))
+}
+
+macro_rules! impl_packed_rgb {
+    ($ty: ident, $int: ty, [$red_bits: expr, $green_bits: expr, $blue_bits: expr], $opaque: expr) => {
+        impl From<$int> for $ty {
+            fn from(color: $int) -> Self {
+                $ty { color }
+            }
+        }
+
+        impl From<$ty> for $int {
+            fn from(packed: $ty) -> Self {
+                packed.color
+            }
+        }
+
+        impl<S, T> From<Rgb<S, T>> for $ty
+        where
+            S: RgbStandard,
+            T: Component + IntoComponent<f64>,
+        {
+            fn from(color: Rgb<S, T>) -> Self {
+                let red = pack(color.red, $red_bits.0);
+                let green = pack(color.green, $green_bits.0);
+                let blue = pack(color.blue, $blue_bits.0);
+                let color = red << $red_bits.1 | green << $green_bits.1 | blue << $blue_bits.1;
+
+                $ty {
+                    color: (color | $opaque) as $int,
+                }
+            }
+        }
+
+        impl<S, T> From<$ty> for Rgb<S, T>
+        where
+            S: RgbStandard,
+            T: Component + FromComponent<f64>,
+        {
+            fn from(packed: $ty) -> Self {
+                let color = u32::from(packed.color);
+
+                Rgb::new(
+                    unpack(color >> $red_bits.1, $red_bits.0),
+                    unpack(color >> $green_bits.1, $green_bits.0),
+                    unpack(color >> $blue_bits.1, $blue_bits.0),
+                )
+            }
+        }
+    };
+}
+
+// The number of bits and the offset for each component, followed by the bits
+// that are set to make the color opaque
+impl_packed_rgb!(Rgb565, u16, [(5, 11), (6, 5), (5, 0)], 0);
+impl_packed_rgb!(Rgb555, u16, [(5, 10), (5, 5), (5, 0)], 0);
+impl_packed_rgb!(Rgb10A2, u32, [(10, 0), (10, 10), (10, 20)], 0xC000_0000);
+
+impl<S, T> From<Rgba<S, T>> for Rgb10A2
+where
+    S: RgbStandard,
+    T: Component + IntoComponent<f64>,
+{
+    fn from(color: Rgba<S, T>) -> Self {
+        let rgb = Rgb10A2::from(color.color);
+        Rgb10A2 {
+            color: rgb.color & 0x3FFF_FFFF | pack(color.alpha, 2) << 30,
+        }
+    }
+}
+
+impl<S, T> From<Rgb10A2> for Rgba<S, T>
+where
+    S: RgbStandard,
+    T: Component + FromComponent<f64>,
+{
+    fn from(packed: Rgb10A2) -> Self {
+        Rgba {
+            color: packed.into(),
+            alpha: unpack(packed.color >> 30, 2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Rgb10A2, Rgb555, Rgb565};
+    use crate::{Pixel, Srgb, Srgba};
+
+    #[test]
+    fn rgb565() {
+        assert_eq!(Rgb565::from(Srgb::new(1.0, 1.0, 1.0)).color, 0xFFFF);
+        assert_eq!(Rgb565::from(Srgb::new(0u8, 0, 0)).color, 0);
+        assert_eq!(Rgb565::from(Srgb::new(0u8, 255, 0)).color, 0x07E0);
+        assert_eq!(Rgb565::from(Srgb::new(2.0, -1.0, 0.0)).color, 0xF800);
+
+        let color: Srgb<u8> = Rgb565::from(0x0821).into();
+        assert_eq!(color, Srgb::new(8, 4, 8));
+    }
+
+    #[test]
+    fn rgb555() {
+        assert_eq!(Rgb555::from(Srgb::new(1.0, 1.0, 1.0)).color, 0x7FFF);
+
+        // The unused bit is ignored
+        let color: Srgb<u8> = Rgb555::from(0x83E0).into();
+        assert_eq!(color, Srgb::new(0, 255, 0));
+    }
+
+    #[test]
+    fn rgb10a2() {
+        assert_eq!(Rgb10A2::from(Srgb::new(1.0, 1.0, 1.0)).color, 0xFFFF_FFFF);
+        assert_eq!(Rgb10A2::from(Srgba::new(0.0, 0.0, 0.0, 0.0)).color, 0);
+        assert_eq!(
+            Rgb10A2::from(Srgba::new(0u16, 0, 65535, 21845)).color,
+            0x7FF0_0000
+        );
+
+        let color: Srgba<f32> = Rgb10A2::from(0x8000_03FF).into();
+        assert_relative_eq!(color, Srgba::new(1.0, 0.0, 0.0, 2.0 / 3.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        for color in 0..=u16::MAX {
+            let rgb: Srgb<u8> = Rgb565::from(color).into();
+            assert_eq!(Rgb565::from(rgb).color, color);
+
+            let rgb: Srgb<u8> = Rgb555::from(color).into();
+            assert_eq!(Rgb555::from(rgb).color, color & 0x7FFF);
+        }
+
+        for value in 0..1024 {
+            let color = value | value << 10 | value << 20 | (value % 4) << 30;
+            let rgba: Srgba<u16> = Rgb10A2::from(color).into();
+            assert_eq!(Rgb10A2::from(rgba).color, color);
+
+            let rgba: Srgba<f32> = Rgb10A2::from(color).into();
+            assert_eq!(Rgb10A2::from(rgba).color, color);
+        }
+    }
+
+    #[test]
+    fn raw_pixels() {
+        let raw = [0xFFFFu16, 0x001F];
+        let colors = Rgb565::from_raw_slice(&raw);
+        assert_eq!(Srgb::<u8>::from(colors[1]), Srgb::new(0, 0, 255));
+    }
+}
@@ -1,11 +1,12 @@
 use core::any::TypeId;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::num::ParseIntError;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use core::str::FromStr;
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::{CheckedAdd, CheckedSub, Saturating};
 #[cfg(feature = "random")]
 use rand::distributions::uniform::{SampleBorrow, SampleUniform, Uniform, UniformSampler};
 #[cfg(feature = "random")]
@@ -14,17 +15,21 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::alpha::Alpha;
-use crate::blend::PreAlpha;
+use crate::blend::{NonSeparable, PreAlpha};
+use crate::chromatic_adaptation::white_balance_matrix;
 use crate::convert::FromColorUnclamped;
+use crate::encoding::limited_range;
 use crate::encoding::linear::LinearFn;
 use crate::encoding::pixel::RawPixel;
-use crate::encoding::{Linear, Srgb};
+use crate::encoding::{srgb, Linear, Srgb};
 use crate::luma::LumaStandard;
-use crate::matrix::{matrix_inverse, multiply_xyz_to_rgb, rgb_to_xyz_matrix};
+use crate::matrix::{multiply_xyz_to_rgb, xyz_to_rgb_matrix};
+use crate::relative_contrast::apca_screen_luminance;
 use crate::rgb::{Packed, RgbChannels, RgbSpace, RgbStandard, TransferFn};
 use crate::{
-    clamp, contrast_ratio, from_f64, Blend, Component, ComponentWise, FloatComponent,
-    FromComponent, GetHue, Limited, Mix, Pixel, RelativeContrast, Shade,
+    apca_contrast, clamp, from_f64, ApcaContrast, Blend, CheckedArithmetic, Component,
+    ComponentWise, ComponentWiseMap, FloatComponent, FromComponent, GetHue, InvalidComponents,
+    Limited, Mix, NonlinearMix, Pixel, RelativeLuminance, SaturatingArithmetic, Shade,
 };
 use crate::{Hsl, Hsv, Luma, RgbHue, Xyz};
 
@@ -82,7 +87,7 @@ impl<S: RgbStandard, T: Component> Clone for Rgb<S, T> {
 
 impl<S: RgbStandard, T: Component> Rgb<S, T> {
     /// Create an RGB color.
-    pub fn new(red: T, green: T, blue: T) -> Rgb<S, T> {
+    pub const fn new(red: T, green: T, blue: T) -> Rgb<S, T> {
         Rgb {
             red,
             green,
@@ -181,6 +186,97 @@ impl<S: RgbStandard> Rgb<S, u8> {
     pub fn from_u32<C: RgbChannels>(color: u32) -> Self {
         Packed::<C>::from(color).into()
     }
+
+    /// Convert from a `u32` in the `0xRRGGBB` format, such as a hex color
+    /// code. The highest byte is ignored.
+    ///
+    /// This is a `const fn`, unlike [`from_u32`](Self::from_u32), so it can
+    /// be used for constants. See also the [`srgb!`](crate::srgb) macro.
+    ///
+    /// ```
+    /// use palette::Srgb;
+    ///
+    /// const DODGER_BLUE: Srgb<u8> = Srgb::from_rgb_u32(0x1E90FF);
+    /// assert_eq!(DODGER_BLUE, Srgb::new(30, 144, 255));
+    /// assert_eq!(DODGER_BLUE.into_rgb_u32(), 0x1E90FF);
+    /// ```
+    pub const fn from_rgb_u32(color: u32) -> Self {
+        Rgb::new((color >> 16) as u8, (color >> 8) as u8, color as u8)
+    }
+
+    /// Convert to a `u32` in the `0xRRGGBB` format, where the highest byte
+    /// is `0`. This is a `const fn`, unlike [`into_u32`](Self::into_u32).
+    pub const fn into_rgb_u32(self) -> u32 {
+        (self.red as u32) << 16 | (self.green as u32) << 8 | self.blue as u32
+    }
+
+    /// Convert the full range components to limited range, where black is
+    /// `16` and white is `235`.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    pub fn into_limited_range(self) -> Self {
+        Rgb::new(
+            limited_range::into_limited(self.red.into(), 8) as u8,
+            limited_range::into_limited(self.green.into(), 8) as u8,
+            limited_range::into_limited(self.blue.into(), 8) as u8,
+        )
+    }
+
+    /// Convert limited range components, where black is `16` and white is
+    /// `235`, to full range.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    pub fn into_full_range(self) -> Self {
+        Rgb::new(
+            limited_range::from_limited(self.red.into(), 8) as u8,
+            limited_range::from_limited(self.green.into(), 8) as u8,
+            limited_range::from_limited(self.blue.into(), 8) as u8,
+        )
+    }
+}
+
+/// Conversion between full and limited range for higher bit depths.
+///
+/// ```
+/// use palette::Srgb;
+///
+/// let limited = Srgb::new(1023u16, 512, 0).into_limited_range(10);
+/// assert_eq!(limited, Srgb::new(940, 502, 64));
+/// assert_eq!(limited.into_full_range(10), Srgb::new(1023, 512, 0));
+/// ```
+impl<S: RgbStandard> Rgb<S, u16> {
+    /// Convert the full range components, with the given bit depth, to
+    /// limited range, where black is `16 * 2^(bit_depth - 8)` and white is
+    /// `235 * 2^(bit_depth - 8)`.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` is not between 8 and 16.
+    pub fn into_limited_range(self, bit_depth: u32) -> Self {
+        Rgb::new(
+            limited_range::into_limited(self.red, bit_depth),
+            limited_range::into_limited(self.green, bit_depth),
+            limited_range::into_limited(self.blue, bit_depth),
+        )
+    }
+
+    /// Convert limited range components, with the given bit depth, to full
+    /// range.
+    ///
+    /// See [`limited_range`](crate::encoding::limited_range) for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` is not between 8 and 16.
+    pub fn into_full_range(self, bit_depth: u32) -> Self {
+        Rgb::new(
+            limited_range::from_limited(self.red, bit_depth),
+            limited_range::from_limited(self.green, bit_depth),
+            limited_range::from_limited(self.blue, bit_depth),
+        )
+    }
 }
 
 impl<S: RgbStandard, T: FloatComponent> Rgb<S, T> {
@@ -221,6 +317,85 @@ impl<S: RgbStandard, T: FloatComponent> Rgb<S, T> {
     }
 }
 
+impl<S: RgbSpace, T: FloatComponent> Rgb<Linear<S>, T> {
+    /// Adjust the white balance of the color, as if it was lit by a light
+    /// source with the correlated color `temperature` in Kelvin and the
+    /// `tint`.
+    ///
+    /// This works like the temperature and tint sliders in photo editors. The
+    /// light source is adapted to the white point of the RGB space, using the
+    /// Bradford method, so a lower temperature makes the color bluer and a
+    /// higher temperature makes it more yellow. The `tint` is the distance
+    /// from the Planckian locus in the CIE 1960 UCS, where positive values
+    /// make the color more magenta and negative values make it greener.
+    /// Useful tints are usually between `-0.02` and `0.02`.
+    ///
+    /// The temperature is clamped to the range of 1667 K to 25000 K.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    ///
+    /// use palette::LinSrgb;
+    ///
+    /// // A white wall, photographed under 3200 K tungsten light
+    /// let wall = LinSrgb::new(1.0, 0.52, 0.19);
+    /// let balanced = wall.white_balance(3200.0, 0.0);
+    ///
+    /// assert_relative_eq!(balanced.red, balanced.green, epsilon = 0.02);
+    /// assert_relative_eq!(balanced.green, balanced.blue, epsilon = 0.02);
+    /// ```
+    pub fn white_balance(self, temperature: T, tint: T) -> Self {
+        let matrix = white_balance_matrix::<S, T>(temperature, tint);
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8] = matrix;
+
+        Rgb::new(
+            c0 * self.red + c1 * self.green + c2 * self.blue,
+            c3 * self.red + c4 * self.green + c5 * self.blue,
+            c6 * self.red + c7 * self.green + c8 * self.blue,
+        )
+    }
+}
+
+/// Fast conversion between 8 bit sRGB and linear RGB.
+///
+/// These use lookup tables instead of evaluating the transfer function, which
+/// makes them much faster when converting large amounts of colors, such as
+/// the pixels of an image. The tables only cover 8 bit components, so other
+/// component types, such as `u16`, are still converted with `into_format`
+/// followed by the floating point `into_linear` and `from_linear`.
+///
+/// ```
+/// use palette::{LinSrgb, Srgb};
+///
+/// let linear: LinSrgb = Srgb::new(255u8, 128, 0).into_linear();
+/// assert_eq!(Srgb::from_linear_u8(linear), Srgb::new(255, 128, 0));
+/// ```
+impl<S> Rgb<S, u8>
+where
+    S: RgbStandard<TransferFn = Srgb>,
+{
+    /// Convert the color to linear RGB.
+    #[inline]
+    pub fn into_linear<T: FloatComponent>(self) -> Rgb<Linear<S::Space>, T> {
+        Rgb::new(
+            srgb::u8_into_linear(self.red),
+            srgb::u8_into_linear(self.green),
+            srgb::u8_into_linear(self.blue),
+        )
+    }
+
+    /// Convert linear RGB to 8 bit nonlinear RGB, rounded to the nearest
+    /// value. This is the same as `from_linear` followed by `into_format`.
+    #[inline]
+    pub fn from_linear_u8<T: FloatComponent>(color: Rgb<Linear<S::Space>, T>) -> Self {
+        Rgb::new(
+            srgb::u8_from_linear(color.red),
+            srgb::u8_from_linear(color.green),
+            srgb::u8_from_linear(color.blue),
+        )
+    }
+}
+
 impl<S: RgbStandard, T: Component> Rgb<S, T> {
     #[inline]
     fn reinterpret_as<St: RgbStandard>(self) -> Rgb<St, T>
@@ -239,7 +414,7 @@ impl<S: RgbStandard, T: Component> Rgb<S, T> {
 /// <span id="Rgba"></span>[`Rgba`](crate::rgb::Rgba) implementations.
 impl<S: RgbStandard, T: Component, A: Component> Alpha<Rgb<S, T>, A> {
     /// Nonlinear RGB.
-    pub fn new(red: T, green: T, blue: T, alpha: A) -> Self {
+    pub const fn new(red: T, green: T, blue: T, alpha: A) -> Self {
         Alpha {
             color: Rgb::new(red, green, blue),
             alpha,
@@ -309,6 +484,36 @@ impl<S: RgbStandard> Rgba<S, u8> {
     pub fn from_u32<C: RgbChannels>(color: u32) -> Self {
         Packed::<C>::from(color).into()
     }
+
+    /// Convert from a `u32` in the `0xRRGGBBAA` format.
+    ///
+    /// This is a `const fn`, unlike [`from_u32`](Self::from_u32), so it can
+    /// be used for constants. See also the [`srgba!`](crate::srgba) macro.
+    ///
+    /// ```
+    /// use palette::Srgba;
+    ///
+    /// const SHADOW: Srgba<u8> = Srgba::from_rgba_u32(0x00000080);
+    /// assert_eq!(SHADOW, Srgba::new(0, 0, 0, 128));
+    /// assert_eq!(SHADOW.into_rgba_u32(), 0x00000080);
+    /// ```
+    pub const fn from_rgba_u32(color: u32) -> Self {
+        Alpha::<Rgb<S, u8>, u8>::new(
+            (color >> 24) as u8,
+            (color >> 16) as u8,
+            (color >> 8) as u8,
+            color as u8,
+        )
+    }
+
+    /// Convert to a `u32` in the `0xRRGGBBAA` format. This is a `const fn`,
+    /// unlike [`into_u32`](Self::into_u32).
+    pub const fn into_rgba_u32(self) -> u32 {
+        (self.color.red as u32) << 24
+            | (self.color.green as u32) << 16
+            | (self.color.blue as u32) << 8
+            | self.alpha as u32
+    }
 }
 
 /// <span id="Rgba"></span>[`Rgba`](crate::rgb::Rgba) implementations.
@@ -380,7 +585,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<<S::Space as RgbSpace>::WhitePoint, T>) -> Self {
-        let transform_matrix = matrix_inverse(&rgb_to_xyz_matrix::<S::Space, T>());
+        let transform_matrix = xyz_to_rgb_matrix::<S::Space, T>();
         Self::from_linear(multiply_xyz_to_rgb(&transform_matrix, &color))
     }
 }
@@ -494,6 +699,22 @@ where
         self.green = clamp(self.green, T::zero(), T::max_intensity());
         self.blue = clamp(self.blue, T::zero(), T::max_intensity());
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check(
+                "red",
+                self.red >= T::zero() && self.red <= T::max_intensity(),
+            )
+            .check(
+                "green",
+                self.green >= T::zero() && self.green <= T::max_intensity(),
+            )
+            .check(
+                "blue",
+                self.blue >= T::zero() && self.blue <= T::max_intensity(),
+            )
+    }
 }
 
 impl<S, T> Mix for Rgb<S, T>
@@ -515,6 +736,25 @@ where
     }
 }
 
+impl<S, T> NonlinearMix for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn mix_encoded(&self, other: &Rgb<S, T>, factor: T) -> Rgb<S, T> {
+        let factor = clamp(factor, T::zero(), T::one());
+
+        Rgb {
+            red: self.red + factor * (other.red - self.red),
+            green: self.green + factor * (other.green - self.green),
+            blue: self.blue + factor * (other.blue - self.blue),
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Shade for Rgb<S, T>
 where
     S: RgbStandard<TransferFn = LinearFn>,
@@ -530,6 +770,36 @@ where
             standard: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Rgb<S, T> {
+        let difference_red = if factor >= T::zero() {
+            T::max_intensity() - self.red
+        } else {
+            self.red
+        };
+        let delta_red = difference_red.max(T::zero()) * factor;
+
+        let difference_green = if factor >= T::zero() {
+            T::max_intensity() - self.green
+        } else {
+            self.green
+        };
+        let delta_green = difference_green.max(T::zero()) * factor;
+
+        let difference_blue = if factor >= T::zero() {
+            T::max_intensity() - self.blue
+        } else {
+            self.blue
+        };
+        let delta_blue = difference_blue.max(T::zero()) * factor;
+
+        Rgb {
+            red: (self.red + delta_red).max(T::zero()),
+            green: (self.green + delta_green).max(T::zero()),
+            blue: (self.blue + delta_blue).max(T::zero()),
+            standard: PhantomData,
+        }
+    }
 }
 
 impl<S, T> GetHue for Rgb<S, T>
@@ -551,6 +821,10 @@ where
             ))
         }
     }
+
+    fn has_hue(&self) -> bool {
+        self.red != self.green || self.red != self.blue
+    }
 }
 
 impl<S, T> Blend for Rgb<S, T>
@@ -561,15 +835,74 @@ where
     type Color = Rgb<S, T>;
 
     fn into_premultiplied(self) -> PreAlpha<Rgb<S, T>, T> {
-        Rgba {
+        PreAlpha {
             color: self,
             alpha: T::one(),
         }
-        .into_premultiplied()
     }
 
     fn from_premultiplied(color: PreAlpha<Rgb<S, T>, T>) -> Self {
-        Rgba::from_premultiplied(color).color
+        Rgba::from(color).color
+    }
+}
+
+impl<S, T> NonSeparable for Rgb<S, T>
+where
+    S: RgbStandard<TransferFn = LinearFn>,
+    T: FloatComponent,
+{
+    fn lum(&self) -> T {
+        from_f64::<T>(0.3) * self.red
+            + from_f64::<T>(0.59) * self.green
+            + from_f64::<T>(0.11) * self.blue
+    }
+
+    fn with_lum(&self, lum: T) -> Self {
+        let difference = lum - self.lum();
+        let mut color = self.component_wise_self(|c| c + difference);
+
+        // Move the components towards the luminosity until they are in range
+        let min = color.red.min(color.green).min(color.blue);
+        let max = color.red.max(color.green).max(color.blue);
+
+        if min < T::zero() && (lum - min).is_normal() {
+            color = color.component_wise_self(|c| lum + (c - lum) * lum / (lum - min));
+        }
+
+        if max > T::one() && (max - lum).is_normal() {
+            color = color.component_wise_self(|c| lum + (c - lum) * (T::one() - lum) / (max - lum));
+        }
+
+        color
+    }
+
+    fn sat(&self) -> T {
+        self.red.max(self.green).max(self.blue) - self.red.min(self.green).min(self.blue)
+    }
+
+    fn with_sat(&self, sat: T) -> Self {
+        let mut components = [self.red, self.green, self.blue];
+
+        let mut order = [0, 1, 2];
+        order.sort_unstable_by(|&a, &b| {
+            components[a]
+                .partial_cmp(&components[b])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        let [min, mid, max] = order;
+
+        if components[max] > components[min] {
+            components[mid] =
+                (components[mid] - components[min]) * sat / (components[max] - components[min]);
+            components[max] = sat;
+        } else {
+            components[mid] = T::zero();
+            components[max] = T::zero();
+        }
+        components[min] = T::zero();
+
+        let [red, green, blue] = components;
+        Rgb::new(red, green, blue)
     }
 }
 
@@ -599,6 +932,33 @@ where
     }
 }
 
+impl<S, T, U> ComponentWiseMap<U> for Rgb<S, T>
+where
+    T: Component,
+    U: Component,
+    S: RgbStandard,
+{
+    type Output = Rgb<S, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(&self, other: &Rgb<S, T>, mut f: F) -> Rgb<S, U> {
+        Rgb {
+            red: f(self.red, other.red),
+            green: f(self.green, other.green),
+            blue: f(self.blue, other.blue),
+            standard: PhantomData,
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> Rgb<S, U> {
+        Rgb {
+            red: f(self.red),
+            green: f(self.green),
+            blue: f(self.blue),
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, T> Default for Rgb<S, T>
 where
     T: Component,
@@ -729,6 +1089,102 @@ where
     }
 }
 
+impl<S, T> SaturatingArithmetic<Rgb<S, T>> for Rgb<S, T>
+where
+    S: RgbStandard<TransferFn = LinearFn>,
+    T: Component + Saturating,
+{
+    fn saturating_add(self, other: Rgb<S, T>) -> Self {
+        Rgb {
+            red: self.red.saturating_add(other.red),
+            green: self.green.saturating_add(other.green),
+            blue: self.blue.saturating_add(other.blue),
+            standard: PhantomData,
+        }
+    }
+
+    fn saturating_sub(self, other: Rgb<S, T>) -> Self {
+        Rgb {
+            red: self.red.saturating_sub(other.red),
+            green: self.green.saturating_sub(other.green),
+            blue: self.blue.saturating_sub(other.blue),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> SaturatingArithmetic<T> for Rgb<S, T>
+where
+    S: RgbStandard<TransferFn = LinearFn>,
+    T: Component + Saturating,
+{
+    fn saturating_add(self, other: T) -> Self {
+        Rgb {
+            red: self.red.saturating_add(other),
+            green: self.green.saturating_add(other),
+            blue: self.blue.saturating_add(other),
+            standard: PhantomData,
+        }
+    }
+
+    fn saturating_sub(self, other: T) -> Self {
+        Rgb {
+            red: self.red.saturating_sub(other),
+            green: self.green.saturating_sub(other),
+            blue: self.blue.saturating_sub(other),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> CheckedArithmetic<Rgb<S, T>> for Rgb<S, T>
+where
+    S: RgbStandard<TransferFn = LinearFn>,
+    T: Component + CheckedAdd + CheckedSub,
+{
+    fn checked_add(self, other: Rgb<S, T>) -> Option<Self> {
+        Some(Rgb {
+            red: self.red.checked_add(&other.red)?,
+            green: self.green.checked_add(&other.green)?,
+            blue: self.blue.checked_add(&other.blue)?,
+            standard: PhantomData,
+        })
+    }
+
+    fn checked_sub(self, other: Rgb<S, T>) -> Option<Self> {
+        Some(Rgb {
+            red: self.red.checked_sub(&other.red)?,
+            green: self.green.checked_sub(&other.green)?,
+            blue: self.blue.checked_sub(&other.blue)?,
+            standard: PhantomData,
+        })
+    }
+}
+
+impl<S, T> CheckedArithmetic<T> for Rgb<S, T>
+where
+    S: RgbStandard<TransferFn = LinearFn>,
+    T: Component + CheckedAdd + CheckedSub,
+{
+    fn checked_add(self, other: T) -> Option<Self> {
+        Some(Rgb {
+            red: self.red.checked_add(&other)?,
+            green: self.green.checked_add(&other)?,
+            blue: self.blue.checked_add(&other)?,
+            standard: PhantomData,
+        })
+    }
+
+    fn checked_sub(self, other: T) -> Option<Self> {
+        Some(Rgb {
+            red: self.red.checked_sub(&other)?,
+            green: self.green.checked_sub(&other)?,
+            blue: self.blue.checked_sub(&other)?,
+            standard: PhantomData,
+        })
+    }
+}
+
 impl<S, T> Mul<Rgb<S, T>> for Rgb<S, T>
 where
     S: RgbStandard<TransferFn = LinearFn>,
@@ -861,6 +1317,34 @@ impl<S: RgbStandard, T: Component> Into<(T, T, T)> for Rgb<S, T> {
     }
 }
 
+/// Create a color from an array of components, in RGB order.
+///
+/// ```
+/// use palette::Srgb;
+///
+/// let color = Srgb::from([255u8, 128, 0]);
+/// assert_eq!(color, Srgb::new(255, 128, 0));
+/// ```
+impl<S: RgbStandard, T: Component> From<[T; 3]> for Rgb<S, T> {
+    fn from([red, green, blue]: [T; 3]) -> Self {
+        Self::new(red, green, blue)
+    }
+}
+
+/// Convert a color into an array of components, in RGB order.
+///
+/// ```
+/// use palette::Srgb;
+///
+/// let bytes: [u8; 3] = Srgb::new(255u8, 128, 0).into();
+/// assert_eq!(bytes, [255, 128, 0]);
+/// ```
+impl<S: RgbStandard, T: Component> From<Rgb<S, T>> for [T; 3] {
+    fn from(color: Rgb<S, T>) -> Self {
+        [color.red, color.green, color.blue]
+    }
+}
+
 impl<S: RgbStandard, T: Component, A: Component> From<(T, T, T, A)> for Alpha<Rgb<S, T>, A> {
     fn from(components: (T, T, T, A)) -> Self {
         Self::from_components(components)
@@ -873,6 +1357,54 @@ impl<S: RgbStandard, T: Component, A: Component> Into<(T, T, T, A)> for Alpha<Rg
     }
 }
 
+impl<S: RgbStandard, T: Component> From<[T; 4]> for Alpha<Rgb<S, T>, T> {
+    fn from([red, green, blue, alpha]: [T; 4]) -> Self {
+        Self::from_components((red, green, blue, alpha))
+    }
+}
+
+impl<S: RgbStandard, T: Component> From<Alpha<Rgb<S, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Rgb<S, T>, T>) -> Self {
+        [color.red, color.green, color.blue, color.alpha]
+    }
+}
+
+impl<S, T> Eq for Rgb<S, T>
+where
+    T: Component + Eq,
+    S: RgbStandard + PartialEq,
+{
+}
+
+/// Hash colors with integer components, for example to find the unique colors
+/// of an image.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use palette::Srgb;
+///
+/// let pixels = [
+///     Srgb::new(255u8, 0, 0),
+///     Srgb::new(0, 0, 255),
+///     Srgb::new(255, 0, 0),
+/// ];
+/// let unique: HashSet<_> = pixels.iter().collect();
+///
+/// assert_eq!(unique.len(), 2);
+/// ```
+impl<S, T> Hash for Rgb<S, T>
+where
+    T: Component + Hash,
+    S: RgbStandard,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.red.hash(state);
+        self.green.hash(state);
+        self.blue.hash(state);
+    }
+}
+
 impl<S, T> AbsDiffEq for Rgb<S, T>
 where
     T: Component + AbsDiffEq,
@@ -1014,43 +1546,59 @@ where
     }
 }
 
-#[derive(Debug)]
+/// An error that occurred while parsing a hexadecimal color code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FromHexError {
-    ParseIntError(ParseIntError),
-    HexFormatError(&'static str),
-}
+    /// The hex code doesn't have a supported number of digits. This contains
+    /// the number of digits in the hex code.
+    InvalidLength(usize),
 
-impl From<ParseIntError> for FromHexError {
-    fn from(err: ParseIntError) -> FromHexError {
-        FromHexError::ParseIntError(err)
-    }
+    /// The hex code contains a character that isn't a hexadecimal digit.
+    InvalidDigit(char),
 }
 
-impl From<&'static str> for FromHexError {
-    fn from(err: &'static str) -> FromHexError {
-        FromHexError::HexFormatError(err)
-    }
-}
 impl core::fmt::Display for FromHexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &*self {
-            FromHexError::ParseIntError(e) => write!(f, "{}", e),
-            FromHexError::HexFormatError(s) => write!(
+        match *self {
+            FromHexError::InvalidLength(length) => write!(
                 f,
-                "{}, please use format '#fff', 'fff', '#ffffff' or 'ffffff'.",
-                s
+                "invalid hex code length of {} digits, please use format '#fff', 'fff', \
+                 '#ffffff' or 'ffffff', or '#ffff', 'ffff', '#ffffffff' or 'ffffffff' with alpha",
+                length
             ),
+            FromHexError::InvalidDigit(digit) => {
+                write!(f, "invalid digit '{}' found in hex code", digit)
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for FromHexError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &*self {
-            FromHexError::HexFormatError(_s) => None,
-            FromHexError::ParseIntError(e) => Some(e),
-        }
+impl std::error::Error for FromHexError {}
+
+/// Parse a hex code, with or without a leading `#`, into its color components
+/// and an optional alpha component. Short codes have one digit per component.
+fn parse_hex(hex: &str) -> Result<([u8; 3], Option<u8>), FromHexError> {
+    let hex_code = hex.strip_prefix('#').unwrap_or(hex);
+
+    if let Some(digit) = hex_code.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(FromHexError::InvalidDigit(digit));
+    }
+
+    // All characters are ASCII digits at this point, so they are one byte each
+    let digit = |index: usize| {
+        u8::from_str_radix(&hex_code[index..index + 1], 16).expect("valid hex digit") * 17
+    };
+    let pair = |index: usize| {
+        u8::from_str_radix(&hex_code[index * 2..index * 2 + 2], 16).expect("valid hex digits")
+    };
+
+    match hex_code.len() {
+        3 => Ok(([digit(0), digit(1), digit(2)], None)),
+        4 => Ok(([digit(0), digit(1), digit(2)], Some(digit(3)))),
+        6 => Ok(([pair(0), pair(1), pair(2)], None)),
+        8 => Ok(([pair(0), pair(1), pair(2)], Some(pair(3)))),
+        length => Err(FromHexError::InvalidLength(length)),
     }
 }
 
@@ -1060,41 +1608,50 @@ impl<S: RgbStandard> FromStr for Rgb<S, u8> {
     // Parses a color hex code of format '#ff00bb' or '#abc' into a
     // Rgb<S, u8> instance.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
-        let hex_code = hex.strip_prefix('#').map_or(hex, |stripped| stripped);
-        match hex_code.len() {
-            3 => {
-                let red = u8::from_str_radix(&hex_code[..1], 16)?;
-                let green = u8::from_str_radix(&hex_code[1..2], 16)?;
-                let blue = u8::from_str_radix(&hex_code[2..3], 16)?;
-                let col: Rgb<S, u8> = Rgb::new(red * 17, green * 17, blue * 17);
-                Ok(col)
-            }
-            6 => {
-                let red = u8::from_str_radix(&hex_code[..2], 16)?;
-                let green = u8::from_str_radix(&hex_code[2..4], 16)?;
-                let blue = u8::from_str_radix(&hex_code[4..6], 16)?;
-                let col: Rgb<S, u8> = Rgb::new(red, green, blue);
-                Ok(col)
-            }
-            _ => Err("invalid hex code format".into()),
+        match parse_hex(hex)? {
+            ([red, green, blue], None) => Ok(Rgb::new(red, green, blue)),
+            (_, Some(_)) => Err(FromHexError::InvalidLength(
+                hex.strip_prefix('#').unwrap_or(hex).len(),
+            )),
         }
     }
 }
 
-impl<S, T> RelativeContrast for Rgb<S, T>
+impl<S: RgbStandard> FromStr for Alpha<Rgb<S, u8>, u8> {
+    type Err = FromHexError;
+
+    // Parses a color hex code of format '#ff00bbaa', '#abcd', '#ff00bb' or
+    // '#abc' into a Rgba<S, u8> instance. The color is opaque if the hex code
+    // has no alpha digits.
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        let ([red, green, blue], alpha) = parse_hex(hex)?;
+        Ok(Rgba::new(red, green, blue, alpha.unwrap_or(u8::MAX)))
+    }
+}
+
+impl<S, T> RelativeLuminance for Rgb<S, T>
 where
     T: FloatComponent,
     S: RgbStandard,
 {
+    type WhitePoint = <S::Space as RgbSpace>::WhitePoint;
     type Scalar = T;
+}
 
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        use crate::FromColor;
+impl<S, T> ApcaContrast for Rgb<S, T>
+where
+    T: FloatComponent,
+    S: RgbStandard<Space = Srgb>,
+{
+    type Scalar = T;
 
-        let xyz1 = Xyz::from_color(*self);
-        let xyz2 = Xyz::from_color(*other);
+    fn get_apca_contrast(&self, background: &Self) -> T {
+        let luminance = |color: &Self| {
+            let srgb = Rgb::<Srgb, T>::from_linear(color.into_linear());
+            apca_screen_luminance(srgb.red, srgb.green, srgb.blue)
+        };
 
-        contrast_ratio(xyz1.y, xyz2.y)
+        apca_contrast(luminance(self), luminance(background))
     }
 }
 
@@ -1190,7 +1747,7 @@ where
 mod test {
     use core::str::FromStr;
 
-    use super::{Rgb, Rgba};
+    use super::{FromHexError, Rgb, Rgba};
     use crate::encoding::Srgb;
     use crate::rgb::packed::channels;
 
@@ -1301,6 +1858,30 @@ mod test {
         );
     }
 
+    #[test]
+    fn rgba_hex() {
+        assert_eq!(
+            format!("{:x}", Rgba::<Srgb, u8>::new(171, 193, 35, 1)),
+            "abc12301"
+        );
+        assert_eq!(
+            format!("{:X}", Rgba::<Srgb, u8>::new(171, 193, 35, 1)),
+            "ABC12301"
+        );
+        assert_eq!(
+            format!("{:x}", Rgba::<Srgb, u16>::new(1, 2, 3, 4)),
+            "0001000200030004"
+        );
+        assert_eq!(
+            format!("{:03x}", Rgba::<Srgb, u8>::new(1, 2, 3, 4)),
+            "001002003004"
+        );
+        assert_eq!(
+            format!("{:03X}", Rgba::<Srgb, u32>::new(1, 2, 3, 4)),
+            "001002003004"
+        );
+    }
+
     #[test]
     fn rgb_hex_into_from() {
         let c1 = Rgb::<Srgb, u8>::from_u32::<channels::Argb>(0x1100_7FFF);
@@ -1362,10 +1943,10 @@ mod test {
         assert!(c.is_ok());
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(18, 52, 86));
         let c = Rgb::<Srgb, u8>::from_str("#iii");
-        assert!(c.is_err());
+        assert_eq!(c, Err(FromHexError::InvalidDigit('i')));
         assert_eq!(
             format!("{}", c.err().unwrap()),
-            "invalid digit found in string"
+            "invalid digit 'i' found in hex code"
         );
         let c = Rgb::<Srgb, u8>::from_str("#08f");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(0, 136, 255));
@@ -1374,11 +1955,12 @@ mod test {
         let c = Rgb::<Srgb, u8>::from_str("ffffff");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(255, 255, 255));
         let c = Rgb::<Srgb, u8>::from_str("#12");
-        assert!(c.is_err());
+        assert_eq!(c, Err(FromHexError::InvalidLength(2)));
         assert_eq!(
             format!("{}", c.err().unwrap()),
-            "invalid hex code format, \
-             please use format \'#fff\', \'fff\', \'#ffffff\' or \'ffffff\'."
+            "invalid hex code length of 2 digits, please use format \'#fff\', \'fff\', \
+             \'#ffffff\' or \'ffffff\', or \'#ffff\', \'ffff\', \'#ffffffff\' or \'ffffffff\' \
+             with alpha"
         );
         let c = Rgb::<Srgb, u8>::from_str("da0bce");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(218, 11, 206));
@@ -1386,6 +1968,26 @@ mod test {
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(240, 52, 230));
         let c = Rgb::<Srgb, u8>::from_str("abc");
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(170, 187, 204));
+        let c = Rgb::<Srgb, u8>::from_str("#abcd");
+        assert_eq!(c, Err(FromHexError::InvalidLength(4)));
+        let c = Rgb::<Srgb, u8>::from_str("#é12");
+        assert_eq!(c, Err(FromHexError::InvalidDigit('é')));
+    }
+
+    #[test]
+    fn from_str_rgba() {
+        let c = Rgba::<Srgb, u8>::from_str("#ff000080");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(255, 0, 0, 128));
+        let c = Rgba::<Srgb, u8>::from_str("08f8");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(0, 136, 255, 136));
+        let c = Rgba::<Srgb, u8>::from_str("#123456");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(18, 52, 86, 255));
+        let c = Rgba::<Srgb, u8>::from_str("#abc");
+        assert_eq!(c.unwrap(), Rgba::<Srgb, u8>::new(170, 187, 204, 255));
+        let c = Rgba::<Srgb, u8>::from_str("#1234567");
+        assert_eq!(c, Err(FromHexError::InvalidLength(7)));
+        let c = Rgba::<Srgb, u8>::from_str("#1234567g");
+        assert_eq!(c, Err(FromHexError::InvalidDigit('g')));
     }
 
     #[test]
@@ -1398,6 +2000,34 @@ mod test {
         assert_relative_eq!(Rgb::<Srgb, f32>::max_blue(), 1.0);
     }
 
+    #[test]
+    fn hash_integer_components() {
+        use std::collections::HashSet;
+
+        let colors = [
+            Rgba::<Srgb, u8>::new(1, 2, 3, 255),
+            Rgba::new(1, 2, 3, 128),
+            Rgba::new(1, 2, 3, 255),
+            Rgba::new(3, 2, 1, 255),
+        ];
+        let unique: HashSet<_> = colors.iter().copied().collect();
+
+        assert_eq!(unique.len(), 3);
+        assert!(unique.contains(&Rgba::new(1, 2, 3, 128)));
+    }
+
+    #[test]
+    fn mix_encoded() {
+        use crate::NonlinearMix;
+
+        let a = Rgba::<Srgb, f64>::new(0.2, 0.4, 1.0, 1.0);
+        let b = Rgba::new(0.6, 0.0, 0.0, 0.5);
+
+        assert_relative_eq!(a.mix_encoded(&b, 0.5), Rgba::new(0.4, 0.2, 0.5, 0.75));
+        assert_eq!(a.mix_encoded(&b, -1.0).color, a.color);
+        assert_eq!(a.mix_encoded(&b, 2.0).color, b.color);
+    }
+
     #[cfg(feature = "random")]
     test_uniform_distribution! {
         Rgb<Srgb, f32> {
@@ -1408,4 +2038,63 @@ mod test {
         min: Rgb::new(0.0f32, 0.0, 0.0),
         max: Rgb::new(1.0, 1.0, 1.0)
     }
+
+    #[test]
+    fn saturating_arithmetic() {
+        use crate::{LinSrgb, LinSrgba, SaturatingArithmetic};
+
+        let color = LinSrgb::new(250u8, 5, 100);
+        assert_eq!(
+            color.saturating_add(LinSrgb::new(10, 10, 10)),
+            LinSrgb::new(255, 15, 110)
+        );
+        assert_eq!(
+            color.saturating_sub(LinSrgb::new(10, 10, 10)),
+            LinSrgb::new(240, 0, 90)
+        );
+        assert_eq!(color.saturating_add(200), LinSrgb::new(255, 205, 255));
+
+        let color = LinSrgba::new(65000u16, 0, 0, 65535);
+        assert_eq!(
+            color.saturating_add(1000),
+            LinSrgba::new(65535, 1000, 1000, 65535)
+        );
+        assert_eq!(
+            color.saturating_sub(LinSrgba::new(0, 1, 0, 35)),
+            LinSrgba::new(65000, 0, 0, 65500)
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        use crate::{CheckedArithmetic, LinSrgb, LinSrgba};
+
+        let color = LinSrgb::new(250u8, 5, 100);
+        assert_eq!(
+            color.checked_add(LinSrgb::new(5, 5, 5)),
+            Some(LinSrgb::new(255, 10, 105))
+        );
+        assert_eq!(color.checked_add(LinSrgb::new(6, 0, 0)), None);
+        assert_eq!(color.checked_sub(5), Some(LinSrgb::new(245, 0, 95)));
+        assert_eq!(color.checked_sub(6), None);
+
+        let color = LinSrgba::new(10u8, 20, 30, 255);
+        assert_eq!(color.checked_add(1), None);
+        assert_eq!(
+            color.checked_sub(LinSrgba::new(10, 10, 10, 10)),
+            Some(LinSrgba::new(0, 10, 20, 245))
+        );
+    }
+
+    #[test]
+    fn lighten_and_darken() {
+        use crate::{LinSrgb, Shade};
+
+        let color = LinSrgb::new(0.0, 0.5, 1.0);
+
+        assert_relative_eq!(color.lighten_relative(0.5), LinSrgb::new(0.5, 0.75, 1.0));
+        assert_relative_eq!(color.darken_relative(0.5), LinSrgb::new(0.0, 0.25, 0.5));
+        assert_relative_eq!(color.lighten(0.1), LinSrgb::new(0.1, 0.6, 1.1));
+        assert_relative_eq!(color.darken(0.1), LinSrgb::new(-0.1, 0.4, 0.9));
+    }
 }
@@ -0,0 +1,407 @@
+//! Wrapper types for alternative serialized formats.
+//!
+//! Colors are serialized as structs by default, with one field per component,
+//! such as `{"red": 1.0, "green": 0.5, "blue": 0.0}`. That's precise and
+//! unambiguous, but it's verbose for hand written files. The wrappers in this
+//! module change the format of the wrapped color, and can be used as field
+//! types. The [`as_hex`] and [`as_array`] modules do the same for fields of
+//! plain color types, with `#[serde(with = "...")]`.
+//!
+//! [`AsHex`] serializes 8 bit RGB colors as hexadecimal color codes, such as
+//! `"#ff8000"`, in human readable formats, and as compact arrays in binary
+//! formats:
+//!
+//! ```
+//! use palette::serializing::AsHex;
+//! use palette::Srgb;
+//!
+//! let color = AsHex(Srgb::new(255u8, 128, 0));
+//! assert_eq!(serde_json::to_string(&color).unwrap(), r##""#ff8000""##);
+//!
+//! let color: AsHex<Srgb<u8>> = serde_json::from_str(r##""#ff8000""##).unwrap();
+//! assert_eq!(color.0, Srgb::new(255, 128, 0));
+//! ```
+//!
+//! [`AsArray`] serializes colors as arrays of components, such as
+//! `[1.0, 0.5, 0.0]`, which is the shape most design token formats use:
+//!
+//! ```
+//! use palette::serializing::AsArray;
+//! use palette::{Hsl, Srgba};
+//!
+//! let color = AsArray(Srgba::new(1.0, 0.5, 0.0, 0.5));
+//! assert_eq!(serde_json::to_string(&color).unwrap(), "[1.0,0.5,0.0,0.5]");
+//!
+//! let color: AsArray<Hsl> = serde_json::from_str("[120.0, 1.0, 0.5]").unwrap();
+//! assert_eq!(color.0, Hsl::new(120.0, 1.0, 0.5));
+//! ```
+//!
+//! Any color can also be converted to a [`CssColor`](crate::css::CssColor),
+//! which is serialized as a CSS color string.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! use palette::{Hsl, Srgb};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     #[serde(with = "palette::serializing::as_hex")]
+//!     background: Srgb<u8>,
+//!     #[serde(with = "palette::serializing::as_array")]
+//!     accent: Hsl,
+//! }
+//!
+//! let theme = Theme {
+//!     background: Srgb::new(255, 128, 0),
+//!     accent: Hsl::new(120.0, 1.0, 0.5),
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&theme).unwrap(),
+//!     r##"{"background":"#ff8000","accent":[120.0,1.0,0.5]}"##
+//! );
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::luma::{Luma, LumaStandard};
+use crate::rgb::{FromHexError, Rgb, RgbStandard, Rgba};
+use crate::white_point::WhitePoint;
+use crate::{Alpha, Component, FloatComponent, Hsl, Hsv, Hwb, Lab, LabHue, Lch, RgbHue, Xyz, Yxy};
+
+/// Serializes 8 bit RGB colors as hexadecimal color codes.
+///
+/// Human readable formats get a string, such as `"#ff8000"` for [`Rgb`] or
+/// `"#ff8000ff"` for [`Rgba`], while binary formats get an array of
+/// components. Any hex code that can be parsed with [`FromStr`] is accepted
+/// when deserializing, including the short forms, such as `"#f80"`.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct AsHex<C>(pub C);
+
+impl<C> From<C> for AsHex<C> {
+    fn from(color: C) -> Self {
+        AsHex(color)
+    }
+}
+
+impl<S: RgbStandard> Serialize for AsHex<Rgb<S, u8>> {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("#{:x}", self.0))
+        } else {
+            [self.0.red, self.0.green, self.0.blue].serialize(serializer)
+        }
+    }
+}
+
+impl<S: RgbStandard> Serialize for AsHex<Rgba<S, u8>> {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("#{:x}", self.0))
+        } else {
+            let (red, green, blue, alpha) = self.0.into_components();
+            [red, green, blue, alpha].serialize(serializer)
+        }
+    }
+}
+
+impl<'de, S: RgbStandard> Deserialize<'de> for AsHex<Rgb<S, u8>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor(PhantomData))
+        } else {
+            let [red, green, blue] = <[u8; 3]>::deserialize(deserializer)?;
+            Ok(AsHex(Rgb::new(red, green, blue)))
+        }
+    }
+}
+
+impl<'de, S: RgbStandard> Deserialize<'de> for AsHex<Rgba<S, u8>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor(PhantomData))
+        } else {
+            let [red, green, blue, alpha] = <[u8; 4]>::deserialize(deserializer)?;
+            Ok(AsHex(Rgba::new(red, green, blue, alpha)))
+        }
+    }
+}
+
+struct HexVisitor<C>(PhantomData<C>);
+
+impl<'de, C> Visitor<'de> for HexVisitor<C>
+where
+    C: FromStr<Err = FromHexError>,
+{
+    type Value = AsHex<C>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a hexadecimal color code")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<AsHex<C>, E> {
+        value.parse().map(AsHex).map_err(E::custom)
+    }
+}
+
+/// Serializes colors as arrays of components.
+///
+/// The components are in the same order as in `into_components`, with the
+/// alpha component last, such as `[red, green, blue, alpha]` for [`Rgba`] or
+/// `[hue, saturation, lightness]` for [`Hsl`]. Hues are given in degrees.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct AsArray<C>(pub C);
+
+impl<C> From<C> for AsArray<C> {
+    fn from(color: C) -> Self {
+        AsArray(color)
+    }
+}
+
+macro_rules! impl_as_array {
+    ($color: ident < $space: ident, $t: ident >, ($($component: ty),+), $space_bound: path, $t_bound: path) => {
+        impl<$space: $space_bound, $t: $t_bound> Serialize for AsArray<$color<$space, $t>>
+        where
+            ($($component,)+): Serialize,
+        {
+            fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+            where
+                Se: Serializer,
+            {
+                self.0.into_components().serialize(serializer)
+            }
+        }
+
+        impl<'de, $space: $space_bound, $t: $t_bound> Deserialize<'de> for AsArray<$color<$space, $t>>
+        where
+            ($($component,)+): Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let components = <($($component,)+)>::deserialize(deserializer)?;
+                Ok(AsArray($color::from_components(components)))
+            }
+        }
+
+        impl<$space: $space_bound, $t: $t_bound, A: Component> Serialize
+            for AsArray<Alpha<$color<$space, $t>, A>>
+        where
+            ($($component,)+ A): Serialize,
+        {
+            fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+            where
+                Se: Serializer,
+            {
+                self.0.into_components().serialize(serializer)
+            }
+        }
+
+        impl<'de, $space: $space_bound, $t: $t_bound, A: Component> Deserialize<'de>
+            for AsArray<Alpha<$color<$space, $t>, A>>
+        where
+            ($($component,)+ A): Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let components = <($($component,)+ A)>::deserialize(deserializer)?;
+                Ok(AsArray(Alpha::<$color<$space, $t>, A>::from_components(components)))
+            }
+        }
+    };
+}
+
+impl_as_array!(Rgb<S, T>, (T, T, T), RgbStandard, Component);
+impl_as_array!(Luma<S, T>, (T), LumaStandard, Component);
+impl_as_array!(Hsl<S, T>, (RgbHue<T>, T, T), RgbStandard, FloatComponent);
+impl_as_array!(Hsv<S, T>, (RgbHue<T>, T, T), RgbStandard, FloatComponent);
+impl_as_array!(Hwb<S, T>, (RgbHue<T>, T, T), RgbStandard, FloatComponent);
+impl_as_array!(Lab<Wp, T>, (T, T, T), WhitePoint, FloatComponent);
+impl_as_array!(Lch<Wp, T>, (T, T, LabHue<T>), WhitePoint, FloatComponent);
+impl_as_array!(Xyz<Wp, T>, (T, T, T), WhitePoint, FloatComponent);
+impl_as_array!(Yxy<Wp, T>, (T, T, T), WhitePoint, FloatComponent);
+
+/// Serialize and deserialize colors as hexadecimal color codes, using
+/// `#[serde(with = "palette::serializing::as_hex")]`. See [`AsHex`] for the
+/// format.
+pub mod as_hex {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::AsHex;
+
+    /// Serialize `color` as if it was wrapped in [`AsHex`].
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Clone,
+        AsHex<C>: Serialize,
+        S: Serializer,
+    {
+        AsHex(color.clone()).serialize(serializer)
+    }
+
+    /// Deserialize a color as if it was wrapped in [`AsHex`].
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        AsHex<C>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        AsHex::deserialize(deserializer).map(|AsHex(color)| color)
+    }
+}
+
+/// Serialize and deserialize colors as arrays of components, using
+/// `#[serde(with = "palette::serializing::as_array")]`. See [`AsArray`] for
+/// the format.
+pub mod as_array {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::AsArray;
+
+    /// Serialize `color` as if it was wrapped in [`AsArray`].
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Clone,
+        AsArray<C>: Serialize,
+        S: Serializer,
+    {
+        AsArray(color.clone()).serialize(serializer)
+    }
+
+    /// Deserialize a color as if it was wrapped in [`AsArray`].
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        AsArray<C>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        AsArray::deserialize(deserializer).map(|AsArray(color)| color)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AsArray, AsHex};
+    use crate::{Hsla, Lch, LinLuma, Srgb, Srgba};
+
+    #[test]
+    fn hex_round_trip() {
+        let color = AsHex(Srgb::new(1u8, 128, 255));
+        let serialized = serde_json::to_string(&color).unwrap();
+        assert_eq!(serialized, r##""#0180ff""##);
+        assert_eq!(
+            serde_json::from_str::<AsHex<Srgb<u8>>>(&serialized).unwrap(),
+            color
+        );
+
+        let color = AsHex(Srgba::new(1u8, 128, 255, 64));
+        let serialized = serde_json::to_string(&color).unwrap();
+        assert_eq!(serialized, r##""#0180ff40""##);
+        assert_eq!(
+            serde_json::from_str::<AsHex<Srgba<u8>>>(&serialized).unwrap(),
+            color
+        );
+    }
+
+    #[test]
+    fn hex_short_forms() {
+        let color: AsHex<Srgb<u8>> = serde_json::from_str(r#""f80""#).unwrap();
+        assert_eq!(color.0, Srgb::new(255, 136, 0));
+
+        let color: AsHex<Srgba<u8>> = serde_json::from_str(r##""#ff8000""##).unwrap();
+        assert_eq!(color.0, Srgba::new(255, 128, 0, 255));
+    }
+
+    #[test]
+    fn hex_errors() {
+        assert!(serde_json::from_str::<AsHex<Srgb<u8>>>(r##""#ff8000ff""##).is_err());
+        assert!(serde_json::from_str::<AsHex<Srgb<u8>>>(r##""#ff80zz""##).is_err());
+        assert!(serde_json::from_str::<AsHex<Srgb<u8>>>("[255, 128, 0]").is_err());
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let color = AsArray(Srgb::new(1u8, 128, 255));
+        let serialized = serde_json::to_string(&color).unwrap();
+        assert_eq!(serialized, "[1,128,255]");
+        assert_eq!(
+            serde_json::from_str::<AsArray<Srgb<u8>>>(&serialized).unwrap(),
+            color
+        );
+
+        let color = AsArray(Hsla::new(120.0, 1.0, 0.5, 0.25));
+        let serialized = serde_json::to_string(&color).unwrap();
+        assert_eq!(serialized, "[120.0,1.0,0.5,0.25]");
+        assert_eq!(
+            serde_json::from_str::<AsArray<Hsla>>(&serialized).unwrap(),
+            color
+        );
+
+        let color = AsArray(Lch::new(50.0, 20.0, 270.0));
+        let serialized = serde_json::to_string(&color).unwrap();
+        assert_eq!(serialized, "[50.0,20.0,270.0]");
+        assert_eq!(
+            serde_json::from_str::<AsArray<Lch>>(&serialized).unwrap(),
+            color
+        );
+
+        let color = AsArray(LinLuma::new(0.5));
+        let serialized = serde_json::to_string(&color).unwrap();
+        assert_eq!(serialized, "[0.5]");
+        assert_eq!(
+            serde_json::from_str::<AsArray<LinLuma>>(&serialized).unwrap(),
+            color
+        );
+    }
+
+    #[test]
+    fn with_attribute() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Colors {
+            #[serde(with = "super::as_hex")]
+            hex: Srgba<u8>,
+            #[serde(with = "super::as_array")]
+            array: Lch,
+        }
+
+        let colors = Colors {
+            hex: Srgba::new(1, 128, 255, 64),
+            array: Lch::new(50.0, 20.0, 270.0),
+        };
+        let serialized = serde_json::to_string(&colors).unwrap();
+        assert_eq!(
+            serialized,
+            r##"{"hex":"#0180ff40","array":[50.0,20.0,270.0]}"##
+        );
+        assert_eq!(serde_json::from_str::<Colors>(&serialized).unwrap(), colors);
+    }
+
+    #[test]
+    fn array_errors() {
+        assert!(serde_json::from_str::<AsArray<Srgb>>("[1.0, 0.5]").is_err());
+        assert!(serde_json::from_str::<AsArray<Srgb>>("[1.0, 0.5, 0.0, 1.0]").is_err());
+        assert!(serde_json::from_str::<AsArray<Srgb>>(
+            r#"{"red": 1.0, "green": 0.5, "blue": 0.0}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,418 @@
+//! Batch conversions of `f32` components, using SIMD instructions.
+//!
+//! The functions in this module perform the most common per component
+//! operations on whole slices of components, four at the time. They use SSE2
+//! on `x86_64`, where it's always available, and fall back to the regular
+//! scalar implementations on other targets and for any components that don't
+//! fill a whole vector.
+//!
+//! The components are passed as raw slices, which can be borrowed from slices
+//! of colors with [`Pixel::into_raw_slice_mut`](crate::Pixel::into_raw_slice_mut):
+//!
+//! ```
+//! use palette::{LinSrgb, Pixel, Srgb};
+//!
+//! let mut colors = vec![Srgb::new(1.0, 0.5, 0.0); 100];
+//! palette::simd::srgb_into_linear(Srgb::into_raw_slice_mut(&mut colors));
+//!
+//! let linear = LinSrgb::from_raw_slice(Srgb::into_raw_slice(&colors));
+//! let expected = Srgb::new(1.0, 0.5, 0.0).into_linear();
+//! assert!((linear[0].green - expected.green).abs() < 0.000001);
+//! ```
+//!
+//! The results are the same as for the scalar implementations within a
+//! small rounding error, for finite components. Alpha components need to be
+//! left out, since they aren't encoded.
+//!
+//! Requires the `simd` feature.
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::{Srgb, TransferFn};
+use crate::matrix::Mat3;
+use crate::white_point::WhitePoint;
+use crate::{Lab, Xyz};
+
+/// Decode sRGB encoded components into linear components, in place.
+///
+/// This is the same as calling `into_linear` on each color, but for raw
+/// components.
+pub fn srgb_into_linear(components: &mut [f32]) {
+    #[cfg(target_arch = "x86_64")]
+    let components = sse2::srgb_into_linear(components);
+
+    for component in components {
+        *component = Srgb::into_linear(*component);
+    }
+}
+
+/// Encode linear components as sRGB components, in place.
+///
+/// This is the same as calling `from_linear` on each color, but for raw
+/// components.
+pub fn srgb_from_linear(components: &mut [f32]) {
+    #[cfg(target_arch = "x86_64")]
+    let components = sse2::srgb_from_linear(components);
+
+    for component in components {
+        *component = Srgb::from_linear(*component);
+    }
+}
+
+/// Multiply each triplet of components with `matrix`, in place.
+///
+/// This is the same as [`multiply_xyz`](crate::matrix::multiply_xyz), and is
+/// used with matrices such as the ones from
+/// [`rgb_to_xyz_matrix`](crate::matrix::rgb_to_xyz_matrix), to convert
+/// between linear RGB and XYZ.
+///
+/// ```
+/// use palette::encoding::Srgb;
+/// use palette::matrix::rgb_to_xyz_matrix;
+/// use palette::{LinSrgb, Pixel, Xyz};
+///
+/// let mut colors = vec![LinSrgb::new(1.0, 1.0, 1.0); 10];
+/// let matrix = rgb_to_xyz_matrix::<Srgb, f32>();
+/// palette::simd::multiply_matrix(&matrix, LinSrgb::into_raw_slice_mut(&mut colors));
+///
+/// let xyz: &[Xyz] = Pixel::from_raw_slice(LinSrgb::into_raw_slice(&colors));
+/// assert!((xyz[0].y - 1.0).abs() < 0.0001);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length of `components` isn't a multiple of 3.
+pub fn multiply_matrix(matrix: &Mat3<f32>, components: &mut [f32]) {
+    assert_eq!(
+        components.len() % 3,
+        0,
+        "the number of components must be a multiple of 3"
+    );
+
+    #[cfg(target_arch = "x86_64")]
+    let components = sse2::multiply_matrix(matrix, components);
+
+    let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = *matrix;
+    for color in components.chunks_exact_mut(3) {
+        let (x, y, z) = (color[0], color[1], color[2]);
+        color[0] = m0 * x + m1 * y + m2 * z;
+        color[1] = m3 * x + m4 * y + m5 * z;
+        color[2] = m6 * x + m7 * y + m8 * z;
+    }
+}
+
+/// Convert each triplet of XYZ components into L\*a\*b\* components, in
+/// place.
+///
+/// This is the same as converting [`Xyz<Wp, f32>`](Xyz) into
+/// [`Lab<Wp, f32>`](Lab), but for raw components.
+///
+/// # Panics
+///
+/// Panics if the length of `components` isn't a multiple of 3.
+pub fn xyz_into_lab<Wp: WhitePoint>(components: &mut [f32]) {
+    assert_eq!(
+        components.len() % 3,
+        0,
+        "the number of components must be a multiple of 3"
+    );
+
+    #[cfg(target_arch = "x86_64")]
+    let components = {
+        let white: Xyz<Wp, f32> = Wp::get_xyz();
+        sse2::xyz_into_lab([white.x, white.y, white.z], components)
+    };
+
+    for color in components.chunks_exact_mut(3) {
+        let xyz = Xyz::<Wp, f32>::with_wp(color[0], color[1], color[2]);
+        let lab = Lab::from_color_unclamped(xyz);
+        color.copy_from_slice(&[lab.l, lab.a, lab.b]);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sse2 {
+    use core::arch::x86_64::*;
+    use core::ops::{Add, Div, Mul, Sub};
+
+    use crate::matrix::Mat3;
+
+    /// Four `f32` values in an SSE register.
+    #[derive(Clone, Copy)]
+    struct F32x4(__m128);
+
+    impl F32x4 {
+        #[inline]
+        fn splat(value: f32) -> Self {
+            F32x4(unsafe { _mm_set1_ps(value) })
+        }
+
+        #[inline]
+        fn load(values: [f32; 4]) -> Self {
+            F32x4(unsafe { _mm_loadu_ps(values.as_ptr()) })
+        }
+
+        #[inline]
+        fn store(self) -> [f32; 4] {
+            let mut values = [0.0; 4];
+            unsafe { _mm_storeu_ps(values.as_mut_ptr(), self.0) };
+            values
+        }
+
+        #[inline]
+        fn sqrt(self) -> Self {
+            F32x4(unsafe { _mm_sqrt_ps(self.0) })
+        }
+
+        /// Pick the lanes from `self` where `value <= threshold`, and from
+        /// `otherwise` everywhere else.
+        #[inline]
+        fn select_le(self, value: Self, threshold: Self, otherwise: Self) -> Self {
+            unsafe {
+                let mask = _mm_cmple_ps(value.0, threshold.0);
+                F32x4(_mm_or_ps(
+                    _mm_and_ps(mask, self.0),
+                    _mm_andnot_ps(mask, otherwise.0),
+                ))
+            }
+        }
+
+        /// The `n`th root of positive values, using Newton's method from an
+        /// estimate based on the bit pattern of the values.
+        #[inline]
+        fn root(self, n: u8) -> Self {
+            let n_float = f32::from(n);
+            let one_bits = 1.0f32.to_bits() as f32;
+
+            let mut root = unsafe {
+                let bits = _mm_cvtepi32_ps(_mm_castps_si128(self.0));
+                let estimate = F32x4(bits) * F32x4::splat(n_float.recip())
+                    + F32x4::splat(one_bits * (n_float - 1.0) / n_float);
+                F32x4(_mm_castsi128_ps(_mm_cvtps_epi32(estimate.0)))
+            };
+
+            let previous_weight = F32x4::splat((n_float - 1.0) / n_float);
+            let value_weight = F32x4::splat(n_float.recip());
+
+            for _ in 0..4 {
+                let mut power = root;
+                for _ in 2..n {
+                    power = power * root;
+                }
+
+                root = root * previous_weight + self / power * value_weight;
+            }
+
+            root
+        }
+    }
+
+    impl Add for F32x4 {
+        type Output = F32x4;
+
+        #[inline]
+        fn add(self, other: F32x4) -> F32x4 {
+            F32x4(unsafe { _mm_add_ps(self.0, other.0) })
+        }
+    }
+
+    impl Sub for F32x4 {
+        type Output = F32x4;
+
+        #[inline]
+        fn sub(self, other: F32x4) -> F32x4 {
+            F32x4(unsafe { _mm_sub_ps(self.0, other.0) })
+        }
+    }
+
+    impl Mul for F32x4 {
+        type Output = F32x4;
+
+        #[inline]
+        fn mul(self, other: F32x4) -> F32x4 {
+            F32x4(unsafe { _mm_mul_ps(self.0, other.0) })
+        }
+    }
+
+    impl Div for F32x4 {
+        type Output = F32x4;
+
+        #[inline]
+        fn div(self, other: F32x4) -> F32x4 {
+            F32x4(unsafe { _mm_div_ps(self.0, other.0) })
+        }
+    }
+
+    /// Apply `f` to each group of 4 components and return the remainder.
+    #[inline]
+    fn map_lanes(components: &mut [f32], f: impl Fn(F32x4) -> F32x4) -> &mut [f32] {
+        let mut chunks = components.chunks_exact_mut(4);
+
+        for chunk in &mut chunks {
+            let values = F32x4::load([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            chunk.copy_from_slice(&f(values).store());
+        }
+
+        chunks.into_remainder()
+    }
+
+    /// Apply `f` to the components of each group of 4 colors, with one
+    /// color per lane, and return the remainder.
+    #[inline]
+    fn map_triplets(components: &mut [f32], f: impl Fn([F32x4; 3]) -> [F32x4; 3]) -> &mut [f32] {
+        let mut chunks = components.chunks_exact_mut(12);
+
+        for chunk in &mut chunks {
+            let first = F32x4::load([chunk[0], chunk[3], chunk[6], chunk[9]]);
+            let second = F32x4::load([chunk[1], chunk[4], chunk[7], chunk[10]]);
+            let third = F32x4::load([chunk[2], chunk[5], chunk[8], chunk[11]]);
+
+            let [first, second, third] = f([first, second, third]);
+            let (first, second, third) = (first.store(), second.store(), third.store());
+
+            for (lane, color) in chunk.chunks_exact_mut(3).enumerate() {
+                color.copy_from_slice(&[first[lane], second[lane], third[lane]]);
+            }
+        }
+
+        chunks.into_remainder()
+    }
+
+    pub(super) fn srgb_into_linear(components: &mut [f32]) -> &mut [f32] {
+        map_lanes(components, |encoded| {
+            let low = encoded * F32x4::splat(12.92f32.recip());
+
+            // x^2.4 = x^2 * x^(2/5)
+            let base = (encoded + F32x4::splat(0.055)) * F32x4::splat(1.055f32.recip());
+            let squared = base * base;
+            let high = squared * squared.root(5);
+
+            low.select_le(encoded, F32x4::splat(0.04045), high)
+        })
+    }
+
+    pub(super) fn srgb_from_linear(components: &mut [f32]) -> &mut [f32] {
+        map_lanes(components, |linear| {
+            let low = linear * F32x4::splat(12.92);
+
+            // x^(1/2.4) = x^(1/4) * x^(1/6)
+            let power = linear.sqrt().sqrt() * linear.root(3).sqrt();
+            let high = power * F32x4::splat(1.055) - F32x4::splat(0.055);
+
+            low.select_le(linear, F32x4::splat(0.0031308), high)
+        })
+    }
+
+    pub(super) fn multiply_matrix<'a>(
+        matrix: &Mat3<f32>,
+        components: &'a mut [f32],
+    ) -> &'a mut [f32] {
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = *matrix;
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = [
+            F32x4::splat(m0),
+            F32x4::splat(m1),
+            F32x4::splat(m2),
+            F32x4::splat(m3),
+            F32x4::splat(m4),
+            F32x4::splat(m5),
+            F32x4::splat(m6),
+            F32x4::splat(m7),
+            F32x4::splat(m8),
+        ];
+
+        map_triplets(components, |[x, y, z]| {
+            [
+                m0 * x + m1 * y + m2 * z,
+                m3 * x + m4 * y + m5 * z,
+                m6 * x + m7 * y + m8 * z,
+            ]
+        })
+    }
+
+    pub(super) fn xyz_into_lab(white: [f32; 3], components: &mut [f32]) -> &mut [f32] {
+        let epsilon = F32x4::splat((6.0f32 / 29.0).powi(3));
+        let kappa = F32x4::splat(841.0 / 108.0);
+        let delta = F32x4::splat(4.0 / 29.0);
+
+        let convert =
+            |value: F32x4| (kappa * value + delta).select_le(value, epsilon, value.root(3));
+
+        let [white_x, white_y, white_z] = white;
+        let (white_x, white_y, white_z) = (
+            F32x4::splat(white_x),
+            F32x4::splat(white_y),
+            F32x4::splat(white_z),
+        );
+
+        map_triplets(components, |[x, y, z]| {
+            let x = convert(x / white_x);
+            let y = convert(y / white_y);
+            let z = convert(z / white_z);
+
+            [
+                y * F32x4::splat(116.0) - F32x4::splat(16.0),
+                (x - y) * F32x4::splat(500.0),
+                (y - z) * F32x4::splat(200.0),
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::convert::IntoColorUnclamped;
+    use crate::encoding::{Srgb, TransferFn};
+    use crate::white_point::D65;
+    use crate::{Lab, Xyz};
+
+    fn samples() -> impl Iterator<Item = f32> {
+        (0..=2500).map(|step| step as f32 / 2000.0)
+    }
+
+    #[test]
+    fn srgb_into_linear() {
+        let mut components: Vec<f32> = samples().collect();
+        super::srgb_into_linear(&mut components);
+
+        for (input, output) in samples().zip(components) {
+            assert_relative_eq!(output, Srgb::into_linear(input), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn srgb_from_linear() {
+        let mut components: Vec<f32> = samples().collect();
+        super::srgb_from_linear(&mut components);
+
+        for (input, output) in samples().zip(components) {
+            assert_relative_eq!(output, Srgb::from_linear(input), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn multiply_matrix() {
+        let matrix = crate::matrix::rgb_to_xyz_matrix::<Srgb, f32>();
+        let mut components: Vec<f32> = samples().take(1500).collect();
+        super::multiply_matrix(&matrix, &mut components);
+
+        let inputs: Vec<f32> = samples().take(1500).collect();
+        for (input, output) in inputs.chunks(3).zip(components.chunks(3)) {
+            let input = Xyz::<D65, f32>::new(input[0], input[1], input[2]);
+            let expected: Xyz<D65, f32> = crate::matrix::multiply_xyz(&matrix, &input);
+            let output = Xyz::new(output[0], output[1], output[2]);
+            assert_relative_eq!(output, expected, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn xyz_into_lab() {
+        let mut components: Vec<f32> = samples().take(1500).collect();
+        super::xyz_into_lab::<D65>(&mut components);
+
+        let inputs: Vec<f32> = samples().take(1500).collect();
+        for (input, output) in inputs.chunks(3).zip(components.chunks(3)) {
+            let expected: Lab = Xyz::new(input[0], input[1], input[2]).into_color_unclamped();
+            let output = Lab::new(output[0], output[1], output[2]);
+            assert_relative_eq!(output, expected, epsilon = 1e-4);
+        }
+    }
+}
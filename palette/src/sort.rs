@@ -0,0 +1,195 @@
+//! Sorting of colors, such as the colors of a palette.
+//!
+//! Colors have no natural order, so they don't implement `Ord` or
+//! `PartialOrd`. They can instead be sorted by one of their properties, such
+//! as their luminance or hue:
+//!
+//! ```
+//! use palette::sort::{sort_by_hue, sort_by_luminance};
+//! use palette::LinSrgb;
+//!
+//! let mut colors = [
+//!     LinSrgb::new(0.0, 0.0, 1.0),
+//!     LinSrgb::new(1.0, 1.0, 1.0),
+//!     LinSrgb::new(1.0, 0.0, 0.0),
+//!     LinSrgb::new(0.0, 1.0, 0.0),
+//! ];
+//!
+//! sort_by_luminance(&mut colors);
+//! assert_eq!(colors[0], LinSrgb::new(0.0, 0.0, 1.0));
+//! assert_eq!(colors[3], LinSrgb::new(1.0, 1.0, 1.0));
+//!
+//! sort_by_hue(&mut colors);
+//! assert_eq!(colors[0], LinSrgb::new(1.0, 1.0, 1.0));
+//! assert_eq!(colors[1], LinSrgb::new(1.0, 0.0, 0.0));
+//! ```
+//!
+//! The sorting is stable, so colors with the same key keep their relative
+//! order. Keys that can't be compared to themselves, such as `NaN`, are
+//! placed last.
+
+use core::cmp::Ordering;
+
+use crate::convert::IntoColor;
+use crate::{GetHue, RelativeLuminance, Xyz};
+
+/// Sort `colors` by a key, that is calculated by `key` for each comparison.
+///
+/// ```
+/// use palette::sort::sort_by_key;
+/// use palette::Hsv;
+///
+/// let mut colors = [
+///     Hsv::new(0.0, 0.8, 1.0),
+///     Hsv::new(0.0, 0.2, 1.0),
+///     Hsv::new(0.0, 0.5, 1.0),
+/// ];
+///
+/// sort_by_key(&mut colors, |color| color.saturation);
+/// assert_eq!(colors[0], Hsv::new(0.0, 0.2, 1.0));
+/// ```
+pub fn sort_by_key<C, K, F>(colors: &mut [C], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&C) -> K,
+{
+    colors.sort_by(|a, b| compare_keys(&key(a), &key(b)));
+}
+
+/// Sort `colors` from dark to light, by their relative luminance.
+///
+/// This is the same order as when sorting by the lightness of
+/// [`Lab`](crate::Lab) or [`Lch`](crate::Lch), since it's calculated from
+/// the luminance.
+pub fn sort_by_luminance<C>(colors: &mut [C])
+where
+    C: RelativeLuminance + IntoColor<Xyz<C::WhitePoint, C::Scalar>> + Copy,
+{
+    sort_by_key(colors, |color| color.relative_luminance());
+}
+
+/// Sort `colors` by their hue, in positive degrees.
+///
+/// Colors without a hue, such as grays, are placed first, since they have no
+/// place on the color circle.
+pub fn sort_by_hue<C>(colors: &mut [C])
+where
+    C: GetHue,
+    C::Hue: Into<f32>,
+{
+    sort_by_key(colors, |color| {
+        color.get_hue().map(|hue| {
+            let degrees: f32 = hue.into();
+            if degrees < 0.0 {
+                degrees + 360.0
+            } else {
+                degrees
+            }
+        })
+    });
+}
+
+/// Compare two keys in a total order, by placing keys that are unordered
+/// with themselves last.
+fn compare_keys<K: PartialOrd>(a: &K, b: &K) -> Ordering {
+    if let Some(ordering) = a.partial_cmp(b) {
+        return ordering;
+    }
+
+    let a_unordered = a.partial_cmp(a).is_none();
+    let b_unordered = b.partial_cmp(b).is_none();
+
+    match (a_unordered, b_unordered) {
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        _ => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sort_by_hue, sort_by_key, sort_by_luminance};
+    use crate::{Hsv, Lab, LabHue, Lch, Srgb};
+
+    #[test]
+    fn nan_last() {
+        let mut values = [2.0, f32::NAN, 1.0, f32::NAN, 0.0];
+        sort_by_key(&mut values, |&value| value);
+
+        assert_eq!(values[..3], [0.0, 1.0, 2.0]);
+        assert!(values[3].is_nan() && values[4].is_nan());
+    }
+
+    #[test]
+    fn stable() {
+        let mut colors = [
+            Hsv::new(10.0, 0.5, 1.0),
+            Hsv::new(20.0, 0.0, 1.0),
+            Hsv::new(30.0, 0.5, 1.0),
+            Hsv::new(40.0, 0.0, 1.0),
+        ];
+        sort_by_key(&mut colors, |color| color.saturation);
+
+        assert_eq!(
+            colors,
+            [
+                Hsv::new(20.0, 0.0, 1.0),
+                Hsv::new(40.0, 0.0, 1.0),
+                Hsv::new(10.0, 0.5, 1.0),
+                Hsv::new(30.0, 0.5, 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn luminance() {
+        let mut colors = [
+            Srgb::new(1.0, 1.0, 0.0),
+            Srgb::new(0.0, 0.0, 0.0),
+            Srgb::new(1.0, 0.0, 0.0),
+            Srgb::new(0.4, 0.4, 0.4),
+        ];
+        sort_by_luminance(&mut colors);
+
+        assert_eq!(
+            colors,
+            [
+                Srgb::new(0.0, 0.0, 0.0),
+                Srgb::new(0.4, 0.4, 0.4),
+                Srgb::new(1.0, 0.0, 0.0),
+                Srgb::new(1.0, 1.0, 0.0),
+            ]
+        );
+
+        let mut lab: Vec<Lab> = [60.0, 20.0, 90.0, 40.0]
+            .iter()
+            .map(|&l| Lab::new(l, 10.0, -10.0))
+            .collect();
+        sort_by_luminance(&mut lab);
+        assert!(lab.windows(2).all(|pair| pair[0].l < pair[1].l));
+    }
+
+    #[test]
+    fn hue() {
+        let mut colors = [
+            Lch::new(50.0, 30.0, -90.0),
+            Lch::new(50.0, 0.0, 90.0),
+            Lch::new(50.0, 30.0, 10.0),
+            Lch::new(50.0, 30.0, 370.0),
+            Lch::new(50.0, 30.0, 180.0),
+        ];
+        sort_by_hue(&mut colors);
+
+        let hues: Vec<LabHue> = colors.iter().map(|color| color.hue).collect();
+        assert_eq!(
+            hues,
+            [
+                LabHue::from(90.0),
+                LabHue::from(10.0),
+                LabHue::from(370.0),
+                LabHue::from(180.0),
+                LabHue::from(270.0),
+            ]
+        );
+    }
+}
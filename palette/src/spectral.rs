@@ -0,0 +1,575 @@
+//! Colors of monochromatic light, such as the colors of the visible spectrum.
+//! Can be toggled with the `"spectral"` Cargo feature.
+//!
+//! The color of light with a single wavelength is calculated using the CIE
+//! 1931 2° standard observer color matching functions, which are embedded as
+//! a table with 5 nm steps between 380 nm and 780 nm. The colors together form
+//! the spectral locus, the outer edge of the chromaticity diagram:
+//!
+//! ```
+//! use palette::spectral::wavelength_to_xyz;
+//! use palette::white_point::D65;
+//! use palette::{FromColor, Xyz, Yxy};
+//!
+//! let green: Xyz<D65, f64> = wavelength_to_xyz(520.0);
+//! let chromaticity = Yxy::from_color(green);
+//!
+//! assert!((chromaticity.x - 0.0743).abs() < 0.0001);
+//! assert!((chromaticity.y - 0.8338).abs() < 0.0001);
+//! ```
+//!
+//! Most of these colors are outside the gamut of RGB displays.
+//! [`wavelength_to_srgb`] maps them into the sRGB gamut, which is often what's
+//! needed for visualizing spectra.
+//!
+//! The colors of black bodies, such as stars and incandescent lights, are
+//! calculated from their temperature with [`blackbody`], and
+//! [`blackbody_gradient`] makes a gradient of them for visualizing
+//! temperatures.
+//!
+//! Going the other way, the [dominant wavelength](Yxy::dominant_wavelength)
+//! and [excitation purity](Yxy::excitation_purity) of a color describe its
+//! chromaticity in terms of the spectral locus and a white point.
+
+#[cfg(feature = "alloc")]
+use crate::convert::FromColorUnclamped;
+use crate::float::Float;
+use crate::gamut::map_into_gamut;
+use crate::white_point::{WhitePoint, D65};
+use crate::{from_f64, Chromaticity, FloatComponent, Srgb, Xyz, Yxy};
+#[cfg(feature = "alloc")]
+use crate::{ComponentWise, Gradient, LinSrgb};
+
+include!(concat!(env!("OUT_DIR"), "/cie_1931.rs"));
+
+/// Calculate the color of light with a wavelength of `wavelength` nanometers.
+///
+/// The color is linearly interpolated from the color matching functions, and
+/// is black outside the range of 380 nm to 780 nm. Its luminance, `y`, is the
+/// sensitivity of the eye to the wavelength, where 1 is the sensitivity at
+/// 555 nm, so it gets darker towards the ends of the spectrum.
+///
+/// ```
+/// use palette::spectral::wavelength_to_xyz;
+/// use palette::white_point::D65;
+/// use palette::Xyz;
+///
+/// let color: Xyz<D65, f64> = wavelength_to_xyz(555.0);
+/// assert_eq!(color, Xyz::with_wp(0.51205, 1.0, 0.00575));
+/// ```
+pub fn wavelength_to_xyz<Wp, T>(wavelength: T) -> Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let last_index = COLOR_MATCHING_FUNCTIONS.len() - 1;
+    let position = (wavelength - from_f64(FIRST_WAVELENGTH)) / from_f64(WAVELENGTH_STEP);
+
+    // This also catches NaN
+    if !(position >= T::zero() && position <= from_f64(last_index as f64)) {
+        return Xyz::with_wp(T::zero(), T::zero(), T::zero());
+    }
+
+    let index = position.floor().to_usize().unwrap_or(0).min(last_index);
+    let factor = position - from_f64(index as f64);
+    let [x1, y1, z1] = COLOR_MATCHING_FUNCTIONS[index];
+    let [x2, y2, z2] = COLOR_MATCHING_FUNCTIONS[(index + 1).min(last_index)];
+
+    let interpolate = |a: f64, b: f64| from_f64::<T>(a) + (from_f64::<T>(b) - from_f64(a)) * factor;
+
+    Xyz::with_wp(
+        interpolate(x1, x2),
+        interpolate(y1, y2),
+        interpolate(z1, z2),
+    )
+}
+
+/// Calculate the color of light with a wavelength of `wavelength`
+/// nanometers, as an sRGB color with the relative luminance `luminance`.
+///
+/// The color is mapped into the sRGB gamut by reducing its chroma, using
+/// [`map_into_gamut`], so it's as saturated as sRGB allows, without changing
+/// its hue or lightness. The luminance is between 0 and 1, where a luminance
+/// of 1 is white. The result is black outside the range of 380 nm to 780 nm.
+///
+/// ```
+/// use palette::spectral::wavelength_to_srgb;
+///
+/// let spectrum: Vec<_> = (0..=6)
+///     .map(|step| wavelength_to_srgb(400.0 + step as f32 * 50.0, 0.2))
+///     .collect();
+///
+/// let red = spectrum[5];
+/// assert!(red.red > red.green && red.red > red.blue);
+/// ```
+///
+/// The luminance of the spectral color itself can be used to make the
+/// brightness of the colors follow the sensitivity of the eye, like in a
+/// spectrum of equal energy light. Here it's halved, so the colors in the
+/// middle of the spectrum don't become white:
+///
+/// ```
+/// use palette::spectral::{wavelength_to_srgb, wavelength_to_xyz};
+/// use palette::white_point::D65;
+/// use palette::Xyz;
+///
+/// let wavelength = 450.0;
+/// let xyz: Xyz<D65, f64> = wavelength_to_xyz(wavelength);
+/// let color = wavelength_to_srgb(wavelength, xyz.y * 0.5);
+///
+/// assert!(color.blue > color.red && color.blue > color.green);
+/// ```
+pub fn wavelength_to_srgb<T>(wavelength: T, luminance: T) -> Srgb<T>
+where
+    T: FloatComponent,
+{
+    let xyz: Xyz<D65, T> = wavelength_to_xyz(wavelength);
+
+    if xyz.y <= T::zero() {
+        return Srgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    map_into_gamut(xyz * (luminance / xyz.y))
+}
+
+/// The second radiation constant, `h * c / k`, in nanometer kelvins.
+const SECOND_RADIATION_CONSTANT: f64 = 1.438_776_877e7;
+
+/// Calculate the color of a black body with a temperature of `kelvin`
+/// kelvins, using Planck's law.
+///
+/// This is the color of the Planckian locus, which matches the color of
+/// incandescent light, stars and other hot objects. The spectrum of the black
+/// body is integrated with the color matching functions, and the result is
+/// normalized to a luminance, `y`, of 1. The color is black for temperatures
+/// that are not positive and finite.
+///
+/// ```
+/// use palette::spectral::blackbody;
+/// use palette::white_point::D65;
+/// use palette::{FromColor, Xyz, Yxy};
+///
+/// // CIE standard illuminant A is a black body at 2856 K
+/// let illuminant_a: Xyz<D65, f64> = blackbody(2856.0);
+/// let chromaticity = Yxy::from_color(illuminant_a);
+///
+/// assert!((chromaticity.x - 0.4476).abs() < 0.0005);
+/// assert!((chromaticity.y - 0.4074).abs() < 0.0005);
+/// ```
+pub fn blackbody<Wp, T>(kelvin: T) -> Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    let kelvin = kelvin.to_f64().unwrap_or(0.0);
+    if !(kelvin > 0.0 && kelvin.is_finite()) {
+        return Xyz::with_wp(T::zero(), T::zero(), T::zero());
+    }
+
+    // The logarithm of the spectral radiance, without its constant factor,
+    // to avoid overflowing at low temperatures
+    let log_radiance = |index: usize| {
+        let wavelength = FIRST_WAVELENGTH + index as f64 * WAVELENGTH_STEP;
+        let exponent = SECOND_RADIATION_CONSTANT / (wavelength * kelvin);
+        -5.0 * Float::ln(wavelength) - exponent - Float::ln_1p(-Float::exp(-exponent))
+    };
+
+    let max_log_radiance = (0..COLOR_MATCHING_FUNCTIONS.len())
+        .map(log_radiance)
+        .fold(f64::NEG_INFINITY, Float::max);
+
+    let mut sum = [0.0; 3];
+    for (index, values) in COLOR_MATCHING_FUNCTIONS.iter().enumerate() {
+        let radiance = Float::exp(log_radiance(index) - max_log_radiance);
+        for (sum, value) in sum.iter_mut().zip(values) {
+            *sum += radiance * value;
+        }
+    }
+
+    Xyz::with_wp(
+        from_f64(sum[0] / sum[1]),
+        T::one(),
+        from_f64(sum[2] / sum[1]),
+    )
+}
+
+/// Create a gradient of black body colors, for temperatures from
+/// `min_kelvin` to `max_kelvin`.
+///
+/// The domain of the gradient is the temperature in kelvins, and it has
+/// `count` evenly spaced control points. The colors are made as bright as
+/// possible in linear sRGB, without changing their chromaticity, except that
+/// the negative components of colors outside the sRGB gamut are clamped to 0.
+/// This makes the gradient useful for showing temperatures and for heat maps,
+/// from red for the lowest temperatures to blue for the highest:
+///
+/// ```
+/// use palette::spectral::blackbody_gradient;
+///
+/// let gradient = blackbody_gradient(1000.0, 12000.0, 100);
+///
+/// let warm = gradient.get(1500.0);
+/// assert_eq!(warm.red, 1.0);
+/// assert!(warm.blue < 0.1);
+///
+/// let cold = gradient.get(12000.0);
+/// assert_eq!(cold.blue, 1.0);
+/// assert!(cold.red < 0.8);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `count` is 0.
+#[cfg(feature = "alloc")]
+pub fn blackbody_gradient<T>(min_kelvin: T, max_kelvin: T, count: usize) -> Gradient<LinSrgb<T>>
+where
+    T: FloatComponent,
+{
+    assert!(count > 0, "a Gradient must contain at least one color");
+
+    let step = if count > 1 {
+        (max_kelvin - min_kelvin) / from_f64((count - 1) as f64)
+    } else {
+        T::zero()
+    };
+
+    let points = (0..count)
+        .map(|index| {
+            let kelvin = min_kelvin + step * from_f64(index as f64);
+            let xyz: Xyz<D65, T> = blackbody(kelvin);
+            let color = LinSrgb::from_color_unclamped(xyz);
+            let color = color.component_wise_self(|component| component.max(T::zero()));
+            let max = color.red.max(color.green).max(color.blue);
+
+            (kelvin, color / max)
+        })
+        .collect();
+
+    Gradient::with_domain(points)
+}
+
+/// The dominant wavelength of a color, as calculated by
+/// [`Yxy::dominant_wavelength`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DominantWavelength<T> {
+    /// The color is a mix of the white point and light with this wavelength,
+    /// in nanometers.
+    Spectral(T),
+
+    /// The color is in the purple region, between the ends of the spectrum,
+    /// and has no dominant wavelength. This is the wavelength of the
+    /// complementary color instead, in nanometers, which is conventionally
+    /// written as a negative number or with a "c" suffix.
+    Complementary(T),
+}
+
+impl<T: Copy> DominantWavelength<T> {
+    /// Get the wavelength, regardless of if it's spectral or complementary.
+    pub fn wavelength(&self) -> T {
+        match *self {
+            DominantWavelength::Spectral(wavelength) => wavelength,
+            DominantWavelength::Complementary(wavelength) => wavelength,
+        }
+    }
+}
+
+/// The point where a line from the white point crosses the edge of the
+/// chromaticity diagram.
+struct BoundaryIntersection<T> {
+    /// The distance along the line, relative to the length of its direction.
+    distance: T,
+
+    /// The wavelength at the intersection, or `None` if it's on the purple
+    /// line.
+    wavelength: Option<T>,
+}
+
+/// Find where a line from `origin`, in the direction `(dx, dy)`, first
+/// crosses the spectral locus or the purple line between its ends.
+fn boundary_intersection<T: FloatComponent>(
+    origin: Chromaticity<T>,
+    dx: T,
+    dy: T,
+) -> Option<BoundaryIntersection<T>> {
+    let chromaticity = |index: usize| {
+        let [x, y, z] = COLOR_MATCHING_FUNCTIONS[index];
+        let sum = x + y + z;
+        Chromaticity::new(from_f64::<T>(x / sum), from_f64(y / sum))
+    };
+
+    let last_index = COLOR_MATCHING_FUNCTIONS.len() - 1;
+    let mut nearest: Option<BoundaryIntersection<T>> = None;
+
+    // The segments between neighboring wavelengths, followed by the purple
+    // line from the last wavelength back to the first
+    for index in 0..=last_index {
+        let next_index = if index == last_index { 0 } else { index + 1 };
+        let start = chromaticity(index);
+        let end = chromaticity(next_index);
+
+        let (ex, ey) = (end.x - start.x, end.y - start.y);
+        let denominator = dx * ey - dy * ex;
+        if denominator == T::zero() {
+            continue;
+        }
+
+        let (ox, oy) = (start.x - origin.x, start.y - origin.y);
+        let distance = (ox * ey - oy * ex) / denominator;
+        let segment_position = (ox * dy - oy * dx) / denominator;
+
+        let is_on_segment = segment_position >= T::zero() && segment_position <= T::one();
+        let is_nearer = match nearest {
+            Some(ref nearest) => distance < nearest.distance,
+            None => true,
+        };
+
+        if distance > T::zero() && is_on_segment && is_nearer {
+            let wavelength = if index == last_index {
+                None
+            } else {
+                let first: T = from_f64(FIRST_WAVELENGTH);
+                let step: T = from_f64(WAVELENGTH_STEP);
+                Some(first + (from_f64::<T>(index as f64) + segment_position) * step)
+            };
+
+            nearest = Some(BoundaryIntersection {
+                distance,
+                wavelength,
+            });
+        }
+    }
+
+    nearest
+}
+
+/// Dominant wavelength and excitation purity, relative to the white point
+/// `Wp`. Requires the `"spectral"` feature.
+impl<Wp, T> Yxy<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+    /// Calculate the dominant wavelength of the color, relative to the white
+    /// point `Wp`.
+    ///
+    /// The dominant wavelength is where a line from the white point, through
+    /// the color, crosses the spectral locus. Colors in the purple region
+    /// have the wavelength of their complementary color instead, where the
+    /// line crosses the locus in the opposite direction. The result is
+    /// `None` for the white point itself and for invalid chromaticities.
+    ///
+    /// The spectral locus is approximated with straight lines between the
+    /// 5 nm steps of the color matching functions, so the result may differ
+    /// slightly from [`wavelength_to_xyz`] between the steps.
+    ///
+    /// ```
+    /// use palette::spectral::DominantWavelength;
+    /// use palette::white_point::D65;
+    /// use palette::Yxy;
+    ///
+    /// // The red primary of sRGB
+    /// let red = Yxy::<D65, f64>::with_wp(0.64, 0.33, 0.2126);
+    /// let wavelength = red.dominant_wavelength().unwrap();
+    ///
+    /// assert!(matches!(wavelength, DominantWavelength::Spectral(_)));
+    /// assert!((wavelength.wavelength() - 611.3).abs() < 0.5);
+    /// ```
+    pub fn dominant_wavelength(&self) -> Option<DominantWavelength<T>> {
+        let white = Chromaticity::<T>::from_white_point::<Wp>();
+        let (dx, dy) = (self.x - white.x, self.y - white.y);
+
+        if dx == T::zero() && dy == T::zero() {
+            return None;
+        }
+
+        match boundary_intersection(white, dx, dy)?.wavelength {
+            Some(wavelength) => Some(DominantWavelength::Spectral(wavelength)),
+            None => boundary_intersection(white, -dx, -dy)?
+                .wavelength
+                .map(DominantWavelength::Complementary),
+        }
+    }
+
+    /// Calculate the excitation purity of the color, relative to the white
+    /// point `Wp`.
+    ///
+    /// The excitation purity is the distance from the white point to the
+    /// color, relative to the distance from the white point to the edge of
+    /// the chromaticity diagram in the same direction. It's `0.0` for the
+    /// white point and `1.0` for spectral colors and colors on the purple
+    /// line. The result is `None` for invalid chromaticities.
+    ///
+    /// ```
+    /// use palette::spectral::wavelength_to_xyz;
+    /// use palette::white_point::D65;
+    /// use palette::{FromColor, Yxy};
+    ///
+    /// let green = Yxy::<D65, f64>::from_color(wavelength_to_xyz::<D65, f64>(520.0));
+    /// assert!((green.excitation_purity().unwrap() - 1.0).abs() < 1e-6);
+    ///
+    /// let white = Yxy::<D65, f64>::default();
+    /// assert_eq!(white.excitation_purity(), Some(0.0));
+    /// ```
+    pub fn excitation_purity(&self) -> Option<T> {
+        let white = Chromaticity::<T>::from_white_point::<Wp>();
+        let (dx, dy) = (self.x - white.x, self.y - white.y);
+
+        if dx == T::zero() && dy == T::zero() {
+            return Some(T::zero());
+        }
+
+        let intersection = boundary_intersection(white, dx, dy)?;
+        Some(T::one() / intersection.distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        blackbody, blackbody_gradient, wavelength_to_srgb, wavelength_to_xyz, DominantWavelength,
+    };
+    use crate::white_point::{D50, D65};
+    use crate::{Chromaticity, FromColor, Limited, Srgb, Xyz, Yxy};
+
+    type Xyz64 = Xyz<D65, f64>;
+
+    #[test]
+    fn table_values() {
+        assert_eq!(
+            wavelength_to_xyz::<D65, f64>(380.0),
+            Xyz64::with_wp(0.001368, 0.000039, 0.00645)
+        );
+        assert_eq!(
+            wavelength_to_xyz::<D65, f64>(780.0),
+            Xyz64::with_wp(0.000042, 0.000015, 0.0)
+        );
+        assert_relative_eq!(
+            wavelength_to_xyz::<D65, f64>(602.5),
+            Xyz64::with_wp(1.0539, 0.5989, 0.0007)
+        );
+    }
+
+    #[test]
+    fn outside_spectrum() {
+        let black = Xyz64::with_wp(0.0, 0.0, 0.0);
+
+        assert_eq!(wavelength_to_xyz::<D65, f64>(379.9), black);
+        assert_eq!(wavelength_to_xyz::<D65, f64>(780.1), black);
+        assert_eq!(wavelength_to_xyz::<D65, f64>(f64::NAN), black);
+        assert_eq!(wavelength_to_srgb(100.0, 0.5), Srgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn equal_energy_white() {
+        // Equal amounts of light in all wavelengths are white, with x = y = 1/3
+        let sum = (0..=400)
+            .map(|wavelength| wavelength_to_xyz::<D65, f64>(380.0 + wavelength as f64))
+            .fold(Xyz64::with_wp(0.0, 0.0, 0.0), |sum, color| sum + color);
+        let chromaticity = Yxy::from_color(sum);
+
+        assert_relative_eq!(chromaticity.x, 1.0 / 3.0, epsilon = 0.0001);
+        assert_relative_eq!(chromaticity.y, 1.0 / 3.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn srgb_colors() {
+        let blue: Srgb<f64> = wavelength_to_srgb(460.0, 0.1);
+        let green: Srgb<f64> = wavelength_to_srgb(530.0, 0.5);
+        let red: Srgb<f64> = wavelength_to_srgb(640.0, 0.2);
+
+        assert!(blue.blue > blue.red && blue.blue > blue.green);
+        assert!(green.green > green.red && green.green > green.blue);
+        assert!(red.red > red.green && red.red > red.blue);
+
+        for color in &[blue, green, red] {
+            assert!(color.is_valid());
+        }
+    }
+
+    #[test]
+    fn blackbody_colors() {
+        let chromaticity = |kelvin: f64| Yxy::from_color(blackbody::<D65, f64>(kelvin));
+
+        // Daylight is close to the Planckian locus
+        let daylight = chromaticity(6500.0);
+        assert_relative_eq!(daylight.x, 0.3135, epsilon = 0.0005);
+        assert_relative_eq!(daylight.y, 0.3236, epsilon = 0.0005);
+
+        // Extreme temperatures don't overflow
+        assert!(chromaticity(100.0).x > 0.7);
+        assert!(chromaticity(1e7).x < 0.25);
+        assert_eq!(blackbody::<D65, f32>(1000.0).y, 1.0);
+
+        let black = Xyz64::with_wp(0.0, 0.0, 0.0);
+        assert_eq!(blackbody::<D65, f64>(0.0), black);
+        assert_eq!(blackbody::<D65, f64>(-100.0), black);
+        assert_eq!(blackbody::<D65, f64>(f64::NAN), black);
+        assert_eq!(blackbody::<D65, f64>(f64::INFINITY), black);
+    }
+
+    #[test]
+    fn blackbody_gradient_domain() {
+        let gradient = blackbody_gradient(2000.0f64, 10000.0, 9);
+        assert_eq!(gradient.domain(), (2000.0, 10000.0));
+
+        let color = gradient.get(6000.0);
+        assert!(color.is_valid());
+        assert_relative_eq!(color.red.max(color.green).max(color.blue), 1.0);
+
+        let single = blackbody_gradient(3000.0f64, 3000.0, 1);
+        assert_eq!(single.get(0.0), single.get(3000.0));
+    }
+
+    #[test]
+    fn dominant_wavelength_of_spectral_colors() {
+        for &wavelength in &[400.0, 452.5, 500.0, 520.0, 577.0, 620.0, 690.0] {
+            let color = Yxy::<D65, f64>::from_color(wavelength_to_xyz::<D65, f64>(wavelength));
+
+            match color.dominant_wavelength() {
+                Some(DominantWavelength::Spectral(dominant)) => {
+                    assert_relative_eq!(dominant, wavelength, epsilon = 0.1)
+                }
+                other => panic!("unexpected dominant wavelength {:?}", other),
+            }
+            assert_relative_eq!(color.excitation_purity().unwrap(), 1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn dominant_wavelength_of_mixed_colors() {
+        let white = Chromaticity::<f64>::from_white_point::<D50>();
+        let spectral = Chromaticity::from_xyz(wavelength_to_xyz::<D50, f64>(480.0));
+        let color = Yxy::<D50, f64>::with_wp(
+            white.x + (spectral.x - white.x) * 0.25,
+            white.y + (spectral.y - white.y) * 0.25,
+            0.5,
+        );
+
+        assert_relative_eq!(
+            color.dominant_wavelength().unwrap().wavelength(),
+            480.0,
+            epsilon = 0.01
+        );
+        assert_relative_eq!(color.excitation_purity().unwrap(), 0.25, epsilon = 1e-9);
+
+        let white = Yxy::<D50, f64>::with_wp(white.x, white.y, 1.0);
+        assert_eq!(white.dominant_wavelength(), None);
+        assert_eq!(white.excitation_purity(), Some(0.0));
+    }
+
+    #[test]
+    fn complementary_wavelength() {
+        // A purple between the white point and the middle of the purple line
+        let magenta = Yxy::<D65, f64>::with_wp(0.35, 0.15, 0.3);
+
+        match magenta.dominant_wavelength() {
+            Some(DominantWavelength::Complementary(wavelength)) => {
+                assert!(wavelength > 490.0 && wavelength < 570.0)
+            }
+            other => panic!("unexpected dominant wavelength {:?}", other),
+        }
+
+        let purity = magenta.excitation_purity().unwrap();
+        assert!(purity > 0.0 && purity < 1.0);
+    }
+}
@@ -0,0 +1,300 @@
+//! Tone mapping of high dynamic range colors.
+//!
+//! Rendering and lighting calculations often produce linear colors that are
+//! much brighter than what a display can show. Tone mapping compresses them
+//! into the `[0.0, 1.0]` range, with a smooth rolloff of the highlights,
+//! instead of clipping everything above `1.0` to white:
+//!
+//! ```
+//! use palette::{LinSrgb, Srgb, ToneMap};
+//!
+//! let hdr = LinSrgb::new(4.0, 1.5, 0.2);
+//! let sdr: Srgb = Srgb::from_linear(hdr.aces());
+//!
+//! assert!(sdr.red <= 1.0 && sdr.green < sdr.red && sdr.blue < sdr.green);
+//! ```
+//!
+//! The operators are applied to each component separately, so very bright
+//! and saturated colors will also become less saturated, similar to how film
+//! responds to overexposure. The exposure can be adjusted before mapping the
+//! color, using the [`Exposure`] trait:
+//!
+//! ```
+//! use palette::{Exposure, LinSrgb, ToneMap};
+//!
+//! let hdr = LinSrgb::new(4.0, 1.5, 0.2);
+//! let darker = hdr.expose(-1.0).aces();
+//!
+//! assert!(darker.red < hdr.aces().red);
+//! ```
+
+use crate::encoding::Linear;
+use crate::luma::Luma;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::white_point::WhitePoint;
+use crate::{from_f64, ComponentWise, FloatComponent, Xyz};
+
+/// Tone mapping operators for linear colors.
+///
+/// The operators map each component from `[0.0, ∞)` into `[0.0, 1.0]`.
+/// Negative components are treated as `0.0`. This is implemented for linear
+/// RGB and luma colors, since the curves are meant to be applied to linear
+/// light, before encoding the color for a display.
+pub trait ToneMap<T: FloatComponent>: ComponentWise<Scalar = T> + Sized {
+    /// The simple Reinhard operator, `x / (1 + x)`.
+    ///
+    /// It never reaches `1.0`, so very bright colors stay slightly gray.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, ToneMap};
+    ///
+    /// assert_eq!(LinSrgb::new(1.0, 3.0, 0.0).reinhard(), LinSrgb::new(0.5, 0.75, 0.0));
+    /// ```
+    fn reinhard(&self) -> Self {
+        self.component_wise_self(|x| {
+            let x = x.max(T::zero());
+            x / (T::one() + x)
+        })
+    }
+
+    /// The extended Reinhard operator, `x * (1 + x / white²) / (1 + x)`, which
+    /// maps `white` and anything brighter to `1.0`.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, ToneMap};
+    ///
+    /// let mapped = LinSrgb::new(4.0, 8.0, 0.0).reinhard_extended(4.0);
+    /// assert_eq!(mapped.red, 1.0);
+    /// assert_eq!(mapped.green, 1.0);
+    /// ```
+    fn reinhard_extended(&self, white: T) -> Self {
+        let white_squared = white * white;
+
+        self.component_wise_self(|x| {
+            let x = x.max(T::zero());
+            let mapped = x * (T::one() + x / white_squared) / (T::one() + x);
+            mapped.min(T::one())
+        })
+    }
+
+    /// John Hable's filmic curve, from Uncharted 2, normalized so that a
+    /// linear white point of `11.2` maps to `1.0`.
+    ///
+    /// It has a slight toe that darkens the shadows, and a long shoulder for
+    /// the highlights. The original implementation multiplies the color by an
+    /// exposure bias of `2.0` before mapping it.
+    ///
+    /// ```
+    /// use palette::{LinLuma, ToneMap};
+    ///
+    /// let mapped: LinLuma = LinLuma::new(11.2).hable();
+    /// assert!((mapped.luma - 1.0f32).abs() < 0.0001);
+    /// ```
+    fn hable(&self) -> Self {
+        let white_scale = T::one() / hable_curve(from_f64(HABLE_WHITE));
+
+        self.component_wise_self(|x| {
+            let mapped = hable_curve(x.max(T::zero())) * white_scale;
+            mapped.min(T::one())
+        })
+    }
+
+    /// Krzysztof Narkowicz's fit of the ACES filmic curve,
+    /// `x * (2.51 * x + 0.03) / (x * (2.43 * x + 0.59) + 0.14)`.
+    ///
+    /// It has more contrast than the other operators, and reaches `1.0` at
+    /// around `x = 10`.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, ToneMap};
+    ///
+    /// let mapped = LinSrgb::new(0.0, 0.18, 100.0).aces();
+    /// assert_eq!(mapped.red, 0.0);
+    /// assert!(mapped.green > 0.18 && mapped.green < 0.3);
+    /// assert_eq!(mapped.blue, 1.0);
+    /// ```
+    fn aces(&self) -> Self {
+        let a: T = from_f64(2.51);
+        let b: T = from_f64(0.03);
+        let c: T = from_f64(2.43);
+        let d: T = from_f64(0.59);
+        let e: T = from_f64(0.14);
+
+        self.component_wise_self(|x| {
+            let x = x.max(T::zero());
+            let mapped = (x * (a * x + b)) / (x * (c * x + d) + e);
+            mapped.min(T::one())
+        })
+    }
+}
+
+impl<S, T> ToneMap<T> for Rgb<Linear<S>, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+}
+
+impl<Wp, T> ToneMap<T> for Luma<Linear<Wp>, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+}
+
+/// Exposure adjustments for linear colors.
+///
+/// Scene-referred colors, such as the output of a renderer or a camera, have
+/// no upper limit and often need to be re-ranged before they are tone mapped
+/// or encoded for a display. This is implemented for linear RGB, linear luma
+/// and XYZ, where scaling the components scales the amount of light.
+pub trait Exposure<T: FloatComponent>: ComponentWise<Scalar = T> + Sized {
+    /// Change the exposure by a number of stops, where each stop doubles the
+    /// amount of light. Negative stops make the color darker.
+    ///
+    /// ```
+    /// use palette::{Exposure, LinSrgb};
+    ///
+    /// let color = LinSrgb::new(0.1, 0.2, 0.4);
+    /// assert_eq!(color.expose(1.0), LinSrgb::new(0.2, 0.4, 0.8));
+    /// assert_eq!(color.expose(-2.0), LinSrgb::new(0.025, 0.05, 0.1));
+    /// ```
+    fn expose(&self, stops: T) -> Self {
+        let factor = from_f64::<T>(2.0).powf(stops);
+        self.component_wise_self(|x| x * factor)
+    }
+
+    /// Scale the color so that its largest component becomes `1.0`, without
+    /// changing the proportions between the components. Colors without any
+    /// positive component are returned unchanged.
+    ///
+    /// ```
+    /// use palette::{Exposure, LinSrgb};
+    ///
+    /// let color = LinSrgb::new(2.0, 8.0, 4.0);
+    /// assert_eq!(color.normalize_to_peak(), LinSrgb::new(0.25, 1.0, 0.5));
+    /// ```
+    fn normalize_to_peak(&self) -> Self {
+        let peak = self.max_component();
+
+        if peak > T::zero() {
+            self.component_wise_self(|x| x / peak)
+        } else {
+            self.component_wise_self(|x| x)
+        }
+    }
+}
+
+impl<S, T> Exposure<T> for Rgb<Linear<S>, T>
+where
+    S: RgbSpace,
+    T: FloatComponent,
+{
+}
+
+impl<Wp, T> Exposure<T> for Luma<Linear<Wp>, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+}
+
+impl<Wp, T> Exposure<T> for Xyz<Wp, T>
+where
+    Wp: WhitePoint,
+    T: FloatComponent,
+{
+}
+
+/// The linear white point of the Hable curve.
+const HABLE_WHITE: f64 = 11.2;
+
+fn hable_curve<T: FloatComponent>(x: T) -> T {
+    let a: T = from_f64(0.15); // Shoulder strength
+    let b: T = from_f64(0.50); // Linear strength
+    let c: T = from_f64(0.10); // Linear angle
+    let d: T = from_f64(0.20); // Toe strength
+    let e: T = from_f64(0.02); // Toe numerator
+    let f: T = from_f64(0.30); // Toe denominator
+
+    ((x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f)) - e / f
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Exposure, ToneMap};
+    use crate::white_point::D65;
+    use crate::{LinSrgb, Xyz};
+
+    type LinLuma = crate::LinLuma<D65, f64>;
+
+    #[test]
+    fn zero_and_negative() {
+        let black = LinSrgb::new(0.0f64, -1.0, -0.0);
+
+        assert_eq!(black.reinhard(), LinSrgb::new(0.0, 0.0, 0.0));
+        assert_eq!(black.reinhard_extended(2.0), LinSrgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(black.hable(), LinSrgb::new(0.0, 0.0, 0.0));
+        assert_eq!(black.aces(), LinSrgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn monotonic_and_in_range() {
+        let operators: [fn(&LinLuma) -> LinLuma; 4] = [
+            LinLuma::reinhard,
+            |color| color.reinhard_extended(8.0),
+            LinLuma::hable,
+            LinLuma::aces,
+        ];
+
+        for operator in &operators {
+            let mut previous = 0.0;
+
+            for step in 1..1000 {
+                let mapped = operator(&LinLuma::new(step as f64 * 0.05)).luma;
+                assert!(mapped >= previous);
+                assert!(mapped <= 1.0);
+                previous = mapped;
+            }
+        }
+    }
+
+    #[test]
+    fn white_points() {
+        assert_eq!(LinLuma::new(8.0).reinhard_extended(8.0).luma, 1.0);
+        assert_eq!(LinLuma::new(20.0).reinhard_extended(8.0).luma, 1.0);
+        assert_relative_eq!(LinLuma::new(11.2).hable().luma, 1.0);
+        assert_eq!(LinLuma::new(20.0).hable().luma, 1.0);
+        assert_eq!(LinLuma::new(20.0).aces().luma, 1.0);
+        assert!(LinLuma::new(1000.0).reinhard().luma < 1.0);
+    }
+
+    #[test]
+    fn per_component() {
+        let color = LinSrgb::new(0.5f64, 2.0, 6.0);
+        let mapped = color.aces();
+
+        assert_eq!(mapped.red, LinLuma::new(0.5).aces().luma);
+        assert_eq!(mapped.green, LinLuma::new(2.0).aces().luma);
+        assert_eq!(mapped.blue, LinLuma::new(6.0).aces().luma);
+    }
+
+    #[test]
+    fn exposure() {
+        let color = Xyz::new(0.2f64, 0.3, 0.1);
+
+        assert_eq!(color.expose(0.0), color);
+        assert_relative_eq!(color.expose(3.0), color * 8.0);
+        assert_relative_eq!(color.expose(1.5).expose(-1.5), color);
+        assert_eq!(LinLuma::new(4.0).expose(-2.0).luma, 1.0);
+    }
+
+    #[test]
+    fn normalize_to_peak() {
+        let color = Xyz::new(0.2f64, 0.3, 0.1);
+        assert_relative_eq!(color.normalize_to_peak(), color / 0.3);
+
+        let black = LinSrgb::new(0.0f64, -1.0, 0.0);
+        assert_eq!(black.normalize_to_peak(), black);
+    }
+}
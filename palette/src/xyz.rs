@@ -15,8 +15,8 @@ use crate::matrix::{multiply_rgb_to_xyz, rgb_to_xyz_matrix};
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, from_f64, Alpha, Component, ComponentWise, FloatComponent, Lab, Limited,
-    Luma, Mix, Pixel, RelativeContrast, Shade, Yxy,
+    clamp, from_f64, Alpha, Component, ComponentWise, ComponentWiseMap, FloatComponent,
+    InvalidComponents, Lab, Limited, Luma, Mix, Pixel, RelativeLuminance, Shade, Yxy,
 };
 
 /// CIE 1931 XYZ with an alpha component. See the [`Xyza` implementation in
@@ -123,6 +123,38 @@ where
         Self::with_wp(x, y, z)
     }
 
+    /// Create a color from components in the `0-100` scale, where the
+    /// luminance `Y` of the reference white is `100.0`.
+    ///
+    /// `Xyz` uses the `0-1` scale, where the reference white has `Y = 1.0`,
+    /// but measurement data and many other sources use the `0-100` scale.
+    /// Passing such values to [`with_wp`](Self::with_wp) would make the color
+    /// 100 times too bright.
+    ///
+    /// ```
+    /// use palette::white_point::D65;
+    /// use palette::Xyz;
+    ///
+    /// // The D65 white point, as it's often written in tables.
+    /// let white = Xyz::<D65>::from_percent(95.047, 100.0, 108.883);
+    /// assert_eq!(white.y, 1.0);
+    ///
+    /// let (x, y, z) = white.into_percent();
+    /// assert_eq!(y, 100.0);
+    /// ```
+    pub fn from_percent(x: T, y: T, z: T) -> Self {
+        let scale: T = from_f64(100.0);
+        Self::with_wp(x / scale, y / scale, z / scale)
+    }
+
+    /// Convert into a `(X, Y, Z)` tuple in the `0-100` scale, where the
+    /// luminance `Y` of the reference white is `100.0`. This is the inverse
+    /// of [`from_percent`](Self::from_percent).
+    pub fn into_percent(self) -> (T, T, T) {
+        let scale: T = from_f64(100.0);
+        (self.x * scale, self.y * scale, self.z * scale)
+    }
+
     /// Return the `x` value minimum.
     pub fn min_x() -> T {
         T::zero()
@@ -302,6 +334,30 @@ impl<Wp: WhitePoint, T: FloatComponent, A: Component> Into<(T, T, T, A)> for Alp
     }
 }
 
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 3]> for Xyz<Wp, T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Self::from_components((x, y, z))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Xyz<Wp, T>> for [T; 3] {
+    fn from(color: Xyz<Wp, T>) -> Self {
+        [color.x, color.y, color.z]
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 4]> for Alpha<Xyz<Wp, T>, T> {
+    fn from([x, y, z, alpha]: [T; 4]) -> Self {
+        Self::from_components((x, y, z, alpha))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Alpha<Xyz<Wp, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Xyz<Wp, T>, T>) -> Self {
+        [color.x, color.y, color.z, color.alpha]
+    }
+}
+
 impl<Wp, T> Limited for Xyz<Wp, T>
 where
     T: FloatComponent,
@@ -327,6 +383,15 @@ where
         self.y = clamp(self.y, T::zero(), xyz_ref.y);
         self.z = clamp(self.z, T::zero(), xyz_ref.z);
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        let xyz_ref: Self = Wp::get_xyz();
+
+        InvalidComponents::new()
+            .check("x", self.x >= T::zero() && self.x <= xyz_ref.x)
+            .check("y", self.y >= T::zero() && self.y <= xyz_ref.y)
+            .check("z", self.z >= T::zero() && self.z <= xyz_ref.z)
+    }
 }
 
 impl<Wp, T> Mix for Xyz<Wp, T>
@@ -363,6 +428,23 @@ where
             white_point: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Xyz<Wp, T> {
+        let difference = if factor >= T::zero() {
+            Wp::get_xyz::<Wp, T>().y - self.y
+        } else {
+            self.y
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Xyz {
+            x: self.x,
+            y: (self.y + delta).max(T::zero()),
+            z: self.z,
+            white_point: PhantomData,
+        }
+    }
 }
 
 impl<Wp, T> ComponentWise for Xyz<Wp, T>
@@ -391,6 +473,33 @@ where
     }
 }
 
+impl<Wp, T, U> ComponentWiseMap<U> for Xyz<Wp, T>
+where
+    T: FloatComponent,
+    U: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Xyz<Wp, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(&self, other: &Xyz<Wp, T>, mut f: F) -> Xyz<Wp, U> {
+        Xyz {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+            white_point: PhantomData,
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> Xyz<Wp, U> {
+        Xyz {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+            white_point: PhantomData,
+        }
+    }
+}
+
 impl<Wp, T> Default for Xyz<Wp, T>
 where
     T: FloatComponent,
@@ -655,16 +764,13 @@ where
     }
 }
 
-impl<Wp, T> RelativeContrast for Xyz<Wp, T>
+impl<Wp, T> RelativeLuminance for Xyz<Wp, T>
 where
     Wp: WhitePoint,
     T: FloatComponent,
 {
+    type WhitePoint = Wp;
     type Scalar = T;
-
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        contrast_ratio(self.y, other.y)
-    }
 }
 
 #[cfg(feature = "random")]
@@ -13,8 +13,8 @@ use crate::encoding::pixel::RawPixel;
 use crate::luma::LumaStandard;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
-    clamp, contrast_ratio, Alpha, Component, ComponentWise, FloatComponent, Limited, Luma, Mix,
-    Pixel, RelativeContrast, Shade, Xyz,
+    clamp, Alpha, Component, ComponentWise, ComponentWiseMap, FloatComponent, InvalidComponents,
+    Limited, Luma, Mix, Pixel, RelativeLuminance, Shade, Xyz,
 };
 
 /// CIE 1931 Yxy (xyY) with an alpha component. See the [`Yxya` implementation
@@ -214,6 +214,30 @@ impl<Wp: WhitePoint, T: FloatComponent, A: Component> Into<(T, T, T, A)> for Alp
     }
 }
 
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 3]> for Yxy<Wp, T> {
+    fn from([x, y, luma]: [T; 3]) -> Self {
+        Self::from_components((x, y, luma))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Yxy<Wp, T>> for [T; 3] {
+    fn from(color: Yxy<Wp, T>) -> Self {
+        [color.x, color.y, color.luma]
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<[T; 4]> for Alpha<Yxy<Wp, T>, T> {
+    fn from([x, y, luma, alpha]: [T; 4]) -> Self {
+        Self::from_components((x, y, luma, alpha))
+    }
+}
+
+impl<Wp: WhitePoint, T: FloatComponent> From<Alpha<Yxy<Wp, T>, T>> for [T; 4] {
+    fn from(color: Alpha<Yxy<Wp, T>, T>) -> Self {
+        [color.x, color.y, color.luma, color.alpha]
+    }
+}
+
 impl<Wp, T> FromColorUnclamped<Yxy<Wp, T>> for Yxy<Wp, T>
 where
     Wp: WhitePoint,
@@ -282,6 +306,13 @@ where
         self.y = clamp(self.y, T::zero(), T::one());
         self.luma = clamp(self.luma, T::zero(), T::one());
     }
+
+    fn invalid_components(&self) -> InvalidComponents {
+        InvalidComponents::new()
+            .check("x", self.x >= T::zero() && self.x <= T::one())
+            .check("y", self.y >= T::zero() && self.y <= T::one())
+            .check("luma", self.luma >= T::zero() && self.luma <= T::one())
+    }
 }
 
 impl<Wp, T> Mix for Yxy<Wp, T>
@@ -318,6 +349,23 @@ where
             white_point: PhantomData,
         }
     }
+
+    fn lighten_relative(&self, factor: T) -> Yxy<Wp, T> {
+        let difference = if factor >= T::zero() {
+            T::one() - self.luma
+        } else {
+            self.luma
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Yxy {
+            x: self.x,
+            y: self.y,
+            luma: (self.luma + delta).max(T::zero()),
+            white_point: PhantomData,
+        }
+    }
 }
 
 impl<Wp, T> ComponentWise for Yxy<Wp, T>
@@ -346,6 +394,33 @@ where
     }
 }
 
+impl<Wp, T, U> ComponentWiseMap<U> for Yxy<Wp, T>
+where
+    T: FloatComponent,
+    U: FloatComponent,
+    Wp: WhitePoint,
+{
+    type Output = Yxy<Wp, U>;
+
+    fn component_wise_zip<F: FnMut(T, T) -> U>(&self, other: &Yxy<Wp, T>, mut f: F) -> Yxy<Wp, U> {
+        Yxy {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            luma: f(self.luma, other.luma),
+            white_point: PhantomData,
+        }
+    }
+
+    fn component_wise_map<F: FnMut(T) -> U>(&self, mut f: F) -> Yxy<Wp, U> {
+        Yxy {
+            x: f(self.x),
+            y: f(self.y),
+            luma: f(self.luma),
+            white_point: PhantomData,
+        }
+    }
+}
+
 impl<Wp, T> Default for Yxy<Wp, T>
 where
     T: FloatComponent,
@@ -617,16 +692,13 @@ where
     }
 }
 
-impl<Wp, T> RelativeContrast for Yxy<Wp, T>
+impl<Wp, T> RelativeLuminance for Yxy<Wp, T>
 where
     Wp: WhitePoint,
     T: FloatComponent,
 {
+    type WhitePoint = Wp;
     type Scalar = T;
-
-    fn get_contrast_ratio(&self, other: &Self) -> T {
-        contrast_ratio(self.luma, other.luma)
-    }
 }
 
 #[cfg(feature = "random")]
@@ -3,7 +3,8 @@ use std::collections::HashSet;
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_quote, DeriveInput, Generics, Ident, Result, Type};
+use syn::spanned::Spanned;
+use syn::{parse_quote, DeriveInput, GenericParam, Generics, Ident, Result, Type, WherePredicate};
 
 use crate::meta::{
     parse_field_attributes, parse_namespaced_attributes, FieldAttributes, IdentOrIndex,
@@ -30,14 +31,43 @@ pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn
 
     let mut item_meta: TypeItemAttributes = parse_namespaced_attributes(attrs)?;
 
-    let fields_meta: FieldAttributes = if let syn::Data::Struct(struct_data) = data {
-        parse_field_attributes(struct_data.fields)?
-    } else {
-        return Err(vec![syn::Error::new(
-            Span::call_site(),
-            "only structs are supported",
-        )]);
-    };
+    let (fields_meta, field_names): (FieldAttributes, Vec<IdentOrIndex>) =
+        if let syn::Data::Struct(struct_data) = data {
+            let field_names = struct_data
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    field
+                        .ident
+                        .clone()
+                        .map(IdentOrIndex::Ident)
+                        .unwrap_or_else(|| IdentOrIndex::Index(index.into()))
+                })
+                .collect();
+
+            (parse_field_attributes(struct_data.fields)?, field_names)
+        } else {
+            return Err(vec![syn::Error::new(
+                Span::call_site(),
+                "only structs are supported",
+            )]);
+        };
+
+    // Structs with color fields are converted field by field, instead of
+    // being treated as colors themselves
+    if !fields_meta.color_fields.is_empty() {
+        let implementation = generate_color_fields_implementation(
+            &ident,
+            &original_generics,
+            &item_meta,
+            &field_names,
+            &fields_meta.color_fields,
+        )
+        .map_err(|error| vec![error])?;
+
+        return Ok(TokenStream::from(implementation));
+    }
 
     let (generic_component, generic_white_point) = find_in_generics(
         item_meta.component.as_ref(),
@@ -296,3 +326,129 @@ fn generate_from_alpha_implementation_with_internal(
         }
     }
 }
+
+fn generate_color_fields_implementation(
+    ident: &Ident,
+    generics: &Generics,
+    meta: &TypeItemAttributes,
+    field_names: &[IdentOrIndex],
+    color_fields: &[(IdentOrIndex, Type)],
+) -> Result<TokenStream2> {
+    let from_trait_path = util::path(["convert", "FromColorUnclamped"], meta.internal);
+
+    // Each type parameter of the color fields gets a matching parameter for
+    // the type that is converted from
+    let mut color_parameters: Vec<(Ident, Ident)> = Vec::new();
+    for (_, ty) in color_fields {
+        let parameter = match ty {
+            Type::Path(path) if path.qself.is_none() => path
+                .path
+                .get_ident()
+                .filter(|&name| generics.type_params().any(|param| param.ident == *name)),
+            _ => None,
+        };
+
+        let parameter = parameter.ok_or_else(|| {
+            syn::parse::Error::new(
+                ty.span(),
+                "expected the type of a color field to be a type parameter, like `C`",
+            )
+        })?;
+
+        if !color_parameters.iter().any(|(name, _)| name == parameter) {
+            let from_parameter = Ident::new(&format!("_From{}", parameter), Span::call_site());
+            color_parameters.push((parameter.clone(), from_parameter));
+        }
+    }
+
+    let rename = |name: &Ident| {
+        color_parameters
+            .iter()
+            .find(|(parameter, _)| parameter == name)
+            .map(|(_, from_parameter)| from_parameter.clone())
+    };
+
+    // The type that is converted from, with the color parameters replaced
+    let mut from_generics = generics.clone();
+    for param in &mut from_generics.params {
+        if let GenericParam::Type(param) = param {
+            if let Some(from_parameter) = rename(&param.ident) {
+                param.ident = from_parameter;
+            }
+        }
+    }
+
+    let mut impl_generics = generics.clone();
+    for param in &from_generics.params {
+        if let GenericParam::Type(type_param) = param {
+            if color_parameters
+                .iter()
+                .any(|(_, from_parameter)| *from_parameter == type_param.ident)
+            {
+                let mut type_param = type_param.clone();
+                type_param.default = None;
+                impl_generics.params.push(GenericParam::Type(type_param));
+            }
+        }
+    }
+
+    {
+        let original_predicates: Vec<WherePredicate> = generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter().cloned())
+            .collect();
+        let where_clause = impl_generics.make_where_clause();
+
+        // Copy the bounds on the color parameters, such as `C: Copy`
+        for predicate in original_predicates {
+            if let WherePredicate::Type(mut predicate) = predicate {
+                let from_parameter = match &predicate.bounded_ty {
+                    Type::Path(path) if path.qself.is_none() => {
+                        path.path.get_ident().and_then(&rename)
+                    }
+                    _ => None,
+                };
+
+                if let Some(from_parameter) = from_parameter {
+                    predicate.bounded_ty = parse_quote!(#from_parameter);
+                    where_clause
+                        .predicates
+                        .push(WherePredicate::Type(predicate));
+                }
+            }
+        }
+
+        for (parameter, from_parameter) in &color_parameters {
+            where_clause
+                .predicates
+                .push(parse_quote!(#parameter: #from_trait_path<#from_parameter>));
+        }
+    }
+
+    let (_, type_generics, _) = generics.split_for_impl();
+    let (_, from_type_generics, _) = from_generics.split_for_impl();
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+
+    let fields = field_names.iter().map(|name| {
+        if color_fields
+            .iter()
+            .any(|(color_field, _)| color_field == name)
+        {
+            quote!(#name: #from_trait_path::from_color_unclamped(color.#name))
+        } else {
+            quote!(#name: color.#name)
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #from_trait_path<#ident #from_type_generics> for #ident #type_generics #where_clause {
+            fn from_color_unclamped(color: #ident #from_type_generics) -> Self {
+                #ident {
+                    #(#fields,)*
+                }
+            }
+        }
+    })
+}
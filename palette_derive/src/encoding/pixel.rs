@@ -26,6 +26,13 @@ pub fn derive(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::
         Data::Struct(struct_item) => {
             let fields_meta: FieldAttributes =
                 meta::parse_field_attributes(struct_item.fields.clone())?;
+            if !fields_meta.color_fields.is_empty() {
+                return Err(vec![syn::Error::new(
+                    Span::call_site(),
+                    "`Pixel` cannot be derived for structs with color fields, because of their other fields",
+                )]);
+            }
+
             let all_fields = match struct_item.fields {
                 Fields::Named(fields) => fields.named,
                 Fields::Unnamed(fields) => fields.unnamed,
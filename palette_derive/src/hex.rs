@@ -0,0 +1,54 @@
+use proc_macro::TokenStream;
+
+use quote::quote;
+use syn::LitStr;
+
+use crate::util;
+
+pub fn hex(tokens: TokenStream) -> ::std::result::Result<TokenStream, ::syn::parse::Error> {
+    let literal: LitStr = syn::parse(tokens)?;
+    let value = literal.value();
+    let digits = value.strip_prefix('#').unwrap_or(&value);
+
+    let components = parse_components(digits).ok_or_else(|| {
+        syn::Error::new(
+            literal.span(),
+            "expected a hex color code with 3, 4, 6 or 8 digits, such as \"#1e90ff\"",
+        )
+    })?;
+
+    let implementation = match *components.as_slice() {
+        [red, green, blue] => {
+            let srgb = util::path(["Srgb"], false);
+            quote!(#srgb::<u8>::new(#red, #green, #blue))
+        }
+        [red, green, blue, alpha] => {
+            let srgba = util::path(["Srgba"], false);
+            quote!(#srgba::<u8>::new(#red, #green, #blue, #alpha))
+        }
+        _ => unreachable!(),
+    };
+
+    Ok(implementation.into())
+}
+
+fn parse_components(digits: &str) -> Option<Vec<u8>> {
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let parse = |digits: &str| u8::from_str_radix(digits, 16).ok();
+
+    match digits.len() {
+        // Shorthand, such as "#f80", where each digit is repeated.
+        3 | 4 => digits
+            .char_indices()
+            .map(|(index, _)| parse(&digits[index..index + 1]).map(|value| value * 17))
+            .collect(),
+        6 | 8 => (0..digits.len())
+            .step_by(2)
+            .map(|index| parse(&digits[index..index + 2]))
+            .collect(),
+        _ => None,
+    }
+}
@@ -34,6 +34,7 @@ macro_rules! syn_try {
 mod alpha;
 mod convert;
 mod encoding;
+mod hex;
 mod meta;
 mod util;
 
@@ -66,3 +67,10 @@ pub fn derive_from_color_unclamped(tokens: TokenStream) -> TokenStream {
 pub fn derive_pixel(tokens: TokenStream) -> TokenStream {
     syn_try!(encoding::derive_pixel(tokens))
 }
+
+/// Create an sRGB color from a hex color code, such as `hex!("#1e90ff")`,
+/// that is checked at compile time. It's re-exported as `palette::hex`.
+#[proc_macro]
+pub fn hex(tokens: TokenStream) -> TokenStream {
+    syn_try!(hex::hex(tokens))
+}
@@ -10,6 +10,7 @@ pub struct FieldAttributes {
     pub alpha_property: Option<(IdentOrIndex, Type)>,
     pub zero_size_fields: HashSet<IdentOrIndex>,
     pub type_substitutes: HashMap<IdentOrIndex, Type>,
+    pub color_fields: Vec<(IdentOrIndex, Type)>,
 }
 
 impl FieldAttributeArgumentParser for FieldAttributes {
@@ -21,6 +22,10 @@ impl FieldAttributeArgumentParser for FieldAttributes {
                 assert_path_meta(&argument)?;
                 self.alpha_property = Some((field_name.clone(), ty.clone()));
             }
+            Some("color") => {
+                assert_path_meta(&argument)?;
+                self.color_fields.push((field_name.clone(), ty.clone()));
+            }
             Some("unsafe_same_layout_as") => {
                 let substitute = if let Meta::NameValue(MetaNameValue {
                     lit: Lit::Str(string),